@@ -0,0 +1,340 @@
+// Charset of a text body, and transcoding to and from UTF-8
+
+use bytes::BytesMut;
+pub use encoding_rs::Encoding;
+use encoding_rs::{UTF_8, UTF_16BE, UTF_16LE};
+use mime_plz::MediaType;
+use mime_plz::default_charset;
+use thiserror::Error;
+
+mod prescan;
+pub use prescan::PRESCAN_LEN;
+use prescan::{meta_charset, xml_encoding};
+
+#[derive(Clone, Error, Eq, Debug, PartialEq)]
+pub enum CharsetError {
+    #[error("unmappable character| {0}")]
+    Unmappable(&'static str),
+}
+
+// where the charset was found
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CharsetSource {
+    Bom,
+    ContentType,
+    Meta,
+    XmlDeclaration,
+    Default,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Charset {
+    pub encoding: &'static Encoding,
+    pub source: CharsetSource,
+}
+
+impl Charset {
+    fn new(encoding: &'static Encoding, source: CharsetSource) -> Self {
+        Charset {
+            encoding,
+            source,
+        }
+    }
+}
+
+/* Steps:
+ *      1. BOM, it overrides any declaration.
+ *      2. charset parameter of the Content-Type, when a known label.
+ *      3. <meta> of html, or XML declaration of xml. Both when the type is
+ *         unknown. A UTF-16 label is read as UTF-8, as the declaration was
+ *         readable as ASCII.
+ *      4. default charset of the media type, or UTF-8.
+ *
+ * Labels are resolved as in the WHATWG Encoding standard, so ISO-8859-1 and
+ * US-ASCII are windows-1252.
+ */
+pub fn detect_charset(media_type: Option<&MediaType>, body: &[u8]) -> Charset {
+    if let Some((encoding, _)) = Encoding::for_bom(body) {
+        return Charset::new(encoding, CharsetSource::Bom);
+    }
+
+    if let Some(encoding) = media_type
+        .and_then(MediaType::charset)
+        .and_then(|label| Encoding::for_label(label.as_bytes()))
+    {
+        return Charset::new(encoding, CharsetSource::ContentType);
+    }
+
+    let (html, xml) = match media_type {
+        Some(media_type) => (is_html(media_type), is_xml(media_type)),
+        None => (true, true),
+    };
+    let declared = xml
+        .then(|| xml_encoding(body))
+        .flatten()
+        .map(|label| (label, CharsetSource::XmlDeclaration))
+        .or_else(|| {
+            html.then(|| meta_charset(body))
+                .flatten()
+                .map(|label| (label, CharsetSource::Meta))
+        });
+    if let Some((label, source)) = declared
+        && let Some(encoding) = Encoding::for_label(label)
+    {
+        let encoding = if encoding == UTF_16LE || encoding == UTF_16BE {
+            UTF_8
+        } else {
+            encoding
+        };
+        return Charset::new(encoding, source);
+    }
+
+    let encoding = media_type
+        .and_then(default_charset)
+        .and_then(|label| Encoding::for_label(label.as_bytes()))
+        .unwrap_or(UTF_8);
+    Charset::new(encoding, CharsetSource::Default)
+}
+
+fn is_html(media_type: &MediaType) -> bool {
+    media_type.essence() == "text/html"
+}
+
+fn is_xml(media_type: &MediaType) -> bool {
+    matches!(media_type.essence().as_str(), "text/xml" | "application/xml")
+        || media_type
+            .suffix()
+            .is_some_and(|suffix| suffix.eq_ignore_ascii_case("xml"))
+}
+
+/* Body decoded to UTF-8. bom is whether the body started with the BOM of
+ * the charset, it is written back by encode. Malformed sequences are
+ * replaced by U+FFFD and set had_errors, encoding such a text does not give
+ * back the original body.
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DecodedBody {
+    pub text: String,
+    pub charset: Charset,
+    pub bom: bool,
+    pub had_errors: bool,
+}
+
+pub fn decode(body: &[u8], charset: Charset) -> DecodedBody {
+    let (bom, body) = match Encoding::for_bom(body) {
+        Some((encoding, len)) if encoding == charset.encoding => {
+            (true, &body[len..])
+        }
+        _ => (false, body),
+    };
+    let (text, had_errors) =
+        charset.encoding.decode_without_bom_handling(body);
+    DecodedBody {
+        text: text.into_owned(),
+        charset,
+        bom,
+        had_errors,
+    }
+}
+
+// decode with the detected charset
+pub fn decode_body(
+    media_type: Option<&MediaType>,
+    body: &[u8],
+) -> DecodedBody {
+    decode(body, detect_charset(media_type, body))
+}
+
+impl DecodedBody {
+    /* text in the original charset. encoding_rs only encodes UTF-16 to
+     * UTF-8, so it is done here.
+     *
+     * Error:
+     *      CharsetError::Unmappable     when a character of the text is not
+     *                                   in the charset.
+     */
+    pub fn encode(&self) -> Result<BytesMut, CharsetError> {
+        let encoding = self.charset.encoding;
+        let mut buf = BytesMut::with_capacity(self.text.len() + 3);
+        if self.bom {
+            buf.extend_from_slice(bom(encoding));
+        }
+        if encoding == UTF_16LE || encoding == UTF_16BE {
+            for unit in self.text.encode_utf16() {
+                if encoding == UTF_16LE {
+                    buf.extend_from_slice(&unit.to_le_bytes());
+                } else {
+                    buf.extend_from_slice(&unit.to_be_bytes());
+                }
+            }
+            return Ok(buf);
+        }
+        let (encoded, _, unmappable) = encoding.encode(&self.text);
+        if unmappable {
+            return Err(CharsetError::Unmappable(encoding.name()));
+        }
+        buf.extend_from_slice(&encoded);
+        Ok(buf)
+    }
+}
+
+fn bom(encoding: &'static Encoding) -> &'static [u8] {
+    if encoding == UTF_16LE {
+        b"\xFF\xFE"
+    } else if encoding == UTF_16BE {
+        b"\xFE\xFF"
+    } else if encoding == UTF_8 {
+        b"\xEF\xBB\xBF"
+    } else {
+        b""
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use encoding_rs::{GBK, SHIFT_JIS, WINDOWS_1252};
+
+    use super::*;
+
+    fn media_type(value: &str) -> MediaType {
+        MediaType::parse(value).unwrap()
+    }
+
+    #[test]
+    fn test_detect_charset_content_type() {
+        let ct = media_type("text/plain; charset=\"Shift_JIS\"");
+        let charset = detect_charset(Some(&ct), b"\x82\xA0");
+        assert_eq!(
+            charset,
+            Charset::new(SHIFT_JIS, CharsetSource::ContentType)
+        );
+        // whatwg label
+        let ct = media_type("text/plain; charset=iso-8859-1");
+        assert_eq!(detect_charset(Some(&ct), b"").encoding, WINDOWS_1252);
+        // unknown label
+        let ct = media_type("text/plain; charset=nope");
+        let charset = detect_charset(Some(&ct), b"");
+        assert_eq!(charset, Charset::new(UTF_8, CharsetSource::Default));
+    }
+
+    #[test]
+    fn test_detect_charset_bom_overrides() {
+        let ct = media_type("text/plain; charset=windows-1252");
+        let charset = detect_charset(Some(&ct), b"\xFF\xFEa\x00");
+        assert_eq!(charset, Charset::new(UTF_16LE, CharsetSource::Bom));
+        let charset = detect_charset(None, b"\xEF\xBB\xBF<meta charset=gbk>");
+        assert_eq!(charset, Charset::new(UTF_8, CharsetSource::Bom));
+    }
+
+    #[test]
+    fn test_detect_charset_meta() {
+        let body = b"<html><head><meta charset=\"GBK\"></head>";
+        let ct = media_type("text/html");
+        let charset = detect_charset(Some(&ct), body);
+        assert_eq!(charset, Charset::new(GBK, CharsetSource::Meta));
+        assert_eq!(detect_charset(None, body), charset);
+        // not html
+        let ct = media_type("text/plain");
+        let charset = detect_charset(Some(&ct), body);
+        assert_eq!(charset, Charset::new(UTF_8, CharsetSource::Default));
+        // utf-16 declared in ascii
+        let charset = detect_charset(None, b"<meta charset=utf-16>");
+        assert_eq!(charset, Charset::new(UTF_8, CharsetSource::Meta));
+    }
+
+    #[test]
+    fn test_detect_charset_xml_declaration() {
+        let body = b"<?xml version=\"1.0\" encoding=\"Shift_JIS\"?><a/>";
+        let expected = Charset::new(SHIFT_JIS, CharsetSource::XmlDeclaration);
+        for ct in ["application/xml", "image/svg+xml", "text/xml"] {
+            assert_eq!(detect_charset(Some(&media_type(ct)), body), expected);
+        }
+        assert_eq!(detect_charset(None, body), expected);
+        let ct = media_type("text/html");
+        assert_eq!(detect_charset(Some(&ct), body).encoding, UTF_8);
+    }
+
+    #[test]
+    fn test_detect_charset_default() {
+        let charset = detect_charset(Some(&media_type("text/css")), b"a{}");
+        assert_eq!(charset, Charset::new(UTF_8, CharsetSource::Default));
+        let charset = detect_charset(None, b"plain");
+        assert_eq!(charset, Charset::new(UTF_8, CharsetSource::Default));
+    }
+
+    #[test]
+    fn test_decode_windows_1252() {
+        let ct = media_type("text/plain; charset=windows-1252");
+        let decoded = decode_body(Some(&ct), b"caf\xE9 \x80");
+        assert_eq!(decoded.text, "café €");
+        assert!(!decoded.bom);
+        assert!(!decoded.had_errors);
+        assert_eq!(decoded.encode().unwrap(), &b"caf\xE9 \x80"[..]);
+    }
+
+    #[test]
+    fn test_decode_shift_jis() {
+        let body = b"<meta charset=shift_jis>\x82\xB1\x82\xF1\x82\xC9\x82\xBF\x82\xCD";
+        let mut decoded = decode_body(Some(&media_type("text/html")), body);
+        assert_eq!(decoded.text, "<meta charset=shift_jis>こんにちは");
+        decoded.text = decoded.text.replace("こんにちは", "こんばんは");
+        assert_eq!(
+            decoded.encode().unwrap(),
+            &b"<meta charset=shift_jis>\x82\xB1\x82\xF1\x82\xCE\x82\xF1\x82\xCD"[..]
+        );
+    }
+
+    #[test]
+    fn test_decode_gbk() {
+        let ct = media_type("text/plain; charset=gbk");
+        let decoded = decode_body(Some(&ct), b"\xC4\xE3\xBA\xC3");
+        assert_eq!(decoded.text, "你好");
+        assert_eq!(decoded.encode().unwrap(), &b"\xC4\xE3\xBA\xC3"[..]);
+    }
+
+    #[test]
+    fn test_decode_utf16() {
+        let body = b"\xFF\xFEh\x00i\x00";
+        let mut decoded = decode_body(None, body);
+        assert_eq!(decoded.text, "hi");
+        assert!(decoded.bom);
+        assert_eq!(decoded.encode().unwrap(), &body[..]);
+        decoded.text.push('€');
+        assert_eq!(
+            decoded.encode().unwrap(),
+            &b"\xFF\xFEh\x00i\x00\xAC\x20"[..]
+        );
+
+        let ct = media_type("text/plain; charset=utf-16be");
+        let decoded = decode_body(Some(&ct), b"\x00h\x00i");
+        assert_eq!(decoded.text, "hi");
+        assert!(!decoded.bom);
+        assert_eq!(decoded.encode().unwrap(), &b"\x00h\x00i"[..]);
+    }
+
+    #[test]
+    fn test_decode_utf8_bom() {
+        let decoded = decode_body(None, b"\xEF\xBB\xBFhi");
+        assert_eq!(decoded.text, "hi");
+        assert!(decoded.bom);
+        assert_eq!(decoded.encode().unwrap(), &b"\xEF\xBB\xBFhi"[..]);
+    }
+
+    #[test]
+    fn test_decode_malformed() {
+        let decoded = decode_body(None, b"a\xFFb");
+        assert_eq!(decoded.text, "a\u{FFFD}b");
+        assert!(decoded.had_errors);
+    }
+
+    #[test]
+    fn test_encode_unmappable() {
+        let ct = media_type("text/plain; charset=windows-1252");
+        let mut decoded = decode_body(Some(&ct), b"caf\xE9");
+        decoded.text.push('你');
+        assert_eq!(
+            decoded.encode(),
+            Err(CharsetError::Unmappable("windows-1252"))
+        );
+    }
+}
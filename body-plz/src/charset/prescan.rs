@@ -0,0 +1,216 @@
+// Charset declared in the body
+// https://html.spec.whatwg.org/multipage/parsing.html#prescan-a-byte-stream-to-determine-its-encoding
+
+// bytes of the body searched for a declaration
+pub const PRESCAN_LEN: usize = 1024;
+
+/* Label of the first <meta charset="..."> or
+ * <meta http-equiv="content-type" content="...; charset=...">. Comments are
+ * skipped.
+ */
+pub(crate) fn meta_charset(body: &[u8]) -> Option<&[u8]> {
+    let body = &body[..body.len().min(PRESCAN_LEN)];
+    let mut pos = 0;
+    while pos < body.len() {
+        let rest = &body[pos..];
+        if rest.starts_with(b"<!--") {
+            pos += find(&rest[4..], b"-->").map_or(rest.len(), |end| end + 7);
+            continue;
+        }
+        if starts_with_meta(rest) {
+            let end =
+                rest.iter().position(|&b| b == b'>').unwrap_or(rest.len());
+            if let Some(label) = meta_label(&rest[5..end]) {
+                return Some(label);
+            }
+            pos += end;
+            continue;
+        }
+        pos += 1;
+    }
+    None
+}
+
+// "<meta" followed by whitespace or "/"
+fn starts_with_meta(input: &[u8]) -> bool {
+    input.len() > 5
+        && input[..5].eq_ignore_ascii_case(b"<meta")
+        && (input[5].is_ascii_whitespace() || input[5] == b'/')
+}
+
+/* charset attribute, or the charset of the content attribute when
+ * http-equiv is content-type. The first of repeated attributes is used.
+ */
+fn meta_label(attrs: &[u8]) -> Option<&[u8]> {
+    let mut charset = None;
+    let mut content = None;
+    let mut got_pragma = false;
+    for (name, value) in Attributes(attrs) {
+        if name.eq_ignore_ascii_case(b"http-equiv") {
+            got_pragma |= value.eq_ignore_ascii_case(b"content-type");
+        } else if name.eq_ignore_ascii_case(b"charset") {
+            charset.get_or_insert(value);
+        } else if name.eq_ignore_ascii_case(b"content") {
+            content.get_or_insert(value);
+        }
+    }
+    if let Some(charset) = charset {
+        return Some(charset);
+    }
+    content.filter(|_| got_pragma).and_then(content_charset)
+}
+
+// charset=value in the content of a pragma, value may be quoted
+fn content_charset(content: &[u8]) -> Option<&[u8]> {
+    let mut pos = 0;
+    loop {
+        let found = find_ignore_case(&content[pos..], b"charset")?;
+        pos += found + b"charset".len();
+        let rest = trim_start(&content[pos..]);
+        let Some(rest) = rest.strip_prefix(b"=") else {
+            continue;
+        };
+        let rest = trim_start(rest);
+        return match rest.first()? {
+            quote @ (b'"' | b'\'') => {
+                let rest = &rest[1..];
+                let end = rest.iter().position(|b| b == quote)?;
+                Some(&rest[..end])
+            }
+            _ => {
+                let end = rest
+                    .iter()
+                    .position(|&b| b.is_ascii_whitespace() || b == b';')
+                    .unwrap_or(rest.len());
+                Some(&rest[..end])
+            }
+        };
+    }
+}
+
+/* encoding of the XML declaration at the start of the body, ie.
+ * <?xml version="1.0" encoding="..."?>
+ */
+pub(crate) fn xml_encoding(body: &[u8]) -> Option<&[u8]> {
+    let body = &body[..body.len().min(PRESCAN_LEN)];
+    if !body.starts_with(b"<?xml") {
+        return None;
+    }
+    let decl = &body[..find(body, b"?>")?];
+    let pos = find(decl, b"encoding")? + b"encoding".len();
+    let rest = trim_start(&decl[pos..]).strip_prefix(b"=")?;
+    let rest = trim_start(rest);
+    let quote = *rest.first().filter(|q| matches!(q, b'"' | b'\''))?;
+    let rest = &rest[1..];
+    let end = rest.iter().position(|&b| b == quote)?;
+    Some(&rest[..end])
+}
+
+// name and unquoted value of each attribute, value is empty when absent
+struct Attributes<'a>(&'a [u8]);
+
+impl<'a> Iterator for Attributes<'a> {
+    type Item = (&'a [u8], &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let input = self.0;
+        let start = input
+            .iter()
+            .position(|&b| !b.is_ascii_whitespace() && b != b'/')?;
+        let input = &input[start..];
+        let name_end = input
+            .iter()
+            .position(|&b| b.is_ascii_whitespace() || b == b'=' || b == b'/')
+            .unwrap_or(input.len());
+        let name = &input[..name_end];
+        let rest = trim_start(&input[name_end..]);
+        let Some(rest) = rest.strip_prefix(b"=") else {
+            self.0 = rest;
+            return Some((name, &[]));
+        };
+        let rest = trim_start(rest);
+        let (value, rest) = match rest.first() {
+            Some(quote @ (b'"' | b'\'')) => {
+                let rest = &rest[1..];
+                let end =
+                    rest.iter().position(|b| b == quote).unwrap_or(rest.len());
+                (&rest[..end], rest.get(end + 1..).unwrap_or_default())
+            }
+            _ => {
+                let end = rest
+                    .iter()
+                    .position(|b| b.is_ascii_whitespace())
+                    .unwrap_or(rest.len());
+                (&rest[..end], &rest[end..])
+            }
+        };
+        self.0 = rest;
+        Some((name, value))
+    }
+}
+
+fn trim_start(input: &[u8]) -> &[u8] {
+    let start = input
+        .iter()
+        .position(|b| !b.is_ascii_whitespace())
+        .unwrap_or(input.len());
+    &input[start..]
+}
+
+fn find(input: &[u8], needle: &[u8]) -> Option<usize> {
+    input.windows(needle.len()).position(|window| window == needle)
+}
+
+fn find_ignore_case(input: &[u8], needle: &[u8]) -> Option<usize> {
+    input
+        .windows(needle.len())
+        .position(|window| window.eq_ignore_ascii_case(needle))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_meta_charset() {
+        let body = b"<!DOCTYPE html><html><head><META Charset='Shift_JIS'>";
+        assert_eq!(meta_charset(body), Some(&b"Shift_JIS"[..]));
+        let body =
+            b"<meta name=viewport content=\"width=1\"><meta charset=gbk>";
+        assert_eq!(meta_charset(body), Some(&b"gbk"[..]));
+    }
+
+    #[test]
+    fn test_meta_charset_pragma() {
+        let body = b"<meta http-equiv=\"Content-Type\" \
+                     content=\"text/html; charset=windows-1252\">";
+        assert_eq!(meta_charset(body), Some(&b"windows-1252"[..]));
+        let body = b"<meta content='text/html; charset=\"koi8-r\"' \
+                     http-equiv=content-type>";
+        assert_eq!(meta_charset(body), Some(&b"koi8-r"[..]));
+        // content without the pragma
+        let body = b"<meta content=\"text/html; charset=koi8-r\">";
+        assert_eq!(meta_charset(body), None);
+    }
+
+    #[test]
+    fn test_meta_charset_skipped() {
+        assert_eq!(meta_charset(b"<!-- <meta charset=gbk> -->"), None);
+        assert_eq!(meta_charset(b"<metadata charset=gbk>"), None);
+        let mut body = vec![b' '; PRESCAN_LEN];
+        body.extend_from_slice(b"<meta charset=gbk>");
+        assert_eq!(meta_charset(&body), None);
+    }
+
+    #[test]
+    fn test_xml_encoding() {
+        let body = b"<?xml version=\"1.0\" encoding='ISO-8859-1'?><a/>";
+        assert_eq!(xml_encoding(body), Some(&b"ISO-8859-1"[..]));
+        assert_eq!(xml_encoding(b"<?xml version=\"1.0\"?><a/>"), None);
+        assert_eq!(xml_encoding(b" <?xml encoding=\"gbk\"?>"), None);
+        assert_eq!(
+            xml_encoding(b"<?xml version=\"1.0\"?>encoding=\"gbk\""),
+            None
+        );
+    }
+}
@@ -1,2 +1,3 @@
+pub mod charset;
 pub mod reader;
 pub mod variants;
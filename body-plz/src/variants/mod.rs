@@ -1,6 +1,10 @@
 use bytes::BytesMut;
 use chunked::ChunkType;
+use mime_plz::MediaType;
+use mime_plz::sniff::sniff;
 use tracing::error;
+
+use crate::charset::{DecodedBody, decode_body};
 pub mod chunked;
 
 // Enum to represent Body
@@ -33,6 +37,33 @@ impl Body {
             _ => Vec::new(),
         }
     }
+
+    /* Computed media type of a raw body, ie. after decompression, with the
+     * supplied Content-Type. None for a chunked body.
+     */
+    pub fn sniff_media_type(
+        &self,
+        supplied: Option<&MediaType>,
+        no_sniff: bool,
+    ) -> Option<MediaType> {
+        match self {
+            Body::Raw(data) => Some(sniff(data, supplied, no_sniff)),
+            Body::Chunked(_) => None,
+        }
+    }
+
+    /* Raw body decoded to UTF-8 with the charset detected from the
+     * Content-Type or the body. None for a chunked body.
+     */
+    pub fn decode_text(
+        &self,
+        media_type: Option<&MediaType>,
+    ) -> Option<DecodedBody> {
+        match self {
+            Body::Raw(data) => Some(decode_body(media_type, data)),
+            Body::Chunked(_) => None,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -81,4 +112,30 @@ mod tests {
         let body = Body::Chunked(vec![ChunkType::Chunk(buf.clone())]);
         assert_eq!(body.into_chunks(), vec![ChunkType::Chunk(buf.clone())]);
     }
+
+    #[test]
+    fn test_variants_body_sniff_media_type_raw() {
+        let body = Body::Raw(BytesMut::from("<!DOCTYPE html><p>hi</p>"));
+        let sniffed = body.sniff_media_type(None, false).unwrap();
+        assert_eq!(sniffed.essence(), "text/html");
+        let supplied = MediaType::parse("text/plain").unwrap();
+        let sniffed = body.sniff_media_type(Some(&supplied), false).unwrap();
+        assert_eq!(sniffed, supplied);
+    }
+
+    #[test]
+    fn test_variants_body_sniff_media_type_chunk() {
+        let body = Body::Chunked(Vec::new());
+        assert_eq!(body.sniff_media_type(None, false), None);
+    }
+
+    #[test]
+    fn test_variants_body_decode_text() {
+        let body = Body::Raw(BytesMut::from(&b"caf\xE9"[..]));
+        let ct = MediaType::parse("text/plain; charset=latin1").unwrap();
+        let decoded = body.decode_text(Some(&ct)).unwrap();
+        assert_eq!(decoded.text, "café");
+        assert_eq!(decoded.encode().unwrap(), &b"caf\xE9"[..]);
+        assert_eq!(Body::Chunked(Vec::new()).decode_text(None), None);
+    }
 }
@@ -4,8 +4,13 @@
  * Steps:
  *      1. Combine ChunkType::Chunk into one body.
  *      2. If trailer is present,
- *          a. remove trailer header
- *          b. add trailer to header_map.
+ *          a. validate the fields against the Trailer header
+ *          b. apply the TrailerPolicy
+ *              Merge    - remove trailer header and add the allowed fields
+ *                         to header_map
+ *              MergeAll - same as Merge, prohibited fields are added too
+ *              Separate - set the trailers on the message
+ *              Drop     - ignore
  */
 
 use body_plz::variants::{
@@ -13,9 +18,66 @@ use body_plz::variants::{
     chunked::{ChunkType, total_chunk_size},
 };
 use bytes::BytesMut;
-use header_plz::{Header, OneHeader};
+use header_plz::{
+    Header, OneHeader, OneHeaderMap,
+    const_headers::*,
+    message_head::header_map::{HeaderStr, Hmap},
+};
+
+use crate::{
+    DecompressTrait, decode_options::TrailerPolicy,
+    decode_struct::DecodeStruct, decompression::diagnostic::Diagnostic,
+};
 
-use crate::{DecompressTrait, decode_struct::DecodeStruct};
+// RFC 9110 6.5.1 - fields needed before the content is processed
+const PROHIBITED_TRAILERS: [&[u8]; 33] = [
+    // framing
+    CONTENT_LENGTH,
+    TRANSFER_ENCODING,
+    TRAILER,
+    // routing
+    HOST,
+    // connection
+    CONNECTION,
+    UPGRADE,
+    TE,
+    // request modifiers
+    CACHE_CONTROL,
+    EXPECT,
+    MAX_FORWARDS,
+    PRAGMA,
+    RANGE,
+    IF_MATCH,
+    IF_NONE_MATCH,
+    IF_MODIFIED_SINCE,
+    IF_UNMODIFIED_SINCE,
+    IF_RANGE,
+    // authentication
+    AUTHORIZATION,
+    PROXY_AUTHORIZATION,
+    WWW_AUTHENTICATE,
+    PROXY_AUTHENTICATE,
+    COOKIE,
+    SET_COOKIE,
+    // response control
+    AGE,
+    DATE,
+    EXPIRES,
+    LOCATION,
+    RETRY_AFTER,
+    VARY,
+    WARNING,
+    // content processing
+    CONTENT_ENCODING,
+    CONTENT_TYPE,
+    CONTENT_RANGE,
+];
+
+pub fn is_prohibited_trailer(name: &[u8]) -> bool {
+    PROHIBITED_TRAILERS
+        .iter()
+        .any(|prohibited| prohibited.eq_ignore_ascii_case(name))
+}
 
 pub trait ChunkedConverter<T> {
     fn convert_chunked(&mut self);
@@ -26,13 +88,19 @@ where
     T: DecompressTrait<HmapType = OneHeader> + std::fmt::Debug,
 {
     fn convert_chunked(&mut self) {
-        chunked_to_raw(self.message, self.buf);
+        convert_chunked_body(
+            self.message,
+            self.buf,
+            self.options.trailers,
+            &mut self.report.diagnostics,
+        );
         if let Some(body) = self.message.take_body() {
             self.body = body.into_bytes().expect("chunked to raw| no body");
         }
     }
 }
 
+// h2 has no chunked transfer coding, see data_frames
 impl<'a, T> ChunkedConverter<Header> for DecodeStruct<'a, T>
 where
     T: DecompressTrait<HmapType = Header> + std::fmt::Debug,
@@ -44,6 +112,17 @@ where
 pub fn chunked_to_raw<T>(message: &mut T, buf: &mut BytesMut)
 where
     T: DecompressTrait<HmapType = OneHeader>,
+{
+    convert_chunked_body(message, buf, TrailerPolicy::Merge, &mut Vec::new())
+}
+
+pub fn convert_chunked_body<T>(
+    message: &mut T,
+    buf: &mut BytesMut,
+    policy: TrailerPolicy,
+    diagnostics: &mut Vec<Diagnostic>,
+) where
+    T: DecompressTrait<HmapType = OneHeader>,
 {
     let body = if let Some(body) = message.take_body() {
         body.into_chunks()
@@ -58,11 +137,25 @@ where
                 buf.extend_from_slice(&data[..data.len() - 2])
             }
             // 2. If trailer is present,
-            ChunkType::Trailers(trailer) => {
-                // 2.a. Remove trailer header
-                message.remove_header_on_key("trailer");
-                // 2.b. Add trailer to header_map
-                message.extend(trailer);
+            ChunkType::Trailers(trailers) => {
+                // 2.a. validate
+                validate_trailers(message, &trailers, diagnostics);
+                // 2.b. apply policy
+                match policy {
+                    TrailerPolicy::Merge => {
+                        message.remove_header_on_key("trailer");
+                        let allowed = trailers.into_iter().filter(|field| {
+                            !is_prohibited_trailer(field.key_as_ref())
+                        });
+                        message.extend(allowed);
+                    }
+                    TrailerPolicy::MergeAll => {
+                        message.remove_header_on_key("trailer");
+                        message.extend(trailers);
+                    }
+                    TrailerPolicy::Separate => message.set_trailers(trailers),
+                    TrailerPolicy::Drop => (),
+                }
             }
             _ => (),
         }
@@ -70,6 +163,51 @@ where
     message.set_body(Body::Raw(buf.split()));
 }
 
+// Field names listed in every Trailer header
+fn declared_trailers<T>(message: &T) -> Vec<&str>
+where
+    T: DecompressTrait<HmapType = OneHeader>,
+{
+    message
+        .header_map()
+        .into_iter()
+        .filter(|header| header.key_as_ref().eq_ignore_ascii_case(TRAILER))
+        .filter_map(|header| header.value_as_str())
+        .flat_map(|value| value.split(','))
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+fn validate_trailers<T>(
+    message: &T,
+    trailers: &OneHeaderMap,
+    diagnostics: &mut Vec<Diagnostic>,
+) where
+    T: DecompressTrait<HmapType = OneHeader>,
+{
+    let declared = declared_trailers(message);
+    for field in trailers {
+        let key = field.key_as_ref();
+        if key.is_empty() {
+            continue;
+        }
+        let name = String::from_utf8_lossy(key).into_owned();
+        if is_prohibited_trailer(key) {
+            diagnostics.push(Diagnostic::ProhibitedTrailer {
+                name,
+            });
+        } else if !declared
+            .iter()
+            .any(|declared| declared.as_bytes().eq_ignore_ascii_case(key))
+        {
+            diagnostics.push(Diagnostic::UndeclaredTrailer {
+                name,
+            });
+        }
+    }
+}
+
 // Partial chunked body
 pub fn partial_chunked_to_raw(vec_body: Vec<ChunkType>) -> Option<BytesMut> {
     let mut iter = vec_body.into_iter().map(|c| c.into_bytes());
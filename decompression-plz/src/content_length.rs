@@ -1,5 +1,6 @@
 use body_plz::variants::Body;
 use bytes::BytesMut;
+use header_plz::{message_head::header_map::HeaderVersion, version::Version};
 
 use crate::decompress_trait::DecompressTrait;
 
@@ -17,6 +18,9 @@ where
     message.set_body(Body::Raw(body));
 }
 
+/* h2 body is framed by DATA frames, content-length is optional and only
+ * checked against the frames. Updated when present, never added.
+ */
 pub fn update_content_length<T>(message: &mut T, len: usize)
 where
     T: DecompressTrait,
@@ -24,6 +28,7 @@ where
     let len_string = len.to_string();
     match message.has_header_key(CONTENT_LENGTH) {
         Some(pos) => message.update_header_value_on_position(pos, &len_string),
+        None if T::HmapType::VERSION == Version::H2 => (),
         None => message.insert_header(CONTENT_LENGTH, len_string.as_str()),
     }
 }
@@ -0,0 +1,25 @@
+/* Description:
+ *      Assemble a h2 body from the payloads of the DATA frames of a stream.
+ *
+ *      h2 has no chunked transfer coding, frames only split the body and
+ *      carry no framing bytes of their own, so the payloads are appended as
+ *      is. Padding is expected to be stripped by the frame reader.
+ */
+
+use body_plz::variants::Body;
+use bytes::BytesMut;
+use header_plz::Header;
+
+use crate::DecompressTrait;
+
+pub fn data_frames_to_raw<T, I>(message: &mut T, frames: I, buf: &mut BytesMut)
+where
+    T: DecompressTrait<HmapType = Header>,
+    I: IntoIterator,
+    I::Item: AsRef<[u8]>,
+{
+    for frame in frames {
+        buf.extend_from_slice(frame.as_ref());
+    }
+    message.set_body(Body::Raw(buf.split()));
+}
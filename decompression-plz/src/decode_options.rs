@@ -0,0 +1,136 @@
+use crate::decompression::options::CodecOptions;
+
+// Layers stripped by decompress, each variant includes the previous one
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum DecodeLayers {
+    // only convert a chunked body to raw
+    Chunked,
+    // every transfer coding
+    TransferEncoding,
+    // transfer and content codings
+    #[default]
+    All,
+}
+
+impl DecodeLayers {
+    pub fn transfer_encoding(&self) -> bool {
+        matches!(self, Self::TransferEncoding | Self::All)
+    }
+
+    pub fn content_encoding(&self) -> bool {
+        matches!(self, Self::All)
+    }
+}
+
+// What to do with the trailers of a chunked body
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum TrailerPolicy {
+    // add to the header map and remove the Trailer header, prohibited
+    // fields are dropped, see chunked::is_prohibited_trailer
+    #[default]
+    Merge,
+    // same as Merge, every field is added including the prohibited ones
+    MergeAll,
+    // keep as a separate header map, see DecompressTrait::set_trailers
+    Separate,
+    // drop the trailers, the Trailer header is kept
+    Drop,
+}
+
+/* Policy for decompress
+ *      layers                  - which codings are decoded
+ *      remove_encoding_headers - remove / rewrite the TE and CE headers of
+ *                                the decoded codings
+ *      update_content_length   - set Content-Length to the decoded size
+ *      trailers                - what to do with the chunked trailers
+ *      verify_digests          - check the integrity fields against the
+ *                                body, see DecodeReport::digests
+ *      update_digests          - recompute the integrity fields when the
+ *                                content codings were decoded
+ *      codec                   - settings passed down to the codecs
+ *
+ * Defaults to decoding everything and updating the headers.
+ */
+#[cfg_attr(test, derive(PartialEq))]
+#[derive(Clone, Copy, Debug)]
+pub struct DecodeOptions<'a> {
+    pub layers: DecodeLayers,
+    pub remove_encoding_headers: bool,
+    pub update_content_length: bool,
+    pub trailers: TrailerPolicy,
+    pub verify_digests: bool,
+    pub update_digests: bool,
+    pub codec: CodecOptions<'a>,
+}
+
+impl Default for DecodeOptions<'_> {
+    fn default() -> Self {
+        Self {
+            layers: DecodeLayers::default(),
+            remove_encoding_headers: true,
+            update_content_length: true,
+            trailers: TrailerPolicy::default(),
+            verify_digests: false,
+            update_digests: true,
+            codec: CodecOptions::default(),
+        }
+    }
+}
+
+impl<'a> DecodeOptions<'a> {
+    pub fn with_layers(mut self, layers: DecodeLayers) -> Self {
+        self.layers = layers;
+        self
+    }
+
+    pub fn with_remove_encoding_headers(mut self, remove: bool) -> Self {
+        self.remove_encoding_headers = remove;
+        self
+    }
+
+    pub fn with_update_content_length(mut self, update: bool) -> Self {
+        self.update_content_length = update;
+        self
+    }
+
+    pub fn with_trailers(mut self, trailers: TrailerPolicy) -> Self {
+        self.trailers = trailers;
+        self
+    }
+
+    pub fn with_verify_digests(mut self, verify: bool) -> Self {
+        self.verify_digests = verify;
+        self
+    }
+
+    pub fn with_update_digests(mut self, update: bool) -> Self {
+        self.update_digests = update;
+        self
+    }
+
+    pub fn with_codec(mut self, codec: CodecOptions<'a>) -> Self {
+        self.codec = codec;
+        self
+    }
+}
+
+impl<'a> From<CodecOptions<'a>> for DecodeOptions<'a> {
+    fn from(codec: CodecOptions<'a>) -> Self {
+        Self::default().with_codec(codec)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_layers() {
+        assert!(!DecodeLayers::Chunked.transfer_encoding());
+        assert!(!DecodeLayers::Chunked.content_encoding());
+        assert!(DecodeLayers::TransferEncoding.transfer_encoding());
+        assert!(!DecodeLayers::TransferEncoding.content_encoding());
+        assert!(DecodeLayers::All.transfer_encoding());
+        assert!(DecodeLayers::All.content_encoding());
+    }
+}
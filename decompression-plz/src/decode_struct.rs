@@ -1,10 +1,16 @@
+use body_plz::variants::Body;
 use bytes::BytesMut;
 use header_plz::body_headers::encoding_info::EncodingInfo;
 use header_plz::body_headers::transfer_types::TransferType;
+use header_plz::message_head::header_map::HeaderVersion;
+use header_plz::version::Version;
 
 use crate::chunked::ChunkedConverter;
-use crate::content_length::add_body_and_update_cl;
+use crate::content_length::update_content_length;
+use crate::decode_options::DecodeOptions;
 use crate::decompress_trait::DecompressTrait;
+use crate::digest::{update_digests, verify_digests_parts};
+use crate::report::DecodeReport;
 
 #[cfg_attr(test, derive(PartialEq))]
 #[derive(Debug)]
@@ -13,6 +19,10 @@ pub struct DecodeStruct<'a, T> {
     pub buf: &'a mut BytesMut,
     pub extra_body: Option<BytesMut>,
     pub message: &'a mut T,
+    pub options: DecodeOptions<'a>,
+    pub report: DecodeReport,
+    // content codings were decoded, integrity fields are stale
+    pub content_changed: bool,
 }
 
 impl<'a, T> DecodeStruct<'a, T>
@@ -31,11 +41,20 @@ where
             buf,
             extra_body,
             message,
+            options: DecodeOptions::default(),
+            report: DecodeReport::default(),
+            content_changed: false,
         }
     }
 
+    pub fn with_options(mut self, options: DecodeOptions<'a>) -> Self {
+        self.options = options;
+        self
+    }
+
     pub fn chunked_to_raw(&mut self) {
         self.convert_chunked();
+        self.report.chunked = true;
     }
 
     pub fn is_chunked_te(&self) -> bool {
@@ -45,11 +64,20 @@ where
             .unwrap_or(false)
     }
 
+    /* h2 has no transfer codings, the body is the payload of the DATA
+     * frames and ends with END_STREAM. Without content-length the body is
+     * read till the end of the stream, same as Close in h1.
+     */
     pub fn is_transfer_type_close(&self) -> bool {
-        self.message
-            .body_headers()
-            .map(|b| b.transfer_type == Some(TransferType::Close))
-            .unwrap_or(false)
+        let transfer_type =
+            self.message.body_headers().and_then(|b| b.transfer_type.as_ref());
+        if T::HmapType::VERSION == Version::H2 {
+            return !matches!(
+                transfer_type,
+                Some(TransferType::ContentLength(_))
+            );
+        }
+        transfer_type == Some(&TransferType::Close)
     }
 
     pub fn transfer_encoding_is_some(&self) -> bool {
@@ -78,6 +106,17 @@ where
             .and_then(|bh| bh.content_encoding.take())
     }
 
+    // content codings, None when the policy does not decode them
+    pub fn take_content_encoding_to_decode(
+        &mut self,
+    ) -> Option<Vec<EncodingInfo>> {
+        if self.options.layers.content_encoding() {
+            self.take_content_encoding()
+        } else {
+            None
+        }
+    }
+
     pub fn set_transfer_encoding(&mut self, te: Vec<EncodingInfo>) {
         if let Some(bh) = self.message.body_headers_as_mut() {
             bh.transfer_encoding = Some(te)
@@ -106,11 +145,30 @@ where
         self.extra_body.take()
     }
 
+    // called once the transfer codings are removed
+    pub fn verify_digests(&mut self) {
+        if !self.options.verify_digests {
+            return;
+        }
+        let extra = self.extra_body.as_deref().unwrap_or_default();
+        self.report.digests = verify_digests_parts(
+            self.message.header_map(),
+            &[&self.body, extra],
+        );
+    }
+
     pub fn add_body_and_update_cl(&mut self) {
         let mut body = self.take_main_body();
         if let Some(extra) = self.take_extra_body() {
             body.unsplit(extra);
         }
-        add_body_and_update_cl(self.message, body);
+        if self.options.update_digests && self.content_changed {
+            update_digests(self.message, &body);
+        }
+        // an empty decoded body still replaces the encoded one
+        if self.options.update_content_length {
+            update_content_length(self.message, body.len());
+        }
+        self.message.set_body(Body::Raw(body));
     }
 }
@@ -1,12 +1,16 @@
 use body_plz::variants::Body;
 use bytes::BytesMut;
 use header_plz::{
+    OneHeaderMap,
     body_headers::BodyHeader,
-    message_head::header_map::{HMap, Hmap},
+    message_head::header_map::{HMap, HeaderVersion, Hmap},
 };
 
 pub trait DecompressTrait {
-    type HmapType: Hmap + std::fmt::Debug + for<'a> From<(&'a str, &'a str)>;
+    type HmapType: Hmap
+        + HeaderVersion
+        + std::fmt::Debug
+        + for<'a> From<(&'a str, &'a str)>;
 
     // Body
     fn take_body(&mut self) -> Option<Body>;
@@ -15,6 +19,10 @@ pub trait DecompressTrait {
 
     fn set_body(&mut self, body: Body);
 
+    // chunked trailers kept apart from the header map, dropped unless
+    // implemented
+    fn set_trailers(&mut self, _trailers: OneHeaderMap) {}
+
     fn body_headers(&self) -> Option<&BodyHeader>;
 
     fn body_headers_as_mut(&mut self) -> Option<&mut BodyHeader>;
@@ -0,0 +1,55 @@
+use header_plz::body_headers::content_encoding::ContentEncoding;
+
+// Observations made while decoding that did not necessarily cause an error
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Diagnostic {
+    /* declared coding failed and the magic bytes point to another coding
+     *      corrected - true  => detected coding was applied instead
+     *                  false => detected coding failed as well
+     */
+    EncodingMismatch {
+        header_index: usize,
+        declared: ContentEncoding,
+        detected: ContentEncoding,
+        corrected: bool,
+    },
+    // output of the declared codings was compressed again
+    UndeclaredEncoding {
+        detected: ContentEncoding,
+    },
+    // deflate stream format, needed to reproduce the encoding
+    Deflate {
+        header_index: usize,
+        variant: DeflateVariant,
+    },
+    /* best effort - input ran out before the end of the stream, the output
+     * decoded so far was kept
+     *      input_offset - offset in the layer input at which it ran out
+     *      output_len   - number of bytes recovered
+     */
+    Truncated {
+        header_index: usize,
+        encoding: ContentEncoding,
+        input_offset: usize,
+        output_len: usize,
+    },
+    // chunked trailer field not listed in the Trailer header
+    UndeclaredTrailer {
+        name: String,
+    },
+    /* chunked trailer field that must not be sent in a trailer, eg. framing
+     * or routing fields. Dropped by TrailerPolicy::Merge, added to the
+     * header map only by TrailerPolicy::MergeAll.
+     */
+    ProhibitedTrailer {
+        name: String,
+    },
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DeflateVariant {
+    // RFC 1950 - zlib header + RFC 1951 stream + adler32
+    Zlib,
+    // RFC 1951 - bare stream, sent by some servers (eg. old IIS)
+    Raw,
+}
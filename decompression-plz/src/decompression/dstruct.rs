@@ -1,4 +1,4 @@
-use std::io::{Cursor, Read};
+use std::io::{BufReader, Cursor, Read};
 
 use bytes::{BytesMut, buf::Writer};
 use header_plz::body_headers::{
@@ -6,16 +6,27 @@ use header_plz::body_headers::{
 };
 
 use crate::decompression::{
-    magic_bytes::is_compressed,
+    diagnostic::{DeflateVariant, Diagnostic},
+    magic_bytes::{deflate_variant, is_compressed},
     multi::{decompress_multi, error::MultiDecompressError},
-    single::{decompress_single, error::DecompressError},
+    options::CodecOptions,
+    single::{
+        decompress_gzip_members, decompress_single, error::DecompressError,
+    },
 };
+use crate::report::{LayerReport, merge_layers};
 
 pub struct DecompressionStruct<'a> {
     pub main: &'a [u8],
     pub extra: Option<&'a [u8]>,
     pub encoding_info: &'a mut [EncodingInfo],
     pub writer: Writer<&'a mut BytesMut>,
+    pub options: CodecOptions<'a>,
+    pub diagnostics: &'a mut Vec<Diagnostic>,
+    // diagnostics recorded before decoding started
+    diagnostics_start: usize,
+    // codings applied by the latest decoding attempt
+    pub layers: Option<&'a mut Vec<LayerReport>>,
 }
 
 impl<'a> DecompressionStruct<'a> {
@@ -24,12 +35,42 @@ impl<'a> DecompressionStruct<'a> {
         extra: Option<&'a [u8]>,
         encoding_info: &'a mut [EncodingInfo],
         writer: Writer<&'a mut BytesMut>,
+        diagnostics: &'a mut Vec<Diagnostic>,
     ) -> Self {
         Self {
             main,
             extra,
             encoding_info,
             writer,
+            options: CodecOptions::default(),
+            diagnostics_start: diagnostics.len(),
+            diagnostics,
+            layers: None,
+        }
+    }
+
+    pub fn with_options(mut self, options: CodecOptions<'a>) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub fn with_layers(mut self, layers: &'a mut Vec<LayerReport>) -> Self {
+        self.layers = Some(layers);
+        self
+    }
+
+    // drop what the abandoned decoding attempts recorded
+    pub fn clear_attempt(&mut self) {
+        self.diagnostics.truncate(self.diagnostics_start);
+        if let Some(layers) = self.layers.as_deref_mut() {
+            layers.clear();
+        }
+    }
+
+    // main and extra decoded separately add up
+    fn record_layers(&mut self, new: Vec<LayerReport>) {
+        if let Some(layers) = self.layers.as_deref_mut() {
+            merge_layers(layers, new);
         }
     }
 
@@ -98,24 +139,48 @@ impl<'a> DecompressionStruct<'a> {
         }
     }
 
+    // extra continues the main gzip/zstd stream as another member/frame
+    pub fn is_extra_member(&self) -> bool {
+        matches!(
+            self.last_encoding(),
+            Some(
+                ContentEncoding::Gzip
+                    | ContentEncoding::Zstd
+                    | ContentEncoding::Compress
+            )
+        ) && self.is_extra_compressed()
+    }
+
     pub fn try_decompress_extra(
         &mut self,
     ) -> Result<BytesMut, MultiDecompressError> {
-        decompress_multi(
+        let mut layers = Vec::new();
+        let result = decompress_multi(
             self.extra.as_ref().expect("no extra"),
             &mut self.writer,
             &mut self.encoding_info.iter(),
-        )
+            &self.options,
+            self.diagnostics,
+            &mut layers,
+        );
+        self.record_layers(layers);
+        result
     }
 
     pub fn try_decompress_main(
         &mut self,
     ) -> Result<BytesMut, MultiDecompressError> {
-        decompress_multi(
+        let mut layers = Vec::new();
+        let result = decompress_multi(
             self.main,
             &mut self.writer,
             &mut self.encoding_info.iter(),
-        )
+            &self.options,
+            self.diagnostics,
+            &mut layers,
+        );
+        self.record_layers(layers);
+        result
     }
 
     pub fn try_decompress_main_plus_extra(
@@ -131,12 +196,24 @@ impl<'a> DecompressionStruct<'a> {
             &mut self.writer,
             &last_encoding,
             len,
+            &self.options,
         );
         if let Err(e) = result {
             self.push_last_encoding(last_encoding);
             return Err(e.into());
         }
         let output = self.writer.get_mut().split();
+        let header_index = self
+            .encoding_info
+            .last()
+            .map(|einfo| einfo.header_index)
+            .unwrap_or_default();
+        self.record_layers(vec![LayerReport {
+            header_index,
+            encoding: last_encoding.clone(),
+            size_before: self.len(),
+            size_after: output.len(),
+        }]);
         if !self.is_encodings_empty() {
             self.try_decompress_chain_remaining(output, last_encoding)
         } else {
@@ -150,18 +227,40 @@ impl<'a> DecompressionStruct<'a> {
         mut writer: &mut Writer<&mut BytesMut>,
         content_encoding: &ContentEncoding,
         len: u64,
+        options: &CodecOptions,
     ) -> Result<(), DecompressError> {
         if let ContentEncoding::Deflate = content_encoding {
-            let mut reader = flate2::read::ZlibDecoder::new(input);
-            std::io::copy(&mut reader, &mut writer)
-                .map_err(DecompressError::Deflate)?;
-            if reader.total_in() != len {
+            let (main_curs, _) = input.get_ref();
+            let total_in = match deflate_variant(main_curs.get_ref()) {
+                DeflateVariant::Zlib => {
+                    let mut reader = flate2::read::ZlibDecoder::new(input);
+                    std::io::copy(&mut reader, &mut writer)
+                        .map_err(DecompressError::Deflate)?;
+                    reader.total_in()
+                }
+                DeflateVariant::Raw => {
+                    let mut reader = flate2::read::DeflateDecoder::new(input);
+                    std::io::copy(&mut reader, &mut writer)
+                        .map_err(DecompressError::Deflate)?;
+                    reader.total_in()
+                }
+            };
+            if total_in != len {
                 return Err(DecompressError::deflate());
             }
             return Ok(());
         }
+        // gzip - extra is read to look for another member
+        if let ContentEncoding::Gzip = content_encoding {
+            let (_, has_trailing) =
+                decompress_gzip_members(BufReader::new(input), &mut writer)?;
+            if has_trailing {
+                return Err(DecompressError::corrupt(content_encoding));
+            }
+            return Ok(());
+        }
         // others
-        decompress_single(&mut input, &mut writer, content_encoding)?;
+        decompress_single(&mut input, &mut writer, content_encoding, options)?;
         let (_, extra_curs) = input.get_ref();
         // brotli
         if extra_curs.position() == 0 {
@@ -176,20 +275,28 @@ impl<'a> DecompressionStruct<'a> {
         last_encoding: ContentEncoding,
     ) -> Result<BytesMut, MultiDecompressError> {
         let iter = &mut self.encoding_info.iter();
-        let result =
-            decompress_multi(&input, &mut self.writer, iter).map_err(|e| {
-                if e.is_corrupt() {
-                    let (header_index, compression_index) =
-                        self.last_header_compression_index();
-                    e.corrupt_to_partial(
-                        input,
-                        header_index,
-                        compression_index,
-                    )
-                } else {
-                    e
-                }
-            });
+        let mut layers = Vec::new();
+        let result = decompress_multi(
+            &input,
+            &mut self.writer,
+            iter,
+            &self.options,
+            self.diagnostics,
+            &mut layers,
+        )
+        .map_err(|e| {
+            if e.is_corrupt() {
+                let (header_index, compression_index) =
+                    self.last_header_compression_index();
+                e.corrupt_to_partial(input, header_index, compression_index)
+            } else {
+                e
+            }
+        });
+        // after the first layer
+        if let Some(recorded) = self.layers.as_deref_mut() {
+            recorded.extend(layers);
+        }
         self.push_last_encoding(last_encoding);
         result
     }
@@ -212,11 +319,13 @@ mod tests {
     ) {
         let mut encoding_info = initial.clone();
         let mut buf = BytesMut::new();
+        let mut diagnostics = Vec::new();
         let mut d = DecompressionStruct::new(
             &[],
             None,
             &mut encoding_info,
             (&mut buf).writer(),
+            &mut diagnostics,
         );
         assert_eq!(d.last_encoding().unwrap(), &expected_last,);
         assert_eq!(d.pop_last_encoding().unwrap(), expected_last,);
@@ -250,11 +359,13 @@ mod tests {
     ) {
         let mut encoding_info = initial.clone();
         let mut buf = BytesMut::new();
+        let mut diagnostics = Vec::new();
         let mut dstruct = DecompressionStruct::new(
             &[],
             None,
             &mut encoding_info,
             (&mut buf).writer(),
+            &mut diagnostics,
         );
         dstruct.push_last_encoding(push);
         assert_eq!(dstruct.encoding_info, expected);
@@ -326,11 +437,13 @@ mod tests {
         expect: bool,
     ) {
         let mut buf = BytesMut::new();
+        let mut diagnostics = Vec::new();
         let d = DecompressionStruct::new(
             &[],
             None,
             &mut encoding_info,
             (&mut buf).writer(),
+            &mut diagnostics,
         );
         assert_eq!(d.is_encodings_empty(), expect,);
     }
@@ -362,11 +475,13 @@ mod tests {
     fn assert_len(main: &[u8], extra: Option<&[u8]>, expected: usize) {
         let mut encoding_info = Vec::new();
         let mut buf = BytesMut::new();
+        let mut diagnostics = Vec::new();
         let dstruct = DecompressionStruct::new(
             main,
             extra,
             &mut encoding_info,
             (&mut buf).writer(),
+            &mut diagnostics,
         );
         assert_eq!(dstruct.len(), expected);
     }
@@ -393,11 +508,13 @@ mod tests {
         expected: (usize, usize),
     ) {
         let mut buf = BytesMut::new();
+        let mut diagnostics = Vec::new();
         let dstruct = DecompressionStruct::new(
             &[],
             None,
             &mut encoding_info,
             (&mut buf).writer(),
+            &mut diagnostics,
         );
         assert_eq!(
             dstruct.last_header_compression_index(),
@@ -437,6 +554,33 @@ mod tests {
         assert_last_header_compression_index(encoding_info, (1, 0));
     }
 
+    // clear_attempt
+    #[test]
+    fn test_dstruct_clear_attempt() {
+        let extra = compress_deflate(INPUT);
+        let mut encoding_info =
+            vec![EncodingInfo::new(0, vec![ContentEncoding::Deflate])];
+        let mut buf = BytesMut::new();
+        let earlier = Diagnostic::UndeclaredTrailer {
+            name: "x-earlier".to_string(),
+        };
+        let mut diagnostics = vec![earlier.clone()];
+        let mut layers = Vec::new();
+        let mut d = DecompressionStruct::new(
+            &[],
+            Some(&extra),
+            &mut encoding_info,
+            (&mut buf).writer(),
+            &mut diagnostics,
+        )
+        .with_layers(&mut layers);
+        assert_eq!(d.try_decompress_extra().unwrap(), INPUT);
+        assert_eq!(d.diagnostics.len(), 2);
+        d.clear_attempt();
+        assert_eq!(*d.diagnostics, vec![earlier]);
+        assert!(layers.is_empty());
+    }
+
     // try_decompress_chain_errors
     #[test]
     fn test_dstruct_d_main_extra_err_extra_raw_all() {
@@ -454,11 +598,13 @@ mod tests {
                 vec![EncodingInfo::new(0, vec![encoding.clone()])];
             let original_info = encoding_info.clone();
             let mut buf = BytesMut::new();
+            let mut diagnostics = Vec::new();
             let mut ds = DecompressionStruct::new(
                 compressed.as_ref(),
                 Some(INPUT),
                 &mut encoding_info,
                 (&mut buf).writer(),
+                &mut diagnostics,
             );
 
             let err = ds.try_decompress_main_plus_extra().unwrap_err();
@@ -478,12 +624,14 @@ mod tests {
         expected_compression_index: usize,
     ) {
         let mut buf = BytesMut::new();
+        let mut diagnostics = Vec::new();
         let verify_encodings = encoding_info.clone();
         let mut ds = DecompressionStruct::new(
             main,
             extra,
             &mut encoding_info,
             (&mut buf).writer(),
+            &mut diagnostics,
         );
 
         let e = ds.try_decompress_main_plus_extra().unwrap_err();
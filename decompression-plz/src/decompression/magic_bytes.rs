@@ -1,5 +1,8 @@
 use header_plz::body_headers::content_encoding::ContentEncoding;
 
+use crate::decompression::diagnostic::DeflateVariant;
+use crate::dictionary::{DCB_MAGIC, DCZ_MAGIC};
+
 // wiki - gzip -  1F 8B
 const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
 
@@ -17,9 +20,32 @@ const DEFLATE_MAGIC_FIRST_BYTE: u8 = 0x78;
 const DEFLATE_MAGIC_SECOND_BYTES: [u8; 8] =
     [0x01, 0x5E, 0x9C, 0xDA, 0x20, 0x7D, 0xBB, 0xF9];
 
-// TODO: Zstandard Dictionary ?
-// https://github.com/facebook/zstd/issues/768 - 0xFD2FB528
+/* RFC 1950 - zlib header
+ *      CMF - CM (low nibble) = 8 (deflate), CINFO (high nibble) <= 7
+ *      FLG - (CMF * 256 + FLG) is a multiple of 31
+ */
+pub fn is_zlib_header(input: &[u8]) -> bool {
+    matches!(
+        input,
+        [cmf, flg, ..]
+        if cmf & 0x0f == 8
+            && cmf >> 4 <= 7
+            && (u16::from(*cmf) << 8 | u16::from(*flg)) % 31 == 0
+    )
+}
+
+// deflate without a valid zlib header is treated as raw deflate
+pub fn deflate_variant(input: &[u8]) -> DeflateVariant {
+    if is_zlib_header(input) {
+        DeflateVariant::Zlib
+    } else {
+        DeflateVariant::Raw
+    }
+}
+
 // wiki - zstd - 28 B5 2F FD
+// dictionary compressed zstd (dcz) is prefixed with its own framing, see
+// crate::dictionary
 const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
 
 pub fn is_compressed(input: &[u8], encoding: &ContentEncoding) -> bool {
@@ -36,17 +62,37 @@ pub fn is_compressed(input: &[u8], encoding: &ContentEncoding) -> bool {
         ContentEncoding::Zstd | ContentEncoding::Compress => {
             input.starts_with(&ZSTD_MAGIC)
         }
+        ContentEncoding::DictionaryBrotli => input.starts_with(&DCB_MAGIC),
+        ContentEncoding::DictionaryZstd => input.starts_with(&DCZ_MAGIC),
         ContentEncoding::Brotli | ContentEncoding::Identity => true,
         ContentEncoding::Chunked | ContentEncoding::Unknown(_) => false,
     }
 }
 
+// Brotli has no magic bytes, so it is never detected
+pub fn detect(input: &[u8]) -> Option<ContentEncoding> {
+    [
+        ContentEncoding::DictionaryBrotli,
+        ContentEncoding::DictionaryZstd,
+        ContentEncoding::Gzip,
+        ContentEncoding::Zstd,
+        ContentEncoding::Deflate,
+    ]
+    .into_iter()
+    .find(|encoding| is_compressed(input, encoding))
+}
+
 #[cfg(test)]
 mod tests {
     use header_plz::body_headers::content_encoding::ContentEncoding;
     use tests_utils::*;
 
-    use crate::decompression::magic_bytes::is_compressed;
+    use crate::decompression::{
+        diagnostic::DeflateVariant,
+        magic_bytes::{
+            deflate_variant, detect, is_compressed, is_zlib_header,
+        },
+    };
 
     #[test]
     fn test_magic_bytes_deflate() {
@@ -74,6 +120,30 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_magic_bytes_dcb() {
+        assert!(is_compressed(
+            &compress_dcb(INPUT, DICTIONARY),
+            &ContentEncoding::DictionaryBrotli
+        ));
+        assert!(!is_compressed(
+            &compress_brotli(INPUT),
+            &ContentEncoding::DictionaryBrotli
+        ));
+    }
+
+    #[test]
+    fn test_magic_bytes_dcz() {
+        assert!(is_compressed(
+            &compress_dcz(INPUT, DICTIONARY),
+            &ContentEncoding::DictionaryZstd
+        ));
+        assert!(!is_compressed(
+            &compress_zstd(INPUT),
+            &ContentEncoding::DictionaryZstd
+        ));
+    }
+
     #[test]
     fn test_magic_bytes_identity() {
         assert!(is_compressed(INPUT, &ContentEncoding::Identity));
@@ -83,4 +153,43 @@ mod tests {
     fn test_magic_bytes_chunked() {
         assert!(!is_compressed(INPUT, &ContentEncoding::Chunked));
     }
+
+    #[test]
+    fn test_magic_bytes_detect() {
+        assert_eq!(detect(&compress_gzip(INPUT)), Some(ContentEncoding::Gzip));
+        assert_eq!(
+            detect(&compress_deflate(INPUT)),
+            Some(ContentEncoding::Deflate)
+        );
+        assert_eq!(detect(&compress_zstd(INPUT)), Some(ContentEncoding::Zstd));
+        assert_eq!(
+            detect(&compress_dcz(INPUT, DICTIONARY)),
+            Some(ContentEncoding::DictionaryZstd)
+        );
+        assert_eq!(detect(&compress_brotli(INPUT)), None);
+        assert_eq!(detect(INPUT), None);
+    }
+
+    #[test]
+    fn test_magic_bytes_zlib_header() {
+        assert!(is_zlib_header(&compress_deflate(INPUT)));
+        for second in [0x01, 0x5e, 0x9c, 0xda] {
+            assert!(is_zlib_header(&[0x78, second]));
+        }
+        assert!(!is_zlib_header(&compress_raw_deflate(INPUT)));
+        assert!(!is_zlib_header(&compress_gzip(INPUT)));
+        assert!(!is_zlib_header(&[0x78]));
+    }
+
+    #[test]
+    fn test_magic_bytes_deflate_variant() {
+        assert_eq!(
+            deflate_variant(&compress_deflate(INPUT)),
+            DeflateVariant::Zlib
+        );
+        assert_eq!(
+            deflate_variant(&compress_raw_deflate(INPUT)),
+            DeflateVariant::Raw
+        );
+    }
 }
@@ -1,6 +1,8 @@
 pub mod magic_bytes;
 
+pub mod diagnostic;
 pub mod dstruct;
 pub mod multi;
+pub mod options;
 pub mod single;
 pub mod state;
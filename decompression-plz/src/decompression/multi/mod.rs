@@ -1,17 +1,31 @@
 use bytes::{BytesMut, buf::Writer};
-use header_plz::body_headers::encoding_info::EncodingInfo;
+use header_plz::body_headers::{
+    content_encoding::ContentEncoding, encoding_info::EncodingInfo,
+};
 
-use crate::decompression::single::{
-    decompress_single, error::DecompressError,
+use crate::decompression::{
+    diagnostic::{DeflateVariant, Diagnostic},
+    magic_bytes::{deflate_variant, detect},
+    options::CodecOptions,
+    single::{
+        decompress_deflate_variant, decompress_single, error::DecompressError,
+    },
 };
+use crate::report::LayerReport;
 
 pub mod error;
 use error::*;
 
+// bound on the undeclared layers decoded
+const MAX_UNDECLARED_LAYERS: usize = 4;
+
 pub fn decompress_multi<'a, T>(
     compressed: &[u8],
-    mut writer: &mut Writer<&mut BytesMut>,
+    writer: &mut Writer<&mut BytesMut>,
     encoding_info: T,
+    options: &CodecOptions,
+    diagnostics: &mut Vec<Diagnostic>,
+    layers: &mut Vec<LayerReport>,
 ) -> Result<BytesMut, MultiDecompressError>
 where
     T: Iterator<Item = &'a EncodingInfo> + std::iter::DoubleEndedIterator,
@@ -23,11 +37,75 @@ where
         for (compression_index, encoding) in
             encoding_info.encodings().iter().rev().enumerate()
         {
-            let curs = std::io::Cursor::new(&mut input);
-            let result = decompress_single(curs, &mut writer, encoding);
+            let mut variant = None;
+            let mut result = decompress_layer(
+                input,
+                writer,
+                encoding,
+                options,
+                &mut variant,
+            );
+            let mut applied = encoding.clone();
+            // best effort - keep what was decoded before the input ran out
+            if options.best_effort
+                && let Err(e) = &result
+                && e.is_truncated()
+            {
+                let output_len = writer.get_ref().len();
+                diagnostics.push(Diagnostic::Truncated {
+                    header_index: encoding_info.header_index,
+                    encoding: encoding.clone(),
+                    input_offset: input.len(),
+                    output_len,
+                });
+                result = Ok(output_len as u64);
+            }
+            /* sniff - declared coding failed, try the one detected from the
+             * magic bytes
+             */
+            if result.is_err()
+                && options.sniff
+                && let Some(detected) =
+                    detect(input).filter(|detected| detected != encoding)
+            {
+                writer.get_mut().clear();
+                let retry = decompress_layer(
+                    input,
+                    writer,
+                    &detected,
+                    options,
+                    &mut variant,
+                );
+                diagnostics.push(Diagnostic::EncodingMismatch {
+                    header_index: encoding_info.header_index,
+                    declared: encoding.clone(),
+                    detected: detected.clone(),
+                    corrected: retry.is_ok(),
+                });
+                if retry.is_ok() {
+                    result = retry;
+                    applied = detected;
+                }
+            }
             match result {
                 Ok(_) => {
+                    if applied == ContentEncoding::Deflate {
+                        // best effort - truncated, the header is all there is
+                        let variant =
+                            variant.unwrap_or_else(|| deflate_variant(input));
+                        diagnostics.push(Diagnostic::Deflate {
+                            header_index: encoding_info.header_index,
+                            variant,
+                        });
+                    }
+                    let size_before = input.len();
                     output = writer.get_mut().split();
+                    layers.push(LayerReport {
+                        header_index: encoding_info.header_index,
+                        encoding: applied,
+                        size_before,
+                        size_after: output.len(),
+                    });
                     input = &output[..];
                 }
                 Err(e) => {
@@ -51,15 +129,58 @@ where
             }
         }
     }
+    if options.undeclared {
+        output = decompress_undeclared(output, writer, options, diagnostics);
+    }
     Ok(output)
 }
 
+// deflate records the variant it decoded
+fn decompress_layer(
+    input: &[u8],
+    writer: &mut Writer<&mut BytesMut>,
+    encoding: &ContentEncoding,
+    options: &CodecOptions,
+    variant: &mut Option<DeflateVariant>,
+) -> Result<u64, DecompressError> {
+    if *encoding == ContentEncoding::Deflate {
+        let (written, decoded) = decompress_deflate_variant(input, writer)?;
+        *variant = Some(decoded);
+        return Ok(written);
+    }
+    decompress_single(input, writer, encoding, options)
+}
+
+// undeclared - keep decoding while the output carries a known magic
+fn decompress_undeclared(
+    mut output: BytesMut,
+    writer: &mut Writer<&mut BytesMut>,
+    options: &CodecOptions,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> BytesMut {
+    for _ in 0..MAX_UNDECLARED_LAYERS {
+        let Some(detected) = detect(&output) else {
+            break;
+        };
+        let curs = std::io::Cursor::new(&output[..]);
+        if decompress_single(curs, &mut *writer, &detected, options).is_err() {
+            writer.get_mut().clear();
+            break;
+        }
+        output = writer.get_mut().split();
+        diagnostics.push(Diagnostic::UndeclaredEncoding {
+            detected,
+        });
+    }
+    output
+}
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use crate::decompression::diagnostic::DeflateVariant;
     use bytes::BufMut;
-    use header_plz::body_headers::content_encoding::ContentEncoding;
     use tests_utils::*;
 
     #[test]
@@ -77,9 +198,15 @@ mod tests {
                 ContentEncoding::Identity,
             ],
         )];
-        let result =
-            decompress_multi(&input, &mut writer, &mut einfo_list.iter())
-                .unwrap();
+        let result = decompress_multi(
+            &input,
+            &mut writer,
+            &mut einfo_list.iter(),
+            &CodecOptions::default(),
+            &mut Vec::new(),
+            &mut Vec::new(),
+        )
+        .unwrap();
         assert_eq!(result, INPUT);
     }
 
@@ -96,9 +223,15 @@ mod tests {
             EncodingInfo::new(4, vec![ContentEncoding::Identity]),
         ];
 
-        let result =
-            decompress_multi(&input, &mut writer, &mut einfo_list.iter())
-                .unwrap();
+        let result = decompress_multi(
+            &input,
+            &mut writer,
+            &mut einfo_list.iter(),
+            &CodecOptions::default(),
+            &mut Vec::new(),
+            &mut Vec::new(),
+        )
+        .unwrap();
         assert_eq!(result, INPUT);
     }
 
@@ -122,9 +255,15 @@ mod tests {
             ),
         ];
 
-        let result =
-            decompress_multi(&input, &mut writer, &mut einfo_list.iter())
-                .unwrap();
+        let result = decompress_multi(
+            &input,
+            &mut writer,
+            &mut einfo_list.iter(),
+            &CodecOptions::default(),
+            &mut Vec::new(),
+            &mut Vec::new(),
+        )
+        .unwrap();
         assert_eq!(result, INPUT);
     }
 
@@ -137,9 +276,15 @@ mod tests {
             0,
             vec![ContentEncoding::Deflate, ContentEncoding::Brotli],
         )];
-        let result =
-            decompress_multi(&input, &mut writer, &mut einfo_list.iter())
-                .unwrap_err();
+        let result = decompress_multi(
+            &input,
+            &mut writer,
+            &mut einfo_list.iter(),
+            &CodecOptions::default(),
+            &mut Vec::new(),
+            &mut Vec::new(),
+        )
+        .unwrap_err();
         if let MultiDecompressErrorReason::Partial {
             partial_body,
             header_index,
@@ -169,9 +314,15 @@ mod tests {
                 ContentEncoding::Identity,
             ],
         )];
-        let result =
-            decompress_multi(&input, &mut writer, &mut einfo_list.iter())
-                .unwrap_err();
+        let result = decompress_multi(
+            &input,
+            &mut writer,
+            &mut einfo_list.iter(),
+            &CodecOptions::default(),
+            &mut Vec::new(),
+            &mut Vec::new(),
+        )
+        .unwrap_err();
         if let MultiDecompressErrorReason::Partial {
             partial_body,
             header_index,
@@ -194,9 +345,15 @@ mod tests {
             EncodingInfo::new(0, vec![ContentEncoding::Zstd]),
             EncodingInfo::new(4, vec![ContentEncoding::Brotli]),
         ];
-        let result =
-            decompress_multi(&input, &mut writer, &mut einfo_list.iter())
-                .unwrap_err();
+        let result = decompress_multi(
+            &input,
+            &mut writer,
+            &mut einfo_list.iter(),
+            &CodecOptions::default(),
+            &mut Vec::new(),
+            &mut Vec::new(),
+        )
+        .unwrap_err();
         if let MultiDecompressErrorReason::Partial {
             partial_body,
             header_index,
@@ -223,9 +380,15 @@ mod tests {
             EncodingInfo::new(4, vec![ContentEncoding::Zstd]),
             EncodingInfo::new(5, vec![ContentEncoding::Identity]),
         ];
-        let result =
-            decompress_multi(&input, &mut writer, &mut einfo_list.iter())
-                .unwrap_err();
+        let result = decompress_multi(
+            &input,
+            &mut writer,
+            &mut einfo_list.iter(),
+            &CodecOptions::default(),
+            &mut Vec::new(),
+            &mut Vec::new(),
+        )
+        .unwrap_err();
         if let MultiDecompressErrorReason::Partial {
             partial_body,
             header_index,
@@ -244,9 +407,15 @@ mod tests {
         let mut buf = BytesMut::new();
         let mut writer = (&mut buf).writer();
         let einfo_list = [EncodingInfo::new(0, vec![ContentEncoding::Zstd])];
-        let result =
-            decompress_multi(INPUT, &mut writer, &mut einfo_list.iter())
-                .unwrap_err();
+        let result = decompress_multi(
+            INPUT,
+            &mut writer,
+            &mut einfo_list.iter(),
+            &CodecOptions::default(),
+            &mut Vec::new(),
+            &mut Vec::new(),
+        )
+        .unwrap_err();
         assert!(matches!(result.reason, MultiDecompressErrorReason::Corrupt));
     }
 
@@ -259,9 +428,305 @@ mod tests {
             EncodingInfo::new(4, vec![ContentEncoding::Brotli]),
         ];
 
-        let result =
-            decompress_multi(INPUT, &mut writer, &mut einfo_list.iter())
-                .unwrap_err();
+        let result = decompress_multi(
+            INPUT,
+            &mut writer,
+            &mut einfo_list.iter(),
+            &CodecOptions::default(),
+            &mut Vec::new(),
+            &mut Vec::new(),
+        )
+        .unwrap_err();
         assert!(matches!(result.reason, MultiDecompressErrorReason::Corrupt));
     }
+
+    // ----- sniff
+    #[track_caller]
+    fn assert_sniff(
+        input: &[u8],
+        declared: Vec<ContentEncoding>,
+        options: CodecOptions,
+    ) -> (Result<BytesMut, MultiDecompressError>, Vec<Diagnostic>) {
+        let mut buf = BytesMut::new();
+        let mut writer = (&mut buf).writer();
+        let einfo_list = [EncodingInfo::new(2, declared)];
+        let mut diagnostics = Vec::new();
+        let result = decompress_multi(
+            input,
+            &mut writer,
+            &mut einfo_list.iter(),
+            &options,
+            &mut diagnostics,
+            &mut Vec::new(),
+        );
+        (result, diagnostics)
+    }
+
+    #[test]
+    fn test_decompress_multi_sniff_gzip_as_deflate() {
+        let input = compress_gzip(INPUT);
+        let (result, diagnostics) = assert_sniff(
+            &input,
+            vec![ContentEncoding::Deflate],
+            CodecOptions::default().with_sniff(true),
+        );
+        assert_eq!(result.unwrap(), INPUT);
+        assert_eq!(
+            diagnostics,
+            vec![Diagnostic::EncodingMismatch {
+                header_index: 2,
+                declared: ContentEncoding::Deflate,
+                detected: ContentEncoding::Gzip,
+                corrected: true,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_decompress_multi_sniff_disabled() {
+        let input = compress_gzip(INPUT);
+        let (result, diagnostics) = assert_sniff(
+            &input,
+            vec![ContentEncoding::Deflate],
+            CodecOptions::default(),
+        );
+        assert!(result.unwrap_err().is_corrupt());
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_decompress_multi_sniff_not_corrected() {
+        let mut input = compress_zstd(INPUT);
+        input.truncate(input.len() / 2);
+        let (result, diagnostics) = assert_sniff(
+            &input,
+            vec![ContentEncoding::Gzip],
+            CodecOptions::default().with_sniff(true),
+        );
+        assert!(result.unwrap_err().is_corrupt());
+        assert_eq!(
+            diagnostics,
+            vec![Diagnostic::EncodingMismatch {
+                header_index: 2,
+                declared: ContentEncoding::Gzip,
+                detected: ContentEncoding::Zstd,
+                corrected: false,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_decompress_multi_sniff_undeclared() {
+        let input = compress_gzip(&compress_zstd(&compress_gzip(INPUT)));
+        let options = CodecOptions::default().with_undeclared(true);
+        let (result, diagnostics) =
+            assert_sniff(&input, vec![ContentEncoding::Gzip], options);
+        assert_eq!(result.unwrap(), INPUT);
+        assert_eq!(
+            diagnostics,
+            vec![
+                Diagnostic::UndeclaredEncoding {
+                    detected: ContentEncoding::Zstd,
+                },
+                Diagnostic::UndeclaredEncoding {
+                    detected: ContentEncoding::Gzip,
+                },
+            ]
+        );
+    }
+
+    // sniff alone keeps the output of the declared codings
+    #[test]
+    fn test_decompress_multi_sniff_undeclared_disabled() {
+        let inner = compress_gzip(INPUT);
+        let input = compress_gzip(&inner);
+        let (result, diagnostics) = assert_sniff(
+            &input,
+            vec![ContentEncoding::Gzip],
+            CodecOptions::default().with_sniff(true),
+        );
+        assert_eq!(result.unwrap(), inner);
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_decompress_multi_sniff_no_mismatch() {
+        let input = all_compressed_data();
+        let (result, diagnostics) = assert_sniff(
+            &input,
+            vec![
+                ContentEncoding::Brotli,
+                ContentEncoding::Deflate,
+                ContentEncoding::Gzip,
+                ContentEncoding::Zstd,
+            ],
+            CodecOptions::default().with_sniff(true),
+        );
+        assert_eq!(result.unwrap(), INPUT);
+        assert_eq!(
+            diagnostics,
+            vec![Diagnostic::Deflate {
+                header_index: 2,
+                variant: DeflateVariant::Zlib,
+            }]
+        );
+    }
+
+    // ----- deflate variant
+    #[test]
+    fn test_decompress_multi_raw_deflate() {
+        let input = compress_gzip(&compress_raw_deflate(INPUT));
+        let mut buf = BytesMut::new();
+        let mut writer = (&mut buf).writer();
+        let einfo_list = [
+            EncodingInfo::new(0, vec![ContentEncoding::Deflate]),
+            EncodingInfo::new(1, vec![ContentEncoding::Gzip]),
+        ];
+        let mut diagnostics = Vec::new();
+        let result = decompress_multi(
+            &input,
+            &mut writer,
+            &mut einfo_list.iter(),
+            &CodecOptions::default(),
+            &mut diagnostics,
+            &mut Vec::new(),
+        )
+        .unwrap();
+        assert_eq!(result, INPUT);
+        assert_eq!(
+            diagnostics,
+            vec![Diagnostic::Deflate {
+                header_index: 0,
+                variant: DeflateVariant::Raw,
+            }]
+        );
+    }
+
+    // raw stream with a valid zlib header
+    #[test]
+    fn test_decompress_multi_raw_deflate_zlib_lookalike() {
+        let input =
+            [0x78, 0x01, 0x00, 0xfe, 0xff, b'a', 0x01, 0x00, 0x00, 0xff, 0xff];
+        let mut buf = BytesMut::new();
+        let mut writer = (&mut buf).writer();
+        let einfo_list =
+            [EncodingInfo::new(0, vec![ContentEncoding::Deflate])];
+        let mut diagnostics = Vec::new();
+        let result = decompress_multi(
+            &input,
+            &mut writer,
+            &mut einfo_list.iter(),
+            &CodecOptions::default(),
+            &mut diagnostics,
+            &mut Vec::new(),
+        )
+        .unwrap();
+        assert_eq!(result, &b"a"[..]);
+        assert_eq!(
+            diagnostics,
+            vec![Diagnostic::Deflate {
+                header_index: 0,
+                variant: DeflateVariant::Raw,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_decompress_multi_best_effort() {
+        let data = large_input();
+        let compressed = compress_gzip(&data);
+        let input = &compressed[..compressed.len() / 2];
+        let mut buf = BytesMut::new();
+        let mut writer = (&mut buf).writer();
+        let einfo_list = [EncodingInfo::new(0, vec![ContentEncoding::Gzip])];
+        let mut diagnostics = Vec::new();
+        let result = decompress_multi(
+            input,
+            &mut writer,
+            &mut einfo_list.iter(),
+            &CodecOptions::default().with_best_effort(true),
+            &mut diagnostics,
+            &mut Vec::new(),
+        )
+        .unwrap();
+        assert!(!result.is_empty());
+        assert!(data.starts_with(&result));
+        assert_eq!(
+            diagnostics,
+            vec![Diagnostic::Truncated {
+                header_index: 0,
+                encoding: ContentEncoding::Gzip,
+                input_offset: input.len(),
+                output_len: result.len(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_decompress_multi_best_effort_disabled() {
+        let compressed = compress_gzip(&large_input());
+        let input = &compressed[..compressed.len() / 2];
+        let mut buf = BytesMut::new();
+        let mut writer = (&mut buf).writer();
+        let einfo_list = [EncodingInfo::new(0, vec![ContentEncoding::Gzip])];
+        let result = decompress_multi(
+            input,
+            &mut writer,
+            &mut einfo_list.iter(),
+            &CodecOptions::default(),
+            &mut Vec::new(),
+            &mut Vec::new(),
+        );
+        assert!(result.unwrap_err().is_corrupt());
+    }
+
+    #[test]
+    fn test_decompress_multi_best_effort_corrupt() {
+        let mut buf = BytesMut::new();
+        let mut writer = (&mut buf).writer();
+        let einfo_list = [EncodingInfo::new(0, vec![ContentEncoding::Gzip])];
+        let mut diagnostics = Vec::new();
+        let result = decompress_multi(
+            INPUT,
+            &mut writer,
+            &mut einfo_list.iter(),
+            &CodecOptions::default().with_best_effort(true),
+            &mut diagnostics,
+            &mut Vec::new(),
+        );
+        assert!(result.unwrap_err().is_corrupt());
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_decompress_multi_best_effort_layers() {
+        let data = large_input();
+        let compressed = compress_brotli(&compress_gzip(&data));
+        let input = &compressed[..compressed.len() / 2];
+        let mut buf = BytesMut::new();
+        let mut writer = (&mut buf).writer();
+        let einfo_list = [EncodingInfo::new(
+            0,
+            vec![ContentEncoding::Gzip, ContentEncoding::Brotli],
+        )];
+        let mut diagnostics = Vec::new();
+        let result = decompress_multi(
+            input,
+            &mut writer,
+            &mut einfo_list.iter(),
+            &CodecOptions::default().with_best_effort(true),
+            &mut diagnostics,
+            &mut Vec::new(),
+        )
+        .unwrap();
+        assert!(data.starts_with(&result));
+        assert_eq!(diagnostics.len(), 2);
+        assert!(diagnostics.iter().all(|d| matches!(
+            d,
+            Diagnostic::Truncated {
+                header_index: 0,
+                ..
+            }
+        )));
+    }
 }
@@ -0,0 +1,59 @@
+use crate::dictionary::DictionaryLookup;
+
+// Settings passed down to the individual codecs
+#[derive(Clone, Copy, Default)]
+pub struct CodecOptions<'a> {
+    pub dictionaries: Option<&'a dyn DictionaryLookup>,
+    // check declared codings against the magic bytes
+    pub sniff: bool,
+    // keep decoding while the decoded output carries a known magic
+    pub undeclared: bool,
+    // keep the output of a stream that ends early instead of failing
+    pub best_effort: bool,
+}
+
+impl<'a> CodecOptions<'a> {
+    pub fn with_dictionaries(
+        mut self,
+        dictionaries: &'a dyn DictionaryLookup,
+    ) -> Self {
+        self.dictionaries = Some(dictionaries);
+        self
+    }
+
+    pub fn with_sniff(mut self, sniff: bool) -> Self {
+        self.sniff = sniff;
+        self
+    }
+
+    pub fn with_undeclared(mut self, undeclared: bool) -> Self {
+        self.undeclared = undeclared;
+        self
+    }
+
+    pub fn with_best_effort(mut self, best_effort: bool) -> Self {
+        self.best_effort = best_effort;
+        self
+    }
+}
+
+impl std::fmt::Debug for CodecOptions<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CodecOptions")
+            .field("dictionaries", &self.dictionaries.is_some())
+            .field("sniff", &self.sniff)
+            .field("undeclared", &self.undeclared)
+            .field("best_effort", &self.best_effort)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+impl PartialEq for CodecOptions<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.dictionaries.is_some() == other.dictionaries.is_some()
+            && self.sniff == other.sniff
+            && self.undeclared == other.undeclared
+            && self.best_effort == other.best_effort
+    }
+}
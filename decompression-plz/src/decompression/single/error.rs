@@ -1,4 +1,6 @@
-use header_plz::body_headers::content_encoding::ContentEncoding;
+use header_plz::body_headers::{
+    content_encoding::ContentEncoding, dictionary::DictionaryHash,
+};
 use std::io::Error;
 use thiserror::Error;
 
@@ -14,6 +16,12 @@ pub enum DecompressError {
     Gzip(Error),
     #[error("zstd| {0}")]
     Zstd(Error),
+    #[error("dcb| {0}")]
+    DictionaryBrotli(Error),
+    #[error("dcz| {0}")]
+    DictionaryZstd(Error),
+    #[error("dictionary not found| {0}")]
+    DictionaryNotFound(DictionaryHash),
     #[error("identity| {0}")]
     Identity(Error),
     #[error("unknown| {0}")]
@@ -26,6 +34,24 @@ impl DecompressError {
         DecompressError::Deflate(err)
     }
 
+    // codec ran out of input before the end of the stream
+    pub fn is_truncated(&self) -> bool {
+        match self {
+            Self::Brotli(e)
+            | Self::Deflate(e)
+            | Self::Gzip(e)
+            | Self::Zstd(e)
+            | Self::DictionaryBrotli(e)
+            | Self::DictionaryZstd(e) => {
+                e.kind() == std::io::ErrorKind::UnexpectedEof
+            }
+            Self::PartialCopy(_)
+            | Self::DictionaryNotFound(_)
+            | Self::Identity(_)
+            | Self::Unknown(_) => false,
+        }
+    }
+
     pub fn corrupt(encoding: &ContentEncoding) -> Self {
         let err = std::io::Error::from(std::io::ErrorKind::InvalidData);
         match encoding {
@@ -35,6 +61,8 @@ impl DecompressError {
             ContentEncoding::Compress | ContentEncoding::Zstd => {
                 Self::Zstd(err)
             }
+            ContentEncoding::DictionaryBrotli => Self::DictionaryBrotli(err),
+            ContentEncoding::DictionaryZstd => Self::DictionaryZstd(err),
             ContentEncoding::Unknown(enc) => Self::Unknown(enc.to_string()),
             ContentEncoding::Identity | ContentEncoding::Chunked => {
                 Self::Identity(err)
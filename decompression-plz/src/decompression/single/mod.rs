@@ -1,15 +1,30 @@
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Error;
+use std::io::ErrorKind;
 use std::io::Read;
 use std::io::Write;
 use std::io::copy;
 
+use brotli::enc::StandardAlloc;
+use brotli::{BrotliDecompressStream, BrotliResult, BrotliState};
 use header_plz::body_headers::content_encoding::ContentEncoding;
+use header_plz::body_headers::dictionary::{
+    DICTIONARY_HASH_LEN, DictionaryHash,
+};
 pub mod error;
 use error::DecompressError;
 
+use crate::decompression::diagnostic::DeflateVariant;
+use crate::decompression::magic_bytes::{deflate_variant, is_compressed};
+use crate::decompression::options::CodecOptions;
+use crate::dictionary::{DCB_MAGIC, DCZ_MAGIC, DictionaryLookup};
+
 pub fn decompress_single<R, W>(
     mut input: R,
     mut writer: W,
     content_encoding: &ContentEncoding,
+    options: &CodecOptions,
 ) -> Result<u64, DecompressError>
 where
     R: Read,
@@ -21,6 +36,12 @@ where
             decompress_zstd(input, writer)
         }
         ContentEncoding::Deflate => decompress_deflate(input, writer),
+        ContentEncoding::DictionaryBrotli => {
+            decompress_dcb(input, writer, options.dictionaries)
+        }
+        ContentEncoding::DictionaryZstd => {
+            decompress_dcz(input, writer, options.dictionaries)
+        }
         ContentEncoding::Gzip => decompress_gzip(input, writer),
         ContentEncoding::Identity | ContentEncoding::Chunked => {
             copy(&mut input, &mut writer).map_err(DecompressError::Identity)
@@ -40,21 +61,185 @@ where
     R: Read,
     W: Write,
 {
-    copy(&mut brotli::Decompressor::new(input, 4096), &mut buf)
-        .map_err(DecompressError::Brotli)
+    unbrotli(input, &mut buf, None).map_err(DecompressError::Brotli)
+}
+
+/* brotli::Decompressor reports a stream that ends early as InvalidData, the
+ * same as corrupt data. Drive the decoder directly so that running out of
+ * input is reported as UnexpectedEof.
+ */
+fn unbrotli<R, W>(
+    mut input: R,
+    mut buf: W,
+    dictionary: Option<&[u8]>,
+) -> Result<u64, Error>
+where
+    R: Read,
+    W: Write,
+{
+    let mut state = match dictionary {
+        Some(dictionary) => BrotliState::new_with_custom_dictionary(
+            StandardAlloc::default(),
+            StandardAlloc::default(),
+            StandardAlloc::default(),
+            dictionary.to_vec().into(),
+        ),
+        None => BrotliState::new(
+            StandardAlloc::default(),
+            StandardAlloc::default(),
+            StandardAlloc::default(),
+        ),
+    };
+    let mut chunk = [0; 4096];
+    let mut out = vec![0; 8192];
+    let (mut available_in, mut input_offset, mut total_out) = (0, 0, 0);
+    let mut eof = false;
+    loop {
+        if available_in == 0 && !eof {
+            available_in = input.read(&mut chunk)?;
+            input_offset = 0;
+            eof = available_in == 0;
+        }
+        let mut available_out = out.len();
+        let mut output_offset = 0;
+        let result = BrotliDecompressStream(
+            &mut available_in,
+            &mut input_offset,
+            &chunk,
+            &mut available_out,
+            &mut output_offset,
+            &mut out,
+            &mut total_out,
+            &mut state,
+        );
+        buf.write_all(&out[..output_offset])?;
+        match result {
+            BrotliResult::ResultSuccess => {
+                // trailing data after the end of the stream
+                if available_in != 0 || input.read(&mut [0])? != 0 {
+                    return Err(Error::from(ErrorKind::InvalidData));
+                }
+                return Ok(total_out as u64);
+            }
+            BrotliResult::ResultFailure => {
+                return Err(Error::from(ErrorKind::InvalidData));
+            }
+            BrotliResult::NeedsMoreInput if eof => {
+                return Err(Error::from(ErrorKind::UnexpectedEof));
+            }
+            BrotliResult::NeedsMoreInput | BrotliResult::NeedsMoreOutput => {}
+        }
+    }
 }
 
 #[inline]
 pub fn decompress_deflate<R, W>(
     input: R,
-    mut buf: W,
+    buf: W,
 ) -> Result<u64, DecompressError>
 where
     R: Read,
     W: Write,
 {
-    copy(&mut flate2::read::ZlibDecoder::new(input), &mut buf)
-        .map_err(DecompressError::Deflate)
+    decompress_deflate_variant(input, buf).map(|(written, _)| written)
+}
+
+/* A valid zlib header does not rule out raw deflate, the first bytes of a
+ * raw stream may pass the check. The input is decoded as zlib first and as
+ * raw when that fails, the error of the zlib attempt is kept when both
+ * fail.
+ *
+ * Returns the number of bytes written and the variant decoded.
+ */
+pub fn decompress_deflate_variant<R, W>(
+    mut input: R,
+    mut buf: W,
+) -> Result<(u64, DeflateVariant), DecompressError>
+where
+    R: Read,
+    W: Write,
+{
+    let mut data = Vec::new();
+    input.read_to_end(&mut data).map_err(DecompressError::Deflate)?;
+    if deflate_variant(&data) == DeflateVariant::Raw {
+        return inflate_raw(&data, &mut buf)
+            .map(|written| (written, DeflateVariant::Raw));
+    }
+    // output is held back until the variant is known
+    let mut zlib = Vec::new();
+    let zlib_error = match inflate(&mut &data[..], &mut zlib, true) {
+        Ok(written) => {
+            buf.write_all(&zlib).map_err(DecompressError::Deflate)?;
+            return Ok((written, DeflateVariant::Zlib));
+        }
+        Err(e) => e,
+    };
+    let mut raw = Vec::new();
+    if let Ok(written) = inflate_raw(&data, &mut raw) {
+        buf.write_all(&raw).map_err(DecompressError::Deflate)?;
+        return Ok((written, DeflateVariant::Raw));
+    }
+    // keep the partial zlib output for best effort
+    buf.write_all(&zlib).map_err(DecompressError::Deflate)?;
+    Err(DecompressError::Deflate(zlib_error))
+}
+
+// no header or checksum to validate, so insist that the stream covers the
+// whole input
+fn inflate_raw<W>(data: &[u8], buf: W) -> Result<u64, DecompressError>
+where
+    W: Write,
+{
+    let mut input = data;
+    let written =
+        inflate(&mut input, buf, false).map_err(DecompressError::Deflate)?;
+    if !input.is_empty() {
+        return Err(DecompressError::deflate());
+    }
+    Ok(written)
+}
+
+/* flate2 read decoders return Ok when the input ends before the end of the
+ * deflate stream, drive the decompressor directly so that a truncated stream
+ * is reported as UnexpectedEof like gzip and zstd. Input after the end of
+ * the stream is left unread.
+ */
+fn inflate<R, W>(
+    input: &mut R,
+    mut buf: W,
+    zlib_header: bool,
+) -> Result<u64, std::io::Error>
+where
+    R: BufRead,
+    W: Write,
+{
+    let mut decompress = flate2::Decompress::new(zlib_header);
+    let mut out = vec![0; 8192];
+    loop {
+        let chunk = input.fill_buf()?;
+        let eof = chunk.is_empty();
+        let flush = if eof {
+            flate2::FlushDecompress::Finish
+        } else {
+            flate2::FlushDecompress::None
+        };
+        let (before_in, before_out) =
+            (decompress.total_in(), decompress.total_out());
+        let status = decompress
+            .decompress(chunk, &mut out, flush)
+            .map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+        let consumed = (decompress.total_in() - before_in) as usize;
+        let produced = (decompress.total_out() - before_out) as usize;
+        input.consume(consumed);
+        buf.write_all(&out[..produced])?;
+        match status {
+            flate2::Status::StreamEnd => return Ok(decompress.total_out()),
+            _ if eof && produced == 0 => {
+                return Err(Error::from(ErrorKind::UnexpectedEof));
+            }
+            _ => {}
+        }
+    }
 }
 
 #[inline]
@@ -66,8 +251,38 @@ where
     R: Read,
     W: Write,
 {
-    copy(&mut flate2::read::GzDecoder::new(input), &mut buf)
-        .map_err(DecompressError::Gzip)
+    decompress_gzip_members(BufReader::new(input), &mut buf)
+        .map(|(written, _)| written)
+}
+
+/* RFC 1952 - a gzip stream is a series of members. Members are decoded as
+ * long as the remaining input starts with the gzip magic, anything else is
+ * left unread.
+ *
+ * Returns the number of bytes written and whether unread bytes remain.
+ */
+pub fn decompress_gzip_members<R, W>(
+    mut input: R,
+    mut buf: W,
+) -> Result<(u64, bool), DecompressError>
+where
+    R: BufRead,
+    W: Write,
+{
+    let mut written = 0;
+    loop {
+        let mut decoder = flate2::bufread::GzDecoder::new(input);
+        written +=
+            copy(&mut decoder, &mut buf).map_err(DecompressError::Gzip)?;
+        input = decoder.into_inner();
+        let remaining = input.fill_buf().map_err(DecompressError::Gzip)?;
+        if remaining.is_empty() {
+            return Ok((written, false));
+        }
+        if !is_compressed(remaining, &ContentEncoding::Gzip) {
+            return Ok((written, true));
+        }
+    }
 }
 
 #[inline]
@@ -87,6 +302,95 @@ where
     .map_err(DecompressError::Zstd)
 }
 
+#[inline]
+pub fn decompress_dcb<R, W>(
+    mut input: R,
+    mut buf: W,
+    dictionaries: Option<&dyn DictionaryLookup>,
+) -> Result<u64, DecompressError>
+where
+    R: Read,
+    W: Write,
+{
+    let dictionary =
+        read_dictionary_header(&mut input, &DCB_MAGIC, dictionaries)
+            .map_err(|e| e.into_dcb())?;
+    unbrotli(input, &mut buf, Some(dictionary))
+        .map_err(DecompressError::DictionaryBrotli)
+}
+
+#[inline]
+pub fn decompress_dcz<R, W>(
+    mut input: R,
+    mut buf: W,
+    dictionaries: Option<&dyn DictionaryLookup>,
+) -> Result<u64, DecompressError>
+where
+    R: Read,
+    W: Write,
+{
+    let dictionary =
+        read_dictionary_header(&mut input, &DCZ_MAGIC, dictionaries)
+            .map_err(|e| e.into_dcz())?;
+    let mut decoder = zstd::stream::read::Decoder::with_dictionary(
+        std::io::BufReader::new(input),
+        dictionary,
+    )
+    .map_err(DecompressError::DictionaryZstd)?;
+    // RFC 9842 allows windows up to 128MB
+    decoder.window_log_max(27).map_err(DecompressError::DictionaryZstd)?;
+    copy(&mut decoder, &mut buf).map_err(DecompressError::DictionaryZstd)
+}
+
+// Consume the magic + dictionary hash framing and look up the dictionary.
+// The returned io::Error is mapped to the codec specific variant by the
+// caller.
+fn read_dictionary_header<'a, R>(
+    input: &mut R,
+    magic: &[u8],
+    dictionaries: Option<&'a dyn DictionaryLookup>,
+) -> Result<&'a [u8], DictionaryHeaderError>
+where
+    R: Read,
+{
+    let mut header = [0u8; DCZ_MAGIC.len() + DICTIONARY_HASH_LEN];
+    let header = &mut header[..magic.len() + DICTIONARY_HASH_LEN];
+    input.read_exact(header).map_err(DictionaryHeaderError::Io)?;
+    let (found_magic, hash) = header.split_at(magic.len());
+    if found_magic != magic {
+        return Err(DictionaryHeaderError::Io(std::io::Error::from(
+            std::io::ErrorKind::InvalidData,
+        )));
+    }
+    let mut hash_bytes = [0u8; DICTIONARY_HASH_LEN];
+    hash_bytes.copy_from_slice(hash);
+    let hash = DictionaryHash(hash_bytes);
+    dictionaries
+        .and_then(|d| d.lookup(&hash))
+        .ok_or(DictionaryHeaderError::NotFound(hash))
+}
+
+enum DictionaryHeaderError {
+    Io(std::io::Error),
+    NotFound(DictionaryHash),
+}
+
+impl DictionaryHeaderError {
+    fn into_dcb(self) -> DecompressError {
+        match self {
+            Self::Io(e) => DecompressError::DictionaryBrotli(e),
+            Self::NotFound(hash) => DecompressError::DictionaryNotFound(hash),
+        }
+    }
+
+    fn into_dcz(self) -> DecompressError {
+        match self {
+            Self::Io(e) => DecompressError::DictionaryZstd(e),
+            Self::NotFound(hash) => DecompressError::DictionaryNotFound(hash),
+        }
+    }
+}
+
 #[cfg(test)]
 pub mod tests {
     use bytes::{BufMut, BytesMut};
@@ -94,9 +398,15 @@ pub mod tests {
     use header_plz::body_headers::content_encoding::ContentEncoding;
     use tests_utils::*;
 
-    use crate::decompression::single::{
-        decompress_single, error::DecompressError,
+    use crate::decompression::diagnostic::DeflateVariant;
+    use crate::decompression::{
+        options::CodecOptions,
+        single::{
+            decompress_deflate_variant, decompress_gzip_members,
+            decompress_single, error::DecompressError,
+        },
     };
+    use crate::dictionary::DictionaryStore;
 
     fn test_decompress(
         data: &[u8],
@@ -109,16 +419,24 @@ pub mod tests {
             ContentEncoding::Zstd | ContentEncoding::Compress => {
                 compress_zstd(data)
             }
+            ContentEncoding::DictionaryBrotli => {
+                compress_dcb(data, DICTIONARY)
+            }
+            ContentEncoding::DictionaryZstd => compress_dcz(data, DICTIONARY),
             ContentEncoding::Identity
             | ContentEncoding::Unknown(_)
             | ContentEncoding::Chunked => data.to_vec(),
         };
+        let mut store = DictionaryStore::new();
+        store.insert(DICTIONARY.to_vec());
+        let options = CodecOptions::default().with_dictionaries(&store);
         let buf = BytesMut::new();
         let mut writer = buf.writer();
         decompress_single(
             compressed.as_slice(),
             &mut writer,
             &content_encoding,
+            &options,
         )
         .unwrap();
         writer.into_inner()
@@ -137,12 +455,102 @@ pub mod tests {
         assert_eq!(result.as_ref(), INPUT);
     }
 
+    #[test]
+    fn test_basic_raw_deflate() {
+        let compressed = compress_raw_deflate(INPUT);
+        let buf = BytesMut::new();
+        let mut writer = buf.writer();
+        decompress_single(
+            compressed.as_slice(),
+            &mut writer,
+            &ContentEncoding::Deflate,
+            &CodecOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(writer.into_inner().as_ref(), INPUT);
+    }
+
+    #[test]
+    fn test_raw_deflate_trailing_data() {
+        let mut compressed = compress_raw_deflate(INPUT);
+        compressed.extend_from_slice(INPUT);
+        let buf = BytesMut::new();
+        let mut writer = buf.writer();
+        let result = decompress_single(
+            compressed.as_slice(),
+            &mut writer,
+            &ContentEncoding::Deflate,
+            &CodecOptions::default(),
+        )
+        .unwrap_err();
+        assert!(matches!(result, DecompressError::Deflate(_)));
+    }
+
+    // stored block of "a" then an empty final block, the first two bytes
+    // pass the zlib header check
+    const RAW_ZLIB_LOOKALIKE: [u8; 11] =
+        [0x78, 0x01, 0x00, 0xfe, 0xff, b'a', 0x01, 0x00, 0x00, 0xff, 0xff];
+
+    #[test]
+    fn test_raw_deflate_zlib_lookalike() {
+        let buf = BytesMut::new();
+        let mut writer = buf.writer();
+        let (_, variant) =
+            decompress_deflate_variant(&RAW_ZLIB_LOOKALIKE[..], &mut writer)
+                .unwrap();
+        assert_eq!(variant, DeflateVariant::Raw);
+        assert_eq!(writer.into_inner().as_ref(), b"a");
+    }
+
     #[test]
     fn test_basic_gzip() {
         let result = test_decompress(INPUT, ContentEncoding::Gzip);
         assert_eq!(result.as_ref(), INPUT);
     }
 
+    #[test]
+    fn test_gzip_multi_member() {
+        let mut compressed = compress_gzip(b"hello ");
+        compressed.extend(compress_gzip(b"world"));
+        let result = decompress_single(
+            compressed.as_slice(),
+            BytesMut::new().writer(),
+            &ContentEncoding::Gzip,
+            &CodecOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(result, 11);
+    }
+
+    #[test]
+    fn test_gzip_members_trailing() {
+        let mut compressed = compress_gzip(INPUT);
+        compressed.extend(compress_gzip(INPUT));
+        compressed.extend_from_slice(b"trailing");
+        let mut writer = BytesMut::new().writer();
+        let (written, has_trailing) =
+            decompress_gzip_members(compressed.as_slice(), &mut writer)
+                .unwrap();
+        assert_eq!(written, 22);
+        assert!(has_trailing);
+        assert_eq!(writer.into_inner(), "hello worldhello world");
+    }
+
+    #[test]
+    fn test_zstd_multi_frame() {
+        let mut compressed = compress_zstd(b"hello ");
+        compressed.extend(compress_zstd(b"world"));
+        let mut writer = BytesMut::new().writer();
+        decompress_single(
+            compressed.as_slice(),
+            &mut writer,
+            &ContentEncoding::Zstd,
+            &CodecOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(writer.into_inner().as_ref(), INPUT);
+    }
+
     #[test]
     fn test_basic_zstd() {
         let result = test_decompress(INPUT, ContentEncoding::Zstd);
@@ -155,6 +563,142 @@ pub mod tests {
         assert_eq!(result.as_ref(), INPUT);
     }
 
+    #[test]
+    fn test_basic_dcb() {
+        let result = test_decompress(INPUT, ContentEncoding::DictionaryBrotli);
+        assert_eq!(result.as_ref(), INPUT);
+    }
+
+    #[test]
+    fn test_basic_dcz() {
+        let result = test_decompress(INPUT, ContentEncoding::DictionaryZstd);
+        assert_eq!(result.as_ref(), INPUT);
+    }
+
+    #[test]
+    fn test_dcz_dictionary_not_found() {
+        let compressed = compress_dcz(INPUT, DICTIONARY);
+        let buf = BytesMut::new();
+        let mut writer = buf.writer();
+        let result = decompress_single(
+            compressed.as_slice(),
+            &mut writer,
+            &ContentEncoding::DictionaryZstd,
+            &CodecOptions::default(),
+        )
+        .unwrap_err();
+        assert!(matches!(result, DecompressError::DictionaryNotFound(_)));
+    }
+
+    #[test]
+    fn test_dcb_wrong_dictionary() {
+        let compressed = compress_dcb(INPUT, DICTIONARY);
+        let mut store = DictionaryStore::new();
+        store.insert(b"another dictionary".to_vec());
+        let buf = BytesMut::new();
+        let mut writer = buf.writer();
+        let result = decompress_single(
+            compressed.as_slice(),
+            &mut writer,
+            &ContentEncoding::DictionaryBrotli,
+            &CodecOptions::default().with_dictionaries(&store),
+        )
+        .unwrap_err();
+        assert!(matches!(result, DecompressError::DictionaryNotFound(_)));
+    }
+
+    #[test]
+    fn test_dcb_missing_magic() {
+        let compressed = compress_brotli(INPUT);
+        let mut store = DictionaryStore::new();
+        store.insert(DICTIONARY.to_vec());
+        let buf = BytesMut::new();
+        let mut writer = buf.writer();
+        let result = decompress_single(
+            compressed.as_slice(),
+            &mut writer,
+            &ContentEncoding::DictionaryBrotli,
+            &CodecOptions::default().with_dictionaries(&store),
+        )
+        .unwrap_err();
+        assert!(matches!(result, DecompressError::DictionaryBrotli(_)));
+    }
+
+    #[test]
+    fn test_truncated() {
+        let data = large_input();
+        let mut store = DictionaryStore::new();
+        store.insert(DICTIONARY.to_vec());
+        let options = CodecOptions::default().with_dictionaries(&store);
+        for (encoding, compressed) in [
+            (ContentEncoding::Brotli, compress_brotli(&data)),
+            (ContentEncoding::Deflate, compress_deflate(&data)),
+            (ContentEncoding::Deflate, compress_raw_deflate(&data)),
+            (ContentEncoding::Gzip, compress_gzip(&data)),
+            (ContentEncoding::Zstd, compress_zstd(&data)),
+            (
+                ContentEncoding::DictionaryBrotli,
+                compress_dcb(&data, DICTIONARY),
+            ),
+            (ContentEncoding::DictionaryZstd, compress_dcz(&data, DICTIONARY)),
+        ] {
+            let truncated = &compressed[..compressed.len() / 2];
+            let mut writer = BytesMut::new().writer();
+            let err =
+                decompress_single(truncated, &mut writer, &encoding, &options)
+                    .unwrap_err();
+            assert!(err.is_truncated(), "{encoding:?}");
+            let output = writer.into_inner();
+            assert!(data.starts_with(&output), "{encoding:?}");
+        }
+    }
+
+    #[test]
+    fn test_truncated_output() {
+        let data = large_input();
+        let compressed = compress_brotli(&data);
+        let mut writer = BytesMut::new().writer();
+        let err = decompress_single(
+            &compressed[..compressed.len() / 2],
+            &mut writer,
+            &ContentEncoding::Brotli,
+            &CodecOptions::default(),
+        )
+        .unwrap_err();
+        assert!(err.is_truncated());
+        assert!(!writer.into_inner().is_empty());
+    }
+
+    #[test]
+    fn test_corrupt_not_truncated() {
+        // brotli has no header, any prefix may be a valid truncated stream
+        for (encoding, corrupt) in [
+            // reserved block type
+            (ContentEncoding::Deflate, &[0x78, 0x9c, 0xff, 0xff][..]),
+            // compression method other than deflate
+            (
+                ContentEncoding::Gzip,
+                &[0x1f, 0x8b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff]
+                    [..],
+            ),
+            // reserved frame header bit
+            (
+                ContentEncoding::Zstd,
+                &[0x28, 0xb5, 0x2f, 0xfd, 0x08, 0x00, 0x00, 0x00, 0x00][..],
+            ),
+        ] {
+            let mut writer = BytesMut::new().writer();
+            let err = decompress_single(
+                corrupt,
+                &mut writer,
+                &encoding,
+                &CodecOptions::default(),
+            )
+            .unwrap_err();
+            assert!(!err.is_truncated(), "{encoding:?}");
+        }
+    }
+
     #[test]
     fn test_basic_identity() {
         let result = test_decompress(INPUT, ContentEncoding::Identity);
@@ -175,6 +719,7 @@ pub mod tests {
             INPUT,
             &mut writer,
             &ContentEncoding::Unknown("unknown".to_string()),
+            &CodecOptions::default(),
         )
         .unwrap_err();
         if let DecompressError::Unknown(e) = result {
@@ -1,6 +1,8 @@
 use crate::decompression::{
-    dstruct::DecompressionStruct, multi::error::MultiDecompressError,
+    diagnostic::Diagnostic, dstruct::DecompressionStruct,
+    multi::error::MultiDecompressError, options::CodecOptions,
 };
+use crate::report::{EndState, LayerReport};
 use bytes::{BufMut, BytesMut, buf::Writer};
 use header_plz::body_headers::encoding_info::EncodingInfo;
 use tracing::error;
@@ -18,7 +20,7 @@ use tracing::error;
     ------------|----------
     brotli      | no error + main decompressed + extra no read
     deflate     | no error + main decompressed + extra read
-    gzip        | no error + main decompressed + extra no read
+    gzip        | error + main decompressed + extra not a member
     zstd        | error + main read + extra read
 
 3. (Main - compressed) + (Extra - compressed) = compressed separately
@@ -27,8 +29,12 @@ use tracing::error;
     ------------|----------
     brotli      | main decompressed + extra no read
     deflate     | main decompressed + extra read
-    gzip        | main decompressed + extra no read
-    zstd        | success
+    gzip        | success - extra is an additional member
+    zstd        | success - extra is an additional frame
+
+gzip (RFC 1952) and zstd (RFC 8878) streams are a series of members/frames,
+so a separately compressed extra is part of the same stream and ends in
+EndExtraMember. For brotli and deflate it ends in EndExtraMainSeparate.
 */
 
 pub enum DecompressionState<'a> {
@@ -44,6 +50,7 @@ pub enum DecompressionState<'a> {
     EndExtraRawMainDone(DecompressionStruct<'a>, BytesMut),
     EndMainPlusExtra(BytesMut),
     EndExtraMainSeparate(BytesMut, BytesMut),
+    EndExtraMember(BytesMut, BytesMut),
 }
 
 impl std::fmt::Debug for DecompressionState<'_> {
@@ -70,6 +77,9 @@ impl std::fmt::Debug for DecompressionState<'_> {
             DecompressionState::EndExtraMainSeparate(..) => {
                 write!(f, "EndExtraMainSeparate")
             }
+            DecompressionState::EndExtraMember(..) => {
+                write!(f, "EndExtraMember")
+            }
         }
     }
 }
@@ -80,8 +90,21 @@ impl<'a> DecompressionState<'a> {
         extra: Option<&'a [u8]>,
         encodings: &'a mut [EncodingInfo],
         writer: Writer<&'a mut BytesMut>,
+        diagnostics: &'a mut Vec<Diagnostic>,
+        options: CodecOptions<'a>,
     ) -> Self {
-        let dstruct = DecompressionStruct::new(main, extra, encodings, writer);
+        let dstruct = DecompressionStruct::new(
+            main,
+            extra,
+            encodings,
+            writer,
+            diagnostics,
+        )
+        .with_options(options);
+        Self::from_dstruct(dstruct)
+    }
+
+    pub fn from_dstruct(dstruct: DecompressionStruct<'a>) -> Self {
         if dstruct.extra.is_some() {
             Self::ExtraTry(dstruct)
         } else {
@@ -119,12 +142,19 @@ impl<'a> DecompressionState<'a> {
                 }
             }
             /* Main - try decompress
-             *        Ok  => EndExtraMainSeparate
+             *        Ok  => extra is a member => EndExtraMember
+             *               else              => EndExtraMainSeparate
              *        Err => ExtraPlusMainTry
              *               [ Maybe main + extra can decompress ]
              */
             DecompressionState::ExtraDoneMainTry(mut dstruct, extra) => {
                 match dstruct.try_decompress_main() {
+                    Ok(main_decompressed) if dstruct.is_extra_member() => {
+                        DecompressionState::EndExtraMember(
+                            main_decompressed,
+                            extra,
+                        )
+                    }
                     Ok(main_decompressed) => {
                         DecompressionState::EndExtraMainSeparate(
                             main_decompressed,
@@ -139,6 +169,7 @@ impl<'a> DecompressionState<'a> {
              *      Err => ExtraRawMainTry
              */
             DecompressionState::ExtraPlusMainTry(mut decompression_struct) => {
+                decompression_struct.clear_attempt();
                 match decompression_struct.try_decompress_main_plus_extra() {
                     Ok(main_plus_extra_decompressed) => {
                         DecompressionState::EndMainPlusExtra(
@@ -158,6 +189,7 @@ impl<'a> DecompressionState<'a> {
              *      Err => Err
              */
             DecompressionState::ExtraRawMainTry(mut decompression_struct) => {
+                decompression_struct.clear_attempt();
                 match decompression_struct.try_decompress_main() {
                     Ok(main_decompressed) => {
                         DecompressionState::EndExtraRawMainDone(
@@ -176,7 +208,8 @@ impl<'a> DecompressionState<'a> {
             DecompressionState::EndMainOnly(_)
             | DecompressionState::EndExtraRawMainDone(..)
             | DecompressionState::EndMainPlusExtra(_)
-            | DecompressionState::EndExtraMainSeparate(..) => {
+            | DecompressionState::EndExtraMainSeparate(..)
+            | DecompressionState::EndExtraMember(..) => {
                 panic!("already ended")
             }
         };
@@ -188,11 +221,29 @@ impl<'a> DecompressionState<'a> {
             || matches!(self, Self::EndMainPlusExtra(_))
             || matches!(self, Self::EndExtraMainSeparate(..))
             || matches!(self, Self::EndExtraRawMainDone(..))
+            || matches!(self, Self::EndExtraMember(..))
     }
 
     pub fn is_extra_raw(&self) -> bool {
         matches!(self, Self::EndExtraRawMainDone(..))
     }
+
+    pub fn is_extra_member(&self) -> bool {
+        matches!(self, Self::EndExtraMember(..))
+    }
+
+    pub fn end_state(&self) -> Option<EndState> {
+        match self {
+            Self::EndMainOnly(_) => Some(EndState::MainOnly),
+            Self::EndExtraRawMainDone(..) => Some(EndState::ExtraRawMainDone),
+            Self::EndMainPlusExtra(_) => Some(EndState::MainPlusExtra),
+            Self::EndExtraMainSeparate(..) => {
+                Some(EndState::ExtraMainSeparate)
+            }
+            Self::EndExtraMember(..) => Some(EndState::ExtraMember),
+            _ => None,
+        }
+    }
 }
 
 impl<'a> From<DecompressionState<'a>> for (BytesMut, Option<BytesMut>) {
@@ -201,7 +252,8 @@ impl<'a> From<DecompressionState<'a>> for (BytesMut, Option<BytesMut>) {
             DecompressionState::EndMainOnly(main)
             | DecompressionState::EndMainPlusExtra(main)
             | DecompressionState::EndExtraRawMainDone(_, main) => (main, None),
-            DecompressionState::EndExtraMainSeparate(main, extra) => {
+            DecompressionState::EndExtraMainSeparate(main, extra)
+            | DecompressionState::EndExtraMember(main, extra) => {
                 (main, Some(extra))
             }
             _ => unreachable!(),
@@ -214,9 +266,20 @@ pub fn decompression_runner<'a>(
     extra: Option<&'a [u8]>,
     encodings: &'a mut [EncodingInfo],
     buf: &'a mut BytesMut,
+    diagnostics: &'a mut Vec<Diagnostic>,
+    layers: &'a mut Vec<LayerReport>,
+    options: CodecOptions<'a>,
 ) -> Result<DecompressionState<'a>, MultiDecompressError> {
-    let mut state =
-        DecompressionState::start(main, extra, encodings, buf.writer());
+    let dstruct = DecompressionStruct::new(
+        main,
+        extra,
+        encodings,
+        buf.writer(),
+        diagnostics,
+    )
+    .with_options(options)
+    .with_layers(layers);
+    let mut state = DecompressionState::from_dstruct(dstruct);
     loop {
         state = state.try_next()?;
         if state.is_ended() {
@@ -240,11 +303,14 @@ mod tests {
         encoding_info: &mut [EncodingInfo],
     ) {
         let mut buf = BytesMut::new();
+        let mut diagnostics = Vec::new();
         let mut state = DecompressionState::start(
             main,
             extra,
             encoding_info,
             (&mut buf).writer(),
+            &mut diagnostics,
+            CodecOptions::default(),
         );
         assert!(matches!(state, DecompressionState::MainOnly(_)));
 
@@ -280,6 +346,23 @@ mod tests {
         assert_main_only_finish_flow(INPUT, None, &mut info);
     }
 
+    #[test]
+    fn test_state_main_only_gzip_multi_member() {
+        let mut info = vec![EncodingInfo::new(0, vec![ContentEncoding::Gzip])];
+        let mut compressed = compress_gzip(b"hello");
+        compressed.extend(compress_gzip(b" "));
+        compressed.extend(compress_gzip(b"world"));
+        assert_main_only_finish_flow(&compressed, None, &mut info);
+    }
+
+    #[test]
+    fn test_state_main_only_zstd_multi_frame() {
+        let mut info = vec![EncodingInfo::new(0, vec![ContentEncoding::Zstd])];
+        let mut compressed = compress_zstd(b"hello ");
+        compressed.extend(compress_zstd(b"world"));
+        assert_main_only_finish_flow(&compressed, None, &mut info);
+    }
+
     #[test]
     fn test_state_main_only_multi_compression_single_header() {
         let mut info = all_encoding_info_single_header();
@@ -306,11 +389,14 @@ mod tests {
         let extra_slice = &compressed[mid..];
 
         let mut buf = BytesMut::new();
+        let mut diagnostics = Vec::new();
         let mut state = DecompressionState::start(
             main_slice,
             Some(extra_slice),
             enc_info,
             (&mut buf).writer(),
+            &mut diagnostics,
+            CodecOptions::default(),
         );
 
         state = state.try_next().unwrap();
@@ -352,13 +438,17 @@ mod tests {
         enc_info: &mut [EncodingInfo],
         main: &[u8],
         extra: &[u8],
+        is_member: bool,
     ) {
         let mut buf = BytesMut::new();
+        let mut diagnostics = Vec::new();
         let mut state = DecompressionState::start(
             main,
             Some(extra),
             enc_info,
             (&mut buf).writer(),
+            &mut diagnostics,
+            CodecOptions::default(),
         );
         state = state.try_next().unwrap();
         assert!(
@@ -367,9 +457,11 @@ mod tests {
 
         state = state.try_next().unwrap();
         assert!(state.is_ended());
+        assert_eq!(state.is_extra_member(), is_member);
 
         match state {
-            DecompressionState::EndExtraMainSeparate(main, extra) => {
+            DecompressionState::EndExtraMainSeparate(main, extra)
+            | DecompressionState::EndExtraMember(main, extra) => {
                 assert_eq!(main, INPUT);
                 assert_eq!(extra, INPUT);
             }
@@ -383,7 +475,29 @@ mod tests {
             vec![EncodingInfo::new(0, vec![ContentEncoding::Brotli])];
         let main = compress_brotli(INPUT);
         let extra = main.clone();
-        assert_main_separate_extra_separate_flow(&mut info, &main, &extra);
+        assert_main_separate_extra_separate_flow(
+            &mut info, &main, &extra, false,
+        );
+    }
+
+    #[test]
+    fn test_state_main_separate_extra_member_gzip() {
+        let mut info = vec![EncodingInfo::new(0, vec![ContentEncoding::Gzip])];
+        let main = compress_gzip(INPUT);
+        let extra = main.clone();
+        assert_main_separate_extra_separate_flow(
+            &mut info, &main, &extra, true,
+        );
+    }
+
+    #[test]
+    fn test_state_main_separate_extra_member_zstd() {
+        let mut info = vec![EncodingInfo::new(0, vec![ContentEncoding::Zstd])];
+        let main = compress_zstd(INPUT);
+        let extra = main.clone();
+        assert_main_separate_extra_separate_flow(
+            &mut info, &main, &extra, true,
+        );
     }
 
     #[test]
@@ -391,7 +505,9 @@ mod tests {
         let mut info = all_encoding_info_single_header();
         let main = all_compressed_data();
         let extra = main.clone();
-        assert_main_separate_extra_separate_flow(&mut info, &main, &extra);
+        assert_main_separate_extra_separate_flow(
+            &mut info, &main, &extra, true,
+        );
     }
 
     #[test]
@@ -399,7 +515,9 @@ mod tests {
         let mut info = all_encoding_info_multi_header();
         let main = all_compressed_data();
         let extra = main.clone();
-        assert_main_separate_extra_separate_flow(&mut info, &main, &extra);
+        assert_main_separate_extra_separate_flow(
+            &mut info, &main, &extra, true,
+        );
     }
 
     // Main - compressed + Extra - raw
@@ -408,11 +526,14 @@ mod tests {
         main: &[u8],
     ) {
         let mut buf = BytesMut::new();
+        let mut diagnostics = Vec::new();
         let mut state = DecompressionState::start(
             main,
             Some(b"extra"),
             enc_info,
             (&mut buf).writer(),
+            &mut diagnostics,
+            CodecOptions::default(),
         );
         state = state.try_next().unwrap();
         assert!(matches!(state, DecompressionState::ExtraPlusMainTry(_)));
@@ -488,8 +609,14 @@ mod tests {
     #[test]
     fn test_state_to_bytes_end_extra_raw_main_done() {
         let mut buf = BytesMut::new();
-        let dstruct =
-            DecompressionStruct::new(&[], None, &mut [], (&mut buf).writer());
+        let mut diagnostics = Vec::new();
+        let dstruct = DecompressionStruct::new(
+            &[],
+            None,
+            &mut [],
+            (&mut buf).writer(),
+            &mut diagnostics,
+        );
         let state = DecompressionState::EndExtraRawMainDone(
             dstruct,
             BytesMut::from(INPUT),
@@ -499,6 +626,17 @@ mod tests {
         assert!(extra.is_none());
     }
 
+    #[test]
+    fn test_state_to_bytes_end_extra_member() {
+        let state = DecompressionState::EndExtraMember(
+            BytesMut::from(INPUT),
+            BytesMut::from(INPUT),
+        );
+        let (main, extra) = state.into();
+        assert_eq!(main, INPUT);
+        assert_eq!(extra.unwrap(), INPUT);
+    }
+
     #[test]
     fn test_state_to_bytes_end_extra_main_separate() {
         let state = DecompressionState::EndExtraMainSeparate(
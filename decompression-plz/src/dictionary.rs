@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+
+use header_plz::body_headers::dictionary::DictionaryHash;
+use sha2::{Digest, Sha256};
+
+/* Compression Dictionary Transport - RFC 9842
+ *
+ * dcb - FF 44 43 42 + 32 byte SHA-256 of dictionary + brotli stream
+ * dcz - 5E 2A 4D 18 20 00 00 00 + 32 byte SHA-256 of dictionary + zstd
+ *       frame
+ */
+pub const DCB_MAGIC: [u8; 4] = [0xff, 0x44, 0x43, 0x42];
+pub const DCZ_MAGIC: [u8; 8] =
+    [0x5e, 0x2a, 0x4d, 0x18, 0x20, 0x00, 0x00, 0x00];
+
+/// Resolve a shared dictionary from the hash carried in a dcb/dcz header.
+pub trait DictionaryLookup {
+    fn lookup(&self, hash: &DictionaryHash) -> Option<&[u8]>;
+}
+
+impl DictionaryLookup for HashMap<DictionaryHash, Vec<u8>> {
+    fn lookup(&self, hash: &DictionaryHash) -> Option<&[u8]> {
+        self.get(hash).map(Vec::as_slice)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct DictionaryStore {
+    dictionaries: HashMap<DictionaryHash, Vec<u8>>,
+}
+
+impl DictionaryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Store the dictionary keyed by its SHA-256 hash
+    pub fn insert(&mut self, dictionary: Vec<u8>) -> DictionaryHash {
+        let hash = dictionary_hash(&dictionary);
+        self.dictionaries.insert(hash, dictionary);
+        hash
+    }
+
+    pub fn remove(&mut self, hash: &DictionaryHash) -> Option<Vec<u8>> {
+        self.dictionaries.remove(hash)
+    }
+
+    pub fn len(&self) -> usize {
+        self.dictionaries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.dictionaries.is_empty()
+    }
+}
+
+impl DictionaryLookup for DictionaryStore {
+    fn lookup(&self, hash: &DictionaryHash) -> Option<&[u8]> {
+        self.dictionaries.lookup(hash)
+    }
+}
+
+pub fn dictionary_hash(dictionary: &[u8]) -> DictionaryHash {
+    DictionaryHash(Sha256::digest(dictionary).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dictionary_hash() {
+        // sha256("hello world")
+        let hash = dictionary_hash(b"hello world");
+        assert_eq!(
+            hash.to_string(),
+            ":uU0nuZNNPgilLlLX2n2r+sSE7+N6U4DukIj3rOLvzek=:"
+        );
+    }
+
+    #[test]
+    fn test_dictionary_store() {
+        let mut store = DictionaryStore::new();
+        assert!(store.is_empty());
+        let hash = store.insert(b"dictionary".to_vec());
+        assert_eq!(store.len(), 1);
+        assert_eq!(store.lookup(&hash), Some(&b"dictionary"[..]));
+        assert!(store.lookup(&dictionary_hash(b"other")).is_none());
+        assert_eq!(store.remove(&hash), Some(b"dictionary".to_vec()));
+        assert!(store.lookup(&hash).is_none());
+    }
+}
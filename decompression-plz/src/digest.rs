@@ -0,0 +1,300 @@
+use header_plz::{
+    body_headers::digest::{Digest, DigestAlgorithm, DigestField, Digests},
+    const_headers::CONTENT_RANGE,
+    message_head::header_map::{HMap, Hmap},
+};
+use md5::Md5;
+use sha1::Sha1;
+use sha2::{Sha256, Sha512};
+
+use crate::DecompressTrait;
+
+/* Integrity fields - Content-Digest, Repr-Digest and legacy Digest,
+ * Content-MD5.
+ *
+ * All of them are computed over the content with the content codings applied
+ * and the transfer codings removed, ie. the encoded representation before
+ * decompress and the decoded one after the content codings were removed.
+ * Repr-Digest and Digest cover the complete representation and cannot be
+ * checked against a partial (Content-Range) body.
+ */
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DigestStatus {
+    Match,
+    Mismatch,
+    // algorithm not implemented
+    Unsupported,
+    // representation digest of a partial body
+    Unverifiable,
+    // header value could not be parsed
+    Invalid,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DigestCheck {
+    pub field: DigestField,
+    // None when the header is Invalid
+    pub algorithm: Option<DigestAlgorithm>,
+    pub status: DigestStatus,
+}
+
+pub fn is_supported(algorithm: &DigestAlgorithm) -> bool {
+    matches!(
+        algorithm,
+        DigestAlgorithm::Sha256
+            | DigestAlgorithm::Sha512
+            | DigestAlgorithm::Md5
+            | DigestAlgorithm::Sha
+    )
+}
+
+fn hash<D>(parts: &[&[u8]]) -> Vec<u8>
+where
+    D: sha2::Digest,
+{
+    let mut hasher = D::new();
+    parts.iter().for_each(|part| hasher.update(part));
+    hasher.finalize().to_vec()
+}
+
+// digest of the concatenated parts, None for unsupported algorithms
+pub fn compute_digest(
+    algorithm: &DigestAlgorithm,
+    parts: &[&[u8]],
+) -> Option<Vec<u8>> {
+    match algorithm {
+        DigestAlgorithm::Sha256 => Some(hash::<Sha256>(parts)),
+        DigestAlgorithm::Sha512 => Some(hash::<Sha512>(parts)),
+        DigestAlgorithm::Md5 => Some(hash::<Md5>(parts)),
+        DigestAlgorithm::Sha => Some(hash::<Sha1>(parts)),
+        _ => None,
+    }
+}
+
+fn is_partial<T>(header_map: &HMap<T>) -> bool
+where
+    T: Hmap,
+{
+    header_map.header_key_position(CONTENT_RANGE).is_some()
+}
+
+// Check every integrity field of header_map against body
+pub fn verify_digests<T>(header_map: &HMap<T>, body: &[u8]) -> Vec<DigestCheck>
+where
+    T: Hmap,
+{
+    verify_digests_parts(header_map, &[body])
+}
+
+pub(crate) fn verify_digests_parts<T>(
+    header_map: &HMap<T>,
+    parts: &[&[u8]],
+) -> Vec<DigestCheck>
+where
+    T: Hmap,
+{
+    let partial = is_partial(header_map);
+    let mut checks = Vec::new();
+    for field in DigestField::ALL {
+        let Some(result) = Digests::from_header_map(header_map, field) else {
+            continue;
+        };
+        let Ok(digests) = result else {
+            checks.push(DigestCheck {
+                field,
+                algorithm: None,
+                status: DigestStatus::Invalid,
+            });
+            continue;
+        };
+        for Digest {
+            algorithm,
+            value,
+        } in digests.0
+        {
+            let status = if partial && field.is_representation() {
+                DigestStatus::Unverifiable
+            } else {
+                match compute_digest(&algorithm, parts) {
+                    Some(computed) if computed == value => DigestStatus::Match,
+                    Some(_) => DigestStatus::Mismatch,
+                    None => DigestStatus::Unsupported,
+                }
+            };
+            checks.push(DigestCheck {
+                field,
+                algorithm: Some(algorithm),
+                status,
+            });
+        }
+    }
+    checks
+}
+
+/* Recompute the integrity fields of the message for body, eg. after the body
+ * was edited or decoded. The algorithms of each header are kept, unsupported
+ * ones are dropped and a header left without digest is removed. Invalid
+ * headers and representation digests of a partial body are left as is.
+ */
+pub fn update_digests<T>(message: &mut T, body: &[u8])
+where
+    T: DecompressTrait,
+{
+    if is_partial(message.header_map()) {
+        update_fields(message, body, |field| !field.is_representation())
+    } else {
+        update_fields(message, body, |_| true)
+    }
+}
+
+fn update_fields<T, F>(message: &mut T, body: &[u8], should_update: F)
+where
+    T: DecompressTrait,
+    F: Fn(&DigestField) -> bool,
+{
+    for field in DigestField::ALL.iter().filter(|field| should_update(field)) {
+        let Some(pos) = message.header_map().header_key_position(field.key())
+        else {
+            continue;
+        };
+        let Some(Ok(digests)) =
+            Digests::from_header_map(message.header_map(), *field)
+        else {
+            continue;
+        };
+        let updated: Vec<Digest> = digests
+            .0
+            .into_iter()
+            .filter_map(|digest| {
+                compute_digest(&digest.algorithm, &[body])
+                    .map(|value| Digest::new(digest.algorithm, value))
+            })
+            .collect();
+        if updated.is_empty() {
+            message.remove_header_on_position(pos);
+        } else {
+            let value = field.serialize(&Digests(updated));
+            message.update_header_value_on_position(pos, &value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use header_plz::OneHeaderMap;
+
+    // RFC 9530 B.1
+    const BODY: &[u8] = b"{\"hello\": \"world\"}";
+    const SHA256_B64: &str = "X48E9qOokqqrvdts8nOJRJN3OWDUoyWxBf7kbu9DBPE=";
+    const SHA512_B64: &str = "WZDPaVn/7XgHaAy8pmojAkGWoRx2UFChF41A2svX+TaPm+AbwAgBWnrIiYllu7BNNyealdVLvRwEmTHWXvJwew==";
+
+    fn header_map(headers: &str) -> OneHeaderMap {
+        OneHeaderMap::from(bytes::BytesMut::from(headers))
+    }
+
+    fn check(
+        field: DigestField,
+        algorithm: DigestAlgorithm,
+        status: DigestStatus,
+    ) -> DigestCheck {
+        DigestCheck {
+            field,
+            algorithm: Some(algorithm),
+            status,
+        }
+    }
+
+    #[test]
+    fn test_compute_digest_rfc_vectors() {
+        let sha256 = Digests::parse(format!("a=:{SHA256_B64}:").as_bytes())
+            .unwrap()
+            .0
+            .remove(0)
+            .value;
+        let sha512 = Digests::parse(format!("a=:{SHA512_B64}:").as_bytes())
+            .unwrap()
+            .0
+            .remove(0)
+            .value;
+        assert_eq!(
+            compute_digest(&DigestAlgorithm::Sha256, &[BODY]),
+            Some(sha256)
+        );
+        assert_eq!(
+            compute_digest(
+                &DigestAlgorithm::Sha512,
+                &[&BODY[..5], &BODY[5..]]
+            ),
+            Some(sha512)
+        );
+        assert!(compute_digest(&DigestAlgorithm::Unixsum, &[BODY]).is_none());
+    }
+
+    #[test]
+    fn test_verify_digests() {
+        let headers = format!(
+            "Content-Digest: sha-256=:{SHA256_B64}:, sha-512=:AAAA:\r\n\
+            Repr-Digest: unixsum=:AAAA:\r\n\
+            Digest: SHA-256={SHA256_B64}\r\n\
+            Content-MD5: invalid\r\n\r\n"
+        );
+        let checks = verify_digests(&header_map(&headers), BODY);
+        assert_eq!(
+            checks,
+            vec![
+                check(
+                    DigestField::ContentDigest,
+                    DigestAlgorithm::Sha256,
+                    DigestStatus::Match
+                ),
+                check(
+                    DigestField::ContentDigest,
+                    DigestAlgorithm::Sha512,
+                    DigestStatus::Mismatch
+                ),
+                check(
+                    DigestField::ReprDigest,
+                    DigestAlgorithm::Unixsum,
+                    DigestStatus::Unsupported
+                ),
+                check(
+                    DigestField::Digest,
+                    DigestAlgorithm::Sha256,
+                    DigestStatus::Match
+                ),
+                DigestCheck {
+                    field: DigestField::ContentMd5,
+                    algorithm: None,
+                    status: DigestStatus::Invalid,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_verify_digests_partial() {
+        let headers = format!(
+            "Content-Range: bytes 0-17/100\r\n\
+            Content-Digest: sha-256=:{SHA256_B64}:\r\n\
+            Repr-Digest: sha-256=:{SHA256_B64}:\r\n\r\n"
+        );
+        let checks = verify_digests(&header_map(&headers), BODY);
+        assert_eq!(
+            checks,
+            vec![
+                check(
+                    DigestField::ContentDigest,
+                    DigestAlgorithm::Sha256,
+                    DigestStatus::Match
+                ),
+                check(
+                    DigestField::ReprDigest,
+                    DigestAlgorithm::Sha256,
+                    DigestStatus::Unverifiable
+                ),
+            ]
+        );
+    }
+}
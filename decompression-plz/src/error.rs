@@ -0,0 +1,214 @@
+use bytes::BytesMut;
+use header_plz::body_headers::content_encoding::ContentEncoding;
+use thiserror::Error;
+
+use crate::decompression::single::error::DecompressError;
+
+/* Error returned by decompress
+ *      header_index    - position of the header that declared the failing
+ *                        coding
+ *      encoding        - failing coding
+ *      source          - error returned by the codec
+ *      original        - main and extra body before the codings of the
+ *                        header kind (TE or CE) were applied, for recovery
+ *
+ * Corrupt - the first coding failed, the message body is left as is.
+ * Partial - some codings were applied, the message body is set to
+ *           partial_body and the header keeps the codings left to decode.
+ * Unlocated - the failing coding is not one of the declared codings, the
+ *           message body and headers are left as is.
+ */
+#[derive(Debug, Error)]
+pub enum DecodeError {
+    #[error("corrupt| {}| header {header_index}| {source}", encoding.as_ref())]
+    Corrupt {
+        header_index: usize,
+        encoding: ContentEncoding,
+        source: DecompressError,
+        original: Box<OriginalBody>,
+    },
+    #[error("partial| {}| header {header_index}| {source}", encoding.as_ref())]
+    Partial {
+        header_index: usize,
+        encoding: ContentEncoding,
+        source: DecompressError,
+        partial_body: BytesMut,
+        is_extra_raw: bool,
+        original: Box<OriginalBody>,
+    },
+    #[error("unlocated| {source}")]
+    Unlocated {
+        source: DecompressError,
+        original: Box<OriginalBody>,
+    },
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct OriginalBody {
+    pub body: BytesMut,
+    pub extra: Option<BytesMut>,
+}
+
+impl DecodeError {
+    pub fn is_corrupt(&self) -> bool {
+        matches!(self, Self::Corrupt { .. })
+    }
+
+    pub fn is_partial(&self) -> bool {
+        matches!(self, Self::Partial { .. })
+    }
+
+    pub fn header_index(&self) -> Option<usize> {
+        match self {
+            Self::Corrupt {
+                header_index,
+                ..
+            }
+            | Self::Partial {
+                header_index,
+                ..
+            } => Some(*header_index),
+            Self::Unlocated {
+                ..
+            } => None,
+        }
+    }
+
+    pub fn encoding(&self) -> Option<&ContentEncoding> {
+        match self {
+            Self::Corrupt {
+                encoding,
+                ..
+            }
+            | Self::Partial {
+                encoding,
+                ..
+            } => Some(encoding),
+            Self::Unlocated {
+                ..
+            } => None,
+        }
+    }
+
+    pub fn codec_error(&self) -> &DecompressError {
+        match self {
+            Self::Corrupt {
+                source,
+                ..
+            }
+            | Self::Partial {
+                source,
+                ..
+            }
+            | Self::Unlocated {
+                source,
+                ..
+            } => source,
+        }
+    }
+
+    pub fn partial_body(&self) -> Option<&BytesMut> {
+        match self {
+            Self::Corrupt {
+                ..
+            }
+            | Self::Unlocated {
+                ..
+            } => None,
+            Self::Partial {
+                partial_body,
+                ..
+            } => Some(partial_body),
+        }
+    }
+
+    pub fn original(&self) -> &BytesMut {
+        match self {
+            Self::Corrupt {
+                original,
+                ..
+            }
+            | Self::Partial {
+                original,
+                ..
+            }
+            | Self::Unlocated {
+                original,
+                ..
+            } => &original.body,
+        }
+    }
+
+    pub fn original_extra(&self) -> Option<&BytesMut> {
+        match self {
+            Self::Corrupt {
+                original,
+                ..
+            }
+            | Self::Partial {
+                original,
+                ..
+            }
+            | Self::Unlocated {
+                original,
+                ..
+            } => original.extra.as_ref(),
+        }
+    }
+}
+
+#[cfg(test)]
+impl PartialEq for DecodeError {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+            && self.partial_body() == other.partial_body()
+            && self.original() == other.original()
+            && self.original_extra() == other.original_extra()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn partial() -> DecodeError {
+        DecodeError::Partial {
+            header_index: 3,
+            encoding: ContentEncoding::Gzip,
+            source: DecompressError::corrupt(&ContentEncoding::Gzip),
+            partial_body: "partial".into(),
+            is_extra_raw: false,
+            original: Box::new(OriginalBody {
+                body: "original".into(),
+                extra: None,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_decode_error_accessors() {
+        let err = partial();
+        assert!(err.is_partial());
+        assert!(!err.is_corrupt());
+        assert_eq!(err.header_index(), Some(3));
+        assert_eq!(err.encoding(), Some(&ContentEncoding::Gzip));
+        assert!(matches!(err.codec_error(), DecompressError::Gzip(_)));
+        assert_eq!(err.partial_body().unwrap(), "partial");
+        assert_eq!(err.original(), "original");
+        assert!(err.original_extra().is_none());
+    }
+
+    #[test]
+    fn test_decode_error_display() {
+        assert_eq!(
+            partial().to_string(),
+            "partial| gzip| header 3| gzip| invalid data"
+        );
+    }
+
+    #[test]
+    fn test_decode_error_source() {
+        use std::error::Error;
+        assert!(partial().source().is_some());
+    }
+}
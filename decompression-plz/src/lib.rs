@@ -1,32 +1,128 @@
+use body_plz::variants::Body;
 use bytes::BytesMut;
 
 use crate::{
-    chunked::ChunkedConverter, decode_struct::DecodeStruct, state::DecodeState,
+    chunked::ChunkedConverter, decode_struct::DecodeStruct,
+    dictionary::DictionaryLookup, state::DecodeState,
 };
 pub mod chunked;
 pub mod content_length;
-pub use decompression::multi::error::MultiDecompressErrorReason;
+pub mod data_frames;
+pub mod decode_options;
+pub use decode_options::{DecodeLayers, DecodeOptions, TrailerPolicy};
+pub use decompression::diagnostic::{DeflateVariant, Diagnostic};
+pub use decompression::single::error::DecompressError;
+pub mod error;
+pub use decompression::options::CodecOptions;
+pub use error::{DecodeError, OriginalBody};
 pub mod decode_struct;
 mod decompress_trait;
 mod decompression;
+pub mod dictionary;
+pub mod digest;
+pub mod negotiate;
 pub use decompress_trait::DecompressTrait;
+pub use digest::{DigestCheck, DigestStatus};
+pub use negotiate::{negotiate_encoding, negotiate_encoding_for};
+pub mod report;
+pub use report::{DecodeReport, EncodingReport, EndState, LayerReport};
 pub mod state;
 
 pub fn decompress<'a, T>(
     message: &'a mut T,
     buf: &'a mut BytesMut,
-) -> Result<(), MultiDecompressErrorReason>
+) -> Result<(), DecodeError>
 where
     T: DecompressTrait + std::fmt::Debug + 'a,
     DecodeStruct<'a, T>: ChunkedConverter<T::HmapType>,
 {
-    let mut state = DecodeState::init(message, buf);
+    decompress_with_options(
+        message,
+        buf,
+        CodecOptions::default(),
+        &mut Vec::new(),
+    )
+}
+
+// dcb/dcz bodies are decoded with the dictionary found in `dictionaries`
+pub fn decompress_with_dictionaries<'a, T>(
+    message: &'a mut T,
+    buf: &'a mut BytesMut,
+    dictionaries: &'a dyn DictionaryLookup,
+) -> Result<(), DecodeError>
+where
+    T: DecompressTrait + std::fmt::Debug + 'a,
+    DecodeStruct<'a, T>: ChunkedConverter<T::HmapType>,
+{
+    let options = CodecOptions::default().with_dictionaries(dictionaries);
+    decompress_with_options(message, buf, options, &mut Vec::new())
+}
+
+/* Diagnostics are collected even when an error is returned, eg. an
+ * EncodingMismatch with corrected false for a body that the detected coding
+ * could not decode either.
+ */
+pub fn decompress_with_options<'a, T>(
+    message: &'a mut T,
+    buf: &'a mut BytesMut,
+    options: impl Into<DecodeOptions<'a>>,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<(), DecodeError>
+where
+    T: DecompressTrait + std::fmt::Debug + 'a,
+    DecodeStruct<'a, T>: ChunkedConverter<T::HmapType>,
+{
+    let (result, mut report) = decompress_with_report(message, buf, options);
+    diagnostics.append(&mut report.diagnostics);
+    result
+}
+
+// The report describes what was done to the message, even on error
+pub fn decompress_with_report<'a, T>(
+    message: &'a mut T,
+    buf: &'a mut BytesMut,
+    options: impl Into<DecodeOptions<'a>>,
+) -> (Result<(), DecodeError>, DecodeReport)
+where
+    T: DecompressTrait + std::fmt::Debug + 'a,
+    DecodeStruct<'a, T>: ChunkedConverter<T::HmapType>,
+{
+    let mut report = DecodeReport::default();
+    let mut state =
+        DecodeState::init_with_options(message, buf, options.into());
     loop {
-        state = state.try_next()?;
+        report.append(&mut state.take_report());
+        state = match state.try_next() {
+            Ok(state) => state,
+            Err(e) => return (Err(e), report),
+        };
         if state.is_ended() {
             break;
         }
     }
 
-    Ok(())
+    (Ok(()), report)
+}
+
+/* Decode a copy of the message, so that the original stays byte exact for
+ * forwarding. Returns the decoded body, header related options have no
+ * effect on it. The report describes the decoding, even on error.
+ */
+pub fn preview<'a, T>(
+    message: &T,
+    options: impl Into<DecodeOptions<'a>>,
+) -> (Result<BytesMut, DecodeError>, DecodeReport)
+where
+    T: DecompressTrait + Clone + std::fmt::Debug,
+    for<'b> DecodeStruct<'b, T>: ChunkedConverter<T::HmapType>,
+{
+    let options: DecodeOptions = options.into();
+    let mut message = message.clone();
+    let mut buf = BytesMut::new();
+    let (result, report) =
+        decompress_with_report(&mut message, &mut buf, options);
+    let result = result.map(|_| {
+        message.take_body().and_then(Body::into_bytes).unwrap_or_default()
+    });
+    (result, report)
 }
@@ -0,0 +1,122 @@
+use header_plz::body_headers::content_encoding::ContentEncoding;
+use header_plz::message_head::header_map::{HMap, Hmap};
+use header_plz::typed_header::TypedHeader;
+use header_plz::typed_header::accept::AcceptEncoding;
+
+/* Content codings this crate decodes without extra input, in order of
+ * preference. compress is left out as it is decoded as zstd.
+ */
+pub const DECODABLE_ENCODINGS: [ContentEncoding; 5] = [
+    ContentEncoding::Zstd,
+    ContentEncoding::Brotli,
+    ContentEncoding::Gzip,
+    ContentEncoding::Deflate,
+    ContentEncoding::Identity,
+];
+
+// need a shared dictionary, see DictionaryLookup
+pub const DICTIONARY_ENCODINGS: [ContentEncoding; 2] =
+    [ContentEncoding::DictionaryZstd, ContentEncoding::DictionaryBrotli];
+
+/* Best coding for a response to a request with accept, among the ones that
+ * can be decoded. dcz and dcb are preferred when dictionary is true, ie. a
+ * dictionary was offered with Available-Dictionary and is known.
+ */
+pub fn negotiate_encoding(
+    accept: &AcceptEncoding,
+    dictionary: bool,
+) -> Option<ContentEncoding> {
+    if dictionary
+        && let Some(encoding) = accept.negotiate(&DICTIONARY_ENCODINGS)
+    {
+        return Some(encoding.clone());
+    }
+    accept.negotiate(&DECODABLE_ENCODINGS).cloned()
+}
+
+/* Accept-Encoding of a request header map. An absent field accepts any
+ * coding, an invalid field is treated as empty, ie. identity only.
+ */
+pub fn negotiate_encoding_for<T>(
+    header_map: &HMap<T>,
+    dictionary: bool,
+) -> Option<ContentEncoding>
+where
+    T: Hmap,
+{
+    let accept = match AcceptEncoding::decode(header_map) {
+        Some(accept) => accept.unwrap_or_default(),
+        None => AcceptEncoding::any(),
+    };
+    negotiate_encoding(&accept, dictionary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::BytesMut;
+    use header_plz::OneHeaderMap;
+
+    fn accept(value: &str) -> AcceptEncoding {
+        AcceptEncoding::parse(value.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn test_negotiate_encoding() {
+        let ae = accept("gzip, deflate, br");
+        assert_eq!(
+            negotiate_encoding(&ae, false),
+            Some(ContentEncoding::Brotli)
+        );
+        let ae = accept("gzip;q=1, br;q=0.5");
+        assert_eq!(
+            negotiate_encoding(&ae, false),
+            Some(ContentEncoding::Gzip)
+        );
+        let ae = accept("compress");
+        assert_eq!(
+            negotiate_encoding(&ae, false),
+            Some(ContentEncoding::Identity)
+        );
+        let ae = accept("compress, identity;q=0");
+        assert_eq!(negotiate_encoding(&ae, false), None);
+    }
+
+    #[test]
+    fn test_negotiate_encoding_dictionary() {
+        let ae = accept("gzip, br, zstd, dcb, dcz");
+        assert_eq!(
+            negotiate_encoding(&ae, true),
+            Some(ContentEncoding::DictionaryZstd)
+        );
+        assert_eq!(
+            negotiate_encoding(&ae, false),
+            Some(ContentEncoding::Zstd)
+        );
+        let ae = accept("gzip");
+        assert_eq!(negotiate_encoding(&ae, true), Some(ContentEncoding::Gzip));
+    }
+
+    #[test]
+    fn test_negotiate_encoding_for_header_map() {
+        let map = |input: &str| OneHeaderMap::from(BytesMut::from(input));
+        assert_eq!(
+            negotiate_encoding_for(&map("Host: a\r\n\r\n"), false),
+            Some(ContentEncoding::Zstd)
+        );
+        assert_eq!(
+            negotiate_encoding_for(
+                &map("Accept-Encoding: gzip;q=0.5, deflate\r\n\r\n"),
+                false
+            ),
+            Some(ContentEncoding::Deflate)
+        );
+        assert_eq!(
+            negotiate_encoding_for(
+                &map("Accept-Encoding: gzip;q=5\r\n\r\n"),
+                false
+            ),
+            Some(ContentEncoding::Identity)
+        );
+    }
+}
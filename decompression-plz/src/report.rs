@@ -0,0 +1,212 @@
+use header_plz::body_headers::content_encoding::ContentEncoding;
+
+use crate::decompression::diagnostic::Diagnostic;
+use crate::digest::DigestCheck;
+
+/* What decompress did to a message
+ *      chunked             - chunked body was converted to raw
+ *      transfer_encoding   - decoding of the Transfer-Encoding codings
+ *      content_encoding    - decoding of the Content-Encoding codings
+ *      removed_headers     - positions of the TE / CE headers removed
+ *      rewritten_headers   - positions of the TE / CE headers whose value
+ *                            was rewritten to the codings left to decode
+ *      diagnostics         - see Diagnostic
+ *      digests             - integrity fields checked against the body, only
+ *                            with DecodeOptions::verify_digests
+ *
+ * Header positions refer to the header map before decoding.
+ */
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DecodeReport {
+    pub chunked: bool,
+    pub transfer_encoding: Option<EncodingReport>,
+    pub content_encoding: Option<EncodingReport>,
+    pub removed_headers: Vec<usize>,
+    pub rewritten_headers: Vec<usize>,
+    pub diagnostics: Vec<Diagnostic>,
+    pub digests: Vec<DigestCheck>,
+}
+
+impl DecodeReport {
+    // codings applied in decode order, transfer codings first
+    pub fn applied_encodings(&self) -> impl Iterator<Item = &ContentEncoding> {
+        self.transfer_encoding
+            .iter()
+            .chain(self.content_encoding.iter())
+            .flat_map(|report| report.layers.iter())
+            .map(|layer| &layer.encoding)
+    }
+
+    // Move everything recorded in other into self
+    pub fn append(&mut self, other: &mut DecodeReport) {
+        self.chunked |= other.chunked;
+        if other.transfer_encoding.is_some() {
+            self.transfer_encoding = other.transfer_encoding.take();
+        }
+        if other.content_encoding.is_some() {
+            self.content_encoding = other.content_encoding.take();
+        }
+        self.removed_headers.append(&mut other.removed_headers);
+        self.rewritten_headers.append(&mut other.rewritten_headers);
+        self.diagnostics.append(&mut other.diagnostics);
+        self.digests.append(&mut other.digests);
+    }
+}
+
+/* Decoding of the codings of one header kind (TE or CE)
+ *      layers          - codings applied, in decode order. On error only the
+ *                        codings applied before the failing one.
+ *      end_state       - None when decoding failed
+ *      is_extra_raw    - extra body was left as is
+ */
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct EncodingReport {
+    pub layers: Vec<LayerReport>,
+    pub end_state: Option<EndState>,
+    pub is_extra_raw: bool,
+}
+
+/* One decoded coding
+ *      encoding        - coding applied, differs from the declared one when
+ *                        corrected by sniffing
+ *      size_before     - size of the input
+ *      size_after      - size of the output
+ *
+ * When main and extra body are decoded separately the sizes are the sum of
+ * both.
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LayerReport {
+    pub header_index: usize,
+    pub encoding: ContentEncoding,
+    pub size_before: usize,
+    pub size_after: usize,
+}
+
+impl LayerReport {
+    fn is_same_layer(&self, other: &LayerReport) -> bool {
+        self.header_index == other.header_index
+            && self.encoding == other.encoding
+    }
+}
+
+// Add the layers of a separately decoded body to the existing ones
+pub(crate) fn merge_layers(
+    layers: &mut Vec<LayerReport>,
+    new: Vec<LayerReport>,
+) {
+    for (index, layer) in new.into_iter().enumerate() {
+        match layers.get_mut(index) {
+            Some(existing) if existing.is_same_layer(&layer) => {
+                existing.size_before += layer.size_before;
+                existing.size_after += layer.size_after;
+            }
+            _ => layers.push(layer),
+        }
+    }
+}
+
+// End state of the decompression state machine, see DecompressionState
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EndState {
+    // no extra body
+    MainOnly,
+    // main decoded, extra left raw
+    ExtraRawMainDone,
+    // main and extra compressed together
+    MainPlusExtra,
+    // main and extra compressed separately
+    ExtraMainSeparate,
+    // extra is another gzip member / zstd frame of main
+    ExtraMember,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layer(header_index: usize, encoding: ContentEncoding) -> LayerReport {
+        LayerReport {
+            header_index,
+            encoding,
+            size_before: 10,
+            size_after: 20,
+        }
+    }
+
+    #[test]
+    fn test_merge_layers() {
+        let mut layers = vec![layer(0, ContentEncoding::Gzip)];
+        merge_layers(
+            &mut layers,
+            vec![
+                layer(0, ContentEncoding::Gzip),
+                layer(1, ContentEncoding::Brotli),
+            ],
+        );
+        assert_eq!(
+            layers,
+            vec![
+                LayerReport {
+                    header_index: 0,
+                    encoding: ContentEncoding::Gzip,
+                    size_before: 20,
+                    size_after: 40,
+                },
+                layer(1, ContentEncoding::Brotli),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_merge_layers_empty() {
+        let mut layers = Vec::new();
+        merge_layers(&mut layers, vec![layer(0, ContentEncoding::Gzip)]);
+        assert_eq!(layers, vec![layer(0, ContentEncoding::Gzip)]);
+    }
+
+    #[test]
+    fn test_report_append() {
+        let mut report = DecodeReport {
+            chunked: true,
+            removed_headers: vec![1],
+            ..Default::default()
+        };
+        let mut other = DecodeReport {
+            content_encoding: Some(EncodingReport::default()),
+            removed_headers: vec![2],
+            ..Default::default()
+        };
+        report.append(&mut other);
+        assert!(report.chunked);
+        assert!(report.content_encoding.is_some());
+        assert_eq!(report.removed_headers, vec![1, 2]);
+        assert_eq!(other, DecodeReport::default());
+    }
+
+    #[test]
+    fn test_report_applied_encodings() {
+        let report = DecodeReport {
+            transfer_encoding: Some(EncodingReport {
+                layers: vec![layer(1, ContentEncoding::Deflate)],
+                ..Default::default()
+            }),
+            content_encoding: Some(EncodingReport {
+                layers: vec![
+                    layer(2, ContentEncoding::Gzip),
+                    layer(2, ContentEncoding::Brotli),
+                ],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(
+            report.applied_encodings().collect::<Vec<_>>(),
+            vec![
+                &ContentEncoding::Deflate,
+                &ContentEncoding::Gzip,
+                &ContentEncoding::Brotli
+            ]
+        );
+    }
+}
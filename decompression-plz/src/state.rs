@@ -6,11 +6,16 @@ use header_plz::body_headers::{
 
 use crate::{
     chunked::ChunkedConverter,
+    decode_options::DecodeOptions,
     decode_struct::DecodeStruct,
     decompress_trait::DecompressTrait,
     decompression::{
-        multi::error::MultiDecompressErrorReason, state::decompression_runner,
+        diagnostic::Diagnostic,
+        multi::error::{MultiDecompressError, MultiDecompressErrorReason},
+        state::decompression_runner,
     },
+    error::{DecodeError, OriginalBody},
+    report::{DecodeReport, EncodingReport},
 };
 
 #[cfg_attr(test, derive(PartialEq))]
@@ -20,7 +25,7 @@ pub enum DecodeState<'a, T> {
     TransferEncoding(DecodeStruct<'a, T>, Vec<EncodingInfo>),
     ContentEncoding(DecodeStruct<'a, T>, Vec<EncodingInfo>),
     UpdateContentLength(DecodeStruct<'a, T>),
-    UpdateContentLengthAndErr(DecodeStruct<'a, T>, MultiDecompressErrorReason),
+    UpdateContentLengthAndErr(DecodeStruct<'a, T>, DecodeError),
     End,
 }
 
@@ -30,6 +35,14 @@ where
     DecodeStruct<'a, T>: ChunkedConverter<T::HmapType>,
 {
     pub fn init(message: &'a mut T, buf: &'a mut BytesMut) -> Self {
+        Self::init_with_options(message, buf, DecodeOptions::default())
+    }
+
+    pub fn init_with_options(
+        message: &'a mut T,
+        buf: &'a mut BytesMut,
+        options: impl Into<DecodeOptions<'a>>,
+    ) -> Self {
         let Some(body) = message.take_body() else {
             return Self::End;
         };
@@ -41,30 +54,39 @@ where
                 buf.split()
             }
         };
-        Self::Start(DecodeStruct::new(body, message, buf))
+        let mut ds =
+            DecodeStruct::new(body, message, buf).with_options(options.into());
+        // without transfer codings the body is the content, verified here
+        // as Start may end without passing the report on
+        if !ds.transfer_encoding_is_some() {
+            ds.verify_digests();
+        }
+        Self::Start(ds)
     }
 
-    pub fn try_next(self) -> Result<Self, MultiDecompressErrorReason> {
+    pub fn try_next(self) -> Result<Self, DecodeError> {
         match self {
             DecodeState::Start(mut ds) => {
-                let next_state = if let Some(encodings) =
-                    ds.take_transfer_encoding()
-                {
-                    Self::TransferEncoding(ds, encodings)
-                } else if let Some(encodings) = ds.take_content_encoding() {
-                    Self::ContentEncoding(ds, encodings)
-                } else if ds.extra_body_is_some()
-                    || ds.is_transfer_type_close()
-                {
-                    Self::UpdateContentLength(ds)
-                } else {
-                    let body = ds.take_main_body();
-                    ds.message.set_body(Body::Raw(body));
-                    Self::End
-                };
+                let next_state =
+                    if let Some(encodings) = ds.take_transfer_encoding() {
+                        Self::TransferEncoding(ds, encodings)
+                    } else if let Some(encodings) =
+                        ds.take_content_encoding_to_decode()
+                    {
+                        Self::ContentEncoding(ds, encodings)
+                    } else if ds.extra_body_is_some()
+                        || ds.is_transfer_type_close()
+                    {
+                        Self::UpdateContentLength(ds)
+                    } else {
+                        let body = ds.take_main_body();
+                        ds.message.set_body(Body::Raw(body));
+                        Self::End
+                    };
                 Ok(next_state)
             }
             DecodeState::TransferEncoding(mut ds, mut encoding_infos) => {
+                let kind = EncodingKind::Transfer;
                 if ds.is_chunked_te() {
                     ds.chunked_to_raw();
                     // remove chunked TE
@@ -72,27 +94,59 @@ where
                     if let Some(last_info) = encoding_infos.last_mut() {
                         last_info.encodings_as_mut().pop();
                         if last_info.encodings().is_empty() {
-                            ds.message.remove_header_on_position(
-                                last_info.header_index,
-                            );
+                            if ds.options.remove_encoding_headers {
+                                ds.message.remove_header_on_position(
+                                    last_info.header_index,
+                                );
+                                ds.report
+                                    .removed_headers
+                                    .push(last_info.header_index);
+                            }
                             // remove the last encoding_info
                             encoding_infos.pop();
+                        } else if !ds.options.layers.transfer_encoding()
+                            && ds.options.remove_encoding_headers
+                        {
+                            // remaining codings are kept, rewrite without
+                            // chunked
+                            let iter = last_info
+                                .encodings()
+                                .iter()
+                                .map(|e| e.as_ref());
+                            ds.message
+                                .update_header_value_on_position_multiple_values(
+                                    last_info.header_index,
+                                    iter,
+                                );
+                            ds.report
+                                .rewritten_headers
+                                .push(last_info.header_index);
                         }
                     }
 
                     // if after removing TE it is empty, remove the header
                 }
                 // If only chunked was present then Vec<EncodingInfo> is empty
-                let mut next_state = if encoding_infos.is_empty() {
-                    if let Some(encodings) = ds.take_content_encoding() {
+                let mut next_state = if encoding_infos.is_empty()
+                    || !ds.options.layers.transfer_encoding()
+                {
+                    // not verifiable while transfer codings are left
+                    if encoding_infos.is_empty() {
+                        ds.verify_digests();
+                    }
+                    if let Some(encodings) =
+                        ds.take_content_encoding_to_decode()
+                    {
                         Self::ContentEncoding(ds, encodings)
                     } else {
                         Self::UpdateContentLength(ds)
                     }
                 } else {
-                    match apply_encoding(&mut ds, &mut encoding_infos) {
+                    match apply_encoding(&mut ds, &mut encoding_infos, kind) {
                         Ok(()) => {
-                            if let Some(encodings) = ds.take_content_encoding()
+                            ds.verify_digests();
+                            if let Some(encodings) =
+                                ds.take_content_encoding_to_decode()
                             {
                                 Self::ContentEncoding(ds, encodings)
                             } else {
@@ -106,8 +160,9 @@ where
                 Ok(next_state)
             }
             DecodeState::ContentEncoding(mut ds, mut encoding_infos) => {
+                let kind = EncodingKind::Content;
                 let mut next_state =
-                    match apply_encoding(&mut ds, &mut encoding_infos) {
+                    match apply_encoding(&mut ds, &mut encoding_infos, kind) {
                         Err(e) => Self::UpdateContentLengthAndErr(ds, e),
                         Ok(_) => Self::UpdateContentLength(ds),
                     };
@@ -130,6 +185,22 @@ where
         matches!(self, DecodeState::End)
     }
 
+    pub fn take_diagnostics(&mut self) -> Vec<Diagnostic> {
+        match self {
+            DecodeState::End => Vec::new(),
+            _ => std::mem::take(
+                &mut self.decode_struct_as_mut().report.diagnostics,
+            ),
+        }
+    }
+
+    pub fn take_report(&mut self) -> DecodeReport {
+        match self {
+            DecodeState::End => DecodeReport::default(),
+            _ => std::mem::take(&mut self.decode_struct_as_mut().report),
+        }
+    }
+
     pub fn decode_struct_as_mut(&mut self) -> &mut DecodeStruct<'a, T> {
         match self {
             DecodeState::Start(ds) => ds,
@@ -152,17 +223,61 @@ where
     }
 }
 
+// Header kind whose codings are being applied
+#[derive(Clone, Copy)]
+enum EncodingKind {
+    Transfer,
+    Content,
+}
+
 fn apply_encoding<T>(
     decode_struct: &mut DecodeStruct<T>,
     encoding_info: &mut [EncodingInfo],
-) -> Result<(), MultiDecompressErrorReason>
+    kind: EncodingKind,
+) -> Result<(), DecodeError>
+where
+    T: DecompressTrait + std::fmt::Debug,
+{
+    let remove_headers = decode_struct.options.remove_encoding_headers;
+    let mut encoding_report = EncodingReport::default();
+    let result = apply_encoding_inner(
+        decode_struct,
+        encoding_info,
+        remove_headers,
+        &mut encoding_report,
+    );
+    let report = &mut decode_struct.report;
+    match kind {
+        EncodingKind::Transfer => {
+            report.transfer_encoding = Some(encoding_report)
+        }
+        EncodingKind::Content => {
+            decode_struct.content_changed = !encoding_report.layers.is_empty();
+            report.content_encoding = Some(encoding_report)
+        }
+    }
+    result
+}
+
+fn apply_encoding_inner<T>(
+    decode_struct: &mut DecodeStruct<T>,
+    encoding_info: &mut [EncodingInfo],
+    remove_headers: bool,
+    encoding_report: &mut EncodingReport,
+) -> Result<(), DecodeError>
 where
     T: DecompressTrait + std::fmt::Debug,
 {
     if is_only_encoding(encoding_info, ContentEncoding::Identity) {
-        decode_struct
-            .message
-            .remove_header_on_position(encoding_info[0].header_index);
+        if remove_headers {
+            decode_struct
+                .message
+                .remove_header_on_position(encoding_info[0].header_index);
+            decode_struct
+                .report
+                .removed_headers
+                .push(encoding_info[0].header_index);
+        }
         return Ok(());
     }
     match decompression_runner(
@@ -170,65 +285,187 @@ where
         decode_struct.extra_body.as_deref(),
         encoding_info,
         decode_struct.buf,
+        &mut decode_struct.report.diagnostics,
+        &mut encoding_report.layers,
+        decode_struct.options.codec,
     ) {
         Ok(state) => {
             let is_extra_raw = state.is_extra_raw();
+            encoding_report.is_extra_raw = is_extra_raw;
+            encoding_report.end_state = state.end_state();
             let (body, extra_body) = state.into();
             decode_struct.body = body;
             if !is_extra_raw {
                 decode_struct.extra_body = extra_body;
             }
-            let iter = encoding_info.iter().map(|einfo| einfo.header_index);
-            // remove applied headers
-            decode_struct
-                .message
-                .header_map_as_mut()
-                .remove_header_multiple_positions(iter);
+            if remove_headers {
+                let iter =
+                    encoding_info.iter().map(|einfo| einfo.header_index);
+                decode_struct.report.removed_headers.extend(iter.clone());
+                // remove applied headers
+                decode_struct
+                    .message
+                    .header_map_as_mut()
+                    .remove_header_multiple_positions(iter);
+            }
             Ok(())
         }
-        Err(mut e) => {
-            // whatever the error clear the buf
-            decode_struct.buf.clear();
-            if let MultiDecompressErrorReason::Partial {
-                ref mut partial_body,
+        Err(e) => Err(apply_decode_error(
+            decode_struct,
+            encoding_info,
+            remove_headers,
+            encoding_report,
+            e,
+        )),
+    }
+}
+
+/* Partial - the message takes the partial body and the header keeps the
+ * codings left to decode.
+ * Corrupt and Unlocated - the message is left as is.
+ */
+fn apply_decode_error<T>(
+    decode_struct: &mut DecodeStruct<T>,
+    encoding_info: &[EncodingInfo],
+    remove_headers: bool,
+    encoding_report: &mut EncodingReport,
+    e: MultiDecompressError,
+) -> DecodeError
+where
+    T: DecompressTrait + std::fmt::Debug,
+{
+    // whatever the error clear the buf
+    decode_struct.buf.clear();
+    let reverse_index = match e.reason {
+        MultiDecompressErrorReason::Partial {
+            header_index,
+            compression_index,
+            ..
+        } => Some((header_index, compression_index)),
+        MultiDecompressErrorReason::Corrupt => None,
+    };
+    let original = Box::new(OriginalBody {
+        body: decode_struct.body.clone(),
+        extra: decode_struct.extra_body.clone(),
+    });
+    // located before the message is touched
+    let err = into_decode_error(e, encoding_info, original);
+    if let DecodeError::Partial {
+        ref partial_body,
+        is_extra_raw,
+        ..
+    } = err
+        && let Some((header_index, compression_index)) = reverse_index
+    {
+        encoding_report.is_extra_raw = is_extra_raw;
+        decode_struct.body = partial_body.clone();
+        if !is_extra_raw {
+            decode_struct.extra_body = None;
+        }
+        if remove_headers {
+            update_partial_headers(
+                decode_struct,
+                encoding_info,
                 header_index,
                 compression_index,
-                is_extra_raw,
-            } = e.reason
-            {
-                decode_struct.body = partial_body.split();
-                if !is_extra_raw {
-                    decode_struct.extra_body = None;
-                }
-                for (index, einfo) in encoding_info.iter().rev().enumerate() {
-                    if index < header_index {
-                        decode_struct
-                            .message
-                            .remove_header_on_position(einfo.header_index);
-                    } else {
-                        let iter = einfo
-                            .encodings()
-                            .iter()
-                            .rev()
-                            .skip(compression_index)
-                            .rev()
-                            .map(|e| e.as_ref());
-                        decode_struct
-                            .message
-                            .header_map_as_mut()
-                            .update_header_value_on_position_multiple_values(
-                                einfo.header_index,
-                                iter,
-                            );
-                        break;
-                    }
-                }
-            }
-            Err(e.reason)
+            );
+        }
+    }
+    err
+}
+
+// remove the applied headers and rewrite the failing one
+fn update_partial_headers<T>(
+    decode_struct: &mut DecodeStruct<T>,
+    encoding_info: &[EncodingInfo],
+    header_index: usize,
+    compression_index: usize,
+) where
+    T: DecompressTrait + std::fmt::Debug,
+{
+    for (index, einfo) in encoding_info.iter().rev().enumerate() {
+        if index < header_index {
+            decode_struct
+                .message
+                .remove_header_on_position(einfo.header_index);
+            decode_struct.report.removed_headers.push(einfo.header_index);
+        } else {
+            let iter = einfo
+                .encodings()
+                .iter()
+                .rev()
+                .skip(compression_index)
+                .rev()
+                .map(|e| e.as_ref());
+            decode_struct
+                .message
+                .header_map_as_mut()
+                .update_header_value_on_position_multiple_values(
+                    einfo.header_index,
+                    iter,
+                );
+            decode_struct.report.rewritten_headers.push(einfo.header_index);
+            break;
         }
     }
 }
 
+/* header_index and compression_index of the reason count from the last
+ * coding, Corrupt is always the last coding of the last header. Indices
+ * outside the declared codings give Unlocated.
+ */
+fn into_decode_error(
+    e: MultiDecompressError,
+    encoding_info: &[EncodingInfo],
+    original: Box<OriginalBody>,
+) -> DecodeError {
+    let (reverse_header_index, compression_index) = match e.reason {
+        MultiDecompressErrorReason::Partial {
+            header_index,
+            compression_index,
+            ..
+        } => (header_index, compression_index),
+        MultiDecompressErrorReason::Corrupt => (0, 0),
+    };
+    let Some((header_index, encoding)) =
+        encoding_info.iter().rev().nth(reverse_header_index).and_then(
+            |einfo| {
+                einfo
+                    .encodings()
+                    .iter()
+                    .rev()
+                    .nth(compression_index)
+                    .map(|encoding| (einfo.header_index, encoding.clone()))
+            },
+        )
+    else {
+        return DecodeError::Unlocated {
+            source: e.error,
+            original,
+        };
+    };
+    match e.reason {
+        MultiDecompressErrorReason::Corrupt => DecodeError::Corrupt {
+            header_index,
+            encoding,
+            source: e.error,
+            original,
+        },
+        MultiDecompressErrorReason::Partial {
+            partial_body,
+            is_extra_raw,
+            ..
+        } => DecodeError::Partial {
+            header_index,
+            encoding,
+            source: e.error,
+            partial_body,
+            is_extra_raw,
+            original,
+        },
+    }
+}
+
 pub fn is_only_encoding(
     encoding_info: &[EncodingInfo],
     encoding: ContentEncoding,
@@ -237,3 +474,113 @@ pub fn is_only_encoding(
         && encoding_info[0].encodings().len() == 1
         && encoding_info[0].encodings()[0] == encoding
 }
+
+#[cfg(test)]
+mod tests {
+    use header_plz::{
+        OneHeader, OneHeaderMap, body_headers::BodyHeader,
+        message_head::header_map::HMap,
+    };
+
+    use super::*;
+    use crate::decompression::single::error::DecompressError;
+
+    #[derive(Debug)]
+    struct Message {
+        header_map: OneHeaderMap,
+    }
+
+    impl DecompressTrait for Message {
+        type HmapType = OneHeader;
+
+        fn take_body(&mut self) -> Option<Body> {
+            None
+        }
+
+        fn take_extra_body(&mut self) -> Option<BytesMut> {
+            None
+        }
+
+        fn set_body(&mut self, _body: Body) {}
+
+        fn body_headers(&self) -> Option<&BodyHeader> {
+            None
+        }
+
+        fn body_headers_as_mut(&mut self) -> Option<&mut BodyHeader> {
+            None
+        }
+
+        fn header_map(&self) -> &HMap<OneHeader> {
+            &self.header_map
+        }
+
+        fn header_map_as_mut(&mut self) -> &mut HMap<OneHeader> {
+            &mut self.header_map
+        }
+    }
+
+    #[test]
+    fn test_into_decode_error_unlocated() {
+        let encoding_info =
+            [EncodingInfo::new(0, vec![ContentEncoding::Gzip])];
+        let reason = MultiDecompressErrorReason::Partial {
+            partial_body: BytesMut::new(),
+            header_index: 1,
+            compression_index: 0,
+            is_extra_raw: false,
+        };
+        let e = MultiDecompressError::new(
+            reason,
+            DecompressError::corrupt(&ContentEncoding::Gzip),
+        );
+        let err = into_decode_error(e, &encoding_info, Box::default());
+        assert!(matches!(err, DecodeError::Unlocated { .. }));
+        assert_eq!(err.header_index(), None);
+        assert_eq!(err.encoding(), None);
+    }
+
+    #[test]
+    fn test_apply_decode_error_unlocated_keeps_message() {
+        let headers = "Content-Encoding: gzip\r\n\r\n";
+        let mut message = Message {
+            header_map: OneHeaderMap::from(BytesMut::from(headers)),
+        };
+        let mut buf = BytesMut::from("scratch");
+        let mut decode_struct = DecodeStruct {
+            body: BytesMut::from("body"),
+            buf: &mut buf,
+            extra_body: Some(BytesMut::from("extra")),
+            message: &mut message,
+            options: DecodeOptions::default(),
+            report: DecodeReport::default(),
+            content_changed: false,
+        };
+        let encoding_info =
+            [EncodingInfo::new(0, vec![ContentEncoding::Gzip])];
+        let reason = MultiDecompressErrorReason::Partial {
+            partial_body: BytesMut::from("partial"),
+            header_index: 1,
+            compression_index: 0,
+            is_extra_raw: false,
+        };
+        let e = MultiDecompressError::new(
+            reason,
+            DecompressError::corrupt(&ContentEncoding::Gzip),
+        );
+        let mut encoding_report = EncodingReport::default();
+        let err = apply_decode_error(
+            &mut decode_struct,
+            &encoding_info,
+            true,
+            &mut encoding_report,
+            e,
+        );
+        assert!(matches!(err, DecodeError::Unlocated { .. }));
+        assert_eq!(decode_struct.body, "body");
+        assert_eq!(decode_struct.extra_body.as_deref(), Some(&b"extra"[..]));
+        assert!(decode_struct.report.removed_headers.is_empty());
+        assert!(decode_struct.report.rewritten_headers.is_empty());
+        assert_eq!(message.header_map.into_bytes(), headers);
+    }
+}
@@ -1,12 +1,14 @@
 pub use body_plz::variants::Body;
 pub use bytes::BytesMut;
 use decompression_plz::DecompressTrait;
-use header_plz::message_head::header_map::Hmap;
+use decompression_plz::data_frames::data_frames_to_raw;
+use decompression_plz::dictionary::{DCB_MAGIC, DCZ_MAGIC, dictionary_hash};
+use header_plz::message_head::header_map::{HeaderVersion, Hmap};
 use std::io::Write;
 
 use flate2::Compression;
 use header_plz::{
-    OneHeader, OneHeaderMap,
+    Header, HeaderMap, OneHeader, OneHeaderMap,
     body_headers::{
         BodyHeader, content_encoding::ContentEncoding,
         encoding_info::EncodingInfo,
@@ -17,17 +19,21 @@ use header_plz::{
 pub const INPUT: &[u8] = b"hello world";
 pub const ALL_COMPRESSIONS: &str = "br, deflate, gzip, zstd";
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct TestMessage<T> {
     pub header_map: HMap<T>,
     body_header: Option<BodyHeader>,
     pub body: Option<Body>,
     extra_body: Option<BytesMut>,
+    pub trailers: Option<OneHeaderMap>,
 }
 
 impl<T> DecompressTrait for TestMessage<T>
 where
-    T: Hmap + std::fmt::Debug + for<'a> From<(&'a str, &'a str)>,
+    T: Hmap
+        + HeaderVersion
+        + std::fmt::Debug
+        + for<'a> From<(&'a str, &'a str)>,
 {
     type HmapType = T;
 
@@ -43,6 +49,10 @@ where
         self.body = Some(body);
     }
 
+    fn set_trailers(&mut self, trailers: OneHeaderMap) {
+        self.trailers = Some(trailers);
+    }
+
     fn body_headers_as_mut(&mut self) -> Option<&mut BodyHeader> {
         self.body_header.as_mut()
     }
@@ -68,6 +78,46 @@ impl TestMessage<OneHeader> {
     }
 }
 
+impl TestMessage<Header> {
+    /* h2 message, body headers are parsed from the h2 header map and the
+     * body is assembled from the DATA frame payloads.
+     */
+    pub fn new_two(
+        mut headers: BytesMut,
+        frames: Vec<BytesMut>,
+        extra: Option<BytesMut>,
+    ) -> Self {
+        // h2 has no empty line ending the headers
+        if headers.ends_with(b"\r\n\r\n") {
+            headers.truncate(headers.len() - 2);
+        }
+        let header_map = HeaderMap::from(OneHeaderMap::from(headers));
+        let body_header = Option::<BodyHeader>::from(&header_map);
+        let mut tm = Self {
+            header_map,
+            body_header,
+            body: None,
+            extra_body: extra,
+            trailers: None,
+        };
+        data_frames_to_raw(&mut tm, frames, &mut BytesMut::new());
+        tm
+    }
+
+    // headers in h1 format with lowercase keys
+    pub fn into_bytes_two(self) -> BytesMut {
+        let mut bytes = OneHeaderMap::from(self.header_map).into_bytes();
+        bytes.extend_from_slice(b"\r\n");
+        bytes.unsplit(self.body.unwrap().into_bytes().unwrap());
+        bytes
+    }
+}
+
+// split body into DATA frame payloads of at most size bytes
+pub fn data_frames(body: &[u8], size: usize) -> Vec<BytesMut> {
+    body.chunks(size).map(BytesMut::from).collect()
+}
+
 impl<H> TestMessage<H>
 where
     H: From<OneHeader>,
@@ -85,6 +135,7 @@ where
             body_header: Some(body_header),
             body: Some(body),
             extra_body: extra,
+            trailers: None,
         }
     }
 
@@ -279,6 +330,13 @@ pub fn all_encoding_info_single_header() -> Vec<EncodingInfo> {
     )]
 }
 
+// large enough for every codec to emit output before the end of the stream
+pub fn large_input() -> Vec<u8> {
+    (0..16384)
+        .flat_map(|i| format!("line {i} of a large body\n").into_bytes())
+        .collect()
+}
+
 pub fn compress_brotli(data: &[u8]) -> Vec<u8> {
     let mut compressed = Vec::new();
     {
@@ -299,6 +357,17 @@ pub fn compress_deflate(data: &[u8]) -> Vec<u8> {
     compressed
 }
 
+pub fn compress_raw_deflate(data: &[u8]) -> Vec<u8> {
+    let mut compressed = Vec::new();
+    let mut encoder = flate2::write::DeflateEncoder::new(
+        &mut compressed,
+        Compression::fast(),
+    );
+    encoder.write_all(data).unwrap();
+    encoder.finish().unwrap();
+    compressed
+}
+
 pub fn compress_gzip(data: &[u8]) -> Vec<u8> {
     let mut compressed = Vec::new();
     let mut encoder =
@@ -311,3 +380,44 @@ pub fn compress_gzip(data: &[u8]) -> Vec<u8> {
 pub fn compress_zstd(data: &[u8]) -> Vec<u8> {
     zstd::encode_all(data, 1).unwrap()
 }
+
+pub const DICTIONARY: &[u8] = b"hello world, this is a shared dictionary";
+
+pub fn compress_dcb(data: &[u8], dictionary: &[u8]) -> Vec<u8> {
+    use brotli::enc::{
+        BrotliCompressCustomIoCustomDict, BrotliEncoderParams,
+        IoReaderWrapper, IoWriterWrapper, StandardAlloc,
+    };
+
+    let mut compressed = DCB_MAGIC.to_vec();
+    compressed.extend_from_slice(dictionary_hash(dictionary).as_bytes());
+    let mut input_buffer = [0; 4096];
+    let mut output_buffer = [0; 4096];
+    BrotliCompressCustomIoCustomDict(
+        &mut IoReaderWrapper(&mut &data[..]),
+        &mut IoWriterWrapper(&mut compressed),
+        &mut input_buffer,
+        &mut output_buffer,
+        &BrotliEncoderParams {
+            quality: 5,
+            ..Default::default()
+        },
+        StandardAlloc::default(),
+        &mut |_: &mut _, _: &mut _, _, _: &mut _| (),
+        dictionary,
+        std::io::Error::from(std::io::ErrorKind::UnexpectedEof),
+    )
+    .unwrap();
+    compressed
+}
+
+pub fn compress_dcz(data: &[u8], dictionary: &[u8]) -> Vec<u8> {
+    let mut compressed = DCZ_MAGIC.to_vec();
+    compressed.extend_from_slice(dictionary_hash(dictionary).as_bytes());
+    let mut encoder = zstd::stream::write::Encoder::with_dictionary(
+        compressed, 1, dictionary,
+    )
+    .unwrap();
+    encoder.write_all(data).unwrap();
+    encoder.finish().unwrap()
+}
@@ -1,4 +1,4 @@
-use decompression_plz::MultiDecompressErrorReason;
+use decompression_plz::DecodeError;
 use header_plz::body_headers::content_encoding::ContentEncoding;
 use rstest::rstest;
 use tests_utils::INPUT;
@@ -50,7 +50,7 @@ fn assert_corrupt_encoding(
     assert!(matches!(state, DecodeState::UpdateContentLengthAndErr(..)));
 
     if let Err(e) = state.try_next() {
-        assert!(matches!(e, MultiDecompressErrorReason::Corrupt));
+        assert!(matches!(e, DecodeError::Corrupt { .. }));
         let result = tm.into_bytes();
         assert_eq!(result, verify);
     } else {
@@ -0,0 +1,246 @@
+use super::*;
+use body_plz::variants::chunked::ChunkType;
+use decompression_plz::{
+    CodecOptions, DecodeLayers, DecodeOptions, Diagnostic, TrailerPolicy,
+    decompress_with_options, preview,
+};
+use header_plz::{
+    Header, OneHeader, OneHeaderMap,
+    body_headers::content_encoding::ContentEncoding,
+};
+use tests_utils::{INPUT, compress_deflate, compress_gzip};
+
+fn chunked_body(data: &[u8]) -> Body {
+    let mut chunk = BytesMut::from(data);
+    chunk.extend_from_slice(b"\r\n");
+    Body::Chunked(vec![
+        ChunkType::Size(format!("{:x}\r\n", data.len()).as_str().into()),
+        ChunkType::Chunk(chunk),
+        ChunkType::LastChunk("0\r\n".into()),
+        ChunkType::EndCRLF("\r\n".into()),
+    ])
+}
+
+fn decode(
+    tm: &mut TestMessage<OneHeader>,
+    options: DecodeOptions,
+) -> Vec<Diagnostic> {
+    let mut buf = BytesMut::new();
+    let mut diagnostics = Vec::new();
+    decompress_with_options(tm, &mut buf, options, &mut diagnostics).unwrap();
+    diagnostics
+}
+
+#[test]
+fn test_decode_options_chunked_only() {
+    let compressed = compress_gzip(INPUT);
+    let headers = "Host: example.com\r\n\
+                   Transfer-Encoding: gzip, chunked\r\n\
+                   Content-Encoding: deflate\r\n";
+    let mut tm = TestMessage::<OneHeader>::new(
+        headers.into(),
+        chunked_body(&compressed),
+        None,
+    );
+    let options = DecodeOptions::default().with_layers(DecodeLayers::Chunked);
+    decode(&mut tm, options);
+    let mut verify = BytesMut::from(
+        format!(
+            "Host: example.com\r\n\
+             Transfer-Encoding: gzip\r\n\
+             Content-Encoding: deflate\r\n\
+             Content-Length: {}\r\n",
+            compressed.len()
+        )
+        .as_str(),
+    );
+    verify.extend_from_slice(&compressed);
+    assert_eq!(tm.into_bytes(), verify);
+}
+
+#[test]
+fn test_decode_options_transfer_encoding_only() {
+    let compressed = compress_deflate(&compress_gzip(INPUT));
+    let headers = "Host: example.com\r\n\
+                   Transfer-Encoding: deflate, chunked\r\n\
+                   Content-Encoding: gzip\r\n";
+    let mut tm = TestMessage::<OneHeader>::new(
+        headers.into(),
+        chunked_body(&compressed),
+        None,
+    );
+    let options =
+        DecodeOptions::default().with_layers(DecodeLayers::TransferEncoding);
+    decode(&mut tm, options);
+    let gzipped = compress_gzip(INPUT);
+    let mut verify = BytesMut::from(
+        format!(
+            "Host: example.com\r\n\
+             Content-Encoding: gzip\r\n\
+             Content-Length: {}\r\n",
+            gzipped.len()
+        )
+        .as_str(),
+    );
+    verify.extend_from_slice(&gzipped);
+    assert_eq!(tm.into_bytes(), verify);
+}
+
+#[test]
+fn test_decode_options_content_encoding_skipped() {
+    let compressed = compress_gzip(INPUT);
+    let headers = format!(
+        "Host: example.com\r\n\
+         Content-Encoding: gzip\r\n\
+         Content-Length: {}\r\n\r\n",
+        compressed.len()
+    );
+    let mut tm = TestMessage::<OneHeader>::new(
+        headers.as_str().into(),
+        Body::Raw(compressed.as_slice().into()),
+        None,
+    );
+    let original = tm.clone();
+    let options = DecodeOptions::default().with_layers(DecodeLayers::Chunked);
+    decode(&mut tm, options);
+    assert_eq!(tm.into_bytes(), original.into_bytes());
+}
+
+#[test]
+fn test_decode_options_keep_headers() {
+    let compressed = compress_gzip(INPUT);
+    let headers = format!(
+        "Host: example.com\r\n\
+         Content-Encoding: gzip\r\n\
+         Content-Length: {}\r\n\r\n",
+        compressed.len()
+    );
+    let mut tm = TestMessage::<OneHeader>::new(
+        headers.as_str().into(),
+        Body::Raw(compressed.as_slice().into()),
+        None,
+    );
+    let options = DecodeOptions::default()
+        .with_remove_encoding_headers(false)
+        .with_update_content_length(false);
+    decode(&mut tm, options);
+    let mut verify = BytesMut::from(headers.as_str());
+    verify.extend_from_slice(INPUT);
+    assert_eq!(tm.into_bytes(), verify);
+}
+
+// the encoded body is replaced even when it decodes to nothing
+#[test]
+fn test_decode_options_empty_body_keep_content_length() {
+    let compressed = compress_gzip(b"");
+    let headers = format!(
+        "Host: example.com\r\n\
+         Content-Encoding: gzip\r\n\
+         Content-Length: {}\r\n\r\n",
+        compressed.len()
+    );
+    let mut tm = TestMessage::<OneHeader>::new(
+        headers.as_str().into(),
+        Body::Raw(compressed.as_slice().into()),
+        None,
+    );
+    let options = DecodeOptions::default().with_update_content_length(false);
+    decode(&mut tm, options);
+    let verify = format!(
+        "Host: example.com\r\n\
+         Content-Length: {}\r\n\r\n",
+        compressed.len()
+    );
+    assert_eq!(tm.into_bytes(), verify.as_str());
+}
+
+#[test]
+fn test_decode_options_drop_trailers() {
+    let mut body = chunked_body(INPUT);
+    body.push_chunk(ChunkType::Trailers(OneHeaderMap::from(BytesMut::from(
+        "Header: Val\r\n",
+    ))));
+    let headers = "Host: example.com\r\n\
+                   Trailer: Header\r\n\
+                   Transfer-Encoding: chunked\r\n";
+    let mut tm = TestMessage::<OneHeader>::new(headers.into(), body, None);
+    let options = DecodeOptions::default().with_trailers(TrailerPolicy::Drop);
+    decode(&mut tm, options);
+    let verify = "Host: example.com\r\n\
+                  Trailer: Header\r\n\
+                  Content-Length: 11\r\n\
+                  hello world";
+    assert_eq!(tm.into_bytes(), verify);
+}
+
+#[test]
+fn test_decode_options_from_codec_options() {
+    let options: DecodeOptions =
+        CodecOptions::default().with_sniff(true).into();
+    assert!(options.codec.sniff);
+    assert_eq!(options.layers, DecodeLayers::All);
+    assert!(options.remove_encoding_headers);
+    assert!(options.update_content_length);
+    assert_eq!(options.trailers, TrailerPolicy::Merge);
+}
+
+#[test]
+fn test_preview_one() {
+    let compressed = compress_gzip(INPUT);
+    let headers = format!(
+        "Host: example.com\r\n\
+         Content-Encoding: gzip\r\n\
+         Content-Length: {}\r\n\r\n",
+        compressed.len()
+    );
+    let tm = TestMessage::<OneHeader>::new(
+        headers.as_str().into(),
+        Body::Raw(compressed.as_slice().into()),
+        None,
+    );
+    let original = tm.clone();
+    let (result, report) = preview(&tm, DecodeOptions::default());
+    assert_eq!(result.unwrap(), INPUT);
+    assert_eq!(
+        report.applied_encodings().collect::<Vec<_>>(),
+        vec![&ContentEncoding::Gzip]
+    );
+    assert_eq!(tm, original);
+}
+
+#[test]
+fn test_preview_two_chunked_with_extra() {
+    let compressed = compress_gzip(INPUT);
+    let headers = "Host: example.com\r\n\
+                   Content-Encoding: gzip\r\n\
+                   Transfer-Encoding: chunked\r\n\r\n";
+    let tm = TestMessage::<Header>::new(
+        headers.into(),
+        Body::Raw(compressed.as_slice().into()),
+        Some(compressed.as_slice().into()),
+    );
+    let original = tm.clone();
+    let (result, _) = preview(&tm, CodecOptions::default());
+    assert_eq!(result.unwrap(), "hello worldhello world");
+    assert_eq!(tm, original);
+}
+
+#[test]
+fn test_preview_error() {
+    let headers = "Host: example.com\r\n\
+                   Content-Encoding: gzip\r\n\
+                   Content-Length: 11\r\n\r\n";
+    let tm = TestMessage::<OneHeader>::new(
+        headers.into(),
+        Body::Raw(INPUT.into()),
+        None,
+    );
+    let original = tm.clone();
+    let (result, report) = preview(&tm, DecodeOptions::default());
+    assert!(result.is_err());
+    // decoding of the content codings is reported as failed
+    let content_encoding = report.content_encoding.unwrap();
+    assert!(content_encoding.layers.is_empty());
+    assert_eq!(content_encoding.end_state, None);
+    assert_eq!(tm, original);
+}
@@ -0,0 +1,101 @@
+use super::*;
+use decompression_plz::{
+    CodecOptions, DecodeError, decompress, decompress_with_dictionaries,
+    dictionary::DictionaryStore,
+};
+use header_plz::{Header, OneHeader};
+use tests_utils::{DICTIONARY, INPUT, compress_dcb, compress_dcz};
+
+fn build_headers(encoding: &str, len: usize) -> String {
+    format!(
+        "Host: example.com\r\n\
+        Content-Type: text/html; charset=utf-8\r\n\
+        Content-Encoding: {}\r\n\
+        Content-Length: {}\r\n\r\n",
+        encoding, len
+    )
+}
+
+fn dictionary_store() -> DictionaryStore {
+    let mut store = DictionaryStore::new();
+    store.insert(DICTIONARY.to_vec());
+    store
+}
+
+fn assert_dictionary_one(encoding: &str, body: Vec<u8>) {
+    let headers = build_headers(encoding, body.len());
+    let mut tm = TestMessage::<OneHeader>::new(
+        headers.as_bytes().into(),
+        Body::Raw(body.as_slice().into()),
+        None,
+    );
+    let store = dictionary_store();
+    let mut buf = BytesMut::new();
+    let options = CodecOptions::default().with_dictionaries(&store);
+    let mut state = DecodeState::init_with_options(&mut tm, &mut buf, options);
+    state = state.try_next().unwrap();
+    assert!(matches!(state, DecodeState::ContentEncoding(..)));
+    state = state.try_next().unwrap();
+    assert!(matches!(state, DecodeState::UpdateContentLength(_)));
+    state = state.try_next().unwrap();
+    assert!(state.is_ended());
+    assert_eq!(tm.into_bytes(), VERIFY_SINGLE_HEADER_BODY_ONLY);
+}
+
+#[test]
+fn test_dictionary_dcb() {
+    assert_dictionary_one("dcb", compress_dcb(INPUT, DICTIONARY));
+}
+
+#[test]
+fn test_dictionary_dcz() {
+    assert_dictionary_one("dcz", compress_dcz(INPUT, DICTIONARY));
+}
+
+#[test]
+fn test_dictionary_dcz_two() {
+    let body = compress_dcz(INPUT, DICTIONARY);
+    let headers = build_headers("dcz", body.len());
+    let mut tm = TestMessage::<Header>::new(
+        headers.as_bytes().into(),
+        Body::Raw(body.as_slice().into()),
+        None,
+    );
+    let store = dictionary_store();
+    let mut buf = BytesMut::new();
+    decompress_with_dictionaries(&mut tm, &mut buf, &store).unwrap();
+    assert_eq!(tm.body.unwrap().into_bytes().unwrap(), INPUT);
+}
+
+#[test]
+fn test_dictionary_dcb_not_found() {
+    let body = compress_dcb(INPUT, DICTIONARY);
+    let headers = build_headers("dcb", body.len());
+    let mut tm = TestMessage::<OneHeader>::new(
+        headers.as_bytes().into(),
+        Body::Raw(body.as_slice().into()),
+        None,
+    );
+    let mut buf = BytesMut::new();
+    let result = decompress(&mut tm, &mut buf).unwrap_err();
+    assert!(matches!(result, DecodeError::Corrupt { .. }));
+    // body and headers left untouched
+    let result = tm.into_bytes();
+    assert!(result.starts_with(headers.as_bytes()));
+    assert!(result.ends_with(&body));
+}
+
+#[test]
+fn test_dictionary_dcz_with_gzip() {
+    let body = tests_utils::compress_gzip(&compress_dcz(INPUT, DICTIONARY));
+    let headers = build_headers("dcz, gzip", body.len());
+    let mut tm = TestMessage::<OneHeader>::new(
+        headers.as_bytes().into(),
+        Body::Raw(body.as_slice().into()),
+        None,
+    );
+    let store = dictionary_store();
+    let mut buf = BytesMut::new();
+    decompress_with_dictionaries(&mut tm, &mut buf, &store).unwrap();
+    assert_eq!(tm.into_bytes(), VERIFY_SINGLE_HEADER_BODY_ONLY);
+}
@@ -0,0 +1,247 @@
+use super::*;
+use body_plz::variants::chunked::ChunkType;
+use decompression_plz::{
+    DecodeOptions, DigestCheck, DigestStatus, decompress_with_report,
+    digest::{compute_digest, update_digests},
+};
+use header_plz::{
+    OneHeader,
+    body_headers::digest::{
+        ContentMd5, Digest, DigestAlgorithm, DigestField, Digests,
+    },
+};
+use tests_utils::{INPUT, compress_gzip};
+
+fn sha256(data: &[u8]) -> Vec<u8> {
+    compute_digest(&DigestAlgorithm::Sha256, &[data]).unwrap()
+}
+
+fn sha256_value(field: DigestField, data: &[u8]) -> String {
+    let digest = Digest::new(DigestAlgorithm::Sha256, sha256(data));
+    field.serialize(&Digests(vec![digest]))
+}
+
+fn check(field: DigestField, status: DigestStatus) -> DigestCheck {
+    DigestCheck {
+        field,
+        algorithm: Some(DigestAlgorithm::Sha256),
+        status,
+    }
+}
+
+fn value_of(tm: &TestMessage<OneHeader>, key: &str) -> Option<String> {
+    tm.header_map
+        .value_of_key(key)
+        .map(|v| String::from_utf8_lossy(v).into_owned())
+}
+
+fn decode(
+    tm: &mut TestMessage<OneHeader>,
+    options: DecodeOptions,
+) -> Vec<DigestCheck> {
+    let mut buf = BytesMut::new();
+    let (result, report) = decompress_with_report(tm, &mut buf, options);
+    result.unwrap();
+    report.digests
+}
+
+fn verify_options() -> DecodeOptions<'static> {
+    DecodeOptions::default().with_verify_digests(true)
+}
+
+#[test]
+fn test_digest_verify_no_encoding() {
+    let headers = format!(
+        "Host: example.com\r\n\
+         Content-Length: 11\r\n\
+         Content-Digest: {}\r\n\
+         Repr-Digest: sha-256=:AAAA:\r\n\r\n",
+        sha256_value(DigestField::ContentDigest, INPUT)
+    );
+    let mut tm = TestMessage::<OneHeader>::new(
+        headers.as_str().into(),
+        Body::Raw(INPUT.into()),
+        None,
+    );
+    let checks = decode(&mut tm, verify_options());
+    assert_eq!(
+        checks,
+        vec![
+            check(DigestField::ContentDigest, DigestStatus::Match),
+            check(DigestField::ReprDigest, DigestStatus::Mismatch),
+        ]
+    );
+}
+
+#[test]
+fn test_digest_verify_disabled() {
+    let headers = "Host: example.com\r\n\
+                   Content-Length: 11\r\n\
+                   Content-Digest: sha-256=:AAAA:\r\n\r\n";
+    let mut tm = TestMessage::<OneHeader>::new(
+        headers.into(),
+        Body::Raw(INPUT.into()),
+        None,
+    );
+    assert!(decode(&mut tm, DecodeOptions::default()).is_empty());
+}
+
+#[test]
+fn test_digest_verify_chunked() {
+    let headers = format!(
+        "Host: example.com\r\n\
+         Transfer-Encoding: chunked\r\n\
+         Content-Digest: {}\r\n\r\n",
+        sha256_value(DigestField::ContentDigest, INPUT)
+    );
+    let mut chunk = BytesMut::from(INPUT);
+    chunk.extend_from_slice(b"\r\n");
+    let body = Body::Chunked(vec![
+        ChunkType::Size(format!("{:x}\r\n", INPUT.len()).as_str().into()),
+        ChunkType::Chunk(chunk),
+        ChunkType::LastChunk("0\r\n".into()),
+    ]);
+    let mut tm =
+        TestMessage::<OneHeader>::new(headers.as_str().into(), body, None);
+    let checks = decode(&mut tm, verify_options());
+    assert_eq!(
+        checks,
+        vec![check(DigestField::ContentDigest, DigestStatus::Match)]
+    );
+}
+
+/* Digests of a content coded message cover the encoded content, after
+ * decompress they are recomputed over the decoded one.
+ */
+#[test]
+fn test_digest_gzip_verify_and_update() {
+    let compressed = compress_gzip(INPUT);
+    let headers = format!(
+        "Host: example.com\r\n\
+         Content-Encoding: gzip\r\n\
+         Content-Length: {}\r\n\
+         Content-Digest: {}\r\n\
+         Repr-Digest: {}, unixsum=:AAAA:\r\n\
+         Content-MD5: {}\r\n\r\n",
+        compressed.len(),
+        sha256_value(DigestField::ContentDigest, &compressed),
+        sha256_value(DigestField::ReprDigest, &compressed),
+        ContentMd5(md5(&compressed)),
+    );
+    let mut tm = TestMessage::<OneHeader>::new(
+        headers.as_str().into(),
+        Body::Raw(compressed.as_slice().into()),
+        None,
+    );
+    let checks = decode(&mut tm, verify_options());
+    assert_eq!(
+        checks,
+        vec![
+            check(DigestField::ContentDigest, DigestStatus::Match),
+            check(DigestField::ReprDigest, DigestStatus::Match),
+            DigestCheck {
+                field: DigestField::ReprDigest,
+                algorithm: Some(DigestAlgorithm::Unixsum),
+                status: DigestStatus::Unsupported,
+            },
+            DigestCheck {
+                field: DigestField::ContentMd5,
+                algorithm: Some(DigestAlgorithm::Md5),
+                status: DigestStatus::Match,
+            },
+        ]
+    );
+    assert_eq!(
+        value_of(&tm, "Content-Digest"),
+        Some(sha256_value(DigestField::ContentDigest, INPUT))
+    );
+    // unsupported unixsum dropped
+    assert_eq!(
+        value_of(&tm, "Repr-Digest"),
+        Some(sha256_value(DigestField::ReprDigest, INPUT))
+    );
+    assert_eq!(
+        value_of(&tm, "Content-MD5"),
+        Some(ContentMd5(md5(INPUT)).to_string())
+    );
+}
+
+#[test]
+fn test_digest_gzip_no_update() {
+    let compressed = compress_gzip(INPUT);
+    let digest = sha256_value(DigestField::ContentDigest, &compressed);
+    let headers = format!(
+        "Host: example.com\r\n\
+         Content-Encoding: gzip\r\n\
+         Content-Digest: {digest}\r\n\r\n"
+    );
+    let mut tm = TestMessage::<OneHeader>::new(
+        headers.as_str().into(),
+        Body::Raw(compressed.as_slice().into()),
+        None,
+    );
+    let options = DecodeOptions::default().with_update_digests(false);
+    decode(&mut tm, options);
+    assert_eq!(value_of(&tm, "Content-Digest"), Some(digest));
+}
+
+#[test]
+fn test_digest_partial_content() {
+    let compressed = compress_gzip(INPUT);
+    let repr = sha256_value(DigestField::ReprDigest, b"complete");
+    let headers = format!(
+        "Host: example.com\r\n\
+         Content-Encoding: gzip\r\n\
+         Content-Range: bytes 0-{}/100\r\n\
+         Content-Digest: {}\r\n\
+         Repr-Digest: {repr}\r\n\r\n",
+        compressed.len() - 1,
+        sha256_value(DigestField::ContentDigest, &compressed),
+    );
+    let mut tm = TestMessage::<OneHeader>::new(
+        headers.as_str().into(),
+        Body::Raw(compressed.as_slice().into()),
+        None,
+    );
+    let checks = decode(&mut tm, verify_options());
+    assert_eq!(
+        checks,
+        vec![
+            check(DigestField::ContentDigest, DigestStatus::Match),
+            check(DigestField::ReprDigest, DigestStatus::Unverifiable),
+        ]
+    );
+    assert_eq!(
+        value_of(&tm, "Content-Digest"),
+        Some(sha256_value(DigestField::ContentDigest, INPUT))
+    );
+    assert_eq!(value_of(&tm, "Repr-Digest"), Some(repr));
+}
+
+#[test]
+fn test_update_digests_after_edit() {
+    let headers = format!(
+        "Host: example.com\r\n\
+         Digest: {}, UNIXSUM=30637\r\n\
+         Repr-Digest: unixsum=:AAAA:\r\n\r\n",
+        Digests(vec![Digest::new(DigestAlgorithm::Sha256, sha256(INPUT))])
+            .to_legacy_string()
+    );
+    let mut tm = TestMessage::<OneHeader>::new(
+        headers.as_str().into(),
+        Body::Raw(INPUT.into()),
+        None,
+    );
+    let edited = b"hello edited world";
+    update_digests(&mut tm, edited);
+    let verify =
+        Digests(vec![Digest::new(DigestAlgorithm::Sha256, sha256(edited))])
+            .to_legacy_string();
+    assert_eq!(value_of(&tm, "Digest"), Some(verify));
+    // no supported algorithm left
+    assert_eq!(value_of(&tm, "Repr-Digest"), None);
+}
+
+fn md5(data: &[u8]) -> [u8; 16] {
+    compute_digest(&DigestAlgorithm::Md5, &[data]).unwrap().try_into().unwrap()
+}
@@ -0,0 +1,82 @@
+use super::*;
+use decompression_plz::{DecodeError, DecompressError, decompress};
+use header_plz::{
+    Header, OneHeader, body_headers::content_encoding::ContentEncoding,
+};
+use tests_utils::{
+    ALL_COMPRESSIONS, DICTIONARY, INPUT, all_compressed_data, compress_dcz,
+};
+
+#[test]
+fn test_error_corrupt() {
+    let mut tm = TestMessage::<OneHeader>::build_tm_single_header(
+        CONTENT_ENCODING,
+        "gzip",
+        INPUT.into(),
+        Some(INPUT.into()),
+    );
+    let mut buf = BytesMut::new();
+    let err = decompress(&mut tm, &mut buf).unwrap_err();
+    let DecodeError::Corrupt {
+        header_index,
+        encoding,
+        source: DecompressError::Gzip(io_err),
+        original,
+    } = err
+    else {
+        panic!("expected corrupt gzip");
+    };
+    assert_eq!(header_index, 2);
+    assert_eq!(encoding, ContentEncoding::Gzip);
+    assert_eq!(io_err.kind(), std::io::ErrorKind::InvalidInput);
+    assert_eq!(original.body, INPUT);
+    assert_eq!(original.extra.unwrap(), INPUT);
+}
+
+#[test]
+fn test_error_partial_multi_header() {
+    let headers = format!(
+        "Host: example.com\r\n\
+        {CONTENT_ENCODING}: br\r\n\
+        Some-Other-Header: value\r\n\
+        {CONTENT_ENCODING}: deflate, gzip, {ALL_COMPRESSIONS}\r\n\
+        Content-Length: 11\r\n\r\n",
+    );
+    let body = all_compressed_data();
+    let mut tm = TestMessage::<Header>::new(
+        headers.as_bytes().into(),
+        Body::Raw(body.clone()),
+        None,
+    );
+    let mut buf = BytesMut::new();
+    let err = decompress(&mut tm, &mut buf).unwrap_err();
+    assert!(err.is_partial());
+    // "gzip" of the second header fails on the decoded body
+    assert_eq!(err.header_index(), Some(3));
+    assert_eq!(err.encoding(), Some(&ContentEncoding::Gzip));
+    assert!(matches!(err.codec_error(), DecompressError::Gzip(_)));
+    assert_eq!(err.partial_body().unwrap(), INPUT);
+    assert_eq!(err.original(), &body);
+    assert!(err.original_extra().is_none());
+    assert_eq!(tm.body.unwrap().into_bytes().unwrap(), INPUT);
+}
+
+#[test]
+fn test_error_dictionary_not_found() {
+    let body = compress_dcz(INPUT, DICTIONARY);
+    let mut tm = TestMessage::<OneHeader>::build_tm_single_header(
+        CONTENT_ENCODING,
+        "dcz",
+        body.as_slice().into(),
+        None,
+    );
+    let mut buf = BytesMut::new();
+    let err = decompress(&mut tm, &mut buf).unwrap_err();
+    assert!(err.is_corrupt());
+    assert_eq!(err.encoding(), Some(&ContentEncoding::DictionaryZstd));
+    assert!(matches!(
+        err.codec_error(),
+        DecompressError::DictionaryNotFound(_)
+    ));
+    assert_eq!(err.to_string().split('|').next(), Some("corrupt"));
+}
@@ -3,8 +3,19 @@ pub mod both_te_ce;
 pub mod chunked;
 pub mod complete;
 pub mod corrupt;
+pub mod decode_options;
+pub mod dictionary;
+pub mod digest;
+pub mod error;
+pub mod multi_member;
 pub mod no_encodings;
 pub mod partial;
+pub mod raw_deflate;
+pub mod report;
+pub mod sniff;
+pub mod trailers;
+pub mod truncated;
+pub mod two;
 
 fn encoding_state<T>(
     header: &str,
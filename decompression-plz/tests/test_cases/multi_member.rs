@@ -0,0 +1,90 @@
+use super::*;
+use decompression_plz::decompress;
+use header_plz::{Header, OneHeader};
+use tests_utils::{INPUT, compress_gzip, compress_zstd};
+
+fn build_headers(encoding: &str, len: usize) -> String {
+    format!(
+        "Host: example.com\r\n\
+        Content-Type: text/html; charset=utf-8\r\n\
+        Content-Encoding: {}\r\n\
+        Content-Length: {}\r\n\r\n",
+        encoding, len
+    )
+}
+
+fn two_members(compress: fn(&[u8]) -> Vec<u8>) -> Vec<u8> {
+    let mut body = compress(b"hello ");
+    body.extend(compress(b"world"));
+    body
+}
+
+#[test]
+fn test_multi_member_gzip_one() {
+    let body = two_members(compress_gzip);
+    let headers = build_headers("gzip", body.len());
+    let mut tm = TestMessage::<OneHeader>::new(
+        headers.as_bytes().into(),
+        Body::Raw(body.as_slice().into()),
+        None,
+    );
+    let mut buf = BytesMut::new();
+    decompress(&mut tm, &mut buf).unwrap();
+    assert_eq!(tm.into_bytes(), VERIFY_SINGLE_HEADER_BODY_ONLY);
+}
+
+#[test]
+fn test_multi_member_gzip_two() {
+    let body = two_members(compress_gzip);
+    let headers = build_headers("gzip", body.len());
+    let mut tm = TestMessage::<Header>::new(
+        headers.as_bytes().into(),
+        Body::Raw(body.as_slice().into()),
+        None,
+    );
+    let mut buf = BytesMut::new();
+    decompress(&mut tm, &mut buf).unwrap();
+    assert_eq!(tm.body.unwrap().into_bytes().unwrap(), INPUT);
+}
+
+#[test]
+fn test_multi_frame_zstd_one() {
+    let body = two_members(compress_zstd);
+    let headers = build_headers("zstd", body.len());
+    let mut tm = TestMessage::<OneHeader>::new(
+        headers.as_bytes().into(),
+        Body::Raw(body.as_slice().into()),
+        None,
+    );
+    let mut buf = BytesMut::new();
+    decompress(&mut tm, &mut buf).unwrap();
+    assert_eq!(tm.into_bytes(), VERIFY_SINGLE_HEADER_BODY_ONLY);
+}
+
+#[test]
+fn test_multi_member_gzip_extra_member() {
+    let body = compress_gzip(INPUT);
+    let headers = build_headers("gzip", body.len());
+    let mut tm = TestMessage::<OneHeader>::new(
+        headers.as_bytes().into(),
+        Body::Raw(body.as_slice().into()),
+        Some(body.as_slice().into()),
+    );
+    let mut buf = BytesMut::new();
+    decompress(&mut tm, &mut buf).unwrap();
+    assert_eq!(tm.into_bytes(), VERIFY_SINGLE_HEADER_BODY_AND_EXTRA);
+}
+
+#[test]
+fn test_multi_member_gzip_extra_raw() {
+    let body = compress_gzip(INPUT);
+    let headers = build_headers("gzip", body.len());
+    let mut tm = TestMessage::<OneHeader>::new(
+        headers.as_bytes().into(),
+        Body::Raw(body.as_slice().into()),
+        Some(INPUT.into()),
+    );
+    let mut buf = BytesMut::new();
+    decompress(&mut tm, &mut buf).unwrap();
+    assert_eq!(tm.into_bytes(), VERIFY_SINGLE_HEADER_BODY_AND_EXTRA);
+}
@@ -1,4 +1,4 @@
-use decompression_plz::MultiDecompressErrorReason;
+use decompression_plz::DecodeError;
 use tests_utils::{ALL_COMPRESSIONS, all_compressed_data};
 
 use super::*;
@@ -55,7 +55,7 @@ fn assert_partial_decompressed_multi_header(
     assert!(matches!(state, DecodeState::UpdateContentLengthAndErr(..)));
 
     if let Err(e) = state.try_next() {
-        assert!(matches!(e, MultiDecompressErrorReason::Partial { .. }));
+        assert!(matches!(e, DecodeError::Partial { .. }));
         let result = tm.into_bytes();
 
         assert_eq!(result, verify);
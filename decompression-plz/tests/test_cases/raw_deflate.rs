@@ -0,0 +1,72 @@
+use super::*;
+use decompression_plz::{
+    CodecOptions, DeflateVariant, Diagnostic, decompress_with_options,
+};
+use header_plz::{Header, OneHeader};
+use tests_utils::{INPUT, compress_raw_deflate};
+
+fn build_headers(len: usize) -> String {
+    format!(
+        "Host: example.com\r\n\
+        Content-Type: text/html; charset=utf-8\r\n\
+        Content-Encoding: deflate\r\n\
+        Content-Length: {}\r\n\r\n",
+        len
+    )
+}
+
+#[test]
+fn test_raw_deflate_one() {
+    let body = compress_raw_deflate(INPUT);
+    let headers = build_headers(body.len());
+    let mut tm = TestMessage::<OneHeader>::new(
+        headers.as_bytes().into(),
+        Body::Raw(body.as_slice().into()),
+        None,
+    );
+    let mut buf = BytesMut::new();
+    let mut diagnostics = Vec::new();
+    decompress_with_options(
+        &mut tm,
+        &mut buf,
+        CodecOptions::default(),
+        &mut diagnostics,
+    )
+    .unwrap();
+    assert_eq!(tm.into_bytes(), VERIFY_SINGLE_HEADER_BODY_ONLY);
+    assert_eq!(
+        diagnostics,
+        vec![Diagnostic::Deflate {
+            header_index: 2,
+            variant: DeflateVariant::Raw,
+        }]
+    );
+}
+
+#[test]
+fn test_raw_deflate_two() {
+    let body = compress_raw_deflate(INPUT);
+    let headers = build_headers(body.len());
+    let mut tm = TestMessage::<Header>::new(
+        headers.as_bytes().into(),
+        Body::Raw(body.as_slice().into()),
+        None,
+    );
+    let mut buf = BytesMut::new();
+    decompression_plz::decompress(&mut tm, &mut buf).unwrap();
+    assert_eq!(tm.body.unwrap().into_bytes().unwrap(), INPUT);
+}
+
+#[test]
+fn test_raw_deflate_with_extra_raw() {
+    let body = compress_raw_deflate(INPUT);
+    let headers = build_headers(body.len());
+    let mut tm = TestMessage::<OneHeader>::new(
+        headers.as_bytes().into(),
+        Body::Raw(body.as_slice().into()),
+        Some(INPUT.into()),
+    );
+    let mut buf = BytesMut::new();
+    decompression_plz::decompress(&mut tm, &mut buf).unwrap();
+    assert_eq!(tm.into_bytes(), VERIFY_SINGLE_HEADER_BODY_AND_EXTRA);
+}
@@ -0,0 +1,229 @@
+use super::*;
+use decompression_plz::{
+    CodecOptions, DecodeError, DecodeReport, EncodingReport, EndState,
+    LayerReport, decompress_with_report,
+};
+use header_plz::{
+    Header, OneHeader, body_headers::content_encoding::ContentEncoding,
+};
+use tests_utils::{
+    INPUT, all_compressed_data, compress_brotli, compress_gzip, compress_zstd,
+};
+
+fn layer(
+    header_index: usize,
+    encoding: ContentEncoding,
+    size_before: usize,
+    size_after: usize,
+) -> LayerReport {
+    LayerReport {
+        header_index,
+        encoding,
+        size_before,
+        size_after,
+    }
+}
+
+#[test]
+fn test_report_content_encoding_one() {
+    let mut tm = TestMessage::<OneHeader>::build_tm_sh_single_compression(
+        CONTENT_ENCODING,
+        ContentEncoding::Gzip,
+    );
+    let compressed_len = compress_gzip(INPUT).len();
+    let mut buf = BytesMut::new();
+    let (result, report) =
+        decompress_with_report(&mut tm, &mut buf, CodecOptions::default());
+    result.unwrap();
+    assert_eq!(
+        report,
+        DecodeReport {
+            content_encoding: Some(EncodingReport {
+                layers: vec![layer(
+                    2,
+                    ContentEncoding::Gzip,
+                    compressed_len,
+                    INPUT.len()
+                )],
+                end_state: Some(EndState::MainOnly),
+                is_extra_raw: false,
+            }),
+            removed_headers: vec![2],
+            ..Default::default()
+        }
+    );
+}
+
+#[test]
+fn test_report_all_layers_two() {
+    let mut tm = TestMessage::<Header>::build_tm_sh_all(TRANSFER_ENCODING);
+    let mut buf = BytesMut::new();
+    let (result, report) =
+        decompress_with_report(&mut tm, &mut buf, CodecOptions::default());
+    result.unwrap();
+    let te = report.transfer_encoding.unwrap();
+    assert_eq!(
+        te.layers.iter().map(|layer| &layer.encoding).collect::<Vec<_>>(),
+        vec![
+            &ContentEncoding::Zstd,
+            &ContentEncoding::Gzip,
+            &ContentEncoding::Deflate,
+            &ContentEncoding::Brotli,
+        ]
+    );
+    // sizes chain from one layer to the next
+    assert_eq!(te.layers[0].size_before, all_compressed_data().len());
+    for pair in te.layers.windows(2) {
+        assert_eq!(pair[0].size_after, pair[1].size_before);
+    }
+    assert_eq!(te.layers[3].size_after, INPUT.len());
+    assert_eq!(te.end_state, Some(EndState::MainOnly));
+    assert!(report.content_encoding.is_none());
+    assert_eq!(report.removed_headers, vec![2]);
+}
+
+#[test]
+fn test_report_extra_raw() {
+    let mut tm =
+        TestMessage::<OneHeader>::build_tm_sh_single_compression_with_extra_raw(
+            CONTENT_ENCODING,
+            ContentEncoding::Brotli,
+        );
+    let mut buf = BytesMut::new();
+    let (result, report) =
+        decompress_with_report(&mut tm, &mut buf, CodecOptions::default());
+    result.unwrap();
+    let ce = report.content_encoding.unwrap();
+    assert_eq!(ce.end_state, Some(EndState::ExtraRawMainDone));
+    assert!(ce.is_extra_raw);
+    assert_eq!(
+        ce.layers,
+        vec![layer(
+            2,
+            ContentEncoding::Brotli,
+            compress_brotli(INPUT).len(),
+            INPUT.len()
+        )]
+    );
+}
+
+#[test]
+fn test_report_extra_separate() {
+    let mut tm = TestMessage::<OneHeader>::build_tm_sh_single_compression_with_extra_compressed_separate(
+        CONTENT_ENCODING,
+        ContentEncoding::Brotli,
+    );
+    let mut buf = BytesMut::new();
+    let (result, report) =
+        decompress_with_report(&mut tm, &mut buf, CodecOptions::default());
+    result.unwrap();
+    let ce = report.content_encoding.unwrap();
+    assert_eq!(ce.end_state, Some(EndState::ExtraMainSeparate));
+    assert!(!ce.is_extra_raw);
+    // main and extra add up
+    assert_eq!(
+        ce.layers,
+        vec![layer(
+            2,
+            ContentEncoding::Brotli,
+            compress_brotli(INPUT).len() * 2,
+            INPUT.len() * 2
+        )]
+    );
+}
+
+#[test]
+fn test_report_extra_together() {
+    let mut tm = TestMessage::<OneHeader>::build_tm_sh_single_compression_with_extra_compressed_together(
+        CONTENT_ENCODING,
+        ContentEncoding::Gzip,
+    );
+    let mut buf = BytesMut::new();
+    let (result, report) =
+        decompress_with_report(&mut tm, &mut buf, CodecOptions::default());
+    result.unwrap();
+    let ce = report.content_encoding.unwrap();
+    assert_eq!(ce.end_state, Some(EndState::MainPlusExtra));
+    assert_eq!(
+        ce.layers,
+        vec![layer(
+            2,
+            ContentEncoding::Gzip,
+            compress_gzip(INPUT).len(),
+            INPUT.len()
+        )]
+    );
+}
+
+#[test]
+fn test_report_extra_member() {
+    let compressed = compress_zstd(INPUT);
+    let mut tm = TestMessage::<OneHeader>::build_tm_single_header(
+        CONTENT_ENCODING,
+        "zstd",
+        compressed.as_slice().into(),
+        Some(compressed.as_slice().into()),
+    );
+    let mut buf = BytesMut::new();
+    let (result, report) =
+        decompress_with_report(&mut tm, &mut buf, CodecOptions::default());
+    result.unwrap();
+    let ce = report.content_encoding.unwrap();
+    assert_eq!(ce.end_state, Some(EndState::ExtraMember));
+}
+
+#[test]
+fn test_report_partial() {
+    let headers = "Host: example.com\r\n\
+                   Content-Type: text/html; charset=utf-8\r\n\
+                   Content-Encoding: br, deflate, gzip, br, deflate, gzip, zstd\r\n\
+                   Content-Length: 11\r\n\r\n";
+    let mut tm = TestMessage::<OneHeader>::new(
+        headers.into(),
+        Body::Raw(all_compressed_data()),
+        None,
+    );
+    let mut buf = BytesMut::new();
+    let (result, report) =
+        decompress_with_report(&mut tm, &mut buf, CodecOptions::default());
+    assert!(matches!(result, Err(DecodeError::Partial { .. })));
+    let ce = report.content_encoding.unwrap();
+    assert_eq!(ce.end_state, None);
+    assert_eq!(
+        ce.layers.iter().map(|layer| &layer.encoding).collect::<Vec<_>>(),
+        vec![
+            &ContentEncoding::Zstd,
+            &ContentEncoding::Gzip,
+            &ContentEncoding::Deflate,
+            &ContentEncoding::Brotli,
+        ]
+    );
+    assert!(report.removed_headers.is_empty());
+    assert_eq!(report.rewritten_headers, vec![2]);
+}
+
+#[test]
+fn test_report_chunked_and_identity() {
+    let headers = "Host: example.com\r\n\
+                   Content-Encoding: identity\r\n\
+                   Transfer-Encoding: chunked\r\n";
+    let body = body_plz::variants::chunked::ChunkType::Chunk(
+        "hello world\r\n".into(),
+    );
+    let mut tm = TestMessage::<OneHeader>::new(
+        headers.into(),
+        Body::Chunked(vec![body]),
+        None,
+    );
+    let mut buf = BytesMut::new();
+    let (result, report) =
+        decompress_with_report(&mut tm, &mut buf, CodecOptions::default());
+    result.unwrap();
+    assert!(report.chunked);
+    assert_eq!(report.removed_headers, vec![2, 1]);
+    assert_eq!(report.applied_encodings().count(), 0);
+    assert_eq!(
+        tm.into_bytes(),
+        "Host: example.com\r\nContent-Length: 11\r\nhello world"
+    );
+}
@@ -0,0 +1,95 @@
+use super::*;
+use decompression_plz::{
+    CodecOptions, DecodeError, Diagnostic, decompress_with_options,
+};
+use header_plz::{
+    Header, OneHeader, body_headers::content_encoding::ContentEncoding,
+};
+use tests_utils::{INPUT, compress_gzip, compress_zstd};
+
+fn build_headers(encoding: &str, len: usize) -> String {
+    format!(
+        "Host: example.com\r\n\
+        Content-Type: text/html; charset=utf-8\r\n\
+        Content-Encoding: {}\r\n\
+        Content-Length: {}\r\n\r\n",
+        encoding, len
+    )
+}
+
+#[test]
+fn test_sniff_gzip_labeled_deflate_one() {
+    let body = compress_gzip(INPUT);
+    let headers = build_headers("deflate", body.len());
+    let mut tm = TestMessage::<OneHeader>::new(
+        headers.as_bytes().into(),
+        Body::Raw(body.as_slice().into()),
+        None,
+    );
+    let mut buf = BytesMut::new();
+    let mut diagnostics = Vec::new();
+    let options = CodecOptions::default().with_sniff(true);
+    decompress_with_options(&mut tm, &mut buf, options, &mut diagnostics)
+        .unwrap();
+    assert_eq!(tm.into_bytes(), VERIFY_SINGLE_HEADER_BODY_ONLY);
+    assert_eq!(
+        diagnostics,
+        vec![Diagnostic::EncodingMismatch {
+            header_index: 2,
+            declared: ContentEncoding::Deflate,
+            detected: ContentEncoding::Gzip,
+            corrected: true,
+        }]
+    );
+}
+
+#[test]
+fn test_sniff_double_compressed_two() {
+    let body = compress_gzip(&compress_gzip(INPUT));
+    let headers = build_headers("gzip", body.len());
+    let mut tm = TestMessage::<Header>::new(
+        headers.as_bytes().into(),
+        Body::Raw(body.as_slice().into()),
+        None,
+    );
+    let mut buf = BytesMut::new();
+    let mut diagnostics = Vec::new();
+    let options = CodecOptions::default().with_undeclared(true);
+    decompress_with_options(&mut tm, &mut buf, options, &mut diagnostics)
+        .unwrap();
+    assert_eq!(tm.body.unwrap().into_bytes().unwrap(), INPUT);
+    assert_eq!(
+        diagnostics,
+        vec![Diagnostic::UndeclaredEncoding {
+            detected: ContentEncoding::Gzip,
+        }]
+    );
+}
+
+#[test]
+fn test_sniff_mismatch_corrupt() {
+    let mut body = compress_zstd(INPUT);
+    body.truncate(body.len() - 4);
+    let headers = build_headers("gzip", body.len());
+    let mut tm = TestMessage::<OneHeader>::new(
+        headers.as_bytes().into(),
+        Body::Raw(body.as_slice().into()),
+        None,
+    );
+    let mut buf = BytesMut::new();
+    let mut diagnostics = Vec::new();
+    let options = CodecOptions::default().with_sniff(true);
+    let result =
+        decompress_with_options(&mut tm, &mut buf, options, &mut diagnostics)
+            .unwrap_err();
+    assert!(matches!(result, DecodeError::Corrupt { .. }));
+    assert_eq!(
+        diagnostics,
+        vec![Diagnostic::EncodingMismatch {
+            header_index: 2,
+            declared: ContentEncoding::Gzip,
+            detected: ContentEncoding::Zstd,
+            corrected: false,
+        }]
+    );
+}
@@ -0,0 +1,181 @@
+use super::*;
+use body_plz::variants::chunked::ChunkType;
+use decompression_plz::{
+    DecodeOptions, Diagnostic, TrailerPolicy,
+    chunked::{chunked_to_raw, is_prohibited_trailer},
+    decompress_with_report,
+};
+use header_plz::{OneHeader, OneHeaderMap};
+use tests_utils::INPUT;
+
+const HEADERS: &str = "Host: example.com\r\n\
+                       Trailer: Server-Timing, Digest\r\n\
+                       Transfer-Encoding: chunked\r\n";
+
+const TRAILERS: &str = "Server-Timing: total;dur=10\r\n\
+                        Content-Length: 100\r\n\
+                        Host: evil.com\r\n\
+                        Digest: sha-256=abc\r\n\
+                        Undeclared: value\r\n";
+
+fn chunked_body_with_trailers(trailers: &str) -> Body {
+    let mut chunk = BytesMut::from(INPUT);
+    chunk.extend_from_slice(b"\r\n");
+    Body::Chunked(vec![
+        ChunkType::Size(format!("{:x}\r\n", INPUT.len()).as_str().into()),
+        ChunkType::Chunk(chunk),
+        ChunkType::LastChunk("0\r\n".into()),
+        ChunkType::Trailers(OneHeaderMap::from(BytesMut::from(trailers))),
+    ])
+}
+
+fn decode_trailers(
+    policy: TrailerPolicy,
+) -> (TestMessage<OneHeader>, Vec<Diagnostic>) {
+    let mut tm = TestMessage::<OneHeader>::new(
+        HEADERS.into(),
+        chunked_body_with_trailers(TRAILERS),
+        None,
+    );
+    let mut buf = BytesMut::new();
+    let options = DecodeOptions::default().with_trailers(policy);
+    let (result, report) = decompress_with_report(&mut tm, &mut buf, options);
+    result.unwrap();
+    (tm, report.diagnostics)
+}
+
+fn trailer_diagnostics() -> Vec<Diagnostic> {
+    vec![
+        Diagnostic::ProhibitedTrailer {
+            name: "Content-Length".into(),
+        },
+        Diagnostic::ProhibitedTrailer {
+            name: "Host".into(),
+        },
+        Diagnostic::UndeclaredTrailer {
+            name: "Undeclared".into(),
+        },
+    ]
+}
+
+#[test]
+fn test_trailers_merge_filters_prohibited() {
+    let (tm, diagnostics) = decode_trailers(TrailerPolicy::Merge);
+    assert_eq!(diagnostics, trailer_diagnostics());
+    assert!(tm.trailers.is_none());
+    let verify = "Host: example.com\r\n\
+                  Server-Timing: total;dur=10\r\n\
+                  Digest: sha-256=abc\r\n\
+                  Undeclared: value\r\n\
+                  Content-Length: 11\r\n\
+                  hello world";
+    assert_eq!(tm.into_bytes(), verify);
+}
+
+#[test]
+fn test_trailers_merge_all() {
+    let (tm, diagnostics) = decode_trailers(TrailerPolicy::MergeAll);
+    assert_eq!(diagnostics, trailer_diagnostics());
+    let verify = "Host: example.com\r\n\
+                  Server-Timing: total;dur=10\r\n\
+                  Content-Length: 11\r\n\
+                  Host: evil.com\r\n\
+                  Digest: sha-256=abc\r\n\
+                  Undeclared: value\r\n\
+                  hello world";
+    assert_eq!(tm.into_bytes(), verify);
+}
+
+#[test]
+fn test_trailers_separate() {
+    let (mut tm, diagnostics) = decode_trailers(TrailerPolicy::Separate);
+    assert_eq!(diagnostics, trailer_diagnostics());
+    assert_eq!(
+        tm.trailers.take(),
+        Some(OneHeaderMap::from(BytesMut::from(TRAILERS)))
+    );
+    let verify = "Host: example.com\r\n\
+                  Trailer: Server-Timing, Digest\r\n\
+                  Content-Length: 11\r\n\
+                  hello world";
+    assert_eq!(tm.into_bytes(), verify);
+}
+
+#[test]
+fn test_trailers_drop() {
+    let (tm, diagnostics) = decode_trailers(TrailerPolicy::Drop);
+    assert_eq!(diagnostics, trailer_diagnostics());
+    assert!(tm.trailers.is_none());
+    let verify = "Host: example.com\r\n\
+                  Trailer: Server-Timing, Digest\r\n\
+                  Content-Length: 11\r\n\
+                  hello world";
+    assert_eq!(tm.into_bytes(), verify);
+}
+
+#[test]
+fn test_trailers_all_declared() {
+    let mut tm = TestMessage::<OneHeader>::new(
+        HEADERS.into(),
+        chunked_body_with_trailers(
+            "server-timing: total;dur=10\r\n\
+             Digest: sha-256=abc\r\n",
+        ),
+        None,
+    );
+    let mut buf = BytesMut::new();
+    let (result, report) =
+        decompress_with_report(&mut tm, &mut buf, DecodeOptions::default());
+    result.unwrap();
+    assert!(report.diagnostics.is_empty());
+}
+
+#[test]
+fn test_trailers_no_trailer_header() {
+    let headers = "Host: example.com\r\n\
+                   Transfer-Encoding: chunked\r\n";
+    let mut tm = TestMessage::<OneHeader>::new(
+        headers.into(),
+        chunked_body_with_trailers("Digest: sha-256=abc\r\n"),
+        None,
+    );
+    let mut buf = BytesMut::new();
+    let (result, report) =
+        decompress_with_report(&mut tm, &mut buf, DecodeOptions::default());
+    result.unwrap();
+    assert_eq!(
+        report.diagnostics,
+        vec![Diagnostic::UndeclaredTrailer {
+            name: "Digest".into()
+        }]
+    );
+}
+
+// chunked_to_raw drops the prohibited fields as well
+#[test]
+fn test_trailers_chunked_to_raw() {
+    let mut tm = TestMessage::<OneHeader>::new(
+        HEADERS.into(),
+        chunked_body_with_trailers(TRAILERS),
+        None,
+    );
+    let mut buf = BytesMut::new();
+    chunked_to_raw(&mut tm, &mut buf);
+    let verify = "Host: example.com\r\n\
+                  Transfer-Encoding: chunked\r\n\
+                  Server-Timing: total;dur=10\r\n\
+                  Digest: sha-256=abc\r\n\
+                  Undeclared: value\r\n\
+                  hello world";
+    assert_eq!(tm.into_bytes(), verify);
+}
+
+#[test]
+fn test_is_prohibited_trailer() {
+    assert!(is_prohibited_trailer(b"content-length"));
+    assert!(is_prohibited_trailer(b"Transfer-Encoding"));
+    assert!(is_prohibited_trailer(b"HOST"));
+    assert!(is_prohibited_trailer(b"Authorization"));
+    assert!(!is_prohibited_trailer(b"Server-Timing"));
+    assert!(!is_prohibited_trailer(b"Digest"));
+}
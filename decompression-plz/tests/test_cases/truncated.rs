@@ -0,0 +1,114 @@
+use super::*;
+use decompression_plz::{
+    CodecOptions, DecodeError, Diagnostic, decompress_with_options,
+};
+use header_plz::{
+    Header, OneHeader, body_headers::content_encoding::ContentEncoding,
+};
+use tests_utils::{compress_brotli, compress_gzip, large_input};
+
+fn build_headers(encoding: &str, len: usize) -> String {
+    format!(
+        "Host: example.com\r\n\
+        Content-Type: text/html; charset=utf-8\r\n\
+        Content-Encoding: {}\r\n\
+        Content-Length: {}\r\n\r\n",
+        encoding, len
+    )
+}
+
+fn verify(len: usize) -> String {
+    format!(
+        "Host: example.com\r\n\
+        Content-Type: text/html; charset=utf-8\r\n\
+        Content-Length: {}\r\n\r\n",
+        len
+    )
+}
+
+#[test]
+fn test_truncated_gzip_one() {
+    let data = large_input();
+    let compressed = compress_gzip(&data);
+    let body = &compressed[..compressed.len() / 2];
+    let headers = build_headers("gzip", body.len());
+    let mut tm = TestMessage::<OneHeader>::new(
+        headers.as_bytes().into(),
+        Body::Raw(body.into()),
+        None,
+    );
+    let mut buf = BytesMut::new();
+    let mut diagnostics = Vec::new();
+    let options = CodecOptions::default().with_best_effort(true);
+    decompress_with_options(&mut tm, &mut buf, options, &mut diagnostics)
+        .unwrap();
+    let Diagnostic::Truncated {
+        header_index,
+        encoding,
+        input_offset,
+        output_len,
+    } = diagnostics.pop().unwrap()
+    else {
+        panic!("expected truncated");
+    };
+    assert!(diagnostics.is_empty());
+    assert_eq!(header_index, 2);
+    assert_eq!(encoding, ContentEncoding::Gzip);
+    assert_eq!(input_offset, body.len());
+    let result = tm.into_bytes();
+    let (head, decoded) = result.split_at(result.len() - output_len);
+    assert_eq!(head, verify(output_len).as_bytes());
+    assert!(!decoded.is_empty());
+    assert!(data.starts_with(decoded));
+}
+
+#[test]
+fn test_truncated_brotli_two() {
+    let data = large_input();
+    let compressed = compress_brotli(&data);
+    let body = &compressed[..compressed.len() / 2];
+    let headers = build_headers("br", body.len());
+    let mut tm = TestMessage::<Header>::new(
+        headers.as_bytes().into(),
+        Body::Raw(body.into()),
+        None,
+    );
+    let mut buf = BytesMut::new();
+    let mut diagnostics = Vec::new();
+    let options = CodecOptions::default().with_best_effort(true);
+    decompress_with_options(&mut tm, &mut buf, options, &mut diagnostics)
+        .unwrap();
+    assert!(matches!(
+        diagnostics.as_slice(),
+        [Diagnostic::Truncated {
+            encoding: ContentEncoding::Brotli,
+            ..
+        }]
+    ));
+    let decoded = tm.body.unwrap().into_bytes().unwrap();
+    assert!(!decoded.is_empty());
+    assert!(data.starts_with(&decoded));
+}
+
+#[test]
+fn test_truncated_best_effort_disabled() {
+    let compressed = compress_gzip(&large_input());
+    let body = &compressed[..compressed.len() / 2];
+    let headers = build_headers("gzip", body.len());
+    let mut tm = TestMessage::<OneHeader>::new(
+        headers.as_bytes().into(),
+        Body::Raw(body.into()),
+        None,
+    );
+    let mut buf = BytesMut::new();
+    let mut diagnostics = Vec::new();
+    let result = decompress_with_options(
+        &mut tm,
+        &mut buf,
+        CodecOptions::default(),
+        &mut diagnostics,
+    )
+    .unwrap_err();
+    assert!(matches!(result, DecodeError::Corrupt { .. }));
+    assert!(diagnostics.is_empty());
+}
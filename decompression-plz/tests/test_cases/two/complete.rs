@@ -0,0 +1,149 @@
+use header_plz::body_headers::content_encoding::ContentEncoding;
+use rstest::rstest;
+use tests_utils::{
+    ALL_COMPRESSIONS, all_compressed_data, compress_gzip, single_compression,
+};
+
+use super::*;
+
+#[track_caller]
+fn assert_case_two(mut tm: TestMessage<Header>, verify: &str) {
+    let mut buf = BytesMut::new();
+    let mut state = DecodeState::init(&mut tm, &mut buf);
+    state = state.try_next().unwrap();
+    assert!(matches!(state, DecodeState::ContentEncoding(_, _)));
+    state = state.try_next().unwrap();
+    assert!(matches!(state, DecodeState::UpdateContentLength(_)));
+    state = state.try_next().unwrap();
+    assert!(state.is_ended());
+    assert_eq!(tm.into_bytes_two(), verify);
+}
+
+// ----- single compression
+#[rstest]
+fn test_two_single_compression(
+    #[values(
+        ContentEncoding::Brotli,
+        ContentEncoding::Compress,
+        ContentEncoding::Deflate,
+        ContentEncoding::Gzip,
+        ContentEncoding::Identity,
+        ContentEncoding::Zstd
+    )]
+    encoding: ContentEncoding,
+) {
+    let body = single_compression(&encoding);
+    let tm = build_tm_two(encoding.as_ref(), &body, None);
+    assert_case_two(tm, VERIFY_TWO_BODY_ONLY);
+}
+
+#[rstest]
+fn test_two_single_compression_extra_raw(
+    #[values(
+        ContentEncoding::Brotli,
+        ContentEncoding::Deflate,
+        ContentEncoding::Gzip,
+        ContentEncoding::Zstd
+    )]
+    encoding: ContentEncoding,
+) {
+    let body = single_compression(&encoding);
+    let tm = build_tm_two(encoding.as_ref(), &body, Some(INPUT.into()));
+    assert_case_two(tm, VERIFY_TWO_BODY_AND_EXTRA);
+}
+
+#[rstest]
+fn test_two_single_compression_extra_compressed_together(
+    #[values(
+        ContentEncoding::Brotli,
+        ContentEncoding::Deflate,
+        ContentEncoding::Gzip,
+        ContentEncoding::Zstd
+    )]
+    encoding: ContentEncoding,
+) {
+    let compressed = single_compression(&encoding);
+    let (body, extra) = compressed.split_at(compressed.len() / 2);
+    let tm = build_tm_two(encoding.as_ref(), body, Some(extra.into()));
+    assert_case_two(tm, VERIFY_TWO_BODY_ONLY);
+}
+
+#[rstest]
+fn test_two_single_compression_extra_compressed_separate(
+    #[values(
+        ContentEncoding::Brotli,
+        ContentEncoding::Deflate,
+        ContentEncoding::Gzip,
+        ContentEncoding::Zstd
+    )]
+    encoding: ContentEncoding,
+) {
+    let body = single_compression(&encoding);
+    let tm = build_tm_two(encoding.as_ref(), &body, Some(body.clone()));
+    assert_case_two(tm, VERIFY_TWO_BODY_AND_EXTRA);
+}
+
+// ----- multi compression
+#[test]
+fn test_two_multi_compression_single_header() {
+    let tm = build_tm_two(ALL_COMPRESSIONS, &all_compressed_data(), None);
+    assert_case_two(tm, VERIFY_TWO_BODY_ONLY);
+}
+
+#[test]
+fn test_two_multi_compression_single_header_extra_raw() {
+    let tm = build_tm_two(
+        ALL_COMPRESSIONS,
+        &all_compressed_data(),
+        Some(INPUT.into()),
+    );
+    assert_case_two(tm, VERIFY_TWO_BODY_AND_EXTRA);
+}
+
+#[test]
+fn test_two_multi_compression_multi_header() {
+    let body = all_compressed_data();
+    let headers = format!(
+        "Host: example.com\r\n\
+        {ENCODING}: br\r\n\
+        Content-Type: text/html; charset=utf-8\r\n\
+        {ENCODING}: deflate\r\n\
+        random: random\r\n\
+        {ENCODING}: identity\r\n\
+        another-random: random\r\n\
+        {ENCODING}: gzip\r\n\
+        test-header: test-header\r\n\
+        {ENCODING}: zstd\r\n\
+        Content-Length: {}\r\n\r\n",
+        body.len()
+    );
+    let tm = TestMessage::new_two(
+        headers.as_bytes().into(),
+        data_frames(&body, FRAME_SIZE),
+        None,
+    );
+    let verify = "host: example.com\r\n\
+                  content-type: text/html; charset=utf-8\r\n\
+                  random: random\r\n\
+                  another-random: random\r\n\
+                  test-header: test-header\r\n\
+                  content-length: 11\r\n\r\n\
+                  hello world";
+    assert_case_two(tm, verify);
+}
+
+// ----- content-length
+#[test]
+fn test_two_no_content_length_not_added() {
+    let body = compress_gzip(INPUT);
+    let headers = "Host: example.com\r\n\
+                   Content-Encoding: gzip\r\n\r\n";
+    let tm = TestMessage::new_two(
+        headers.into(),
+        data_frames(&body, FRAME_SIZE),
+        None,
+    );
+    let verify = "host: example.com\r\n\r\n\
+                  hello world";
+    assert_case_two(tm, verify);
+}
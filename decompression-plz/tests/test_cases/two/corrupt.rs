@@ -0,0 +1,52 @@
+use decompression_plz::DecodeError;
+use header_plz::body_headers::content_encoding::ContentEncoding;
+use rstest::rstest;
+
+use super::*;
+
+#[rstest]
+fn test_two_corrupt(
+    #[values(
+        ContentEncoding::Brotli,
+        ContentEncoding::Compress,
+        ContentEncoding::Deflate,
+        ContentEncoding::Gzip,
+        ContentEncoding::Zstd
+    )]
+    encoding: ContentEncoding,
+    #[values(true, false)] extra: bool,
+) {
+    let mut tm =
+        build_tm_two(encoding.as_ref(), INPUT, extra.then(|| INPUT.into()));
+    let mut buf = BytesMut::new();
+    let mut state = DecodeState::init(&mut tm, &mut buf);
+    state = state.try_next().unwrap();
+    assert!(matches!(state, DecodeState::ContentEncoding(_, _)));
+    state = state.try_next().unwrap();
+    assert!(matches!(state, DecodeState::UpdateContentLengthAndErr(..)));
+    let Err(e) = state.try_next() else {
+        panic!()
+    };
+    assert!(matches!(e, DecodeError::Corrupt { .. }));
+
+    let verify = if extra {
+        format!(
+            "host: example.com\r\n\
+            content-type: text/html; charset=utf-8\r\n\
+            content-encoding: {}\r\n\
+            content-length: 22\r\n\r\n\
+            hello worldhello world",
+            encoding.as_ref()
+        )
+    } else {
+        format!(
+            "host: example.com\r\n\
+            content-type: text/html; charset=utf-8\r\n\
+            content-encoding: {}\r\n\
+            content-length: 11\r\n\r\n\
+            hello world",
+            encoding.as_ref()
+        )
+    };
+    assert_eq!(tm.into_bytes_two(), verify);
+}
@@ -0,0 +1,74 @@
+use header_plz::Header;
+use tests_utils::{INPUT, data_frames};
+
+use super::*;
+pub mod complete;
+pub mod corrupt;
+pub mod no_encodings;
+pub mod partial;
+
+// h2 has no transfer codings
+const ENCODING: &str = CONTENT_ENCODING;
+
+// small enough to split every compressed body into multiple frames
+const FRAME_SIZE: usize = 4;
+
+const VERIFY_TWO_BODY_ONLY: &str = "host: example.com\r\n\
+                                    content-type: text/html; charset=utf-8\r\n\
+                                    content-length: 11\r\n\r\n\
+                                    hello world";
+
+const VERIFY_TWO_BODY_AND_EXTRA: &str = "host: example.com\r\n\
+                                         content-type: text/html; charset=utf-8\r\n\
+                                         content-length: 22\r\n\r\n\
+                                         hello worldhello world";
+
+fn build_tm_two(
+    encodings: &str,
+    body: &[u8],
+    extra: Option<BytesMut>,
+) -> TestMessage<Header> {
+    let headers = format!(
+        "Host: example.com\r\n\
+        Content-Type: text/html; charset=utf-8\r\n\
+        {ENCODING}: {encodings}\r\n\
+        Content-Length: {}\r\n\r\n",
+        body.len()
+    );
+    TestMessage::new_two(
+        headers.as_bytes().into(),
+        data_frames(body, FRAME_SIZE),
+        extra,
+    )
+}
+
+#[test]
+fn test_two_data_frames_assembled() {
+    let body = tests_utils::large_input();
+    let headers = "Host: example.com\r\n\r\n";
+    let tm =
+        TestMessage::new_two(headers.into(), data_frames(&body, 16384), None);
+    assert_eq!(tm.body, Some(Body::Raw(body.as_slice().into())));
+}
+
+#[test]
+fn test_two_te_ignored() {
+    let body = tests_utils::compress_gzip(INPUT);
+    let headers = format!(
+        "Host: example.com\r\n\
+        Transfer-Encoding: gzip\r\n\
+        Content-Length: {}\r\n\r\n",
+        body.len()
+    );
+    let mut tm = TestMessage::new_two(
+        headers.as_bytes().into(),
+        data_frames(&body, FRAME_SIZE),
+        None,
+    );
+    let verify = tm.clone();
+    let mut buf = BytesMut::new();
+    let mut state = DecodeState::init(&mut tm, &mut buf);
+    state = state.try_next().unwrap();
+    assert!(state.is_ended());
+    assert_eq!(tm, verify);
+}
@@ -0,0 +1,57 @@
+use super::*;
+
+fn build_tm_two_no_enc(
+    content_length: bool,
+    extra: Option<BytesMut>,
+) -> TestMessage<Header> {
+    let mut headers = String::from(
+        "Host: example.com\r\n\
+        Content-Type: text/html; charset=utf-8\r\n",
+    );
+    if content_length {
+        headers.push_str("Content-Length: 11\r\n");
+    }
+    headers.push_str("\r\n");
+    TestMessage::new_two(
+        headers.as_bytes().into(),
+        data_frames(INPUT, FRAME_SIZE),
+        extra,
+    )
+}
+
+#[test]
+fn test_two_no_enc() {
+    let mut tm = build_tm_two_no_enc(true, None);
+    let mut buf = BytesMut::new();
+    let mut state = DecodeState::init(&mut tm, &mut buf);
+    state = state.try_next().unwrap();
+    assert!(state.is_ended());
+    assert_eq!(tm.into_bytes_two(), VERIFY_TWO_BODY_ONLY);
+}
+
+#[test]
+fn test_two_no_enc_extra_body() {
+    let mut tm = build_tm_two_no_enc(true, Some(INPUT.into()));
+    let mut buf = BytesMut::new();
+    let mut state = DecodeState::init(&mut tm, &mut buf);
+    state = state.try_next().unwrap();
+    assert!(matches!(state, DecodeState::UpdateContentLength(_)));
+    state = state.try_next().unwrap();
+    assert!(state.is_ended());
+    assert_eq!(tm.into_bytes_two(), VERIFY_TWO_BODY_AND_EXTRA);
+}
+
+#[test]
+fn test_two_no_enc_no_content_length() {
+    let mut tm = build_tm_two_no_enc(false, None);
+    let mut buf = BytesMut::new();
+    let mut state = DecodeState::init(&mut tm, &mut buf);
+    state = state.try_next().unwrap();
+    assert!(matches!(state, DecodeState::UpdateContentLength(_)));
+    state = state.try_next().unwrap();
+    assert!(state.is_ended());
+    let verify = "host: example.com\r\n\
+                  content-type: text/html; charset=utf-8\r\n\r\n\
+                  hello world";
+    assert_eq!(tm.into_bytes_two(), verify);
+}
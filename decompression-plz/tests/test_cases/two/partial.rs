@@ -0,0 +1,75 @@
+use decompression_plz::DecodeError;
+use tests_utils::{ALL_COMPRESSIONS, all_compressed_data};
+
+use super::*;
+
+#[track_caller]
+fn assert_partial_two(
+    mut tm: TestMessage<Header>,
+    extra: bool,
+    verify_headers: &str,
+) {
+    let mut buf = BytesMut::new();
+    let mut state = DecodeState::init(&mut tm, &mut buf);
+    state = state.try_next().unwrap();
+    assert!(matches!(state, DecodeState::ContentEncoding(_, _)));
+    state = state.try_next().unwrap();
+    assert!(matches!(state, DecodeState::UpdateContentLengthAndErr(..)));
+    let Err(e) = state.try_next() else {
+        panic!()
+    };
+    assert!(matches!(e, DecodeError::Partial { .. }));
+
+    let verify = if extra {
+        format!(
+            "{verify_headers}\
+            content-length: 22\r\n\r\n\
+            hello worldhello world"
+        )
+    } else {
+        format!(
+            "{verify_headers}\
+            content-length: 11\r\n\r\n\
+            hello world"
+        )
+    };
+    assert_eq!(tm.into_bytes_two(), verify);
+}
+
+#[rstest::rstest]
+fn test_two_partial_single_header(#[values(true, false)] extra: bool) {
+    let tm = build_tm_two(
+        &format!("br, deflate, gzip, {ALL_COMPRESSIONS}"),
+        &all_compressed_data(),
+        extra.then(|| INPUT.into()),
+    );
+    let verify_headers = "host: example.com\r\n\
+                          content-type: text/html; charset=utf-8\r\n\
+                          content-encoding: br, deflate, gzip\r\n";
+    assert_partial_two(tm, extra, verify_headers);
+}
+
+#[rstest::rstest]
+fn test_two_partial_multi_header(#[values(true, false)] extra: bool) {
+    let body = all_compressed_data();
+    let headers = format!(
+        "Host: example.com\r\n\
+        Content-Type: text/html; charset=utf-8\r\n\
+        {ENCODING}: br\r\n\
+        Some-Other-Header: value\r\n\
+        {ENCODING}: deflate, gzip, {ALL_COMPRESSIONS}\r\n\
+        Content-Length: {}\r\n\r\n",
+        body.len()
+    );
+    let tm = TestMessage::new_two(
+        headers.as_bytes().into(),
+        data_frames(&body, FRAME_SIZE),
+        extra.then(|| INPUT.into()),
+    );
+    let verify_headers = "host: example.com\r\n\
+                          content-type: text/html; charset=utf-8\r\n\
+                          content-encoding: br\r\n\
+                          some-other-header: value\r\n\
+                          content-encoding: deflate, gzip\r\n";
+    assert_partial_two(tm, extra, verify_headers);
+}
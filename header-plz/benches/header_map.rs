@@ -0,0 +1,106 @@
+use std::hint::black_box;
+
+use bytes::BytesMut;
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use header_plz::{HeaderName, OneHeaderMap};
+
+// keys looked up while parsing the body headers, the last one is absent
+const KEYS: [&str; 6] = [
+    "Content-Length",
+    "Transfer-Encoding",
+    "Content-Encoding",
+    "Content-Type",
+    "Trailer",
+    "X-Missing",
+];
+
+// head of size headers, the looked up ones at the end
+fn head(size: usize) -> BytesMut {
+    let mut head = String::new();
+    for i in 0..size - 5 {
+        head.push_str(&format!("X-Custom-Header-{i}: value-{i}\r\n"));
+    }
+    head.push_str(
+        "Content-Type: text/html\r\n\
+         Content-Encoding: gzip\r\n\
+         Transfer-Encoding: chunked\r\n\
+         Trailer: Expires\r\n\
+         Content-Length: 20\r\n",
+    );
+    BytesMut::from(head.as_str())
+}
+
+fn lookups(map: &OneHeaderMap) -> usize {
+    KEYS.iter().filter_map(|key| map.value_of_key(black_box(key))).count()
+}
+
+// keys interned once, the index uses their hash_code
+fn name_lookups(map: &OneHeaderMap, names: &[HeaderName]) -> usize {
+    names.iter().filter_map(|name| map.value_of_name(black_box(name))).count()
+}
+
+pub fn criterion_benchmark_lookup(c: &mut Criterion) {
+    let mut group = c.benchmark_group("header_map_lookup");
+    for size in [50, 75, 100] {
+        let map = OneHeaderMap::from(head(size));
+        group.bench_with_input(
+            BenchmarkId::new("scan", size),
+            &map,
+            |b, map| b.iter(|| lookups(map)),
+        );
+        let map = map.with_index();
+        group.bench_with_input(
+            BenchmarkId::new("indexed", size),
+            &map,
+            |b, map| b.iter(|| lookups(map)),
+        );
+        let names: Vec<HeaderName> =
+            KEYS.iter().map(|key| HeaderName::from(*key)).collect();
+        group.bench_with_input(
+            BenchmarkId::new("indexed_name", size),
+            &map,
+            |b, map| b.iter(|| name_lookups(map, &names)),
+        );
+    }
+    group.finish();
+}
+
+// parse, look up and remove as for a decompressed body
+pub fn criterion_benchmark_message(c: &mut Criterion) {
+    let mut group = c.benchmark_group("header_map_message");
+    for size in [50, 100] {
+        let input = head(size);
+        for indexed in [false, true] {
+            let name = if indexed {
+                "indexed"
+            } else {
+                "scan"
+            };
+            group.bench_with_input(
+                BenchmarkId::new(name, size),
+                &input,
+                |b, input| {
+                    b.iter(|| {
+                        let mut map = OneHeaderMap::from(input.clone());
+                        if indexed {
+                            map.build_index();
+                        }
+                        let found = lookups(&map) + lookups(&map);
+                        map.remove_header_on_key("Content-Encoding");
+                        map.remove_header_on_key("Transfer-Encoding");
+                        map.update_header_value_on_key("Content-Length", "40");
+                        found + lookups(&map)
+                    })
+                },
+            );
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    criterion_benchmark_lookup,
+    criterion_benchmark_message
+);
+criterion_main!(benches);
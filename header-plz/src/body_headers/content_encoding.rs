@@ -1,6 +1,8 @@
 pub const BROTLI: &str = "br";
 pub const CHUNKED: &str = "chunked";
 pub const COMPRESS: &str = "compress";
+pub const DICTIONARY_BROTLI: &str = "dcb";
+pub const DICTIONARY_ZSTD: &str = "dcz";
 pub const DEFLATE: &str = "deflate";
 pub const GZIP: &str = "gzip";
 pub const IDENTITY: &str = "identity";
@@ -12,6 +14,8 @@ pub enum ContentEncoding {
     Chunked,
     Compress,
     Deflate,
+    DictionaryBrotli,
+    DictionaryZstd,
     Gzip,
     Identity,
     Zstd,
@@ -26,6 +30,8 @@ impl AsRef<str> for ContentEncoding {
             Chunked => CHUNKED,
             Compress => COMPRESS,
             Deflate => DEFLATE,
+            DictionaryBrotli => DICTIONARY_BROTLI,
+            DictionaryZstd => DICTIONARY_ZSTD,
             Gzip => GZIP,
             Identity => IDENTITY,
             Zstd => ZSTD,
@@ -42,6 +48,8 @@ impl From<&str> for ContentEncoding {
             CHUNKED => Chunked,
             COMPRESS => Compress,
             DEFLATE => Deflate,
+            DICTIONARY_BROTLI => DictionaryBrotli,
+            DICTIONARY_ZSTD => DictionaryZstd,
             GZIP => Gzip,
             IDENTITY => Identity,
             ZSTD => Zstd,
@@ -72,6 +80,14 @@ mod tests {
         assert_eq!(ce, ContentEncoding::Deflate);
         assert_eq!(ce.as_ref(), DEFLATE);
 
+        let ce = ContentEncoding::from("dcb");
+        assert_eq!(ce, ContentEncoding::DictionaryBrotli);
+        assert_eq!(ce.as_ref(), DICTIONARY_BROTLI);
+
+        let ce = ContentEncoding::from("dcz");
+        assert_eq!(ce, ContentEncoding::DictionaryZstd);
+        assert_eq!(ce.as_ref(), DICTIONARY_ZSTD);
+
         let ce = ContentEncoding::from("gzip");
         assert_eq!(ce, ContentEncoding::Gzip);
         assert_eq!(ce.as_ref(), GZIP);
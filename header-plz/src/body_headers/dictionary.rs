@@ -0,0 +1,375 @@
+// Compression Dictionary Transport
+// https://www.rfc-editor.org/rfc/rfc9842.html
+
+use std::fmt::{self, Display};
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use thiserror::Error;
+
+use crate::const_headers::{
+    AVAILABLE_DICTIONARY, DICTIONARY_ID, USE_AS_DICTIONARY,
+};
+use crate::message_head::header_map::{HMap, Hmap};
+use crate::structured_field::{
+    self, BareItem, Item, ListEntry, StructuredFieldError,
+};
+use crate::typed_header::impl_typed_header;
+
+pub const DICTIONARY_HASH_LEN: usize = 32;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum DictionaryHeaderError {
+    #[error("structured field| {0}")]
+    StructuredField(#[from] StructuredFieldError),
+    #[error("not utf8")]
+    NotUtf8,
+    #[error("expected byte sequence")]
+    NotByteSequence,
+    #[error("expected string")]
+    NotString,
+    #[error("invalid hash length| {0}")]
+    InvalidHashLength(usize),
+    #[error("missing match")]
+    MissingMatch,
+}
+
+/// SHA-256 hash identifying a shared dictionary.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct DictionaryHash(pub [u8; DICTIONARY_HASH_LEN]);
+
+impl DictionaryHash {
+    pub fn as_bytes(&self) -> &[u8; DICTIONARY_HASH_LEN] {
+        &self.0
+    }
+}
+
+impl TryFrom<&[u8]> for DictionaryHash {
+    type Error = DictionaryHeaderError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        value
+            .try_into()
+            .map(DictionaryHash)
+            .map_err(|_| DictionaryHeaderError::InvalidHashLength(value.len()))
+    }
+}
+
+// sf-binary form, as used in the Available-Dictionary header
+impl Display for DictionaryHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ":{}:", STANDARD.encode(self.0))
+    }
+}
+
+/// Available-Dictionary request header.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AvailableDictionary(pub DictionaryHash);
+
+impl AvailableDictionary {
+    pub fn parse(value: &[u8]) -> Result<Self, DictionaryHeaderError> {
+        let item = parse_sf_item(value)?;
+        let hash = item
+            .bare_item
+            .as_bytes()
+            .ok_or(DictionaryHeaderError::NotByteSequence)?;
+        DictionaryHash::try_from(hash).map(AvailableDictionary)
+    }
+
+    pub fn from_header_map<T>(
+        header_map: &HMap<T>,
+    ) -> Option<Result<Self, DictionaryHeaderError>>
+    where
+        T: Hmap,
+    {
+        header_map.value_of_key(AVAILABLE_DICTIONARY).map(Self::parse)
+    }
+}
+
+impl Display for AvailableDictionary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Dictionary-ID request header.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DictionaryId(pub String);
+
+impl DictionaryId {
+    pub fn parse(value: &[u8]) -> Result<Self, DictionaryHeaderError> {
+        let item = parse_sf_item(value)?;
+        match item.bare_item {
+            BareItem::String(id) => Ok(DictionaryId(id)),
+            _ => Err(DictionaryHeaderError::NotString),
+        }
+    }
+
+    pub fn from_header_map<T>(
+        header_map: &HMap<T>,
+    ) -> Option<Result<Self, DictionaryHeaderError>>
+    where
+        T: Hmap,
+    {
+        header_map.value_of_key(DICTIONARY_ID).map(Self::parse)
+    }
+}
+
+impl Display for DictionaryId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut out = String::new();
+        structured_field::serialize_bare_item(
+            &BareItem::String(self.0.clone()),
+            &mut out,
+        );
+        f.write_str(&out)
+    }
+}
+
+/// Use-As-Dictionary response header.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UseAsDictionary {
+    pub match_pattern: String,
+    pub match_dest: Vec<String>,
+    pub id: Option<String>,
+    pub dictionary_type: String,
+}
+
+impl UseAsDictionary {
+    pub fn new(match_pattern: String) -> Self {
+        UseAsDictionary {
+            match_pattern,
+            match_dest: Vec::new(),
+            id: None,
+            dictionary_type: "raw".to_string(),
+        }
+    }
+
+    pub fn parse(value: &[u8]) -> Result<Self, DictionaryHeaderError> {
+        let value = str::from_utf8(value)
+            .map_err(|_| DictionaryHeaderError::NotUtf8)?;
+        let dict = structured_field::parse_dictionary(value)?;
+        let mut match_pattern = None;
+        let mut match_dest = Vec::new();
+        let mut id = None;
+        let mut dictionary_type = "raw".to_string();
+        // unknown keys and values of unexpected type are ignored
+        for (key, entry) in dict {
+            match (key.as_str(), entry) {
+                (
+                    "match",
+                    ListEntry::Item(Item {
+                        bare_item: BareItem::String(s),
+                        ..
+                    }),
+                ) => match_pattern = Some(s),
+                ("match-dest", ListEntry::InnerList(list)) => {
+                    match_dest = list
+                        .items
+                        .into_iter()
+                        .filter_map(|item| match item.bare_item {
+                            BareItem::String(s) => Some(s),
+                            _ => None,
+                        })
+                        .collect()
+                }
+                (
+                    "id",
+                    ListEntry::Item(Item {
+                        bare_item: BareItem::String(s),
+                        ..
+                    }),
+                ) => id = Some(s),
+                (
+                    "type",
+                    ListEntry::Item(Item {
+                        bare_item: BareItem::Token(t),
+                        ..
+                    }),
+                ) => dictionary_type = t,
+                _ => {}
+            }
+        }
+        Ok(UseAsDictionary {
+            match_pattern: match_pattern
+                .ok_or(DictionaryHeaderError::MissingMatch)?,
+            match_dest,
+            id,
+            dictionary_type,
+        })
+    }
+
+    pub fn from_header_map<T>(
+        header_map: &HMap<T>,
+    ) -> Option<Result<Self, DictionaryHeaderError>>
+    where
+        T: Hmap,
+    {
+        header_map.value_of_key(USE_AS_DICTIONARY).map(Self::parse)
+    }
+
+    pub fn is_raw(&self) -> bool {
+        self.dictionary_type == "raw"
+    }
+}
+
+impl Display for UseAsDictionary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let string = |s: &str| {
+            ListEntry::Item(Item::new(BareItem::String(s.to_string())))
+        };
+        let mut dict =
+            vec![("match".to_string(), string(&self.match_pattern))];
+        if !self.match_dest.is_empty() {
+            dict.push((
+                "match-dest".to_string(),
+                ListEntry::InnerList(structured_field::InnerList {
+                    items: self
+                        .match_dest
+                        .iter()
+                        .map(|d| Item::new(BareItem::String(d.clone())))
+                        .collect(),
+                    params: Vec::new(),
+                }),
+            ));
+        }
+        if let Some(id) = &self.id {
+            dict.push(("id".to_string(), string(id)));
+        }
+        if !self.is_raw() {
+            dict.push((
+                "type".to_string(),
+                ListEntry::Item(Item::new(BareItem::Token(
+                    self.dictionary_type.clone(),
+                ))),
+            ));
+        }
+        f.write_str(&structured_field::serialize_dictionary(&dict))
+    }
+}
+
+impl_typed_header!(
+    AvailableDictionary,
+    AVAILABLE_DICTIONARY,
+    DictionaryHeaderError
+);
+impl_typed_header!(DictionaryId, DICTIONARY_ID, DictionaryHeaderError);
+impl_typed_header!(UseAsDictionary, USE_AS_DICTIONARY, DictionaryHeaderError);
+
+fn parse_sf_item(value: &[u8]) -> Result<Item, DictionaryHeaderError> {
+    let value =
+        str::from_utf8(value).map_err(|_| DictionaryHeaderError::NotUtf8)?;
+    Ok(structured_field::parse_item(value)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::typed_header::TypedHeader;
+    use crate::{HeaderMap, OneHeaderMap};
+    use bytes::BytesMut;
+
+    const HASH_B64: &str = "pZGm1Av0IEBKARczz7exkNYsZb8LzaMrV7J32a2fFG4=";
+
+    fn hash() -> DictionaryHash {
+        let bytes = STANDARD.decode(HASH_B64).unwrap();
+        DictionaryHash::try_from(bytes.as_slice()).unwrap()
+    }
+
+    #[test]
+    fn test_available_dictionary_parse() {
+        let value = format!(":{HASH_B64}:");
+        let ad = AvailableDictionary::parse(value.as_bytes()).unwrap();
+        assert_eq!(ad.0, hash());
+        assert_eq!(ad.to_string(), value);
+    }
+
+    #[test]
+    fn test_available_dictionary_parse_invalid() {
+        assert_eq!(
+            AvailableDictionary::parse(b"\"abc\""),
+            Err(DictionaryHeaderError::NotByteSequence)
+        );
+        assert_eq!(
+            AvailableDictionary::parse(b":aGVsbG8=:"),
+            Err(DictionaryHeaderError::InvalidHashLength(5))
+        );
+    }
+
+    #[test]
+    fn test_available_dictionary_from_header_map() {
+        let input = format!(
+            "Host: example.com\r\nAvailable-Dictionary: :{HASH_B64}:\r\n\r\n"
+        );
+        let map = OneHeaderMap::from(BytesMut::from(input.as_str()));
+        let ad = AvailableDictionary::from_header_map(&map).unwrap().unwrap();
+        assert_eq!(ad.0, hash());
+
+        let map = HeaderMap::from(map);
+        let ad = AvailableDictionary::from_header_map(&map).unwrap().unwrap();
+        assert_eq!(ad.0, hash());
+    }
+
+    #[test]
+    fn test_dictionary_id() {
+        let id = DictionaryId::parse(br#""dictionary-12345""#).unwrap();
+        assert_eq!(id.0, "dictionary-12345");
+        assert_eq!(id.to_string(), r#""dictionary-12345""#);
+        assert_eq!(
+            DictionaryId::parse(b"token"),
+            Err(DictionaryHeaderError::NotString)
+        );
+    }
+
+    #[test]
+    fn test_use_as_dictionary_parse() {
+        let uad = UseAsDictionary::parse(
+            br#"match="/product/*", match-dest=("document"), id="v1""#,
+        )
+        .unwrap();
+        assert_eq!(uad.match_pattern, "/product/*");
+        assert_eq!(uad.match_dest, vec!["document"]);
+        assert_eq!(uad.id.as_deref(), Some("v1"));
+        assert!(uad.is_raw());
+    }
+
+    #[test]
+    fn test_use_as_dictionary_missing_match() {
+        assert_eq!(
+            UseAsDictionary::parse(br#"id="v1""#),
+            Err(DictionaryHeaderError::MissingMatch)
+        );
+    }
+
+    #[test]
+    fn test_use_as_dictionary_roundtrip() {
+        let input = r#"match="/app/*.js", match-dest=("script" "worker"), id="a1", type=custom"#;
+        let uad = UseAsDictionary::parse(input.as_bytes()).unwrap();
+        assert!(!uad.is_raw());
+        assert_eq!(uad.to_string(), input);
+
+        let uad = UseAsDictionary::new("/js/*".to_string());
+        assert_eq!(uad.to_string(), r#"match="/js/*""#);
+    }
+
+    #[test]
+    fn test_use_as_dictionary_from_header_map() {
+        let input = "Use-As-Dictionary: match=\"/a\"\r\n\r\n";
+        let map = OneHeaderMap::from(BytesMut::from(input));
+        let uad = UseAsDictionary::from_header_map(&map).unwrap().unwrap();
+        assert_eq!(uad.match_pattern, "/a");
+        let empty = OneHeaderMap::from(BytesMut::from("\r\n"));
+        assert!(UseAsDictionary::from_header_map(&empty).is_none());
+    }
+
+    #[test]
+    fn test_dictionary_typed_header() {
+        let input = "Dictionary-ID: \"a\"\r\n\r\n";
+        let mut map =
+            HeaderMap::from(OneHeaderMap::from(BytesMut::from(input)));
+        let id = DictionaryId::decode(&map).unwrap().unwrap();
+        assert_eq!(id.0, "a");
+        DictionaryId("b".to_string()).encode(&mut map);
+        assert_eq!(map.value_of_key(DICTIONARY_ID), Some(&b"\"b\""[..]));
+        assert!(AvailableDictionary::decode(&map).is_none());
+    }
+}
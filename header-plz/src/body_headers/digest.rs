@@ -0,0 +1,568 @@
+// Digest Fields
+// https://www.rfc-editor.org/rfc/rfc9530.html
+//
+// Legacy
+//      Digest      - https://www.rfc-editor.org/rfc/rfc3230.html
+//      Content-MD5 - https://www.rfc-editor.org/rfc/rfc1864.html
+
+use std::fmt::{self, Display};
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use thiserror::Error;
+
+use crate::const_headers::{
+    CONTENT_DIGEST, CONTENT_MD5, DIGEST, REPR_DIGEST, WANT_CONTENT_DIGEST,
+    WANT_REPR_DIGEST,
+};
+use crate::message_head::header_map::{HMap, Hmap};
+use crate::structured_field::{
+    self, BareItem, Item, ListEntry, StructuredFieldError,
+};
+use crate::typed_header::impl_typed_header;
+
+pub const MD5_LEN: usize = 16;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum DigestHeaderError {
+    #[error("structured field| {0}")]
+    StructuredField(#[from] StructuredFieldError),
+    #[error("not utf8")]
+    NotUtf8,
+    #[error("expected byte sequence| {0}")]
+    NotByteSequence(String),
+    #[error("expected integer 0-10| {0}")]
+    InvalidPreference(String),
+    #[error("invalid base64| {0}")]
+    InvalidBase64(String),
+    #[error("missing value| {0}")]
+    MissingValue(String),
+    #[error("invalid md5 length| {0}")]
+    InvalidMd5Length(usize),
+}
+
+// Hash algorithm registry, names are case insensitive in the legacy Digest
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum DigestAlgorithm {
+    Sha256,
+    Sha512,
+    // deprecated, insecure
+    Md5,
+    Sha,
+    Unixsum,
+    Unixcksum,
+    Adler,
+    Crc32c,
+    Other(String),
+}
+
+impl DigestAlgorithm {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Sha256 => "sha-256",
+            Self::Sha512 => "sha-512",
+            Self::Md5 => "md5",
+            Self::Sha => "sha",
+            Self::Unixsum => "unixsum",
+            Self::Unixcksum => "unixcksum",
+            Self::Adler => "adler",
+            Self::Crc32c => "crc32c",
+            Self::Other(name) => name,
+        }
+    }
+
+    // only sha-256 and sha-512 are registered as active
+    pub fn is_deprecated(&self) -> bool {
+        !matches!(self, Self::Sha256 | Self::Sha512)
+    }
+
+    // legacy Digest sends checksums as decimal / hex text, hashes as base64
+    fn is_legacy_base64(&self) -> bool {
+        matches!(self, Self::Sha256 | Self::Sha512 | Self::Md5 | Self::Sha)
+    }
+}
+
+impl From<&str> for DigestAlgorithm {
+    fn from(name: &str) -> Self {
+        match name.to_ascii_lowercase().as_str() {
+            "sha-256" => Self::Sha256,
+            "sha-512" => Self::Sha512,
+            "md5" => Self::Md5,
+            "sha" => Self::Sha,
+            "unixsum" => Self::Unixsum,
+            "unixcksum" => Self::Unixcksum,
+            "adler" | "adler32" => Self::Adler,
+            "crc32c" => Self::Crc32c,
+            other => Self::Other(other.to_string()),
+        }
+    }
+}
+
+impl Display for DigestAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Digest {
+    pub algorithm: DigestAlgorithm,
+    pub value: Vec<u8>,
+}
+
+impl Digest {
+    pub fn new(algorithm: DigestAlgorithm, value: Vec<u8>) -> Self {
+        Digest {
+            algorithm,
+            value,
+        }
+    }
+}
+
+// Integrity fields that carry digests
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DigestField {
+    // content of the message, after content codings
+    ContentDigest,
+    // complete selected representation, even for partial content
+    ReprDigest,
+    // legacy, representation as Repr-Digest
+    Digest,
+    // legacy, md5 of the content
+    ContentMd5,
+}
+
+impl DigestField {
+    pub const ALL: [DigestField; 4] = [
+        Self::ContentDigest,
+        Self::ReprDigest,
+        Self::Digest,
+        Self::ContentMd5,
+    ];
+
+    pub fn key(&self) -> &'static [u8] {
+        match self {
+            Self::ContentDigest => CONTENT_DIGEST,
+            Self::ReprDigest => REPR_DIGEST,
+            Self::Digest => DIGEST,
+            Self::ContentMd5 => CONTENT_MD5,
+        }
+    }
+
+    /* Digest of the whole representation. Cannot be checked against the
+     * body of a partial (206) response.
+     */
+    pub fn is_representation(&self) -> bool {
+        matches!(self, Self::ReprDigest | Self::Digest)
+    }
+
+    pub fn parse(&self, value: &[u8]) -> Result<Digests, DigestHeaderError> {
+        match self {
+            Self::ContentDigest | Self::ReprDigest => Digests::parse(value),
+            Self::Digest => Digests::parse_legacy(value),
+            Self::ContentMd5 => ContentMd5::parse(value).map(Digests::from),
+        }
+    }
+
+    // header value in the format of the field
+    pub fn serialize(&self, digests: &Digests) -> String {
+        match self {
+            Self::ContentDigest | Self::ReprDigest => digests.to_string(),
+            Self::Digest => digests.to_legacy_string(),
+            Self::ContentMd5 => digests
+                .get(&DigestAlgorithm::Md5)
+                .map(|value| STANDARD.encode(value))
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Content-Digest / Repr-Digest / Digest header value.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Digests(pub Vec<Digest>);
+
+impl Digests {
+    // sf-dictionary of byte sequences, eg. sha-256=:base64:
+    pub fn parse(value: &[u8]) -> Result<Self, DigestHeaderError> {
+        let value =
+            str::from_utf8(value).map_err(|_| DigestHeaderError::NotUtf8)?;
+        let dict = structured_field::parse_dictionary(value)?;
+        dict.into_iter()
+            .map(|(key, entry)| match entry {
+                ListEntry::Item(Item {
+                    bare_item: BareItem::ByteSequence(value),
+                    ..
+                }) => {
+                    Ok(Digest::new(DigestAlgorithm::from(key.as_str()), value))
+                }
+                _ => Err(DigestHeaderError::NotByteSequence(key)),
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Digests)
+    }
+
+    // legacy, comma separated algorithm=base64
+    pub fn parse_legacy(value: &[u8]) -> Result<Self, DigestHeaderError> {
+        let value =
+            str::from_utf8(value).map_err(|_| DigestHeaderError::NotUtf8)?;
+        value
+            .split(',')
+            .map(str::trim)
+            .filter(|member| !member.is_empty())
+            .map(|member| {
+                let (name, encoded) =
+                    member.split_once('=').ok_or_else(|| {
+                        DigestHeaderError::MissingValue(member.to_string())
+                    })?;
+                let algorithm = DigestAlgorithm::from(name.trim());
+                let encoded = encoded.trim();
+                let value = if algorithm.is_legacy_base64() {
+                    STANDARD.decode(encoded).map_err(|_| {
+                        DigestHeaderError::InvalidBase64(name.to_string())
+                    })?
+                } else {
+                    encoded.as_bytes().to_vec()
+                };
+                Ok(Digest::new(algorithm, value))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Digests)
+    }
+
+    pub fn from_header_map<T>(
+        header_map: &HMap<T>,
+        field: DigestField,
+    ) -> Option<Result<Self, DigestHeaderError>>
+    where
+        T: Hmap,
+    {
+        header_map.value_of_key(field.key()).map(|value| field.parse(value))
+    }
+
+    pub fn get(&self, algorithm: &DigestAlgorithm) -> Option<&[u8]> {
+        self.0
+            .iter()
+            .find(|digest| &digest.algorithm == algorithm)
+            .map(|digest| digest.value.as_slice())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn to_legacy_string(&self) -> String {
+        self.0
+            .iter()
+            .map(|digest| {
+                let value = if digest.algorithm.is_legacy_base64() {
+                    STANDARD.encode(&digest.value)
+                } else {
+                    String::from_utf8_lossy(&digest.value).into_owned()
+                };
+                format!(
+                    "{}={}",
+                    digest.algorithm.as_str().to_ascii_uppercase(),
+                    value
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+impl From<ContentMd5> for Digests {
+    fn from(md5: ContentMd5) -> Self {
+        Digests(vec![Digest::new(DigestAlgorithm::Md5, md5.0.to_vec())])
+    }
+}
+
+impl Display for Digests {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let dict = self
+            .0
+            .iter()
+            .map(|digest| {
+                (
+                    digest.algorithm.as_str().to_string(),
+                    ListEntry::Item(Item::new(BareItem::ByteSequence(
+                        digest.value.clone(),
+                    ))),
+                )
+            })
+            .collect();
+        f.write_str(&structured_field::serialize_dictionary(&dict))
+    }
+}
+
+/// Content-MD5 header.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ContentMd5(pub [u8; MD5_LEN]);
+
+impl ContentMd5 {
+    pub fn parse(value: &[u8]) -> Result<Self, DigestHeaderError> {
+        let value = STANDARD.decode(value.trim_ascii()).map_err(|_| {
+            DigestHeaderError::InvalidBase64("content-md5".to_string())
+        })?;
+        value
+            .as_slice()
+            .try_into()
+            .map(ContentMd5)
+            .map_err(|_| DigestHeaderError::InvalidMd5Length(value.len()))
+    }
+
+    pub fn from_header_map<T>(
+        header_map: &HMap<T>,
+    ) -> Option<Result<Self, DigestHeaderError>>
+    where
+        T: Hmap,
+    {
+        header_map.value_of_key(CONTENT_MD5).map(Self::parse)
+    }
+}
+
+impl Display for ContentMd5 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&STANDARD.encode(self.0))
+    }
+}
+
+impl_typed_header!(ContentMd5, CONTENT_MD5, DigestHeaderError);
+
+/// Want-Content-Digest / Want-Repr-Digest header.
+///
+/// Preference from 1 (least) to 10 (most), 0 means not acceptable.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct WantDigest(pub Vec<(DigestAlgorithm, u8)>);
+
+impl WantDigest {
+    pub fn parse(value: &[u8]) -> Result<Self, DigestHeaderError> {
+        let value =
+            str::from_utf8(value).map_err(|_| DigestHeaderError::NotUtf8)?;
+        let dict = structured_field::parse_dictionary(value)?;
+        dict.into_iter()
+            .map(|(key, entry)| {
+                let preference = entry
+                    .as_item()
+                    .and_then(|item| item.bare_item.as_integer())
+                    .and_then(|i| u8::try_from(i).ok())
+                    .filter(|i| *i <= 10)
+                    .ok_or_else(|| {
+                        DigestHeaderError::InvalidPreference(key.clone())
+                    })?;
+                Ok((DigestAlgorithm::from(key.as_str()), preference))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(WantDigest)
+    }
+
+    pub fn from_header_map<T>(
+        header_map: &HMap<T>,
+        field: DigestField,
+    ) -> Option<Result<Self, DigestHeaderError>>
+    where
+        T: Hmap,
+    {
+        let key = match field {
+            DigestField::ContentDigest | DigestField::ContentMd5 => {
+                WANT_CONTENT_DIGEST
+            }
+            DigestField::ReprDigest | DigestField::Digest => WANT_REPR_DIGEST,
+        };
+        header_map.value_of_key(key).map(Self::parse)
+    }
+
+    // most preferred acceptable algorithm that satisfies is_supported
+    pub fn preferred<F>(&self, is_supported: F) -> Option<&DigestAlgorithm>
+    where
+        F: Fn(&DigestAlgorithm) -> bool,
+    {
+        self.0
+            .iter()
+            .filter(|(algorithm, preference)| {
+                *preference > 0 && is_supported(algorithm)
+            })
+            .fold(
+                None,
+                |best: Option<&(DigestAlgorithm, u8)>, want| match best {
+                    Some(best) if best.1 >= want.1 => Some(best),
+                    _ => Some(want),
+                },
+            )
+            .map(|(algorithm, _)| algorithm)
+    }
+}
+
+impl Display for WantDigest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let dict = self
+            .0
+            .iter()
+            .map(|(algorithm, preference)| {
+                (
+                    algorithm.as_str().to_string(),
+                    ListEntry::Item(Item::new(BareItem::Integer(
+                        *preference as i64,
+                    ))),
+                )
+            })
+            .collect();
+        f.write_str(&structured_field::serialize_dictionary(&dict))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{HeaderMap, OneHeaderMap};
+    use bytes::BytesMut;
+
+    // RFC 9530 B.1, sha-256 and sha-512 of {"hello": "world"}
+    const SHA256_B64: &str = "X48E9qOokqqrvdts8nOJRJN3OWDUoyWxBf7kbu9DBPE=";
+    const SHA512_B64: &str = "WZDPaVn/7XgHaAy8pmojAkGWoRx2UFChF41A2svX+TaPm+AbwAgBWnrIiYllu7BNNyealdVLvRwEmTHWXvJwew==";
+
+    fn decode(b64: &str) -> Vec<u8> {
+        STANDARD.decode(b64).unwrap()
+    }
+
+    #[test]
+    fn test_digest_algorithm() {
+        assert_eq!(DigestAlgorithm::from("SHA-256"), DigestAlgorithm::Sha256);
+        assert_eq!(DigestAlgorithm::from("md5"), DigestAlgorithm::Md5);
+        assert_eq!(
+            DigestAlgorithm::from("blake3"),
+            DigestAlgorithm::Other("blake3".to_string())
+        );
+        assert!(!DigestAlgorithm::Sha512.is_deprecated());
+        assert!(DigestAlgorithm::Sha.is_deprecated());
+    }
+
+    #[test]
+    fn test_digests_parse() {
+        let input = format!("sha-256=:{SHA256_B64}:, sha-512=:{SHA512_B64}:");
+        let digests = Digests::parse(input.as_bytes()).unwrap();
+        assert_eq!(
+            digests.get(&DigestAlgorithm::Sha256),
+            Some(decode(SHA256_B64).as_slice())
+        );
+        assert_eq!(
+            digests.get(&DigestAlgorithm::Sha512),
+            Some(decode(SHA512_B64).as_slice())
+        );
+        assert_eq!(digests.to_string(), input);
+    }
+
+    #[test]
+    fn test_digests_parse_not_byte_sequence() {
+        assert_eq!(
+            Digests::parse(b"sha-256=abc"),
+            Err(DigestHeaderError::NotByteSequence("sha-256".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_digests_parse_legacy() {
+        let input = format!("SHA-256={SHA256_B64},unixsum=30637");
+        let digests = Digests::parse_legacy(input.as_bytes()).unwrap();
+        assert_eq!(digests.0.len(), 2);
+        assert_eq!(digests.0[0].algorithm, DigestAlgorithm::Sha256);
+        assert_eq!(digests.0[0].value, decode(SHA256_B64));
+        assert_eq!(digests.0[1].algorithm, DigestAlgorithm::Unixsum);
+        assert_eq!(digests.0[1].value, b"30637");
+        assert_eq!(
+            digests.to_legacy_string(),
+            format!("SHA-256={SHA256_B64}, UNIXSUM=30637")
+        );
+    }
+
+    #[test]
+    fn test_digests_parse_legacy_invalid() {
+        assert_eq!(
+            Digests::parse_legacy(b"sha-256"),
+            Err(DigestHeaderError::MissingValue("sha-256".to_string()))
+        );
+        assert_eq!(
+            Digests::parse_legacy(b"md5=!!"),
+            Err(DigestHeaderError::InvalidBase64("md5".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_content_md5() {
+        let input = b"Q2hlY2sgSW50ZWdyaXR5IQ==";
+        let md5 = ContentMd5::parse(input).unwrap();
+        assert_eq!(&md5.0, b"Check Integrity!");
+        assert_eq!(md5.to_string().as_bytes(), input);
+        assert_eq!(
+            ContentMd5::parse(b"aGVsbG8="),
+            Err(DigestHeaderError::InvalidMd5Length(5))
+        );
+    }
+
+    #[test]
+    fn test_want_digest() {
+        let want = WantDigest::parse(b"sha-512=3, sha-256=10, md5=0").unwrap();
+        assert_eq!(want.preferred(|_| true), Some(&DigestAlgorithm::Sha256));
+        assert_eq!(
+            want.preferred(|a| *a != DigestAlgorithm::Sha256),
+            Some(&DigestAlgorithm::Sha512)
+        );
+        assert_eq!(want.preferred(|a| *a == DigestAlgorithm::Md5), None);
+        assert_eq!(want.to_string(), "sha-512=3, sha-256=10, md5=0");
+        assert_eq!(
+            WantDigest::parse(b"sha-256=11"),
+            Err(DigestHeaderError::InvalidPreference("sha-256".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_digest_field_serialize() {
+        let digests = Digests(vec![
+            Digest::new(DigestAlgorithm::Sha256, decode(SHA256_B64)),
+            Digest::new(DigestAlgorithm::Md5, b"Check Integrity!".to_vec()),
+        ]);
+        assert_eq!(
+            DigestField::ContentDigest.serialize(&digests),
+            format!("sha-256=:{SHA256_B64}:, md5=:Q2hlY2sgSW50ZWdyaXR5IQ==:")
+        );
+        assert_eq!(
+            DigestField::Digest.serialize(&digests),
+            format!("SHA-256={SHA256_B64}, MD5=Q2hlY2sgSW50ZWdyaXR5IQ==")
+        );
+        assert_eq!(
+            DigestField::ContentMd5.serialize(&digests),
+            "Q2hlY2sgSW50ZWdyaXR5IQ=="
+        );
+    }
+
+    #[test]
+    fn test_digests_from_header_map() {
+        let input = format!(
+            "Host: example.com\r\n\
+            Repr-Digest: sha-256=:{SHA256_B64}:\r\n\
+            Content-MD5: Q2hlY2sgSW50ZWdyaXR5IQ==\r\n\
+            Want-Content-Digest: sha-256=1\r\n\r\n"
+        );
+        let map = OneHeaderMap::from(BytesMut::from(input.as_str()));
+        let repr = Digests::from_header_map(&map, DigestField::ReprDigest)
+            .unwrap()
+            .unwrap();
+        assert_eq!(repr.0[0].value, decode(SHA256_B64));
+        assert!(
+            Digests::from_header_map(&map, DigestField::ContentDigest)
+                .is_none()
+        );
+
+        let map = HeaderMap::from(map);
+        let md5 = Digests::from_header_map(&map, DigestField::ContentMd5)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            md5.get(&DigestAlgorithm::Md5),
+            Some(&b"Check Integrity!"[..])
+        );
+        let want =
+            WantDigest::from_header_map(&map, DigestField::ContentDigest)
+                .unwrap()
+                .unwrap();
+        assert_eq!(want.0, vec![(DigestAlgorithm::Sha256, 1)]);
+    }
+}
@@ -85,6 +85,9 @@ where
             if let Some((main_type, _)) = value.split_once('/') {
                 bh.content_type = Some(mime_plz::ContentType::from(main_type));
             }
+            if let Ok(media_type) = mime_plz::MediaType::parse(value) {
+                bh.media_type = Some(media_type);
+            }
         }
         None => {}
     }
@@ -92,13 +95,12 @@ where
 
 #[inline(always)]
 fn identify_header(is_one_one: bool, key: &[u8]) -> BodyHeaderId {
-    if is_one_one {
-        if key.eq_ignore_ascii_case(CONTENT_LENGTH) {
-            return BodyHeaderId::ContentLength;
-        }
-        if key.eq_ignore_ascii_case(TRANSFER_ENCODING) {
-            return BodyHeaderId::TransferEncoding;
-        }
+    if key.eq_ignore_ascii_case(CONTENT_LENGTH) {
+        return BodyHeaderId::ContentLength;
+    }
+    // connection specific, not allowed in h2
+    if is_one_one && key.eq_ignore_ascii_case(TRANSFER_ENCODING) {
+        return BodyHeaderId::TransferEncoding;
     }
     if key.eq_ignore_ascii_case(CONTENT_ENCODING) {
         return BodyHeaderId::ContentEncoding;
@@ -114,7 +116,7 @@ mod tests {
     use super::*;
     use crate::body_headers::ContentEncoding;
     use bytes::BytesMut;
-    use mime_plz::ContentType;
+    use mime_plz::{ContentType, MediaType};
 
     fn build_body_header(input: &str) -> BodyHeader {
         let header_map = OneHeaderMap::from(BytesMut::from(input));
@@ -326,6 +328,7 @@ mod tests {
         ];
         let verify = BodyHeader {
             content_encoding: Some(einfo),
+            transfer_type: Some(TransferType::ContentLength(20)),
             ..Default::default()
         };
         assert_eq!(result.unwrap(), verify);
@@ -339,6 +342,7 @@ mod tests {
         let result = Option::<BodyHeader>::from(&header_map);
         let verify = BodyHeader {
             content_type: Some(ContentType::Application),
+            media_type: Some(MediaType::new("application", "json")),
             transfer_type: Some(TransferType::Close),
             ..Default::default()
         };
@@ -353,8 +357,60 @@ mod tests {
         let result = Option::<BodyHeader>::from(&header_map);
         let verify = BodyHeader {
             content_type: Some(ContentType::Application),
+            media_type: Some(MediaType::new("application", "json")),
             ..Default::default()
         };
         assert_eq!(result.unwrap(), verify);
     }
+
+    #[test]
+    fn test_body_header_from_header_map_ct_media_type() {
+        let input =
+            "Content-Type: multipart/Form-Data; boundary=\"x y\"\r\n\r\n";
+        let result = build_body_header(input);
+        assert_eq!(result.content_type, Some(ContentType::Multipart));
+        let media_type = result.media_type.unwrap();
+        assert_eq!(media_type.essence(), "multipart/form-data");
+        assert_eq!(media_type.boundary(), Some("x y"));
+    }
+
+    #[test]
+    fn test_body_header_from_header_map_ct_media_type_invalid() {
+        let input = "Content-Type: text/html; charset\r\n\r\n";
+        let result = build_body_header(input);
+        assert_eq!(result.content_type, Some(ContentType::Text));
+        assert!(result.media_type.is_none());
+    }
+
+    // ----- h2
+    #[test]
+    fn test_body_header_from_header_map_two_cl() {
+        let input = "Content-Length: 10\r\n\r\n";
+        let header_map =
+            HeaderMap::from(OneHeaderMap::from(BytesMut::from(input)));
+        let result = Option::<BodyHeader>::from(&header_map);
+        let verify = BodyHeader {
+            transfer_type: Some(TransferType::ContentLength(10)),
+            ..Default::default()
+        };
+        assert_eq!(result.unwrap(), verify);
+    }
+
+    #[test]
+    fn test_body_header_from_header_map_two_te_ignored() {
+        let input = "Transfer-Encoding: gzip, chunked\r\n\r\n";
+        let header_map =
+            HeaderMap::from(OneHeaderMap::from(BytesMut::from(input)));
+        let result = Option::<BodyHeader>::from(&header_map);
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_body_header_from_header_map_two_no_body_headers() {
+        let input = "Host: localhost\r\n\r\n";
+        let header_map =
+            HeaderMap::from(OneHeaderMap::from(BytesMut::from(input)));
+        let result = Option::<BodyHeader>::from(&header_map);
+        assert!(result.is_none());
+    }
 }
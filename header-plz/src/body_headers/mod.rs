@@ -1,9 +1,11 @@
 use content_encoding::ContentEncoding;
-use mime_plz::ContentType;
+use mime_plz::{ContentType, MediaType};
 use transfer_types::TransferType;
 
 use crate::body_headers::encoding_info::EncodingInfo;
 pub mod content_encoding;
+pub mod dictionary;
+pub mod digest;
 pub mod encoding_info;
 pub mod transfer_types;
 
@@ -14,6 +16,8 @@ pub mod parse;
 pub struct BodyHeader {
     pub content_encoding: Option<Vec<EncodingInfo>>,
     pub content_type: Option<ContentType>,
+    // full Content-Type, None when not a valid media type
+    pub media_type: Option<MediaType>,
     pub transfer_encoding: Option<Vec<EncodingInfo>>,
     pub transfer_type: Option<TransferType>,
 }
@@ -22,6 +26,7 @@ impl BodyHeader {
     pub fn sanitize(self) -> Option<Self> {
         if self.content_encoding.is_some()
             || self.content_type.is_some()
+            || self.media_type.is_some()
             || self.transfer_encoding.is_some()
             || self.transfer_type.is_some()
         {
@@ -91,6 +96,7 @@ mod tests {
                 vec![ContentEncoding::Gzip],
             )]),
             content_type: Some(ContentType::Application),
+            media_type: Some(MediaType::new("application", "json")),
             transfer_encoding: Some(vec![EncodingInfo::new(
                 0,
                 vec![ContentEncoding::Gzip],
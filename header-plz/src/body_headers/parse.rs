@@ -242,7 +242,10 @@ mod tests {
         assert!(body_headers.content_encoding.is_none());
         assert_eq!(body_headers.content_type.unwrap(), ContentType::Text);
         assert!(body_headers.transfer_encoding.is_none());
-        assert!(body_headers.transfer_type.is_none());
+        assert_eq!(
+            body_headers.transfer_type.unwrap(),
+            TransferType::ContentLength(12)
+        );
     }
 
     #[test]
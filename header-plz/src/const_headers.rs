@@ -5,22 +5,82 @@ macro_rules! standard_headers {
     (
         $(
             $(#[$docs:meta])*
-            ($upcase:ident, $name_bytes:literal);
+            ($konst:ident, $upcase:ident, $name_bytes:literal);
         )+
     ) => {
         $(
             $(#[$docs])*
             pub const $upcase: &[u8] = $name_bytes;
         )+
+
+        /// Interned name of a standard header, see
+        /// [`HeaderName`](crate::header_name::HeaderName).
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub enum StandardHeader {
+            $(
+                $(#[$docs])*
+                $konst,
+            )+
+        }
+
+        // in declaration order, indexed by the discriminant
+        const STANDARD_HEADERS: &[StandardHeader] = &[
+            $( StandardHeader::$konst, )+
+        ];
+
+        const STANDARD_HASHES: &[u64] = &[
+            $( fnv1a($name_bytes), )+
+        ];
+
+        impl StandardHeader {
+            pub fn all() -> &'static [StandardHeader] {
+                STANDARD_HEADERS
+            }
+
+            // lowercase name
+            pub const fn as_bytes(&self) -> &'static [u8] {
+                match self {
+                    $( StandardHeader::$konst => $upcase, )+
+                }
+            }
+
+            pub const fn hash_code(&self) -> u64 {
+                STANDARD_HASHES[*self as usize]
+            }
+
+            // name must be lowercase
+            pub(crate) fn from_lowercase(name: &[u8]) -> Option<Self> {
+                match name {
+                    $( $name_bytes => Some(StandardHeader::$konst), )+
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+/* FNV-1a of the lowercase name. Computed at compile time for standard
+ * headers and on construction for custom ones, so equal names have equal
+ * hashes whether interned or not.
+ */
+pub(crate) const fn fnv1a(name: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    let mut i = 0;
+    while i < name.len() {
+        hash ^= name[i].to_ascii_lowercase() as u64;
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        i += 1;
     }
+    hash
 }
 
-// Generate constants for all standard HTTP headers. This includes a static hash
-// code for the "fast hash" path. The hash code for static headers *do not* have
-// to match the text representation of those headers. This is because header
-// strings are always converted to the static values (when they match) before
-// being hashed. This means that it is impossible to compare the static hash
-// code of CONTENT_LENGTH with "content-length".
+// longest standard header name, content-security-policy-report-only
+pub(crate) const MAX_STANDARD_LEN: usize = 35;
+
+// Generate constants and the StandardHeader enum for all standard HTTP
+// headers. Each header has a lowercase byte constant, eg. CONTENT_LENGTH, and
+// an interned variant, eg. StandardHeader::ContentLength, with the hash of its
+// name precomputed.
 standard_headers! {
     /// Advertises which content types the client is able to understand.
     ///
@@ -32,7 +92,7 @@ standard_headers! {
     /// where the request is done: when fetching a CSS stylesheet a different
     /// value is set for the request than when fetching an image, video or a
     /// script.
-    (ACCEPT, b"accept");
+    (Accept, ACCEPT, b"accept");
 
     /// Advertises which character set the client is able to understand.
     ///
@@ -47,7 +107,7 @@ standard_headers! {
     /// theoretically send back a 406 (Not Acceptable) error code. But, for a
     /// better user experience, this is rarely done and the more common way is
     /// to ignore the Accept-Charset header in this case.
-    (ACCEPT_CHARSET, b"accept-charset");
+    (AcceptCharset, ACCEPT_CHARSET, b"accept-charset");
 
     /// Advertises which content encoding the client is able to understand.
     ///
@@ -75,7 +135,7 @@ standard_headers! {
     /// forbidden, by an identity;q=0 or a *;q=0 without another explicitly set
     /// value for identity, the server must never send back a 406 Not Acceptable
     /// error.
-    (ACCEPT_ENCODING, b"accept-encoding");
+    (AcceptEncoding, ACCEPT_ENCODING, b"accept-encoding");
 
     /// Advertises which languages the client is able to understand.
     ///
@@ -100,7 +160,7 @@ standard_headers! {
     /// send back a 406 (Not Acceptable) error code. But, for a better user
     /// experience, this is rarely done and more common way is to ignore the
     /// Accept-Language header in this case.
-    (ACCEPT_LANGUAGE, b"accept-language");
+    (AcceptLanguage, ACCEPT_LANGUAGE, b"accept-language");
 
     /// Marker used by the server to advertise partial request support.
     ///
@@ -110,7 +170,7 @@ standard_headers! {
     ///
     /// In presence of an Accept-Ranges header, the browser may try to resume an
     /// interrupted download, rather than to start it from the start again.
-    (ACCEPT_RANGES, b"accept-ranges");
+    (AcceptRanges, ACCEPT_RANGES, b"accept-ranges");
 
     /// Preflight response indicating if the response to the request can be
     /// exposed to the page.
@@ -135,7 +195,7 @@ standard_headers! {
     /// be set on both sides (the Access-Control-Allow-Credentials header and in
     /// the XHR or Fetch request) in order for the CORS request with credentials
     /// to succeed.
-    (ACCESS_CONTROL_ALLOW_CREDENTIALS, b"access-control-allow-credentials");
+    (AccessControlAllowCredentials, ACCESS_CONTROL_ALLOW_CREDENTIALS, b"access-control-allow-credentials");
 
     /// Preflight response indicating permitted HTTP headers.
     ///
@@ -151,33 +211,33 @@ standard_headers! {
     ///
     /// This header is required if the request has an
     /// Access-Control-Request-Headers header.
-    (ACCESS_CONTROL_ALLOW_HEADERS, b"access-control-allow-headers");
+    (AccessControlAllowHeaders, ACCESS_CONTROL_ALLOW_HEADERS, b"access-control-allow-headers");
 
     /// Preflight header response indicating permitted access methods.
     ///
     /// The Access-Control-Allow-Methods response header specifies the method or
     /// methods allowed when accessing the resource in response to a preflight
     /// request.
-    (ACCESS_CONTROL_ALLOW_METHODS, b"access-control-allow-methods");
+    (AccessControlAllowMethods, ACCESS_CONTROL_ALLOW_METHODS, b"access-control-allow-methods");
 
     /// Indicates whether the response can be shared with resources with the
     /// given origin.
-    (ACCESS_CONTROL_ALLOW_ORIGIN, b"access-control-allow-origin");
+    (AccessControlAllowOrigin, ACCESS_CONTROL_ALLOW_ORIGIN, b"access-control-allow-origin");
 
     /// Indicates which headers can be exposed as part of the response by
     /// listing their names.
-    (ACCESS_CONTROL_EXPOSE_HEADERS, b"access-control-expose-headers");
+    (AccessControlExposeHeaders, ACCESS_CONTROL_EXPOSE_HEADERS, b"access-control-expose-headers");
 
     /// Indicates how long the results of a preflight request can be cached.
-    (ACCESS_CONTROL_MAX_AGE, b"access-control-max-age");
+    (AccessControlMaxAge, ACCESS_CONTROL_MAX_AGE, b"access-control-max-age");
 
     /// Informs the server which HTTP headers will be used when an actual
     /// request is made.
-    (ACCESS_CONTROL_REQUEST_HEADERS, b"access-control-request-headers");
+    (AccessControlRequestHeaders, ACCESS_CONTROL_REQUEST_HEADERS, b"access-control-request-headers");
 
     /// Informs the server know which HTTP method will be used when the actual
     /// request is made.
-    (ACCESS_CONTROL_REQUEST_METHOD, b"access-control-request-method");
+    (AccessControlRequestMethod, ACCESS_CONTROL_REQUEST_METHOD, b"access-control-request-method");
 
     /// Indicates the time in seconds the object has been in a proxy cache.
     ///
@@ -185,7 +245,7 @@ standard_headers! {
     /// probably just fetched from the origin server; otherwise It is usually
     /// calculated as a difference between the proxy's current date and the Date
     /// general header included in the HTTP response.
-    (AGE, b"age");
+    (Age, AGE, b"age");
 
     /// Lists the set of methods support by a resource.
     ///
@@ -194,16 +254,22 @@ standard_headers! {
     /// empty Allow header indicates that the resource allows no request
     /// methods, which might occur temporarily for a given resource, for
     /// example.
-    (ALLOW, b"allow");
+    (Allow, ALLOW, b"allow");
 
     /// Advertises the availability of alternate services to clients.
-    (ALT_SVC, b"alt-svc");
+    (AltSvc, ALT_SVC, b"alt-svc");
 
     /// Contains the credentials to authenticate a user agent with a server.
     ///
     /// Usually this header is included after the server has responded with a
     /// 401 Unauthorized status and the WWW-Authenticate header.
-    (AUTHORIZATION, b"authorization");
+    (Authorization, AUTHORIZATION, b"authorization");
+
+    /// Advertises the hash of the best matching compression dictionary the
+    /// client has available for the request.
+    ///
+    /// See [RFC 9842](https://www.rfc-editor.org/rfc/rfc9842.html).
+    (AvailableDictionary, AVAILABLE_DICTIONARY, b"available-dictionary");
 
     /// Specifies directives for caching mechanisms in both requests and
     /// responses.
@@ -211,19 +277,19 @@ standard_headers! {
     /// Caching directives are unidirectional, meaning that a given directive in
     /// a request is not implying that the same directive is to be given in the
     /// response.
-    (CACHE_CONTROL, b"cache-control");
+    (CacheControl, CACHE_CONTROL, b"cache-control");
 
     /// Indicates how caches have handled a response and its corresponding request.
     ///
     /// See [RFC 9211](https://www.rfc-editor.org/rfc/rfc9211.html).
-    (CACHE_STATUS, b"cache-status");
+    (CacheStatus, CACHE_STATUS, b"cache-status");
 
     /// Specifies directives that allow origin servers to control the behavior of CDN caches
     /// interposed between them and clients separately from other caches that might handle the
     /// response.
     ///
     /// See [RFC 9213](https://www.rfc-editor.org/rfc/rfc9213.html).
-    (CDN_CACHE_CONTROL, b"cdn-cache-control");
+    (CdnCacheControl, CDN_CACHE_CONTROL, b"cdn-cache-control");
 
     /// Controls whether or not the network connection stays open after the
     /// current transaction finishes.
@@ -238,7 +304,7 @@ standard_headers! {
     /// to consume them and not to forward them further. Standard hop-by-hop
     /// headers can be listed too (it is often the case of Keep-Alive, but this
     /// is not mandatory.
-    (CONNECTION, b"connection");
+    (Connection, CONNECTION, b"connection");
 
     /// Indicates if the content is expected to be displayed inline.
     ///
@@ -258,7 +324,7 @@ standard_headers! {
     /// to HTTP forms and POST requests. Only the value form-data, as well as
     /// the optional directive name and filename, can be used in the HTTP
     /// context.
-    (CONTENT_DISPOSITION, b"content-disposition");
+    (ContentDisposition, CONTENT_DISPOSITION, b"content-disposition");
 
     /// Used to compress the media-type.
     ///
@@ -270,7 +336,13 @@ standard_headers! {
     /// use this field, but some types of resources, like jpeg images, are
     /// already compressed.  Sometimes using additional compression doesn't
     /// reduce payload size and can even make the payload longer.
-    (CONTENT_ENCODING, b"content-encoding");
+    (ContentEncoding, CONTENT_ENCODING, b"content-encoding");
+
+    /// Digest of the content of the message, after content codings and
+    /// before transfer codings.
+    ///
+    /// See [RFC 9530](https://www.rfc-editor.org/rfc/rfc9530.html).
+    (ContentDigest, CONTENT_DIGEST, b"content-digest");
 
     /// Used to describe the languages intended for the audience.
     ///
@@ -285,13 +357,18 @@ standard_headers! {
     /// intended for all language audiences. Multiple language tags are also
     /// possible, as well as applying the Content-Language header to various
     /// media types and not only to textual documents.
-    (CONTENT_LANGUAGE, b"content-language");
+    (ContentLanguage, CONTENT_LANGUAGE, b"content-language");
 
     /// Indicates the size of the entity-body.
     ///
     /// The header value must be a decimal indicating the number of octets sent
     /// to the recipient.
-    (CONTENT_LENGTH, b"content-length");
+    (ContentLength, CONTENT_LENGTH, b"content-length");
+
+    /// Legacy base64 MD5 of the content.
+    ///
+    /// See [RFC 1864](https://www.rfc-editor.org/rfc/rfc1864.html).
+    (ContentMd5, CONTENT_MD5, b"content-md5");
 
     /// Indicates an alternate location for the returned data.
     ///
@@ -304,10 +381,10 @@ standard_headers! {
     /// without the need of further content negotiation. Location is a header
     /// associated with the response, while Content-Location is associated with
     /// the entity returned.
-    (CONTENT_LOCATION, b"content-location");
+    (ContentLocation, CONTENT_LOCATION, b"content-location");
 
     /// Indicates where in a full body message a partial message belongs.
-    (CONTENT_RANGE, b"content-range");
+    (ContentRange, CONTENT_RANGE, b"content-range");
 
     /// Allows controlling resources the user agent is allowed to load for a
     /// given page.
@@ -315,7 +392,7 @@ standard_headers! {
     /// With a few exceptions, policies mostly involve specifying server origins
     /// and script endpoints. This helps guard against cross-site scripting
     /// attacks (XSS).
-    (CONTENT_SECURITY_POLICY, b"content-security-policy");
+    (ContentSecurityPolicy, CONTENT_SECURITY_POLICY, b"content-security-policy");
 
     /// Allows experimenting with policies by monitoring their effects.
     ///
@@ -323,7 +400,7 @@ standard_headers! {
     /// developers to experiment with policies by monitoring (but not enforcing)
     /// their effects. These violation reports consist of JSON documents sent
     /// via an HTTP POST request to the specified URI.
-    (CONTENT_SECURITY_POLICY_REPORT_ONLY, b"content-security-policy-report-only");
+    (ContentSecurityPolicyReportOnly, CONTENT_SECURITY_POLICY_REPORT_ONLY, b"content-security-policy-report-only");
 
     /// Used to indicate the media type of the resource.
     ///
@@ -335,23 +412,34 @@ standard_headers! {
     ///
     /// In requests, (such as POST or PUT), the client tells the server what
     /// type of data is actually sent.
-    (CONTENT_TYPE, b"content-type");
+    (ContentType, CONTENT_TYPE, b"content-type");
 
     /// Contains stored HTTP cookies previously sent by the server with the
     /// Set-Cookie header.
     ///
     /// The Cookie header might be omitted entirely, if the privacy setting of
     /// the browser are set to block them, for example.
-    (COOKIE, b"cookie");
+    (Cookie, COOKIE, b"cookie");
 
     /// Indicates the client's tracking preference.
     ///
     /// This header lets users indicate whether they would prefer privacy rather
     /// than personalized content.
-    (DNT, b"dnt");
+    (Dnt, DNT, b"dnt");
 
     /// Contains the date and time at which the message was originated.
-    (DATE, b"date");
+    (Date, DATE, b"date");
+
+    /// Legacy instance digest, obsoleted by Content-Digest and Repr-Digest.
+    ///
+    /// See [RFC 3230](https://www.rfc-editor.org/rfc/rfc3230.html).
+    (Digest, DIGEST, b"digest");
+
+    /// Carries the server provided identifier of the dictionary advertised in
+    /// Available-Dictionary.
+    ///
+    /// See [RFC 9842](https://www.rfc-editor.org/rfc/rfc9842.html).
+    (DictionaryId, DICTIONARY_ID, b"dictionary-id");
 
     /// Identifier for a specific version of a resource.
     ///
@@ -367,7 +455,7 @@ standard_headers! {
     /// to quickly determine whether two representations of a resource are the
     /// same, but they might also be set to persist indefinitely by a tracking
     /// server.
-    (ETAG, b"etag");
+    (Etag, ETAG, b"etag");
 
     /// Indicates expectations that need to be fulfilled by the server in order
     /// to properly handle the request.
@@ -386,7 +474,7 @@ standard_headers! {
     ///
     /// No common browsers send the Expect header, but some other clients such
     /// as cURL do so by default.
-    (EXPECT, b"expect");
+    (Expect, EXPECT, b"expect");
 
     /// Contains the date/time after which the response is considered stale.
     ///
@@ -395,7 +483,7 @@ standard_headers! {
     ///
     /// If there is a Cache-Control header with the "max-age" or "s-max-age"
     /// directive in the response, the Expires header is ignored.
-    (EXPIRES, b"expires");
+    (Expires, EXPIRES, b"expires");
 
     /// Contains information from the client-facing side of proxy servers that
     /// is altered or lost when a proxy is involved in the path of the request.
@@ -407,7 +495,7 @@ standard_headers! {
     /// location-dependent content and by design it exposes privacy sensitive
     /// information, such as the IP address of the client. Therefore the user's
     /// privacy must be kept in mind when deploying this header.
-    (FORWARDED, b"forwarded");
+    (Forwarded, FORWARDED, b"forwarded");
 
     /// Contains an Internet email address for a human user who controls the
     /// requesting user agent.
@@ -416,7 +504,7 @@ standard_headers! {
     /// header should be sent, so you can be contacted if problems occur on
     /// servers, such as if the robot is sending excessive, unwanted, or invalid
     /// requests.
-    (FROM, b"from");
+    (From, FROM, b"from");
 
     /// Specifies the domain name of the server and (optionally) the TCP port
     /// number on which the server is listening.
@@ -427,7 +515,7 @@ standard_headers! {
     /// A Host header field must be sent in all HTTP/1.1 request messages. A 400
     /// (Bad Request) status code will be sent to any HTTP/1.1 request message
     /// that lacks a Host header field or contains more than one.
-    (HOST, b"host");
+    (Host, HOST, b"host");
 
     /// Makes a request conditional based on the E-Tag.
     ///
@@ -452,7 +540,7 @@ standard_headers! {
     /// that has been done since the original resource was fetched. If the
     /// request cannot be fulfilled, the 412 (Precondition Failed) response is
     /// returned.
-    (IF_MATCH, b"if-match");
+    (IfMatch, IF_MATCH, b"if-match");
 
     /// Makes a request conditional based on the modification date.
     ///
@@ -469,7 +557,7 @@ standard_headers! {
     ///
     /// The most common use case is to update a cached entity that has no
     /// associated ETag.
-    (IF_MODIFIED_SINCE, b"if-modified-since");
+    (IfModifiedSince, IF_MODIFIED_SINCE, b"if-modified-since");
 
     /// Makes a request conditional based on the E-Tag.
     ///
@@ -505,7 +593,7 @@ standard_headers! {
     /// guaranteeing that another upload didn't happen before, losing the data
     /// of the previous put; this problems is the variation of the lost update
     /// problem.
-    (IF_NONE_MATCH, b"if-none-match");
+    (IfNoneMatch, IF_NONE_MATCH, b"if-none-match");
 
     /// Makes a request conditional based on range.
     ///
@@ -521,7 +609,7 @@ standard_headers! {
     /// The most common use case is to resume a download, to guarantee that the
     /// stored resource has not been modified since the last fragment has been
     /// received.
-    (IF_RANGE, b"if-range");
+    (IfRange, IF_RANGE, b"if-range");
 
     /// Makes the request conditional based on the last modification date.
     ///
@@ -542,17 +630,17 @@ standard_headers! {
     /// * In conjunction with a range request with a If-Range header, it can be
     /// used to ensure that the new fragment requested comes from an unmodified
     /// document.
-    (IF_UNMODIFIED_SINCE, b"if-unmodified-since");
+    (IfUnmodifiedSince, IF_UNMODIFIED_SINCE, b"if-unmodified-since");
 
     /// The Last-Modified header contains the date and time when the origin believes
     /// the resource was last modified.
     ///
     /// The value is a valid Date/Time string defined in [RFC9910](https://datatracker.ietf.org/doc/html/rfc9110#section-5.6.7)
-    (LAST_MODIFIED, b"last-modified");
+    (LastModified, LAST_MODIFIED, b"last-modified");
 
     /// Allows the server to point an interested client to another resource
     /// containing metadata about the requested resource.
-    (LINK, b"link");
+    (Link, LINK, b"link");
 
     /// Indicates the URL to redirect a page to.
     ///
@@ -583,11 +671,11 @@ standard_headers! {
     /// when content negotiation happened, without the need of further content
     /// negotiation. Location is a header associated with the response, while
     /// Content-Location is associated with the entity returned.
-    (LOCATION, b"location");
+    (Location, LOCATION, b"location");
 
     /// Indicates the max number of intermediaries the request should be sent
     /// through.
-    (MAX_FORWARDS, b"max-forwards");
+    (MaxForwards, MAX_FORWARDS, b"max-forwards");
 
     /// Indicates where a fetch originates from.
     ///
@@ -595,7 +683,7 @@ standard_headers! {
     /// sent with CORS requests, as well as with POST requests. It is similar to
     /// the Referer header, but, unlike this header, it doesn't disclose the
     /// whole path.
-    (ORIGIN, b"origin");
+    (Origin, ORIGIN, b"origin");
 
     /// HTTP/1.0 header usually used for backwards compatibility.
     ///
@@ -603,7 +691,7 @@ standard_headers! {
     /// that may have various effects along the request-response chain. It is
     /// used for backwards compatibility with HTTP/1.0 caches where the
     /// Cache-Control HTTP/1.1 header is not yet present.
-    (PRAGMA, b"pragma");
+    (Pragma, PRAGMA, b"pragma");
 
     /// Defines the authentication method that should be used to gain access to
     /// a proxy.
@@ -621,18 +709,18 @@ standard_headers! {
     ///
     /// The `proxy-authenticate` header is sent along with a `407 Proxy
     /// Authentication Required`.
-    (PROXY_AUTHENTICATE, b"proxy-authenticate");
+    (ProxyAuthenticate, PROXY_AUTHENTICATE, b"proxy-authenticate");
 
     /// Contains the credentials to authenticate a user agent to a proxy server.
     ///
     /// This header is usually included after the server has responded with a
     /// 407 Proxy Authentication Required status and the Proxy-Authenticate
     /// header.
-    (PROXY_AUTHORIZATION, b"proxy-authorization");
+    (ProxyAuthorization, PROXY_AUTHORIZATION, b"proxy-authorization");
 
 
     // TODO: write docs
-    (PROXY_CONNECTION, b"proxy-connection");
+    (ProxyConnection, PROXY_CONNECTION, b"proxy-connection");
 
     /// Associates a specific cryptographic public key with a certain server.
     ///
@@ -640,14 +728,14 @@ standard_headers! {
     /// or several keys are pinned and none of them are used by the server, the
     /// browser will not accept the response as legitimate, and will not display
     /// it.
-    (PUBLIC_KEY_PINS, b"public-key-pins");
+    (PublicKeyPins, PUBLIC_KEY_PINS, b"public-key-pins");
 
     /// Sends reports of pinning violation to the report-uri specified in the
     /// header.
     ///
     /// Unlike `Public-Key-Pins`, this header still allows browsers to connect
     /// to the server if the pinning is violated.
-    (PUBLIC_KEY_PINS_REPORT_ONLY, b"public-key-pins-report-only");
+    (PublicKeyPinsReportOnly, PUBLIC_KEY_PINS_REPORT_ONLY, b"public-key-pins-report-only");
 
     /// Indicates the part of a document that the server should return.
     ///
@@ -657,7 +745,13 @@ standard_headers! {
     /// the ranges are invalid, the server returns the 416 Range Not Satisfiable
     /// error. The server can also ignore the Range header and return the whole
     /// document with a 200 status code.
-    (RANGE, b"range");
+    (Range, RANGE, b"range");
+
+    /// Digest of the selected representation, independent of the range sent
+    /// in the message.
+    ///
+    /// See [RFC 9530](https://www.rfc-editor.org/rfc/rfc9530.html).
+    (ReprDigest, REPR_DIGEST, b"repr-digest");
 
     /// Contains the address of the previous web page from which a link to the
     /// currently requested page was followed.
@@ -665,15 +759,15 @@ standard_headers! {
     /// The Referer header allows servers to identify where people are visiting
     /// them from and may use that data for analytics, logging, or optimized
     /// caching, for example.
-    (REFERER, b"referer");
+    (Referer, REFERER, b"referer");
 
     /// Governs which referrer information should be included with requests
     /// made.
-    (REFERRER_POLICY, b"referrer-policy");
+    (ReferrerPolicy, REFERRER_POLICY, b"referrer-policy");
 
     /// Informs the web browser that the current page or frame should be
     /// refreshed.
-    (REFRESH, b"refresh");
+    (Refresh, REFRESH, b"refresh");
 
     /// The Retry-After response HTTP header indicates how long the user agent
     /// should wait before making a follow-up request. There are two main cases
@@ -685,20 +779,20 @@ standard_headers! {
     /// * When sent with a redirect response, such as 301 (Moved Permanently),
     /// it indicates the minimum time that the user agent is asked to wait
     /// before issuing the redirected request.
-    (RETRY_AFTER, b"retry-after");
+    (RetryAfter, RETRY_AFTER, b"retry-after");
 
     /// The |Sec-WebSocket-Accept| header field is used in the WebSocket
     /// opening handshake. It is sent from the server to the client to
     /// confirm that the server is willing to initiate the WebSocket
     /// connection.
-    (SEC_WEBSOCKET_ACCEPT, b"sec-websocket-accept");
+    (SecWebsocketAccept, SEC_WEBSOCKET_ACCEPT, b"sec-websocket-accept");
 
     /// The |Sec-WebSocket-Extensions| header field is used in the WebSocket
     /// opening handshake. It is initially sent from the client to the
     /// server, and then subsequently sent from the server to the client, to
     /// agree on a set of protocol-level extensions to use for the duration
     /// of the connection.
-    (SEC_WEBSOCKET_EXTENSIONS, b"sec-websocket-extensions");
+    (SecWebsocketExtensions, SEC_WEBSOCKET_EXTENSIONS, b"sec-websocket-extensions");
 
     /// The |Sec-WebSocket-Key| header field is used in the WebSocket opening
     /// handshake. It is sent from the client to the server to provide part
@@ -707,14 +801,14 @@ standard_headers! {
     /// does not accept connections from non-WebSocket clients (e.g., HTTP
     /// clients) that are being abused to send data to unsuspecting WebSocket
     /// servers.
-    (SEC_WEBSOCKET_KEY, b"sec-websocket-key");
+    (SecWebsocketKey, SEC_WEBSOCKET_KEY, b"sec-websocket-key");
 
     /// The |Sec-WebSocket-Protocol| header field is used in the WebSocket
     /// opening handshake. It is sent from the client to the server and back
     /// from the server to the client to confirm the subprotocol of the
     /// connection.  This enables scripts to both select a subprotocol and be
     /// sure that the server agreed to serve that subprotocol.
-    (SEC_WEBSOCKET_PROTOCOL, b"sec-websocket-protocol");
+    (SecWebsocketProtocol, SEC_WEBSOCKET_PROTOCOL, b"sec-websocket-protocol");
 
     /// The |Sec-WebSocket-Version| header field is used in the WebSocket
     /// opening handshake.  It is sent from the client to the server to
@@ -722,7 +816,7 @@ standard_headers! {
     /// servers to correctly interpret the opening handshake and subsequent
     /// data being sent from the data, and close the connection if the server
     /// cannot interpret that data in a safe manner.
-    (SEC_WEBSOCKET_VERSION, b"sec-websocket-version");
+    (SecWebsocketVersion, SEC_WEBSOCKET_VERSION, b"sec-websocket-version");
 
     /// Contains information about the software used by the origin server to
     /// handle the request.
@@ -731,13 +825,13 @@ standard_headers! {
     /// potentially reveal internal implementation details that might make it
     /// (slightly) easier for attackers to find and exploit known security
     /// holes.
-    (SERVER, b"server");
+    (Server, SERVER, b"server");
 
     /// Used to send cookies from the server to the user agent.
-    (SET_COOKIE, b"set-cookie");
+    (SetCookie, SET_COOKIE, b"set-cookie");
 
     /// Tells the client to communicate with HTTPS instead of using HTTP.
-    (STRICT_TRANSPORT_SECURITY, b"strict-transport-security");
+    (StrictTransportSecurity, STRICT_TRANSPORT_SECURITY, b"strict-transport-security");
 
     /// Informs the server of transfer encodings willing to be accepted as part
     /// of the response.
@@ -747,11 +841,11 @@ standard_headers! {
     /// recipients and you that don't have to specify "chunked" using the TE
     /// header. However, it is useful for setting if the client is accepting
     /// trailer fields in a chunked transfer coding using the "trailers" value.
-    (TE, b"te");
+    (Te, TE, b"te");
 
     /// Allows the sender to include additional fields at the end of chunked
     /// messages.
-    (TRAILER, b"trailer");
+    (Trailer, TRAILER, b"trailer");
 
     /// Specifies the form of encoding used to safely transfer the entity to the
     /// client.
@@ -765,18 +859,24 @@ standard_headers! {
     /// When present on a response to a `HEAD` request that has no body, it
     /// indicates the value that would have applied to the corresponding `GET`
     /// message.
-    (TRANSFER_ENCODING, b"transfer-encoding");
+    (TransferEncoding, TRANSFER_ENCODING, b"transfer-encoding");
 
     /// Contains a string that allows identifying the requesting client's
     /// software.
-    (USER_AGENT, b"user-agent");
+    (UserAgent, USER_AGENT, b"user-agent");
 
     /// Used as part of the exchange to upgrade the protocol.
-    (UPGRADE, b"upgrade");
+    (Upgrade, UPGRADE, b"upgrade");
 
     /// Sends a signal to the server expressing the client’s preference for an
     /// encrypted and authenticated response.
-    (UPGRADE_INSECURE_REQUESTS, b"upgrade-insecure-requests");
+    (UpgradeInsecureRequests, UPGRADE_INSECURE_REQUESTS, b"upgrade-insecure-requests");
+
+    /// Marks a response as a compression dictionary for future requests that
+    /// match the given URL pattern.
+    ///
+    /// See [RFC 9842](https://www.rfc-editor.org/rfc/rfc9842.html).
+    (UseAsDictionary, USE_AS_DICTIONARY, b"use-as-dictionary");
 
     /// Determines how to match future requests with cached responses.
     ///
@@ -788,7 +888,7 @@ standard_headers! {
     ///
     /// The `vary` header should be set on a 304 Not Modified response exactly
     /// like it would have been set on an equivalent 200 OK response.
-    (VARY, b"vary");
+    (Vary, VARY, b"vary");
 
     /// Added by proxies to track routing.
     ///
@@ -797,7 +897,17 @@ standard_headers! {
     /// It is used for tracking message forwards, avoiding request loops, and
     /// identifying the protocol capabilities of senders along the
     /// request/response chain.
-    (VIA, b"via");
+    (Via, VIA, b"via");
+
+    /// Algorithms the sender would like to receive Content-Digest with.
+    ///
+    /// See [RFC 9530](https://www.rfc-editor.org/rfc/rfc9530.html).
+    (WantContentDigest, WANT_CONTENT_DIGEST, b"want-content-digest");
+
+    /// Algorithms the sender would like to receive Repr-Digest with.
+    ///
+    /// See [RFC 9530](https://www.rfc-editor.org/rfc/rfc9530.html).
+    (WantReprDigest, WANT_REPR_DIGEST, b"want-repr-digest");
 
     /// General HTTP header contains information about possible problems with
     /// the status of the message.
@@ -805,11 +915,11 @@ standard_headers! {
     /// More than one `warning` header may appear in a response. Warning header
     /// fields can in general be applied to any message, however some warn-codes
     /// are specific to caches and can only be applied to response messages.
-    (WARNING, b"warning");
+    (Warning, WARNING, b"warning");
 
     /// Defines the authentication method that should be used to gain access to
     /// a resource.
-    (WWW_AUTHENTICATE, b"www-authenticate");
+    (WwwAuthenticate, WWW_AUTHENTICATE, b"www-authenticate");
 
     /// Marker used by the server to indicate that the MIME types advertised in
     /// the `content-type` headers should not be changed and be followed.
@@ -824,7 +934,7 @@ standard_headers! {
     /// less aggressive.
     ///
     /// Site security testers usually expect this header to be set.
-    (X_CONTENT_TYPE_OPTIONS, b"x-content-type-options");
+    (XContentTypeOptions, X_CONTENT_TYPE_OPTIONS, b"x-content-type-options");
 
     /// Controls DNS prefetching.
     ///
@@ -837,7 +947,7 @@ standard_headers! {
     /// This prefetching is performed in the background, so that the DNS is
     /// likely to have been resolved by the time the referenced items are
     /// needed. This reduces latency when the user clicks a link.
-    (X_DNS_PREFETCH_CONTROL, b"x-dns-prefetch-control");
+    (XDnsPrefetchControl, X_DNS_PREFETCH_CONTROL, b"x-dns-prefetch-control");
 
     /// Indicates whether or not a browser should be allowed to render a page in
     /// a frame.
@@ -847,7 +957,7 @@ standard_headers! {
     ///
     /// The added security is only provided if the user accessing the document
     /// is using a browser supporting `x-frame-options`.
-    (X_FRAME_OPTIONS, b"x-frame-options");
+    (XFrameOptions, X_FRAME_OPTIONS, b"x-frame-options");
 
     /// Stop pages from loading when an XSS attack is detected.
     ///
@@ -858,5 +968,5 @@ standard_headers! {
     /// implement a strong Content-Security-Policy that disables the use of
     /// inline JavaScript ('unsafe-inline'), they can still provide protections
     /// for users of older web browsers that don't yet support CSP.
-    (X_XSS_PROTECTION, b"x-xss-protection");
+    (XXssProtection, X_XSS_PROTECTION, b"x-xss-protection");
 }
@@ -0,0 +1,271 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use bytes::Bytes;
+
+pub use crate::const_headers::StandardHeader;
+use crate::const_headers::{MAX_STANDARD_LEN, fnv1a};
+
+/* Header name, standard names are interned as StandardHeader and others
+ * kept as lowercase Bytes with their hash. Equal names are equal and hash
+ * the same whatever their case in the message.
+ */
+#[derive(Clone, Debug)]
+pub enum HeaderName {
+    Standard(StandardHeader),
+    Custom(CustomName),
+}
+
+// lowercase name that is not a standard header
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CustomName {
+    name: Bytes,
+    hash: u64,
+}
+
+impl CustomName {
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.name
+    }
+}
+
+impl HeaderName {
+    pub fn from_static(name: &'static str) -> Self {
+        Self::from_bytes_with(name.as_bytes(), |lower| {
+            if lower == name.as_bytes() {
+                Bytes::from_static(name.as_bytes())
+            } else {
+                Bytes::copy_from_slice(lower)
+            }
+        })
+    }
+
+    // case-insensitive, copied only when not standard
+    pub fn from_bytes(name: &[u8]) -> Self {
+        Self::from_bytes_with(name, Bytes::copy_from_slice)
+    }
+
+    fn from_bytes_with<F>(name: &[u8], custom: F) -> Self
+    where
+        F: FnOnce(&[u8]) -> Bytes,
+    {
+        if name.len() <= MAX_STANDARD_LEN {
+            let mut buf = [0; MAX_STANDARD_LEN];
+            let lower = &mut buf[..name.len()];
+            lower.copy_from_slice(name);
+            lower.make_ascii_lowercase();
+            return match StandardHeader::from_lowercase(lower) {
+                Some(standard) => HeaderName::Standard(standard),
+                None => HeaderName::custom(custom(lower)),
+            };
+        }
+        HeaderName::custom(custom(&name.to_ascii_lowercase()))
+    }
+
+    // name must be lowercase
+    fn custom(name: Bytes) -> Self {
+        let hash = fnv1a(&name);
+        HeaderName::Custom(CustomName {
+            name,
+            hash,
+        })
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            HeaderName::Standard(standard) => standard.as_bytes(),
+            HeaderName::Custom(custom) => custom.as_bytes(),
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        str::from_utf8(self.as_bytes()).ok()
+    }
+
+    pub fn standard(&self) -> Option<StandardHeader> {
+        match self {
+            HeaderName::Standard(standard) => Some(*standard),
+            HeaderName::Custom(_) => None,
+        }
+    }
+
+    pub fn is_standard(&self) -> bool {
+        matches!(self, HeaderName::Standard(_))
+    }
+
+    // precomputed FNV-1a of the lowercase name
+    pub fn hash_code(&self) -> u64 {
+        match self {
+            HeaderName::Standard(standard) => standard.hash_code(),
+            HeaderName::Custom(custom) => custom.hash,
+        }
+    }
+
+    // case-insensitive comparison with a key of a header map
+    pub fn matches(&self, key: &[u8]) -> bool {
+        self.as_bytes().eq_ignore_ascii_case(key)
+    }
+}
+
+impl PartialEq for HeaderName {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (HeaderName::Standard(one), HeaderName::Standard(two)) => {
+                one == two
+            }
+            (HeaderName::Custom(one), HeaderName::Custom(two)) => {
+                one.hash == two.hash && one.name == two.name
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Eq for HeaderName {}
+
+impl Hash for HeaderName {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.hash_code());
+    }
+}
+
+impl PartialEq<[u8]> for HeaderName {
+    fn eq(&self, other: &[u8]) -> bool {
+        self.matches(other)
+    }
+}
+
+impl PartialEq<str> for HeaderName {
+    fn eq(&self, other: &str) -> bool {
+        self.matches(other.as_bytes())
+    }
+}
+
+impl PartialEq<&str> for HeaderName {
+    fn eq(&self, other: &&str) -> bool {
+        self.matches(other.as_bytes())
+    }
+}
+
+impl AsRef<[u8]> for HeaderName {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl AsRef<[u8]> for StandardHeader {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl From<StandardHeader> for HeaderName {
+    fn from(standard: StandardHeader) -> Self {
+        HeaderName::Standard(standard)
+    }
+}
+
+impl From<&[u8]> for HeaderName {
+    fn from(name: &[u8]) -> Self {
+        HeaderName::from_bytes(name)
+    }
+}
+
+impl From<&str> for HeaderName {
+    fn from(name: &str) -> Self {
+        HeaderName::from_bytes(name.as_bytes())
+    }
+}
+
+// reuses the buffer when already lowercase
+impl From<Bytes> for HeaderName {
+    fn from(name: Bytes) -> Self {
+        if name.iter().any(|b| b.is_ascii_uppercase()) {
+            return HeaderName::from_bytes(&name);
+        }
+        match StandardHeader::from_lowercase(&name) {
+            Some(standard) => HeaderName::Standard(standard),
+            None => HeaderName::custom(name),
+        }
+    }
+}
+
+impl fmt::Display for HeaderName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", String::from_utf8_lossy(self.as_bytes()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::const_headers::{CONTENT_LENGTH, X_XSS_PROTECTION};
+
+    #[test]
+    fn test_header_name_standard() {
+        let name = HeaderName::from("Content-Length");
+        assert_eq!(name, HeaderName::Standard(StandardHeader::ContentLength));
+        assert_eq!(name.as_bytes(), CONTENT_LENGTH);
+        assert_eq!(name.as_str(), Some("content-length"));
+        assert!(name.is_standard());
+        let name = HeaderName::from(&b"X-XSS-PROTECTION"[..]);
+        assert_eq!(name.standard(), Some(StandardHeader::XXssProtection));
+        assert_eq!(name.as_bytes(), X_XSS_PROTECTION);
+    }
+
+    #[test]
+    fn test_header_name_custom() {
+        let name = HeaderName::from("X-Custom-Header");
+        assert!(!name.is_standard());
+        assert_eq!(name.as_bytes(), b"x-custom-header");
+        assert_eq!(name, HeaderName::from_static("x-custom-header"));
+        assert_eq!(name, HeaderName::from(Bytes::from("X-CUSTOM-header")));
+        assert_ne!(name, HeaderName::from("x-custom"));
+        // longer than any standard header
+        let long = "X-A-Very-Long-Custom-Header-Name-Over-The-Limit";
+        assert_eq!(
+            HeaderName::from(long).as_str(),
+            Some(&*long.to_lowercase())
+        );
+    }
+
+    #[test]
+    fn test_header_name_from_bytes_standard() {
+        let name = HeaderName::from(Bytes::from_static(b"host"));
+        assert_eq!(name, HeaderName::Standard(StandardHeader::Host));
+    }
+
+    #[test]
+    fn test_header_name_all_standard_interned() {
+        for standard in StandardHeader::all() {
+            let upper = standard.as_bytes().to_ascii_uppercase();
+            let name = HeaderName::from(&upper[..]);
+            assert_eq!(name.standard(), Some(*standard));
+            assert_eq!(name.hash_code(), fnv1a(standard.as_bytes()));
+            assert!(standard.as_bytes().len() <= MAX_STANDARD_LEN);
+        }
+    }
+
+    #[test]
+    fn test_header_name_hash() {
+        let mut set = HashSet::new();
+        set.insert(HeaderName::from("Content-Type"));
+        set.insert(HeaderName::from("X-Custom"));
+        assert!(set.contains(&HeaderName::from("content-type")));
+        assert!(set.contains(&HeaderName::from("x-CUSTOM")));
+        assert!(!set.contains(&HeaderName::from("x-other")));
+        let standard = HeaderName::from("accept");
+        assert_eq!(standard.hash_code(), fnv1a(b"ACCEPT"));
+    }
+
+    #[test]
+    fn test_header_name_eq_str() {
+        let name = HeaderName::from(StandardHeader::ContentType);
+        assert_eq!(name, "Content-Type");
+        assert!(name.matches(b"CONTENT-TYPE"));
+        assert!(!name.matches(b"content-length"));
+        assert_eq!(name.to_string(), "content-type");
+    }
+}
@@ -0,0 +1,303 @@
+use bytes::Bytes;
+
+use crate::hpack::primitive::{decode_int, decode_string};
+use crate::hpack::pseudo::{
+    check_trailers, request_line, response_line, split_fields,
+};
+use crate::hpack::table::{DynamicTable, STATIC_TABLE};
+use crate::hpack::{DEFAULT_TABLE_SIZE, Field, HpackError};
+use crate::{HeaderMap, RequestLine, ResponseLine};
+
+/* HPACK decoder of header blocks, RFC 7541. Blocks must be decoded in the
+ * order they are received, the whole block is decoded before the pseudo
+ * headers are checked so the table stays in sync on errors.
+ */
+#[derive(Debug)]
+pub struct Decoder {
+    table: DynamicTable,
+    // SETTINGS_HEADER_TABLE_SIZE sent to the peer
+    max_size_limit: usize,
+    // the limit was lowered below the table size
+    update_required: bool,
+}
+
+impl Default for Decoder {
+    fn default() -> Self {
+        Decoder::new(DEFAULT_TABLE_SIZE)
+    }
+}
+
+impl Decoder {
+    pub fn new(max_table_size: usize) -> Self {
+        Decoder {
+            table: DynamicTable::new(max_table_size),
+            max_size_limit: max_table_size,
+            update_required: false,
+        }
+    }
+
+    // once acknowledged by the peer, RFC 9113 6.5.3
+    pub fn set_max_table_size(&mut self, size: usize) {
+        if size < self.table.max_size() {
+            self.update_required = true;
+        }
+        self.max_size_limit = size;
+    }
+
+    pub fn table_size(&self) -> usize {
+        self.table.size()
+    }
+
+    pub fn decode_request(
+        &mut self,
+        block: &[u8],
+    ) -> Result<(RequestLine, HeaderMap), HpackError> {
+        let (pseudo, map) = split_fields(self.decode_fields(block)?)?;
+        Ok((request_line(pseudo)?, map))
+    }
+
+    pub fn decode_response(
+        &mut self,
+        block: &[u8],
+    ) -> Result<(ResponseLine, HeaderMap), HpackError> {
+        let (pseudo, map) = split_fields(self.decode_fields(block)?)?;
+        Ok((response_line(pseudo)?, map))
+    }
+
+    pub fn decode_trailers(
+        &mut self,
+        block: &[u8],
+    ) -> Result<HeaderMap, HpackError> {
+        let (pseudo, map) = split_fields(self.decode_fields(block)?)?;
+        check_trailers(pseudo)?;
+        Ok(map)
+    }
+
+    /* Fields of the block in order, pseudo-headers included.
+     *
+     * Error:
+     *      InvalidIndex            when an index is not in either table.
+     *      TableSizeUpdate         when an update is above the limit.
+     *      LateTableSizeUpdate     when an update follows a field.
+     *      MissingTableSizeUpdate  when the limit was lowered and the block
+     *                              does not start with an update.
+     *      UppercaseName           when a literal name has uppercase.
+     *      and errors of the integer and string representations.
+     */
+    pub fn decode_fields(
+        &mut self,
+        block: &[u8],
+    ) -> Result<Vec<Field>, HpackError> {
+        let mut buf = block;
+        let mut fields = Vec::new();
+        let mut at_start = true;
+        while let Some(&first) = buf.first() {
+            if first & 0xe0 == 0x20 {
+                // 6.3, dynamic table size update
+                if !at_start {
+                    return Err(HpackError::LateTableSizeUpdate);
+                }
+                let size = decode_int(&mut buf, 5)?;
+                if size > self.max_size_limit {
+                    return Err(HpackError::TableSizeUpdate(size));
+                }
+                self.table.set_max_size(size);
+                self.update_required = false;
+                continue;
+            }
+            if self.update_required {
+                return Err(HpackError::MissingTableSizeUpdate);
+            }
+            at_start = false;
+            let field = if first & 0x80 != 0 {
+                // 6.1, indexed
+                let (name, value) = self.get(decode_int(&mut buf, 7)?)?;
+                Field::new(name, value)
+            } else if first & 0x40 != 0 {
+                // 6.2.1, with incremental indexing
+                let (name, value) = self.decode_literal(&mut buf, 6)?;
+                self.table.insert(name.clone(), value.clone());
+                Field::new(name, value)
+            } else if first & 0x10 != 0 {
+                // 6.2.3, never indexed
+                let (name, value) = self.decode_literal(&mut buf, 4)?;
+                Field::never_indexed(name, value)
+            } else {
+                // 6.2.2, without indexing
+                let (name, value) = self.decode_literal(&mut buf, 4)?;
+                Field::new(name, value)
+            };
+            fields.push(field);
+        }
+        Ok(fields)
+    }
+
+    fn decode_literal(
+        &self,
+        buf: &mut &[u8],
+        prefix: u8,
+    ) -> Result<(Bytes, Bytes), HpackError> {
+        let index = decode_int(buf, prefix)?;
+        let name = if index == 0 {
+            let name = decode_string(buf, 7)?;
+            if name.iter().any(u8::is_ascii_uppercase) {
+                return Err(HpackError::UppercaseName);
+            }
+            name
+        } else {
+            self.get(index)?.0
+        };
+        Ok((name, decode_string(buf, 7)?))
+    }
+
+    // static entries are 1 to 61, dynamic entries follow
+    fn get(&self, index: usize) -> Result<(Bytes, Bytes), HpackError> {
+        if let Some((name, value)) =
+            index.checked_sub(1).and_then(|i| STATIC_TABLE.get(i))
+        {
+            return Ok((
+                Bytes::from_static(name.as_bytes()),
+                Bytes::from_static(value.as_bytes()),
+            ));
+        }
+        index
+            .checked_sub(STATIC_TABLE.len() + 1)
+            .and_then(|i| self.table.get(i))
+            .cloned()
+            .ok_or(HpackError::InvalidIndex(index))
+    }
+
+    #[cfg(test)]
+    pub(crate) fn table(&self) -> &DynamicTable {
+        &self.table
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode(
+        decoder: &mut Decoder,
+        block: &[u8],
+    ) -> Result<Vec<(Bytes, Bytes, bool)>, HpackError> {
+        Ok(decoder
+            .decode_fields(block)?
+            .into_iter()
+            .map(|field| {
+                let sensitive = field.is_sensitive();
+                let (name, value) = field.into_parts();
+                (name, value, sensitive)
+            })
+            .collect())
+    }
+
+    // C.2.1
+    #[test]
+    fn test_decoder_literal_indexed() {
+        let mut decoder = Decoder::default();
+        let fields =
+            decode(&mut decoder, b"\x40\x0acustom-key\x0dcustom-header")
+                .unwrap();
+        assert_eq!(
+            fields,
+            [("custom-key".into(), "custom-header".into(), false)]
+        );
+        assert_eq!(decoder.table_size(), 55);
+    }
+
+    // C.2.2
+    #[test]
+    fn test_decoder_literal_not_indexed() {
+        let mut decoder = Decoder::default();
+        let fields = decode(&mut decoder, b"\x04\x0c/sample/path").unwrap();
+        assert_eq!(fields, [(":path".into(), "/sample/path".into(), false)]);
+        assert_eq!(decoder.table_size(), 0);
+    }
+
+    // C.2.3
+    #[test]
+    fn test_decoder_never_indexed() {
+        let mut decoder = Decoder::default();
+        let fields =
+            decode(&mut decoder, b"\x10\x08password\x06secret").unwrap();
+        assert_eq!(fields, [("password".into(), "secret".into(), true)]);
+        assert_eq!(decoder.table_size(), 0);
+    }
+
+    // C.2.4
+    #[test]
+    fn test_decoder_indexed() {
+        let mut decoder = Decoder::default();
+        let fields = decode(&mut decoder, &[0x82]).unwrap();
+        assert_eq!(fields, [(":method".into(), "GET".into(), false)]);
+        assert_eq!(decoder.table_size(), 0);
+    }
+
+    #[test]
+    fn test_decoder_invalid_index() {
+        let mut decoder = Decoder::default();
+        assert_eq!(
+            decode(&mut decoder, &[0x80]),
+            Err(HpackError::InvalidIndex(0))
+        );
+        assert_eq!(
+            decode(&mut decoder, &[0xbe]),
+            Err(HpackError::InvalidIndex(62))
+        );
+    }
+
+    #[test]
+    fn test_decoder_table_size_update() {
+        let mut decoder = Decoder::default();
+        decode(&mut decoder, b"\x40\x01a\x01b").unwrap();
+        // 0 then 4096
+        decode(&mut decoder, &[0x20, 0x3f, 0xe1, 0x1f, 0x82]).unwrap();
+        assert_eq!(decoder.table_size(), 0);
+        assert_eq!(
+            decode(&mut decoder, &[0x82, 0x20]),
+            Err(HpackError::LateTableSizeUpdate)
+        );
+        assert_eq!(
+            decode(&mut decoder, &[0x3f, 0xe2, 0x1f]),
+            Err(HpackError::TableSizeUpdate(4097))
+        );
+    }
+
+    #[test]
+    fn test_decoder_limit_lowered() {
+        let mut decoder = Decoder::default();
+        decoder.set_max_table_size(100);
+        assert_eq!(
+            decode(&mut decoder, &[0x82]),
+            Err(HpackError::MissingTableSizeUpdate)
+        );
+        assert_eq!(
+            decode(&mut decoder, &[0x3f, 0x46]),
+            Err(HpackError::TableSizeUpdate(101))
+        );
+        decode(&mut decoder, &[0x3f, 0x45, 0x82]).unwrap();
+        decode(&mut decoder, &[0x82]).unwrap();
+    }
+
+    #[test]
+    fn test_decoder_uppercase_name() {
+        let mut decoder = Decoder::default();
+        assert_eq!(
+            decode(&mut decoder, b"\x00\x01A\x01b"),
+            Err(HpackError::UppercaseName)
+        );
+    }
+
+    #[test]
+    fn test_decoder_pseudo_checked_after_table() {
+        let mut decoder = Decoder::default();
+        // regular field, then :method
+        let block = b"\x40\x01a\x01b\x82";
+        assert!(matches!(
+            decoder.decode_request(block),
+            Err(HpackError::Pseudo(_))
+        ));
+        assert_eq!(decoder.table_size(), 34);
+    }
+}
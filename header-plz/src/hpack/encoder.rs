@@ -0,0 +1,293 @@
+use bytes::{Bytes, BytesMut};
+
+use crate::hpack::primitive::{encode_int, encode_string};
+use crate::hpack::pseudo::{request_fields, response_fields};
+use crate::hpack::table::{
+    DynamicTable, STATIC_TABLE, TableMatch, entry_size, find_static,
+};
+use crate::hpack::{DEFAULT_TABLE_SIZE, Field, is_sensitive, lowercase};
+use crate::message_head::header_map::Hmap;
+use crate::{HeaderMap, RequestLine, ResponseLine};
+
+/* HPACK encoder of header blocks, RFC 7541. A field is sent as an index
+ * when the table has it, else as a literal added to the dynamic table.
+ * Sensitive fields are never indexed and fields larger than the table are
+ * not indexed.
+ *
+ * The encoder keeps its table in sync with the decoder of the peer, blocks
+ * must be sent in the order they are encoded.
+ */
+#[derive(Debug)]
+pub struct Encoder {
+    table: DynamicTable,
+    // smallest and last size since the previous block, RFC 7541 4.2
+    size_update: Option<(usize, usize)>,
+    huffman: bool,
+}
+
+impl Default for Encoder {
+    fn default() -> Self {
+        Encoder::new(DEFAULT_TABLE_SIZE)
+    }
+}
+
+impl Encoder {
+    // max_table_size is the SETTINGS_HEADER_TABLE_SIZE of the peer
+    pub fn new(max_table_size: usize) -> Self {
+        Encoder {
+            table: DynamicTable::new(max_table_size),
+            size_update: None,
+            huffman: true,
+        }
+    }
+
+    pub fn set_huffman(&mut self, huffman: bool) {
+        self.huffman = huffman;
+    }
+
+    pub fn table_size(&self) -> usize {
+        self.table.size()
+    }
+
+    pub fn max_table_size(&self) -> usize {
+        self.table.max_size()
+    }
+
+    // signaled at the start of the next block
+    pub fn set_max_table_size(&mut self, size: usize) {
+        self.size_update = match self.size_update {
+            Some((min, _)) => Some((min.min(size), size)),
+            None => Some((size, size)),
+        };
+        self.table.set_max_size(size);
+    }
+
+    pub fn encode_request(
+        &mut self,
+        line: &RequestLine,
+        headers: &HeaderMap,
+        dst: &mut BytesMut,
+    ) {
+        self.start_block(dst);
+        for (name, value) in request_fields(line) {
+            self.encode_field(name, value, false, dst);
+        }
+        self.encode_map(headers, dst);
+    }
+
+    pub fn encode_response(
+        &mut self,
+        line: &ResponseLine,
+        headers: &HeaderMap,
+        dst: &mut BytesMut,
+    ) {
+        self.start_block(dst);
+        for (name, value) in response_fields(line) {
+            self.encode_field(name, value, false, dst);
+        }
+        self.encode_map(headers, dst);
+    }
+
+    // block without pseudo-headers, ie. trailers
+    pub fn encode_headers(&mut self, headers: &HeaderMap, dst: &mut BytesMut) {
+        self.start_block(dst);
+        self.encode_map(headers, dst);
+    }
+
+    // fields are encoded as is, pseudo-headers included
+    pub fn encode_fields<'a, I>(&mut self, fields: I, dst: &mut BytesMut)
+    where
+        I: IntoIterator<Item = &'a Field>,
+    {
+        self.start_block(dst);
+        for field in fields {
+            let name = lowercase(field.name());
+            self.encode_field(&name, field.value(), field.is_sensitive(), dst);
+        }
+    }
+
+    fn start_block(&mut self, dst: &mut BytesMut) {
+        let Some((min, last)) = self.size_update.take() else {
+            return;
+        };
+        if min < last {
+            encode_int(min, 5, 0x20, dst);
+        }
+        encode_int(last, 5, 0x20, dst);
+    }
+
+    // removed headers are skipped
+    fn encode_map(&mut self, headers: &HeaderMap, dst: &mut BytesMut) {
+        for header in headers.iter().filter(|header| !header.is_empty()) {
+            let name = lowercase(header.key_as_ref());
+            self.encode_field(&name, header.value_as_ref(), false, dst);
+        }
+    }
+
+    fn encode_field(
+        &mut self,
+        name: &[u8],
+        value: &[u8],
+        sensitive: bool,
+        dst: &mut BytesMut,
+    ) {
+        let found = self.find(name, value);
+        if sensitive || is_sensitive(name, value) {
+            // 6.2.3, never indexed
+            self.encode_literal(found, name, value, 4, 0x10, dst);
+            return;
+        }
+        match found {
+            // 6.1, indexed
+            Some(TableMatch::Full(index)) => encode_int(index, 7, 0x80, dst),
+            // 6.2.2, without indexing
+            _ if entry_size(name, value) > self.table.max_size() => {
+                self.encode_literal(found, name, value, 4, 0x00, dst)
+            }
+            // 6.2.1, with incremental indexing
+            _ => {
+                self.encode_literal(found, name, value, 6, 0x40, dst);
+                self.table.insert(
+                    Bytes::copy_from_slice(name),
+                    Bytes::copy_from_slice(value),
+                );
+            }
+        }
+    }
+
+    fn encode_literal(
+        &self,
+        found: Option<TableMatch>,
+        name: &[u8],
+        value: &[u8],
+        prefix: u8,
+        flags: u8,
+        dst: &mut BytesMut,
+    ) {
+        match found {
+            Some(TableMatch::Full(index) | TableMatch::Name(index)) => {
+                encode_int(index, prefix, flags, dst)
+            }
+            None => {
+                dst.extend_from_slice(&[flags]);
+                encode_string(name, 7, 0, self.huffman, dst);
+            }
+        }
+        encode_string(value, 7, 0, self.huffman, dst);
+    }
+
+    /* Index of the field in the combined address space, static entries
+     * first. A full match in either table is preferred over a name match.
+     */
+    fn find(&self, name: &[u8], value: &[u8]) -> Option<TableMatch> {
+        let found_static = find_static(&STATIC_TABLE, name, value)
+            .map(|found| shift(found, 1));
+        if let Some(found @ TableMatch::Full(_)) = found_static {
+            return Some(found);
+        }
+        let found_dynamic = self
+            .table
+            .find(name, value)
+            .map(|found| shift(found, STATIC_TABLE.len() + 1));
+        match found_dynamic {
+            Some(found @ TableMatch::Full(_)) => Some(found),
+            _ => found_static.or(found_dynamic),
+        }
+    }
+}
+
+fn shift(found: TableMatch, offset: usize) -> TableMatch {
+    match found {
+        TableMatch::Full(index) => TableMatch::Full(index + offset),
+        TableMatch::Name(index) => TableMatch::Name(index + offset),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode(encoder: &mut Encoder, fields: &[Field]) -> BytesMut {
+        let mut dst = BytesMut::new();
+        encoder.encode_fields(fields, &mut dst);
+        dst
+    }
+
+    fn field(name: &'static str, value: &'static str) -> Field {
+        Field::new(
+            Bytes::from_static(name.as_bytes()),
+            Bytes::from_static(value.as_bytes()),
+        )
+    }
+
+    #[test]
+    fn test_encoder_indexed_static() {
+        let mut encoder = Encoder::default();
+        let dst = encode(&mut encoder, &[field(":method", "GET")]);
+        assert_eq!(&dst[..], [0x82]);
+        assert_eq!(encoder.table_size(), 0);
+    }
+
+    // C.2.3
+    #[test]
+    fn test_encoder_never_indexed() {
+        let mut encoder = Encoder::default();
+        encoder.set_huffman(false);
+        let password = Field::never_indexed(
+            Bytes::from_static(b"password"),
+            Bytes::from_static(b"secret"),
+        );
+        let dst = encode(&mut encoder, &[password]);
+        assert_eq!(&dst[..], b"\x10\x08password\x06secret");
+        assert_eq!(encoder.table_size(), 0);
+        // name index of the static table
+        let dst = encode(&mut encoder, &[field("authorization", "Basic")]);
+        assert_eq!(&dst[..], b"\x1f\x08\x05Basic");
+    }
+
+    #[test]
+    fn test_encoder_larger_than_table() {
+        let mut encoder = Encoder::new(40);
+        encoder.set_huffman(false);
+        let dst = encode(&mut encoder, &[field("x-large", "0123456789")]);
+        assert_eq!(&dst[..], b"\x00\x07x-large\x0a0123456789");
+        assert_eq!(encoder.table_size(), 0);
+    }
+
+    #[test]
+    fn test_encoder_lowercase() {
+        let mut encoder = Encoder::default();
+        encoder.set_huffman(false);
+        let dst = encode(&mut encoder, &[field("X-Custom", "A")]);
+        assert_eq!(&dst[..], b"\x40\x08x-custom\x01A");
+        let dst = encode(&mut encoder, &[field("x-custom", "A")]);
+        assert_eq!(&dst[..], [0xbe]);
+    }
+
+    #[test]
+    fn test_encoder_table_size_update() {
+        let mut encoder = Encoder::default();
+        encode(&mut encoder, &[field("x-custom", "a")]);
+        encoder.set_max_table_size(0);
+        encoder.set_max_table_size(100);
+        assert_eq!(encoder.table_size(), 0);
+        let dst = encode(&mut encoder, &[field(":method", "GET")]);
+        // 0, then 100 with a 5 bit prefix
+        assert_eq!(&dst[..], [0x20, 0x3f, 0x45, 0x82]);
+        let dst = encode(&mut encoder, &[field(":method", "GET")]);
+        assert_eq!(&dst[..], [0x82]);
+    }
+
+    #[test]
+    fn test_encoder_skips_removed() {
+        let mut encoder = Encoder::default();
+        encoder.set_huffman(false);
+        let mut map = HeaderMap::new();
+        map.insert("a", "1");
+        map.insert("b", "2");
+        map.remove_header_on_key("a");
+        let mut dst = BytesMut::new();
+        encoder.encode_headers(&map, &mut dst);
+        assert_eq!(&dst[..], b"\x40\x01b\x012");
+    }
+}
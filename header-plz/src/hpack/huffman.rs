@@ -0,0 +1,165 @@
+// Huffman coding of string literals, RFC 7541 5.2 and Appendix B
+
+use std::sync::OnceLock;
+
+use bytes::{BufMut, BytesMut};
+
+use crate::hpack::HpackError;
+use crate::hpack::huffman_table::CODES;
+
+const EOS: u16 = 256;
+const LEAF: u16 = 0x8000;
+
+// length in bytes of the encoded value, padding included
+pub(crate) fn encoded_len(value: &[u8]) -> usize {
+    let bits: usize =
+        value.iter().map(|&b| CODES[b as usize].1 as usize).sum();
+    bits.div_ceil(8)
+}
+
+// codes are written msb first, the last byte is padded with the EOS prefix
+pub(crate) fn encode(value: &[u8], dst: &mut BytesMut) {
+    let mut bits: u64 = 0;
+    let mut pending: u8 = 0;
+    for &byte in value {
+        let (code, len) = CODES[byte as usize];
+        bits = bits << len | code as u64;
+        pending += len;
+        while pending >= 8 {
+            pending -= 8;
+            dst.put_u8((bits >> pending) as u8);
+        }
+    }
+    if pending > 0 {
+        let pad = 8 - pending;
+        dst.put_u8((bits << pad) as u8 | ((1 << pad) - 1));
+    }
+}
+
+/* Error:
+ *      HuffmanEos          when the EOS symbol is decoded.
+ *      HuffmanPadding      when the padding is longer than 7 bits or not
+ *                          the most significant bits of EOS.
+ */
+pub(crate) fn decode(
+    src: &[u8],
+    dst: &mut BytesMut,
+) -> Result<(), HpackError> {
+    let tree = tree();
+    let mut node = 0;
+    let mut depth = 0;
+    let mut all_ones = true;
+    for &byte in src {
+        for shift in (0..8).rev() {
+            let bit = (byte >> shift) & 1;
+            all_ones &= bit == 1;
+            depth += 1;
+            let next = tree[node][bit as usize];
+            if next & LEAF == 0 {
+                node = next as usize;
+                continue;
+            }
+            let symbol = next & !LEAF;
+            if symbol == EOS {
+                return Err(HpackError::HuffmanEos);
+            }
+            dst.put_u8(symbol as u8);
+            node = 0;
+            depth = 0;
+            all_ones = true;
+        }
+    }
+    if depth > 7 || !all_ones {
+        return Err(HpackError::HuffmanPadding);
+    }
+    Ok(())
+}
+
+/* Binary tree of the code, a node holds its two children. A child is a
+ * node index, or a symbol with the LEAF bit set. The code is complete so
+ * every node has both children.
+ */
+fn tree() -> &'static [[u16; 2]] {
+    static TREE: OnceLock<Vec<[u16; 2]>> = OnceLock::new();
+    TREE.get_or_init(|| {
+        let mut nodes = vec![[0u16; 2]];
+        for (symbol, &(code, len)) in CODES.iter().enumerate() {
+            let mut node = 0;
+            for shift in (0..len).rev() {
+                let bit = ((code >> shift) & 1) as usize;
+                if shift == 0 {
+                    nodes[node][bit] = LEAF | symbol as u16;
+                    break;
+                }
+                if nodes[node][bit] == 0 {
+                    nodes.push([0; 2]);
+                    nodes[node][bit] = (nodes.len() - 1) as u16;
+                }
+                node = nodes[node][bit] as usize;
+            }
+        }
+        nodes
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encoded(value: &str) -> BytesMut {
+        let mut dst = BytesMut::new();
+        encode(value.as_bytes(), &mut dst);
+        assert_eq!(dst.len(), encoded_len(value.as_bytes()));
+        dst
+    }
+
+    fn decoded(src: &[u8]) -> Result<BytesMut, HpackError> {
+        let mut dst = BytesMut::new();
+        decode(src, &mut dst).map(|_| dst)
+    }
+
+    // strings of C.4 and C.6
+    #[test]
+    fn test_huffman_rfc() {
+        let cases: [(&str, &[u8]); 5] = [
+            (
+                "www.example.com",
+                &[
+                    0xf1, 0xe3, 0xc2, 0xe5, 0xf2, 0x3a, 0x6b, 0xa0, 0xab,
+                    0x90, 0xf4, 0xff,
+                ],
+            ),
+            ("no-cache", &[0xa8, 0xeb, 0x10, 0x64, 0x9c, 0xbf]),
+            ("302", &[0x64, 0x02]),
+            ("private", &[0xae, 0xc3, 0x77, 0x1a, 0x4b]),
+            ("custom-key", &[0x25, 0xa8, 0x49, 0xe9, 0x5b, 0xa9, 0x7d, 0x7f]),
+        ];
+        for (value, code) in cases {
+            assert_eq!(encoded(value), code);
+            assert_eq!(decoded(code).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn test_huffman_all_bytes() {
+        let value: Vec<u8> = (0..=255).collect();
+        let mut dst = BytesMut::new();
+        encode(&value, &mut dst);
+        assert_eq!(decoded(&dst).unwrap(), &value[..]);
+    }
+
+    #[test]
+    fn test_huffman_padding() {
+        // "0" is 00000, padded with 111
+        assert_eq!(decoded(&[0x07]).unwrap(), "0");
+        // padding not all ones
+        assert_eq!(decoded(&[0x06]), Err(HpackError::HuffmanPadding));
+        // a full byte of padding
+        assert_eq!(decoded(&[0x07, 0xff]), Err(HpackError::HuffmanPadding));
+        // EOS
+        assert_eq!(
+            decoded(&[0xff, 0xff, 0xff, 0xff]),
+            Err(HpackError::HuffmanEos)
+        );
+    }
+}
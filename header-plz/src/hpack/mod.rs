@@ -0,0 +1,368 @@
+use std::borrow::Cow;
+
+use bytes::Bytes;
+use thiserror::Error;
+
+mod decoder;
+mod encoder;
+pub(crate) mod huffman;
+mod huffman_table;
+pub(crate) mod primitive;
+pub mod pseudo;
+pub(crate) mod table;
+
+pub use decoder::Decoder;
+pub use encoder::Encoder;
+pub use pseudo::PseudoHeaderError;
+
+use crate::const_headers::{AUTHORIZATION, COOKIE, PROXY_AUTHORIZATION};
+
+// SETTINGS_HEADER_TABLE_SIZE before any SETTINGS, RFC 9113 6.5.2
+pub const DEFAULT_TABLE_SIZE: usize = 4096;
+
+// cookies shorter than this are easy to guess, RFC 7541 7.1.3
+const MIN_COOKIE_LEN: usize = 20;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum HpackError {
+    #[error("truncated")]
+    Truncated,
+    #[error("integer overflow")]
+    IntegerOverflow,
+    #[error("invalid huffman padding")]
+    HuffmanPadding,
+    #[error("huffman eos")]
+    HuffmanEos,
+    #[error("invalid index| {0}")]
+    InvalidIndex(usize),
+    #[error("table size update above limit| {0}")]
+    TableSizeUpdate(usize),
+    #[error("table size update after a field")]
+    LateTableSizeUpdate,
+    #[error("missing table size update")]
+    MissingTableSizeUpdate,
+    #[error("uppercase name")]
+    UppercaseName,
+    #[error("pseudo header| {0}")]
+    Pseudo(#[from] PseudoHeaderError),
+}
+
+/* Field of a header block. A sensitive field is never indexed, and
+ * intermediaries must encode it the same way, RFC 7541 6.2.3.
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Field {
+    name: Bytes,
+    value: Bytes,
+    sensitive: bool,
+}
+
+impl Field {
+    pub fn new(name: Bytes, value: Bytes) -> Self {
+        Field {
+            name,
+            value,
+            sensitive: false,
+        }
+    }
+
+    pub fn never_indexed(name: Bytes, value: Bytes) -> Self {
+        Field {
+            name,
+            value,
+            sensitive: true,
+        }
+    }
+
+    pub fn name(&self) -> &[u8] {
+        &self.name
+    }
+
+    pub fn value(&self) -> &[u8] {
+        &self.value
+    }
+
+    pub fn is_sensitive(&self) -> bool {
+        self.sensitive
+    }
+
+    pub fn into_parts(self) -> (Bytes, Bytes) {
+        (self.name, self.value)
+    }
+}
+
+// credentials and short cookies are never indexed
+pub(crate) fn is_sensitive(name: &[u8], value: &[u8]) -> bool {
+    match name {
+        AUTHORIZATION | PROXY_AUTHORIZATION => true,
+        COOKIE => value.len() < MIN_COOKIE_LEN,
+        _ => false,
+    }
+}
+
+// names are sent lowercase, RFC 9113 8.2.1
+pub(crate) fn lowercase(name: &[u8]) -> Cow<'_, [u8]> {
+    if name.iter().any(u8::is_ascii_uppercase) {
+        Cow::Owned(name.to_ascii_lowercase())
+    } else {
+        Cow::Borrowed(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+
+    use super::*;
+    use crate::message_head::header_map::Hmap;
+    use crate::{HeaderMap, Method, RequestLine, ResponseLine, StatusCode};
+
+    fn hex(src: &str) -> Vec<u8> {
+        (0..src.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&src[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn headers(fields: &[(&str, &str)]) -> HeaderMap {
+        let mut map = HeaderMap::new();
+        for (name, value) in fields {
+            map.insert(*name, *value);
+        }
+        map
+    }
+
+    fn pairs(map: &HeaderMap) -> Vec<(&[u8], &[u8])> {
+        map.iter()
+            .map(|header| (header.key_as_ref(), header.value_as_ref()))
+            .collect()
+    }
+
+    fn table(decoder: &Decoder) -> Vec<(&[u8], &[u8])> {
+        decoder.table().entries().collect()
+    }
+
+    // request of C.3 and C.4
+    struct Request {
+        scheme: &'static str,
+        path: &'static str,
+        headers: &'static [(&'static str, &'static str)],
+        plain: &'static str,
+        huffman: &'static str,
+        table: &'static [(&'static str, &'static str)],
+        size: usize,
+    }
+
+    static REQUESTS: [Request; 3] = [
+        Request {
+            scheme: "http",
+            path: "/",
+            headers: &[],
+            plain: "828684410f7777772e6578616d706c652e636f6d",
+            huffman: "828684418cf1e3c2e5f23a6ba0ab90f4ff",
+            table: &[(":authority", "www.example.com")],
+            size: 57,
+        },
+        Request {
+            scheme: "http",
+            path: "/",
+            headers: &[("cache-control", "no-cache")],
+            plain: "828684be58086e6f2d6361636865",
+            huffman: "828684be5886a8eb10649cbf",
+            table: &[
+                ("cache-control", "no-cache"),
+                (":authority", "www.example.com"),
+            ],
+            size: 110,
+        },
+        Request {
+            scheme: "https",
+            path: "/index.html",
+            headers: &[("custom-key", "custom-value")],
+            plain: "828785bf400a637573746f6d2d6b65790c637573746f6d2d76616c7565",
+            huffman: "828785bf408825a849e95ba97d7f8925a849e95bb8e8b4bf",
+            table: &[
+                ("custom-key", "custom-value"),
+                ("cache-control", "no-cache"),
+                (":authority", "www.example.com"),
+            ],
+            size: 164,
+        },
+    ];
+
+    fn request_line(scheme: &str, path: &str) -> RequestLine {
+        let uri = crate::Uri::builder()
+            .scheme(scheme)
+            .authority("www.example.com")
+            .path(path)
+            .build()
+            .unwrap();
+        RequestLine::new(Method::GET, uri)
+    }
+
+    // C.3 and C.4
+    #[test]
+    fn test_hpack_rfc_requests() {
+        for huffman in [false, true] {
+            let mut encoder = Encoder::default();
+            encoder.set_huffman(huffman);
+            let mut decoder = Decoder::default();
+            for request in &REQUESTS {
+                let expected = hex(if huffman {
+                    request.huffman
+                } else {
+                    request.plain
+                });
+                let line = request_line(request.scheme, request.path);
+                let map = headers(request.headers);
+                let mut dst = BytesMut::new();
+                encoder.encode_request(&line, &map, &mut dst);
+                assert_eq!(&dst[..], &expected[..]);
+
+                let (decoded_line, decoded_map) =
+                    decoder.decode_request(&expected).unwrap();
+                assert_eq!(decoded_line, line);
+                assert_eq!(decoded_map, map);
+                let expected_table: Vec<(&[u8], &[u8])> = request
+                    .table
+                    .iter()
+                    .map(|(name, value)| (name.as_bytes(), value.as_bytes()))
+                    .collect();
+                assert_eq!(table(&decoder), expected_table);
+                assert_eq!(decoder.table().size(), request.size);
+            }
+        }
+    }
+
+    // response of C.5 and C.6, with a table of 256 bytes
+    struct Response {
+        status: u16,
+        date: &'static str,
+        headers: &'static [(&'static str, &'static str)],
+        plain: &'static str,
+        huffman: &'static str,
+        table: &'static [&'static str],
+        size: usize,
+    }
+
+    const LOCATION: (&str, &str) = ("location", "https://www.example.com");
+    const COOKIE: &str =
+        "foo=ASDJKHQKBZXOQWEOPIUAXQWEOIU; max-age=3600; version=1";
+
+    static RESPONSES: [Response; 3] = [
+        Response {
+            status: 302,
+            date: "Mon, 21 Oct 2013 20:13:21 GMT",
+            headers: &[LOCATION],
+            plain: "4803333032580770726976617465611d4d6f6e2c203231204f63742032\
+                    3031332032303a31333a323120474d546e1768747470733a2f2f777777\
+                    2e6578616d706c652e636f6d",
+            huffman: "488264025885aec3771a4b6196d07abe941054d444a8200595040b81\
+                      66e082a62d1bff6e919d29ad171863c78f0b97c8e9ae82ae43d3",
+            table: &["location", "date", "cache-control", ":status"],
+            size: 222,
+        },
+        Response {
+            status: 307,
+            date: "Mon, 21 Oct 2013 20:13:21 GMT",
+            headers: &[LOCATION],
+            plain: "4803333037c1c0bf",
+            huffman: "4883640effc1c0bf",
+            table: &[":status", "location", "date", "cache-control"],
+            size: 222,
+        },
+        Response {
+            status: 200,
+            date: "Mon, 21 Oct 2013 20:13:22 GMT",
+            headers: &[
+                LOCATION,
+                ("content-encoding", "gzip"),
+                ("set-cookie", COOKIE),
+            ],
+            plain: "88c1611d4d6f6e2c203231204f637420323031332032303a31333a3232\
+                    20474d54c05a04677a69707738666f6f3d4153444a4b48514b425a584f\
+                    5157454f50495541585157454f49553b206d61782d6167653d33363030\
+                    3b2076657273696f6e3d31",
+            huffman: "88c16196d07abe941054d444a8200595040b8166e084a62d1bffc05a\
+                      839bd9ab77ad94e7821dd7f2e6c7b335dfdfcd5b3960d5af27087f36\
+                      72c1ab270fb5291f9587316065c003ed4ee5b1063d5007",
+            table: &["set-cookie", "content-encoding", "date"],
+            size: 215,
+        },
+    ];
+
+    // C.5 and C.6
+    #[test]
+    fn test_hpack_rfc_responses() {
+        for huffman in [false, true] {
+            let mut encoder = Encoder::new(256);
+            encoder.set_huffman(huffman);
+            let mut decoder = Decoder::new(256);
+            for response in &RESPONSES {
+                let expected = hex(if huffman {
+                    response.huffman
+                } else {
+                    response.plain
+                });
+                let line = ResponseLine::new(
+                    StatusCode::from_u16(response.status).unwrap(),
+                );
+                let mut map = headers(&[
+                    ("cache-control", "private"),
+                    ("date", response.date),
+                ]);
+                for (name, value) in response.headers {
+                    map.insert(*name, *value);
+                }
+                let mut dst = BytesMut::new();
+                encoder.encode_response(&line, &map, &mut dst);
+                assert_eq!(&dst[..], &expected[..]);
+
+                let (decoded_line, decoded_map) =
+                    decoder.decode_response(&expected).unwrap();
+                assert_eq!(decoded_line, line);
+                assert_eq!(pairs(&decoded_map), pairs(&map));
+                let names: Vec<&[u8]> = table(&decoder)
+                    .into_iter()
+                    .map(|(name, _)| name)
+                    .collect();
+                let expected_names: Vec<&[u8]> = response
+                    .table
+                    .iter()
+                    .map(|name| name.as_bytes())
+                    .collect();
+                assert_eq!(names, expected_names);
+                assert_eq!(decoder.table().size(), response.size);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hpack_sensitive_round_trip() {
+        let mut encoder = Encoder::default();
+        let mut decoder = Decoder::default();
+        let map = headers(&[
+            ("authorization", "Bearer secret"),
+            ("cookie", "id=1"),
+            ("cookie", "session=a-long-enough-session-value"),
+        ]);
+        let mut dst = BytesMut::new();
+        encoder.encode_headers(&map, &mut dst);
+        let fields = decoder.decode_fields(&dst).unwrap();
+        let sensitive: Vec<bool> =
+            fields.iter().map(Field::is_sensitive).collect();
+        assert_eq!(sensitive, [true, true, false]);
+        // only the long cookie was indexed
+        assert_eq!(table(&decoder).len(), 1);
+        assert_eq!(decoder.decode_trailers(&dst).unwrap(), map);
+    }
+
+    #[test]
+    fn test_hpack_is_sensitive() {
+        assert!(is_sensitive(b"authorization", b""));
+        assert!(is_sensitive(b"proxy-authorization", b"Basic YQ=="));
+        assert!(is_sensitive(b"cookie", b"a=b"));
+        assert!(!is_sensitive(b"cookie", COOKIE.as_bytes()));
+        assert!(!is_sensitive(b"set-cookie", b"a=b"));
+    }
+}
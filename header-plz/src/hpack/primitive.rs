@@ -0,0 +1,196 @@
+// Integer and string literal representations, RFC 7541 5.1 and 5.2. Shared
+// with QPACK which uses other prefixes.
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+
+use crate::hpack::HpackError;
+use crate::hpack::huffman;
+
+/* Integer with a prefix of n bits in the first byte, the bits above the
+ * prefix are flags.
+ */
+pub(crate) fn encode_int(
+    value: usize,
+    prefix: u8,
+    flags: u8,
+    dst: &mut BytesMut,
+) {
+    let max = (1usize << prefix) - 1;
+    if value < max {
+        dst.put_u8(flags | value as u8);
+        return;
+    }
+    dst.put_u8(flags | max as u8);
+    let mut rest = value - max;
+    while rest >= 128 {
+        dst.put_u8((rest % 128) as u8 | 0x80);
+        rest /= 128;
+    }
+    dst.put_u8(rest as u8);
+}
+
+/* Error:
+ *      Truncated           when the input ends inside the integer.
+ *      IntegerOverflow     when the value does not fit in a usize.
+ */
+pub(crate) fn decode_int(
+    buf: &mut &[u8],
+    prefix: u8,
+) -> Result<usize, HpackError> {
+    if !buf.has_remaining() {
+        return Err(HpackError::Truncated);
+    }
+    let max = (1usize << prefix) - 1;
+    let mut value = buf.get_u8() as usize & max;
+    if value < max {
+        return Ok(value);
+    }
+    let mut shift = 0u32;
+    loop {
+        if !buf.has_remaining() {
+            return Err(HpackError::Truncated);
+        }
+        let byte = buf.get_u8();
+        value = ((byte & 0x7f) as usize)
+            .checked_shl(shift)
+            .filter(|part| part >> shift == (byte & 0x7f) as usize)
+            .and_then(|part| value.checked_add(part))
+            .ok_or(HpackError::IntegerOverflow)?;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+        shift += 7;
+    }
+}
+
+/* String with a length prefix of n bits, the Huffman flag is the bit above
+ * the prefix. Huffman is used when allowed and not longer.
+ */
+pub(crate) fn encode_string(
+    value: &[u8],
+    prefix: u8,
+    flags: u8,
+    huffman: bool,
+    dst: &mut BytesMut,
+) {
+    let encoded_len = huffman::encoded_len(value);
+    if huffman && encoded_len <= value.len() {
+        encode_int(encoded_len, prefix, flags | 1 << prefix, dst);
+        huffman::encode(value, dst);
+    } else {
+        encode_int(value.len(), prefix, flags, dst);
+        dst.extend_from_slice(value);
+    }
+}
+
+pub(crate) fn decode_string(
+    buf: &mut &[u8],
+    prefix: u8,
+) -> Result<Bytes, HpackError> {
+    let is_huffman = buf.first().is_some_and(|b| b & 1 << prefix != 0);
+    let len = decode_int(buf, prefix)?;
+    if buf.len() < len {
+        return Err(HpackError::Truncated);
+    }
+    let (data, rest) = buf.split_at(len);
+    *buf = rest;
+    if is_huffman {
+        let mut dst = BytesMut::with_capacity(len * 8 / 5);
+        huffman::decode(data, &mut dst)?;
+        Ok(dst.freeze())
+    } else {
+        Ok(Bytes::copy_from_slice(data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn int(value: usize, prefix: u8) -> BytesMut {
+        let mut dst = BytesMut::new();
+        encode_int(value, prefix, 0, &mut dst);
+        dst
+    }
+
+    // C.1.1, C.1.2 and C.1.3
+    #[test]
+    fn test_int_rfc() {
+        assert_eq!(int(10, 5), &[0x0a][..]);
+        assert_eq!(int(1337, 5), &[0x1f, 0x9a, 0x0a][..]);
+        assert_eq!(int(42, 8), &[0x2a][..]);
+        assert_eq!(decode_int(&mut &[0xea][..], 5), Ok(10));
+        assert_eq!(decode_int(&mut &[0x1f, 0x9a, 0x0a][..], 5), Ok(1337));
+        assert_eq!(decode_int(&mut &[0x2a][..], 8), Ok(42));
+    }
+
+    #[test]
+    fn test_int_round_trip() {
+        for prefix in 1..=8 {
+            for value in [0, 1, 30, 31, 127, 128, 255, 256, 16_384, 1 << 30] {
+                let encoded = int(value, prefix);
+                let mut buf = &encoded[..];
+                assert_eq!(decode_int(&mut buf, prefix), Ok(value));
+                assert!(buf.is_empty());
+            }
+        }
+        assert_eq!(int(usize::MAX, 7).len(), 11);
+        let encoded = int(usize::MAX, 7);
+        assert_eq!(decode_int(&mut &encoded[..], 7), Ok(usize::MAX));
+    }
+
+    #[test]
+    fn test_int_errors() {
+        assert_eq!(decode_int(&mut &[][..], 5), Err(HpackError::Truncated));
+        assert_eq!(
+            decode_int(&mut &[0x1f, 0x9a][..], 5),
+            Err(HpackError::Truncated)
+        );
+        let mut overflow = vec![0xff];
+        overflow.extend([0xff; 10]);
+        overflow.push(0x01);
+        assert_eq!(
+            decode_int(&mut &overflow[..], 8),
+            Err(HpackError::IntegerOverflow)
+        );
+    }
+
+    #[test]
+    fn test_string_round_trip() {
+        for huffman in [false, true] {
+            for prefix in [3, 5, 7] {
+                let mut dst = BytesMut::new();
+                encode_string(
+                    b"www.example.com",
+                    prefix,
+                    0,
+                    huffman,
+                    &mut dst,
+                );
+                let flag = dst[0] & 1 << prefix != 0;
+                assert_eq!(flag, huffman);
+                let mut buf = &dst[..];
+                assert_eq!(
+                    decode_string(&mut buf, prefix).unwrap(),
+                    "www.example.com"
+                );
+                assert!(buf.is_empty());
+            }
+        }
+    }
+
+    #[test]
+    fn test_string_huffman_not_shorter() {
+        let mut dst = BytesMut::new();
+        encode_string(&[0xff, 0xfe], 7, 0, true, &mut dst);
+        assert_eq!(dst, &[0x02, 0xff, 0xfe][..]);
+    }
+
+    #[test]
+    fn test_string_truncated() {
+        assert_eq!(
+            decode_string(&mut &[0x05, b'a', b'b'][..], 7),
+            Err(HpackError::Truncated)
+        );
+    }
+}
@@ -0,0 +1,369 @@
+// Pseudo-header fields of requests and responses, RFC 9113 8.3. Shared by
+// HPACK and QPACK.
+
+use bytes::Bytes;
+use thiserror::Error;
+
+use crate::hpack::Field;
+use crate::{
+    Header, HeaderMap, Method, RequestLine, ResponseLine, StatusCode, Uri,
+};
+
+pub const AUTHORITY: &[u8] = b":authority";
+pub const METHOD: &[u8] = b":method";
+pub const PATH: &[u8] = b":path";
+pub const PROTOCOL: &[u8] = b":protocol";
+pub const SCHEME: &[u8] = b":scheme";
+pub const STATUS: &[u8] = b":status";
+
+#[derive(Debug, Error, PartialEq)]
+pub enum PseudoHeaderError {
+    #[error("unknown| {0}")]
+    Unknown(String),
+    #[error("duplicate| {0}")]
+    Duplicate(String),
+    #[error("after regular header| {0}")]
+    AfterRegular(String),
+    #[error("missing| {0}")]
+    Missing(&'static str),
+    #[error("not allowed| {0}")]
+    NotAllowed(String),
+    #[error("invalid status| {0}")]
+    Status(String),
+    #[error("invalid uri| {0}")]
+    Uri(String),
+}
+
+// pseudo-headers of a field section, before validation
+#[derive(Debug, Default)]
+pub(crate) struct Pseudo {
+    method: Option<Bytes>,
+    scheme: Option<Bytes>,
+    authority: Option<Bytes>,
+    path: Option<Bytes>,
+    protocol: Option<Bytes>,
+    status: Option<Bytes>,
+}
+
+impl Pseudo {
+    fn slot(&mut self, name: &[u8]) -> Option<&mut Option<Bytes>> {
+        let slot = match name {
+            METHOD => &mut self.method,
+            SCHEME => &mut self.scheme,
+            AUTHORITY => &mut self.authority,
+            PATH => &mut self.path,
+            PROTOCOL => &mut self.protocol,
+            STATUS => &mut self.status,
+            _ => return None,
+        };
+        Some(slot)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.request_names().is_empty() && self.status.is_none()
+    }
+
+    // names of the request pseudo-headers present
+    fn request_names(&self) -> Vec<&'static [u8]> {
+        [
+            (METHOD, &self.method),
+            (SCHEME, &self.scheme),
+            (AUTHORITY, &self.authority),
+            (PATH, &self.path),
+            (PROTOCOL, &self.protocol),
+        ]
+        .into_iter()
+        .filter(|(_, value)| value.is_some())
+        .map(|(name, _)| name)
+        .collect()
+    }
+}
+
+fn lossy(value: &[u8]) -> String {
+    String::from_utf8_lossy(value).into_owned()
+}
+
+/* Split decoded fields in pseudo-headers and a header map.
+ *
+ * Error:
+ *      Unknown         when a pseudo-header is not defined.
+ *      Duplicate       when a pseudo-header is repeated.
+ *      AfterRegular    when a pseudo-header follows a regular header.
+ */
+pub(crate) fn split_fields(
+    fields: Vec<Field>,
+) -> Result<(Pseudo, HeaderMap), PseudoHeaderError> {
+    let mut pseudo = Pseudo::default();
+    let mut map = HeaderMap::new();
+    for field in fields {
+        let (name, value) = field.into_parts();
+        if !name.starts_with(b":") {
+            map.extend([Header::new(name, value)]);
+            continue;
+        }
+        if !map.is_empty() {
+            return Err(PseudoHeaderError::AfterRegular(lossy(&name)));
+        }
+        let slot = pseudo
+            .slot(&name)
+            .ok_or_else(|| PseudoHeaderError::Unknown(lossy(&name)))?;
+        if slot.is_some() {
+            return Err(PseudoHeaderError::Duplicate(lossy(&name)));
+        }
+        *slot = Some(value);
+    }
+    Ok((pseudo, map))
+}
+
+/* Request line from the pseudo-headers. A CONNECT without :protocol only
+ * has :authority, other requests need :scheme and :path, RFC 9113 8.5 and
+ * RFC 8441 4.
+ *
+ * Error:
+ *      NotAllowed      when :status is present, or :scheme or :path in a
+ *                      CONNECT.
+ *      Missing         when a required pseudo-header is absent.
+ *      Uri             when the uri can not be built.
+ */
+pub(crate) fn request_line(
+    pseudo: Pseudo,
+) -> Result<RequestLine, PseudoHeaderError> {
+    if pseudo.status.is_some() {
+        return Err(PseudoHeaderError::NotAllowed(lossy(STATUS)));
+    }
+    let method = Method::from(
+        &pseudo.method.ok_or(PseudoHeaderError::Missing(":method"))?[..],
+    );
+    let mut builder = Uri::builder();
+    if method == Method::CONNECT && pseudo.protocol.is_none() {
+        if pseudo.scheme.is_some() {
+            return Err(PseudoHeaderError::NotAllowed(lossy(SCHEME)));
+        }
+        if pseudo.path.is_some() {
+            return Err(PseudoHeaderError::NotAllowed(lossy(PATH)));
+        }
+        let authority = pseudo
+            .authority
+            .ok_or(PseudoHeaderError::Missing(":authority"))?;
+        builder = builder.authority(&authority[..]);
+    } else {
+        let scheme =
+            pseudo.scheme.ok_or(PseudoHeaderError::Missing(":scheme"))?;
+        let path = pseudo
+            .path
+            .filter(|path| !path.is_empty())
+            .ok_or(PseudoHeaderError::Missing(":path"))?;
+        builder = builder.scheme(&scheme[..]).path(&path[..]);
+        if let Some(authority) = &pseudo.authority {
+            builder = builder.authority(&authority[..]);
+        }
+    }
+    let uri =
+        builder.build().map_err(|e| PseudoHeaderError::Uri(e.to_string()))?;
+    let mut line = RequestLine::new(method, uri);
+    if let Some(protocol) = pseudo.protocol {
+        line.set_extension(protocol);
+    }
+    Ok(line)
+}
+
+/* Error:
+ *      NotAllowed      when a request pseudo-header is present.
+ *      Missing         when :status is absent.
+ *      Status          when :status is not a valid status code.
+ */
+pub(crate) fn response_line(
+    pseudo: Pseudo,
+) -> Result<ResponseLine, PseudoHeaderError> {
+    if let Some(name) = pseudo.request_names().first() {
+        return Err(PseudoHeaderError::NotAllowed(lossy(name)));
+    }
+    let status = pseudo.status.ok_or(PseudoHeaderError::Missing(":status"))?;
+    StatusCode::from_bytes(&status)
+        .map(ResponseLine::new)
+        .map_err(|_| PseudoHeaderError::Status(lossy(&status)))
+}
+
+// trailers have no pseudo-headers
+pub(crate) fn check_trailers(pseudo: Pseudo) -> Result<(), PseudoHeaderError> {
+    if pseudo.is_empty() {
+        return Ok(());
+    }
+    let name = pseudo.request_names().first().copied().unwrap_or(STATUS);
+    Err(PseudoHeaderError::NotAllowed(lossy(name)))
+}
+
+/* Pseudo-headers of a request line in the order of RFC 7541 C.3, a
+ * CONNECT without extension only has :method and :authority.
+ */
+pub(crate) fn request_fields(line: &RequestLine) -> Vec<(&[u8], &[u8])> {
+    let uri = line.uri();
+    let mut fields = vec![(METHOD, line.method().as_ref())];
+    if *line.method() == Method::CONNECT && line.extension().is_none() {
+        if let Some(authority) = uri.authority() {
+            fields.push((AUTHORITY, authority.as_bytes()));
+        }
+        return fields;
+    }
+    if let Some(scheme) = uri.scheme() {
+        fields.push((SCHEME, scheme.as_str().as_bytes()));
+    }
+    fields.push((PATH, uri.path_and_query().as_str().as_bytes()));
+    if let Some(authority) = uri.authority() {
+        fields.push((AUTHORITY, authority.as_bytes()));
+    }
+    if let Some(protocol) = line.extension() {
+        fields.push((PROTOCOL, &protocol[..]));
+    }
+    fields
+}
+
+pub(crate) fn response_fields(line: &ResponseLine) -> [(&[u8], &[u8]); 1] {
+    [(STATUS, line.status().as_str().as_bytes())]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(
+        fields: &[(&'static str, &'static str)],
+    ) -> Result<(Pseudo, HeaderMap), PseudoHeaderError> {
+        split_fields(
+            fields
+                .iter()
+                .map(|(name, value)| {
+                    Field::new(
+                        Bytes::from_static(name.as_bytes()),
+                        Bytes::from_static(value.as_bytes()),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    fn request(
+        fields: &[(&'static str, &'static str)],
+    ) -> Result<RequestLine, PseudoHeaderError> {
+        request_line(split(fields)?.0)
+    }
+
+    #[test]
+    fn test_pseudo_request() {
+        let line = request(&[
+            (":method", "POST"),
+            (":scheme", "https"),
+            (":authority", "example.com"),
+            (":path", "/a?b=c"),
+        ])
+        .unwrap();
+        assert_eq!(*line.method(), Method::POST);
+        assert_eq!(line.uri(), "https://example.com/a?b=c");
+        let fields = request_fields(&line);
+        let names: Vec<&[u8]> = fields.iter().map(|(name, _)| *name).collect();
+        assert_eq!(names, [METHOD, SCHEME, PATH, AUTHORITY]);
+    }
+
+    #[test]
+    fn test_pseudo_connect() {
+        let line = request(&[
+            (":method", "CONNECT"),
+            (":authority", "example.com:443"),
+        ])
+        .unwrap();
+        assert_eq!(line.uri().authority(), Some("example.com:443"));
+        assert_eq!(
+            request_fields(&line),
+            [(METHOD, &b"CONNECT"[..]), (AUTHORITY, &b"example.com:443"[..])]
+        );
+        assert_eq!(
+            request(&[(":method", "CONNECT"), (":path", "/")]),
+            Err(PseudoHeaderError::NotAllowed(":path".into()))
+        );
+        assert_eq!(
+            request(&[(":method", "CONNECT")]),
+            Err(PseudoHeaderError::Missing(":authority"))
+        );
+    }
+
+    #[test]
+    fn test_pseudo_extended_connect() {
+        let line = request(&[
+            (":method", "CONNECT"),
+            (":protocol", "websocket"),
+            (":scheme", "https"),
+            (":path", "/chat"),
+            (":authority", "example.com"),
+        ])
+        .unwrap();
+        assert_eq!(line.extension().map(|e| &e[..]), Some(&b"websocket"[..]));
+        assert_eq!(
+            request_fields(&line).last(),
+            Some(&(PROTOCOL, &b"websocket"[..]))
+        );
+    }
+
+    #[test]
+    fn test_pseudo_request_errors() {
+        assert_eq!(
+            request(&[(":scheme", "https"), (":path", "/")]),
+            Err(PseudoHeaderError::Missing(":method"))
+        );
+        assert_eq!(
+            request(&[
+                (":method", "GET"),
+                (":scheme", "https"),
+                (":path", "")
+            ]),
+            Err(PseudoHeaderError::Missing(":path"))
+        );
+        assert_eq!(
+            request(&[(":method", "GET"), (":status", "200")]),
+            Err(PseudoHeaderError::NotAllowed(":status".into()))
+        );
+        assert_eq!(
+            split(&[(":method", "GET"), (":method", "POST")]).err(),
+            Some(PseudoHeaderError::Duplicate(":method".into()))
+        );
+        assert_eq!(
+            split(&[(":foo", "bar")]).err(),
+            Some(PseudoHeaderError::Unknown(":foo".into()))
+        );
+        assert_eq!(
+            split(&[("accept", "*/*"), (":method", "GET")]).err(),
+            Some(PseudoHeaderError::AfterRegular(":method".into()))
+        );
+    }
+
+    #[test]
+    fn test_pseudo_response() {
+        let (pseudo, map) =
+            split(&[(":status", "404"), ("server", "plz")]).unwrap();
+        assert_eq!(map.iter().count(), 1);
+        assert_eq!(
+            response_line(pseudo).unwrap(),
+            ResponseLine::new(StatusCode::from_u16(404).unwrap())
+        );
+        let (pseudo, _) = split(&[(":status", "20")]).unwrap();
+        assert_eq!(
+            response_line(pseudo),
+            Err(PseudoHeaderError::Status("20".into()))
+        );
+        let (pseudo, _) = split(&[(":path", "/")]).unwrap();
+        assert_eq!(
+            response_line(pseudo),
+            Err(PseudoHeaderError::NotAllowed(":path".into()))
+        );
+    }
+
+    #[test]
+    fn test_pseudo_trailers() {
+        let (pseudo, _) = split(&[("grpc-status", "0")]).unwrap();
+        assert!(check_trailers(pseudo).is_ok());
+        let (pseudo, _) = split(&[(":status", "200")]).unwrap();
+        assert_eq!(
+            check_trailers(pseudo),
+            Err(PseudoHeaderError::NotAllowed(":status".into()))
+        );
+    }
+}
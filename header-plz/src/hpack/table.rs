@@ -0,0 +1,284 @@
+use std::collections::VecDeque;
+
+use bytes::Bytes;
+
+// RFC 7541 Appendix A, index 1 is the first entry
+#[rustfmt::skip]
+pub(crate) static STATIC_TABLE: [(&str, &str); 61] = [
+    (":authority", ""),
+    (":method", "GET"),
+    (":method", "POST"),
+    (":path", "/"),
+    (":path", "/index.html"),
+    (":scheme", "http"),
+    (":scheme", "https"),
+    (":status", "200"),
+    (":status", "204"),
+    (":status", "206"),
+    (":status", "304"),
+    (":status", "400"),
+    (":status", "404"),
+    (":status", "500"),
+    ("accept-charset", ""),
+    ("accept-encoding", "gzip, deflate"),
+    ("accept-language", ""),
+    ("accept-ranges", ""),
+    ("accept", ""),
+    ("access-control-allow-origin", ""),
+    ("age", ""),
+    ("allow", ""),
+    ("authorization", ""),
+    ("cache-control", ""),
+    ("content-disposition", ""),
+    ("content-encoding", ""),
+    ("content-language", ""),
+    ("content-length", ""),
+    ("content-location", ""),
+    ("content-range", ""),
+    ("content-type", ""),
+    ("cookie", ""),
+    ("date", ""),
+    ("etag", ""),
+    ("expect", ""),
+    ("expires", ""),
+    ("from", ""),
+    ("host", ""),
+    ("if-match", ""),
+    ("if-modified-since", ""),
+    ("if-none-match", ""),
+    ("if-range", ""),
+    ("if-unmodified-since", ""),
+    ("last-modified", ""),
+    ("link", ""),
+    ("location", ""),
+    ("max-forwards", ""),
+    ("proxy-authenticate", ""),
+    ("proxy-authorization", ""),
+    ("range", ""),
+    ("referer", ""),
+    ("refresh", ""),
+    ("retry-after", ""),
+    ("server", ""),
+    ("set-cookie", ""),
+    ("strict-transport-security", ""),
+    ("transfer-encoding", ""),
+    ("user-agent", ""),
+    ("vary", ""),
+    ("via", ""),
+    ("www-authenticate", ""),
+];
+
+// overhead of an entry, RFC 7541 4.1
+pub(crate) const ENTRY_OVERHEAD: usize = 32;
+
+pub(crate) fn entry_size(name: &[u8], value: &[u8]) -> usize {
+    name.len() + value.len() + ENTRY_OVERHEAD
+}
+
+/* Match in a table, Name when only the name is in it. Indices start at
+ * 1 for HPACK and 0 for QPACK, see the callers.
+ */
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum TableMatch {
+    Full(usize),
+    Name(usize),
+}
+
+// best match in a static table, 0 based
+pub(crate) fn find_static(
+    table: &[(&str, &str)],
+    name: &[u8],
+    value: &[u8],
+) -> Option<TableMatch> {
+    let mut found = None;
+    for (index, (entry_name, entry_value)) in table.iter().enumerate() {
+        if entry_name.as_bytes() != name {
+            continue;
+        }
+        if entry_value.as_bytes() == value {
+            return Some(TableMatch::Full(index));
+        }
+        found.get_or_insert(TableMatch::Name(index));
+    }
+    found
+}
+
+/* Dynamic table of HPACK, the newest entry first. Entries are evicted from
+ * the end until the size is at most the maximum size.
+ *
+ * QPACK addresses entries by absolute index, the number of entries
+ * inserted before them, see RFC 9204 3.2.4.
+ */
+#[derive(Clone, Debug, Default)]
+pub(crate) struct DynamicTable {
+    entries: VecDeque<(Bytes, Bytes)>,
+    size: usize,
+    max_size: usize,
+    inserted: usize,
+}
+
+impl DynamicTable {
+    pub(crate) fn new(max_size: usize) -> Self {
+        DynamicTable {
+            entries: VecDeque::new(),
+            size: 0,
+            max_size,
+            inserted: 0,
+        }
+    }
+
+    pub(crate) fn size(&self) -> usize {
+        self.size
+    }
+
+    pub(crate) fn max_size(&self) -> usize {
+        self.max_size
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    // total number of inserts, evicted entries included
+    pub(crate) fn insert_count(&self) -> usize {
+        self.inserted
+    }
+
+    // 0 is the newest entry
+    pub(crate) fn get(&self, index: usize) -> Option<&(Bytes, Bytes)> {
+        self.entries.get(index)
+    }
+
+    pub(crate) fn get_absolute(
+        &self,
+        index: usize,
+    ) -> Option<&(Bytes, Bytes)> {
+        index
+            .checked_add(1)
+            .and_then(|offset| self.inserted.checked_sub(offset))
+            .and_then(|relative| self.entries.get(relative))
+    }
+
+    pub(crate) fn set_max_size(&mut self, max_size: usize) {
+        self.max_size = max_size;
+        self.evict(0);
+    }
+
+    // an entry larger than the table empties it, RFC 7541 4.4
+    pub(crate) fn insert(&mut self, name: Bytes, value: Bytes) {
+        let size = entry_size(&name, &value);
+        self.evict(size);
+        if size <= self.max_size {
+            self.size += size;
+            self.entries.push_front((name, value));
+            self.inserted += 1;
+        }
+    }
+
+    /* Number of entries evicted, oldest first, for an entry of size to fit
+     * in a table of max_size. None when it can not fit.
+     */
+    pub(crate) fn evictions(
+        &self,
+        max_size: usize,
+        size: usize,
+    ) -> Option<usize> {
+        let available = max_size.checked_sub(size)?;
+        let mut kept = self.size;
+        let mut count = 0;
+        for (name, value) in self.entries.iter().rev() {
+            if kept <= available {
+                break;
+            }
+            kept -= entry_size(name, value);
+            count += 1;
+        }
+        Some(count)
+    }
+
+    // evict until an entry of size fits
+    fn evict(&mut self, size: usize) {
+        while self.size + size > self.max_size {
+            let Some((name, value)) = self.entries.pop_back() else {
+                break;
+            };
+            self.size -= entry_size(&name, &value);
+        }
+    }
+
+    // best match, 0 is the newest entry
+    pub(crate) fn find(
+        &self,
+        name: &[u8],
+        value: &[u8],
+    ) -> Option<TableMatch> {
+        let mut found = None;
+        for (index, (entry_name, entry_value)) in
+            self.entries.iter().enumerate()
+        {
+            if entry_name != name {
+                continue;
+            }
+            if entry_value == value {
+                return Some(TableMatch::Full(index));
+            }
+            found.get_or_insert(TableMatch::Name(index));
+        }
+        found
+    }
+
+    #[cfg(test)]
+    pub(crate) fn entries(&self) -> impl Iterator<Item = (&[u8], &[u8])> {
+        self.entries
+            .iter()
+            .map(|(name, value)| (name.as_ref(), value.as_ref()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_static() {
+        assert_eq!(
+            find_static(&STATIC_TABLE, b":method", b"POST"),
+            Some(TableMatch::Full(2))
+        );
+        assert_eq!(
+            find_static(&STATIC_TABLE, b":status", b"418"),
+            Some(TableMatch::Name(7))
+        );
+        assert_eq!(find_static(&STATIC_TABLE, b"x-custom", b""), None);
+    }
+
+    #[test]
+    fn test_dynamic_table_evict() {
+        let mut table = DynamicTable::new(100);
+        table.insert(Bytes::from("aaaa"), Bytes::from("1111"));
+        table.insert(Bytes::from("bbbb"), Bytes::from("2222"));
+        assert_eq!(table.size(), 80);
+        table.insert(Bytes::from("cccc"), Bytes::from("3333"));
+        assert_eq!(table.entries().count(), 2);
+        assert_eq!(table.get(0).unwrap().0, "cccc");
+        assert_eq!(table.get(1).unwrap().0, "bbbb");
+        assert_eq!(table.find(b"bbbb", b"0000"), Some(TableMatch::Name(1)));
+        table.set_max_size(40);
+        assert_eq!(table.entries().count(), 1);
+        // larger than the table
+        table.insert(Bytes::from("dddd"), Bytes::from("44444"));
+        assert_eq!(table.entries().count(), 0);
+        assert_eq!(table.size(), 0);
+    }
+
+    #[test]
+    fn test_dynamic_table_get_absolute() {
+        let mut table = DynamicTable::new(100);
+        table.insert(Bytes::from("aaaa"), Bytes::from("1111"));
+        table.insert(Bytes::from("bbbb"), Bytes::from("2222"));
+        assert_eq!(table.get_absolute(0).unwrap().0, "aaaa");
+        assert_eq!(table.get_absolute(1).unwrap().0, "bbbb");
+        assert!(table.get_absolute(2).is_none());
+        assert!(table.get_absolute(usize::MAX).is_none());
+    }
+}
@@ -2,12 +2,18 @@ pub mod abnf;
 pub mod body_headers;
 pub mod bytes_str;
 pub mod const_headers;
+pub mod header_name;
+pub mod hpack;
 pub mod message_head;
 pub mod method;
+pub mod qpack;
 pub mod status;
+pub mod structured_field;
+pub mod typed_header;
 pub mod uri;
 pub mod version;
 
+pub use header_name::HeaderName;
 pub use method::Method;
 pub use status::StatusCode;
 pub use uri::Uri;
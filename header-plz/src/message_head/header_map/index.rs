@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+use std::hash::{BuildHasherDefault, Hasher};
+use std::ops::Range;
+
+use crate::const_headers::fnv1a;
+use crate::message_head::header_map::Hmap;
+
+const NONE: usize = usize::MAX;
+
+/* Positions of the entries of a header map by the hash of their lowercase
+ * key, ie. HeaderName::hash_code. Each hash maps to its first and last
+ * position, and next links a position to the following one with the same
+ * hash, so building the index does not allocate per name.
+ *
+ * Names with the same hash share a chain, the key of every candidate is
+ * compared. Chains are in order, cleared entries are not indexed.
+ */
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct HeaderIndex {
+    chains: HashMap<u64, (usize, usize), BuildHasherDefault<NameHasher>>,
+    next: Vec<usize>,
+}
+
+impl HeaderIndex {
+    pub(crate) fn build<T>(entries: &[T]) -> Self
+    where
+        T: Hmap,
+    {
+        let mut index = HeaderIndex {
+            chains: HashMap::with_capacity_and_hasher(
+                entries.len(),
+                Default::default(),
+            ),
+            next: Vec::with_capacity(entries.len()),
+        };
+        for (pos, entry) in entries.iter().enumerate() {
+            index.push(entry.key_as_ref(), pos);
+        }
+        index
+    }
+
+    // pos is after every indexed position
+    pub(crate) fn push(&mut self, key: &[u8], pos: usize) {
+        if self.next.len() <= pos {
+            self.next.resize(pos + 1, NONE);
+        }
+        if key.is_empty() {
+            return;
+        }
+        let hash = fnv1a(key);
+        match self.chains.get_mut(&hash) {
+            Some((_, last)) => {
+                self.next[*last] = pos;
+                *last = pos;
+            }
+            None => {
+                self.chains.insert(hash, (pos, pos));
+            }
+        }
+    }
+
+    pub(crate) fn insert(&mut self, key: &[u8], pos: usize) {
+        if key.is_empty() {
+            return;
+        }
+        let hash = fnv1a(key);
+        let Some((first, last)) = self.chains.get_mut(&hash) else {
+            self.chains.insert(hash, (pos, pos));
+            return;
+        };
+        if pos < *first {
+            self.next[pos] = *first;
+            *first = pos;
+            return;
+        }
+        let mut prev = *first;
+        while self.next[prev] != NONE && self.next[prev] < pos {
+            prev = self.next[prev];
+        }
+        if prev == pos || self.next[prev] == pos {
+            return;
+        }
+        self.next[pos] = self.next[prev];
+        self.next[prev] = pos;
+        if *last == prev {
+            *last = pos;
+        }
+    }
+
+    pub(crate) fn remove(&mut self, key: &[u8], pos: usize) {
+        let hash = fnv1a(key);
+        let Some((first, last)) = self.chains.get_mut(&hash) else {
+            return;
+        };
+        if *first == pos {
+            if *last == pos {
+                self.chains.remove(&hash);
+            } else {
+                *first = self.next[pos];
+            }
+            self.next[pos] = NONE;
+            return;
+        }
+        let mut prev = *first;
+        while self.next[prev] != NONE && self.next[prev] != pos {
+            prev = self.next[prev];
+        }
+        if self.next[prev] == pos {
+            self.next[prev] = self.next[pos];
+            self.next[pos] = NONE;
+            if *last == pos {
+                *last = prev;
+            }
+        }
+    }
+
+    pub(crate) fn candidates(&self, key: &[u8]) -> Candidates<'_> {
+        self.candidates_of_hash(fnv1a(key))
+    }
+
+    // hash is HeaderName::hash_code, the key is not hashed again
+    pub(crate) fn candidates_of_hash(&self, hash: u64) -> Candidates<'_> {
+        let pos = self.chains.get(&hash).map_or(NONE, |chain| chain.0);
+        Candidates::Indexed {
+            next: &self.next,
+            pos,
+        }
+    }
+}
+
+// positions to compare with a key, all of them without an index
+pub(crate) enum Candidates<'a> {
+    Indexed {
+        next: &'a [usize],
+        pos: usize,
+    },
+    Scan(Range<usize>),
+}
+
+impl Iterator for Candidates<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        match self {
+            Candidates::Indexed {
+                next,
+                pos,
+            } => {
+                let current = *pos;
+                if current == NONE {
+                    return None;
+                }
+                *pos = next[current];
+                Some(current)
+            }
+            Candidates::Scan(range) => range.next(),
+        }
+    }
+}
+
+// keys are already FNV-1a hashes
+#[derive(Default)]
+pub(crate) struct NameHasher(u64);
+
+impl Hasher for NameHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 << 8) | u64::from(byte);
+        }
+    }
+
+    fn write_u64(&mut self, hash: u64) {
+        self.0 = hash;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn positions(index: &HeaderIndex, key: &[u8]) -> Vec<usize> {
+        index.candidates(key).collect()
+    }
+
+    #[test]
+    fn test_header_index_chain() {
+        let mut index = HeaderIndex::default();
+        for (pos, key) in [b"a", b"b", b"a", b"c", b"a"].iter().enumerate() {
+            index.push(*key, pos);
+        }
+        assert_eq!(positions(&index, b"A"), [0, 2, 4]);
+        index.remove(b"a", 2);
+        assert_eq!(positions(&index, b"a"), [0, 4]);
+        index.remove(b"a", 4);
+        index.remove(b"a", 0);
+        assert_eq!(positions(&index, b"a"), []);
+        index.insert(b"a", 3);
+        // key of 1 changed from b
+        index.remove(b"b", 1);
+        index.insert(b"a", 1);
+        index.insert(b"a", 4);
+        index.insert(b"a", 2);
+        index.insert(b"a", 2);
+        assert_eq!(positions(&index, b"a"), [1, 2, 3, 4]);
+        assert_eq!(positions(&index, b"b"), []);
+        assert_eq!(positions(&index, b"missing"), []);
+    }
+}
@@ -3,18 +3,26 @@ use bytes::{Buf, buf::Chain};
 use one::OneHeader;
 use std::str::{self};
 use two::Header;
+mod index;
 pub mod one;
 pub mod two;
 
 use bytes::BytesMut;
 
 use crate::abnf::CRLF;
+use crate::header_name::HeaderName;
+use index::{Candidates, HeaderIndex};
 
 pub trait Hmap {
     fn key_as_ref(&self) -> &[u8];
 
     fn value_as_ref(&self) -> &[u8];
 
+    // interned key
+    fn name(&self) -> HeaderName {
+        HeaderName::from_bytes(self.key_as_ref())
+    }
+
     fn change_key(&mut self, key: &[u8]);
 
     fn change_value(&mut self, value: &[u8]);
@@ -33,6 +41,9 @@ pub trait HeaderStr {
 }
 
 pub trait HeaderVersion {
+    // version without an instance, the default of Version unless set
+    const VERSION: Version = Version::H11;
+
     fn version(&self) -> Version;
 
     fn is_one_one(&self) -> bool;
@@ -43,11 +54,31 @@ pub trait HeaderVersion {
 pub type OneHeaderMap = HMap<OneHeader>;
 pub type HeaderMap = HMap<Header>;
 
-#[derive(Clone, Eq, Debug, PartialEq)]
+/* Field lines in order, duplicates and cleared entries included. With an
+ * index, lookups by key compare only the entries with the same name
+ * instead of every entry. Lookups by HeaderName, eg. value_of_name, use
+ * its precomputed hash instead of hashing the key.
+ *
+ * Mutable access to the entries, eg. iter_mut, drops the index as keys may
+ * change. It is rebuilt on the next change made through the map, lookups
+ * scan the entries until then.
+ */
+#[derive(Clone, Debug)]
 pub struct HMap<T> {
     entries: Vec<T>,
+    index: Option<HeaderIndex>,
+    indexed: bool,
 }
 
+// index is not compared
+impl<T: PartialEq> PartialEq for HMap<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.entries == other.entries
+    }
+}
+
+impl<T: Eq> Eq for HMap<T> {}
+
 impl Default for HeaderMap {
     fn default() -> Self {
         Self::new()
@@ -65,8 +96,14 @@ where
     T: Hmap,
 {
     pub fn new() -> Self {
+        HMap::from_entries(Vec::new())
+    }
+
+    fn from_entries(entries: Vec<T>) -> Self {
         HMap {
-            entries: Vec::new(),
+            entries,
+            index: None,
+            indexed: false,
         }
     }
 
@@ -74,14 +111,98 @@ where
     where
         T: From<(K, V)>,
     {
-        self.entries.push(T::from((key, value)));
+        self.push(T::from((key, value)));
     }
 
     pub fn extend<I>(&mut self, to_add: I)
     where
         I: IntoIterator<Item = T>,
     {
-        self.entries.extend(to_add);
+        for entry in to_add {
+            self.push(entry);
+        }
+    }
+
+    fn push(&mut self, entry: T) {
+        self.sync_index();
+        if let Some(index) = &mut self.index {
+            index.push(entry.key_as_ref(), self.entries.len());
+        }
+        self.entries.push(entry);
+    }
+
+    // ---------- Index
+    pub fn build_index(&mut self) {
+        self.index = Some(HeaderIndex::build(&self.entries));
+        self.indexed = true;
+    }
+
+    pub fn with_index(mut self) -> Self {
+        self.build_index();
+        self
+    }
+
+    pub fn drop_index(&mut self) {
+        self.index = None;
+        self.indexed = false;
+    }
+
+    // false while dropped by mutable access to the entries
+    pub fn is_indexed(&self) -> bool {
+        self.index.is_some()
+    }
+
+    // rebuild an index dropped by mutable access to the entries
+    fn sync_index(&mut self) {
+        if self.indexed && self.index.is_none() {
+            self.index = Some(HeaderIndex::build(&self.entries));
+        }
+    }
+
+    fn entries_mut(&mut self) -> &mut [T] {
+        self.index = None;
+        &mut self.entries
+    }
+
+    fn candidates(&self, key: &[u8]) -> Candidates<'_> {
+        match &self.index {
+            Some(index) => index.candidates(key),
+            None => Candidates::Scan(0..self.entries.len()),
+        }
+    }
+
+    fn candidates_of_name(&self, name: &HeaderName) -> Candidates<'_> {
+        match &self.index {
+            Some(index) => index.candidates_of_hash(name.hash_code()),
+            None => Candidates::Scan(0..self.entries.len()),
+        }
+    }
+
+    fn positions_of_key(&self, key: &[u8]) -> Vec<usize> {
+        self.candidates(key)
+            .filter(|&pos| {
+                self.entries[pos].key_as_ref().eq_ignore_ascii_case(key)
+            })
+            .collect()
+    }
+
+    fn change_key_at(&mut self, pos: usize, key: &[u8]) {
+        self.sync_index();
+        if let Some(index) = &mut self.index {
+            index.remove(self.entries[pos].key_as_ref(), pos);
+        }
+        self.entries[pos].change_key(key);
+        if let Some(index) = &mut self.index {
+            index.insert(self.entries[pos].key_as_ref(), pos);
+        }
+    }
+
+    fn clear_at(&mut self, pos: usize) {
+        self.sync_index();
+        if let Some(index) = &mut self.index {
+            index.remove(self.entries[pos].key_as_ref(), pos);
+        }
+        self.entries[pos].clear();
     }
 
     pub fn find_position_all<F>(
@@ -91,7 +212,7 @@ where
     where
         F: FnMut(&T) -> bool + 'a,
     {
-        self.entries.iter_mut().filter_map(move |h| f(h).then_some(h))
+        self.entries_mut().iter_mut().filter_map(move |h| f(h).then_some(h))
     }
 
     pub fn find_position<F>(&self, f: F) -> Option<usize>
@@ -111,11 +232,7 @@ where
         T: From<K>,
     {
         let to_find: T = to_find_hdr.into();
-        self.find_position_all(move |h| {
-            h.key_as_ref().eq_ignore_ascii_case(to_find.key_as_ref())
-                && h.value_as_ref()
-                    .eq_ignore_ascii_case(to_find.value_as_ref())
-        })
+        self.find_position_all(move |h| same_header(h, &to_find))
     }
 
     pub fn header_position<K>(&self, to_find_hdr: K) -> Option<usize>
@@ -123,11 +240,14 @@ where
         T: From<K>,
     {
         let to_find: T = to_find_hdr.into();
-        self.find_position(|h| {
-            h.key_as_ref().eq_ignore_ascii_case(to_find.key_as_ref())
-                && h.value_as_ref()
-                    .eq_ignore_ascii_case(to_find.value_as_ref())
-        })
+        self.candidates(to_find.key_as_ref())
+            .find(|&pos| same_header(&self.entries[pos], &to_find))
+    }
+
+    fn header_positions(&self, to_find: &T) -> Vec<usize> {
+        self.candidates(to_find.key_as_ref())
+            .filter(|&pos| same_header(&self.entries[pos], to_find))
+            .collect()
     }
 
     pub fn has_header<H>(&self, hdr: H) -> bool
@@ -144,15 +264,13 @@ where
     where
         T: From<K>,
     {
-        let mut result = false;
         let new = T::from(new);
-
-        for h in self.header_position_all(old) {
-            result = true;
-            h.change_key(new.key_as_ref());
-            h.change_value(new.value_as_ref());
+        let positions = self.header_positions(&T::from(old));
+        for &pos in &positions {
+            self.change_key_at(pos, new.key_as_ref());
+            self.entries[pos].change_value(new.value_as_ref());
         }
-        result
+        !positions.is_empty()
     }
 
     pub fn update_header<K>(&mut self, old: K, new: K) -> bool
@@ -163,7 +281,7 @@ where
         let new_hdr = T::from(new);
         if let Some(index) = self.header_position(old) {
             result = true;
-            self.entries[index].change_key(new_hdr.key_as_ref());
+            self.change_key_at(index, new_hdr.key_as_ref());
             self.entries[index].change_value(new_hdr.value_as_ref());
         }
         result
@@ -177,7 +295,7 @@ where
         let mut result = false;
         for index in positions {
             result = true;
-            self.entries[index].clear();
+            self.clear_at(index);
         }
         result
     }
@@ -186,12 +304,11 @@ where
     where
         T: From<K>,
     {
-        let mut result = false;
-        for entries in self.header_position_all(to_remove) {
-            result = true;
-            entries.clear()
+        let positions = self.header_positions(&T::from(to_remove));
+        for &pos in &positions {
+            self.clear_at(pos);
         }
-        result
+        !positions.is_empty()
     }
 
     pub fn remove_header<K>(&mut self, to_remove: K) -> bool
@@ -201,14 +318,14 @@ where
         let mut result = false;
         if let Some(index) = self.header_position(to_remove) {
             result = true;
-            self.entries[index].clear();
+            self.clear_at(index);
         }
 
         result
     }
 
     pub fn remove_header_on_position(&mut self, pos: usize) {
-        self.entries[pos].clear();
+        self.clear_at(pos);
     }
 
     // ---------- Key
@@ -229,8 +346,9 @@ where
     where
         K: AsRef<[u8]> + 'a,
     {
-        self.find_position(|h| {
-            h.key_as_ref().eq_ignore_ascii_case(key.as_ref())
+        let key = key.as_ref();
+        self.candidates(key).find(|&pos| {
+            self.entries[pos].key_as_ref().eq_ignore_ascii_case(key)
         })
     }
 
@@ -246,10 +364,62 @@ where
     where
         K: AsRef<[u8]> + 'a,
     {
-        self.find_position(|h| {
-            h.key_as_ref().eq_ignore_ascii_case(key.as_ref())
+        self.header_key_position(key)
+            .map(|pos| self.entries[pos].value_as_ref())
+    }
+
+    // values of every field line with key, in order
+    pub fn values_of_key<K>(
+        &'a self,
+        key: K,
+    ) -> impl Iterator<Item = &'a [u8]> + 'a
+    where
+        K: AsRef<[u8]> + 'a,
+    {
+        self.header_key_positions(key)
+            .map(|pos| self.entries[pos].value_as_ref())
+    }
+
+    pub fn header_key_positions<K>(
+        &'a self,
+        key: K,
+    ) -> impl Iterator<Item = usize> + 'a
+    where
+        K: AsRef<[u8]> + 'a,
+    {
+        self.candidates(key.as_ref()).filter(move |&pos| {
+            self.entries[pos].key_as_ref().eq_ignore_ascii_case(key.as_ref())
         })
-        .map(|pos| self.entries[pos].value_as_ref())
+    }
+
+    // ---------- HeaderName
+    // same as the key lookups, the index uses the precomputed hash_code
+    pub fn name_position(&self, name: &HeaderName) -> Option<usize> {
+        self.candidates_of_name(name)
+            .find(|&pos| name.matches(self.entries[pos].key_as_ref()))
+    }
+
+    pub fn name_positions(
+        &'a self,
+        name: &'a HeaderName,
+    ) -> impl Iterator<Item = usize> + 'a {
+        self.candidates_of_name(name)
+            .filter(move |&pos| name.matches(self.entries[pos].key_as_ref()))
+    }
+
+    pub fn has_name(&self, name: &HeaderName) -> bool {
+        self.name_position(name).is_some()
+    }
+
+    pub fn value_of_name(&self, name: &HeaderName) -> Option<&[u8]> {
+        self.name_position(name).map(|pos| self.entries[pos].value_as_ref())
+    }
+
+    pub fn values_of_name(
+        &'a self,
+        name: &'a HeaderName,
+    ) -> impl Iterator<Item = &'a [u8]> + 'a {
+        self.name_positions(name).map(|pos| self.entries[pos].value_as_ref())
     }
 
     // ----- update
@@ -261,12 +431,11 @@ where
     where
         K: AsRef<[u8]>,
     {
-        let mut result = false;
-        for entry in self.header_key_position_all(old_key) {
-            result = true;
-            entry.change_key(new_key.as_ref());
+        let positions = self.positions_of_key(old_key.as_ref());
+        for &pos in &positions {
+            self.change_key_at(pos, new_key.as_ref());
         }
-        result
+        !positions.is_empty()
     }
 
     pub fn update_header_key<K>(&mut self, old_key: K, new_key: K) -> bool
@@ -276,7 +445,7 @@ where
         let mut result = false;
         if let Some(pos) = self.header_key_position(old_key) {
             result = true;
-            self.entries[pos].change_key(new_key.as_ref());
+            self.change_key_at(pos, new_key.as_ref());
         }
         result
     }
@@ -289,12 +458,11 @@ where
     where
         K: AsRef<[u8]>,
     {
-        let mut result = false;
-        for entry in self.header_key_position_all(key) {
-            result = true;
-            entry.change_value(value.as_ref());
+        let positions = self.positions_of_key(key.as_ref());
+        for &pos in &positions {
+            self.entries[pos].change_value(value.as_ref());
         }
-        result
+        !positions.is_empty()
     }
 
     pub fn update_header_value_on_key<K>(&mut self, key: K, value: K) -> bool
@@ -314,12 +482,11 @@ where
     where
         K: AsRef<[u8]>,
     {
-        let mut result = false;
-        for entry in self.header_key_position_all(key) {
-            result = true;
-            entry.clear();
+        let positions = self.positions_of_key(key.as_ref());
+        for &pos in &positions {
+            self.clear_at(pos);
         }
-        result
+        !positions.is_empty()
     }
 
     pub fn remove_header_on_key<K>(&mut self, key: K) -> bool
@@ -329,7 +496,7 @@ where
         let mut result = false;
         if let Some(pos) = self.header_key_position(key) {
             result = true;
-            self.entries[pos].clear();
+            self.clear_at(pos);
         }
         result
     }
@@ -405,6 +572,12 @@ where
     }
 }
 
+// same key and value, case-insensitive
+fn same_header<T: Hmap>(one: &T, two: &T) -> bool {
+    one.key_as_ref().eq_ignore_ascii_case(two.key_as_ref())
+        && one.value_as_ref().eq_ignore_ascii_case(two.value_as_ref())
+}
+
 impl OneHeaderMap {
     pub fn as_chain(&self) -> HmapBuf<'_> {
         HmapBuf::new(&self.entries)
@@ -483,9 +656,7 @@ impl From<BytesMut> for HMap<OneHeader> {
             let header = input.split_to(crlf_index + 2);
             entries.push(OneHeader::from(header))
         }
-        HMap {
-            entries,
-        }
+        HMap::from_entries(entries)
     }
 }
 
@@ -504,6 +675,7 @@ impl HMap<OneHeader> {
 
 impl From<HMap<OneHeader>> for HMap<Header> {
     fn from(one: HMap<OneHeader>) -> Self {
+        let indexed = one.indexed;
         let entries = one
             .entries
             .into_iter()
@@ -515,14 +687,17 @@ impl From<HMap<OneHeader>> for HMap<Header> {
                 }
             })
             .collect();
-        HMap {
-            entries,
+        let mut map = HMap::from_entries(entries);
+        if indexed {
+            map.build_index();
         }
+        map
     }
 }
 
 impl From<HMap<Header>> for HMap<OneHeader> {
     fn from(two: HMap<Header>) -> Self {
+        let indexed = two.indexed;
         let entries = two
             .entries
             .into_iter()
@@ -534,9 +709,11 @@ impl From<HMap<Header>> for HMap<OneHeader> {
                 }
             })
             .collect();
-        HMap {
-            entries,
+        let mut map = HMap::from_entries(entries);
+        if indexed {
+            map.build_index();
         }
+        map
     }
 }
 
@@ -549,11 +726,13 @@ impl<'a, T> IntoIterator for &'a HMap<T> {
     }
 }
 
+// drops the index
 impl<'a, T> IntoIterator for &'a mut HMap<T> {
     type Item = &'a mut T;
     type IntoIter = std::slice::IterMut<'a, T>;
 
     fn into_iter(self) -> Self::IntoIter {
+        self.index = None;
         self.entries.iter_mut()
     }
 }
@@ -570,6 +749,7 @@ impl<T> IntoIterator for HMap<T> {
 #[cfg(test)]
 mod tests {
     use crate::body_headers::content_encoding::ContentEncoding;
+    use crate::header_name::StandardHeader;
 
     use super::*;
     use bytes::Bytes;
@@ -922,6 +1102,31 @@ mod tests {
         assert_eq!(result, Some(verify.as_bytes()));
     }
 
+    #[test]
+    fn test_hmap_header_name_key_one() {
+        let map = build_test_one();
+        let name = HeaderName::from(StandardHeader::ContentType);
+        assert_eq!(map.header_key_position(&name), Some(2));
+        assert_eq!(map.values_of_key(&name).count(), 3);
+        let custom = HeaderName::from("X-Custom-Header");
+        assert_eq!(map.value_of_key(&custom), Some(&b"somevalue"[..]));
+        assert!(map.has_key(StandardHeader::Trailer));
+        assert_eq!(map.iter().nth(2).map(Hmap::name), Some(name));
+    }
+
+    #[test]
+    fn test_hmap_header_name_key_two() {
+        let mut map = build_test_two();
+        let name = HeaderName::from("Content-Length");
+        assert_eq!(map.header_key_positions(&name).count(), 3);
+        assert!(map.remove_header_on_key_all(&name));
+        assert!(!map.has_key(StandardHeader::ContentLength));
+        assert_eq!(
+            map.iter().nth(11).map(Hmap::name),
+            Some(HeaderName::from("x-custom-header"))
+        );
+    }
+
     #[test]
     fn test_hmap_has_key_one() {
         let map = build_test_one();
@@ -1234,6 +1439,146 @@ mod tests {
         assert_eq!(one, verify);
     }
 
+    // ----- index
+    fn assert_index<T: Hmap>(map: &HMap<T>) {
+        assert_eq!(map.index, Some(HeaderIndex::build(&map.entries)));
+    }
+
+    #[test]
+    fn test_hmap_index_lookups() {
+        let plain = build_test_one();
+        let map = build_test_one().with_index();
+        assert!(map.is_indexed());
+        assert_index(&map);
+        for key in ["content-type", "HOST", "x-custom-header", "missing"] {
+            assert_eq!(
+                map.header_key_position(key),
+                plain.header_key_position(key)
+            );
+            assert_eq!(map.value_of_key(key), plain.value_of_key(key));
+            assert!(
+                map.header_key_positions(key)
+                    .eq(plain.header_key_positions(key))
+            );
+            assert!(map.values_of_key(key).eq(plain.values_of_key(key)));
+        }
+        assert_eq!(map.header_position("content-length: 20"), Some(1));
+        assert_eq!(map.header_position("content-length: 30"), None);
+        assert!(map.has_key(StandardHeader::Trailer));
+    }
+
+    #[test]
+    fn test_hmap_index_header_name_lookups() {
+        let plain = build_test_two();
+        let map = build_test_two().with_index();
+        for key in ["content-length", "HOST", "x-custom-header", "missing"] {
+            let name = HeaderName::from(key);
+            assert_eq!(
+                map.name_position(&name),
+                plain.header_key_position(key)
+            );
+            assert_eq!(plain.name_position(&name), map.name_position(&name));
+            assert_eq!(map.has_name(&name), plain.has_key(key));
+            assert_eq!(map.value_of_name(&name), plain.value_of_key(key));
+            assert!(
+                map.name_positions(&name).eq(plain.header_key_positions(key))
+            );
+            assert!(map.values_of_name(&name).eq(plain.values_of_key(key)));
+        }
+    }
+
+    #[test]
+    fn test_hmap_index_insert_extend() {
+        let mut map = OneHeaderMap::new().with_index();
+        map.insert("Host: ", "localhost\r\n");
+        let mut other = OneHeaderMap::new();
+        other.insert("host: ", "other\r\n");
+        other.insert("Accept: ", "*/*\r\n");
+        map.extend(other);
+        assert_index(&map);
+        assert!(map.header_key_positions("HOST").eq([0, 1]));
+        assert_eq!(map.header_key_position("accept"), Some(2));
+    }
+
+    #[test]
+    fn test_hmap_index_remove() {
+        let mut map = build_test_one().with_index();
+        assert!(map.remove_header_on_key("content-length"));
+        assert!(map.header_key_positions("content-length").eq([4, 7]));
+        assert!(map.remove_header_multiple_positions([4, 0].into_iter()));
+        assert!(map.header_key_positions("content-length").eq([7]));
+        assert!(!map.has_key("host"));
+        assert!(map.remove_header_all("content-type: application/json"));
+        assert!(!map.has_key("content-type"));
+        map.remove_header_on_position(7);
+        assert!(map.remove_header_on_key_all("connection"));
+        assert!(!map.remove_header_on_key_all("connection"));
+        assert_index(&map);
+
+        let mut plain = build_test_one();
+        plain.remove_header_on_key("content-length");
+        plain.remove_header_multiple_positions([4, 0].into_iter());
+        plain.remove_header_all("content-type: application/json");
+        plain.remove_header_on_position(7);
+        plain.remove_header_on_key_all("connection");
+        assert_eq!(map, plain);
+        assert_eq!(map.into_bytes(), plain.into_bytes());
+    }
+
+    #[test]
+    fn test_hmap_index_update_key() {
+        let mut map = build_test_two().with_index();
+        assert!(map.update_header_key("content-length", "x-length"));
+        assert!(map.update_header_key_all("content-type", "x-type"));
+        assert!(map.update_header("host: localhost", "x-length: 1"));
+        assert!(map.update_header_all("x-length: 20", "accept: 20"));
+        assert_index(&map);
+        assert!(map.header_key_positions("x-length").eq([0]));
+        assert!(map.header_key_positions("accept").eq([1]));
+        assert!(map.header_key_positions("x-type").eq([2, 5, 8]));
+        assert!(map.header_key_positions("content-length").eq([4, 7]));
+        assert!(map.update_header_value_on_key_all("x-type", "text/html"));
+        assert_eq!(map.value_of_key("X-Type"), Some(&b"text/html"[..]));
+        assert_index(&map);
+    }
+
+    #[test]
+    fn test_hmap_index_dropped_by_iter_mut() {
+        let mut map = build_test_one().with_index();
+        for entry in map.iter_mut() {
+            if entry.key_as_ref() == b"Host" {
+                entry.change_key(b"X-Host");
+            }
+        }
+        assert!(!map.is_indexed());
+        assert_eq!(map.header_key_position("x-host"), Some(0));
+        assert!(!map.has_key("host"));
+        map.insert("Host: ", "example.com\r\n");
+        assert!(map.is_indexed());
+        assert_index(&map);
+        assert_eq!(map.header_key_position("host"), Some(12));
+
+        assert!(map.update_header_value_on_key_all("content-length", "1"));
+        assert!(map.header_key_position_all("trailer").count() == 1);
+        assert!(!map.is_indexed());
+        map.remove_header_on_key("x-host");
+        assert_index(&map);
+        map.drop_index();
+        assert!(!map.is_indexed());
+        map.remove_header_on_key("host");
+        assert!(!map.is_indexed());
+    }
+
+    #[test]
+    fn test_hmap_index_conversion() {
+        let two = HeaderMap::from(build_test_one().with_index());
+        assert_index(&two);
+        assert_eq!(two, build_test_two());
+        let one = OneHeaderMap::from(two);
+        assert_index(&one);
+        assert!(!OneHeaderMap::from(build_test_two()).is_indexed());
+    }
+
     #[test]
     fn test_empty() {
         let map = HMap::<Header>::new();
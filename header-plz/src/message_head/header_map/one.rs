@@ -63,6 +63,8 @@ impl HeaderStr for OneHeader {
 }
 
 impl HeaderVersion for OneHeader {
+    const VERSION: Version = Version::H11;
+
     fn version(&self) -> crate::version::Version {
         Version::H11
     }
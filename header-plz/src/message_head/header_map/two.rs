@@ -1,5 +1,6 @@
 use crate::{
     abnf::*,
+    header_name::HeaderName,
     message_head::header_map::{
         HeaderStr, HeaderVersion, Hmap, one::OneHeader,
     },
@@ -43,6 +44,8 @@ impl HeaderStr for Header {
 }
 
 impl HeaderVersion for Header {
+    const VERSION: Version = Version::H2;
+
     fn version(&self) -> crate::version::Version {
         Version::H2
     }
@@ -65,6 +68,10 @@ impl Hmap for Header {
         &self.value
     }
 
+    fn name(&self) -> HeaderName {
+        HeaderName::from(self.key.clone())
+    }
+
     fn change_key(&mut self, key: &[u8]) {
         self.key = Bytes::from(key.to_owned());
     }
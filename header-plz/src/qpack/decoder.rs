@@ -0,0 +1,553 @@
+use std::collections::HashMap;
+
+use bytes::{Bytes, BytesMut};
+
+use crate::hpack::primitive::{decode_int, decode_string};
+use crate::hpack::pseudo::{
+    check_trailers, request_line, response_line, split_fields,
+};
+use crate::hpack::table::{DynamicTable, entry_size};
+use crate::hpack::{Field, HpackError};
+use crate::qpack::instruction::{DecoderInstruction, EncoderInstruction};
+use crate::qpack::static_table::STATIC_TABLE;
+use crate::qpack::{QpackError, decode_prefix, max_entries};
+use crate::{HeaderMap, RequestLine, ResponseLine};
+
+/* QPACK decoder of field sections, RFC 9204. A section referencing entries
+ * not yet received blocks its stream, the caller decodes it again once the
+ * stream is no longer blocked. Insert Count Increments are sent with the
+ * next instructions taken, acknowledgments when a section is decoded.
+ */
+#[derive(Debug)]
+pub struct Decoder {
+    table: DynamicTable,
+    // SETTINGS_QPACK_MAX_TABLE_CAPACITY sent to the peer
+    max_table_capacity: usize,
+    // SETTINGS_QPACK_BLOCKED_STREAMS sent to the peer
+    max_blocked_streams: usize,
+    known_received_count: usize,
+    // blocked streams and their required insert count
+    blocked: HashMap<u64, usize>,
+    instructions: BytesMut,
+}
+
+impl Default for Decoder {
+    fn default() -> Self {
+        Decoder::new(0, 0)
+    }
+}
+
+impl Decoder {
+    pub fn new(max_table_capacity: usize, max_blocked_streams: usize) -> Self {
+        Decoder {
+            table: DynamicTable::new(0),
+            max_table_capacity,
+            max_blocked_streams,
+            known_received_count: 0,
+            blocked: HashMap::new(),
+            instructions: BytesMut::new(),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.table.max_size()
+    }
+
+    pub fn table_size(&self) -> usize {
+        self.table.size()
+    }
+
+    pub fn insert_count(&self) -> usize {
+        self.table.insert_count()
+    }
+
+    pub fn is_blocked(&self, stream_id: u64) -> bool {
+        self.blocked.contains_key(&stream_id)
+    }
+
+    pub fn blocked_streams(&self) -> usize {
+        self.blocked.len()
+    }
+
+    // decoder stream data to send
+    pub fn take_instructions(&mut self) -> BytesMut {
+        let increment = self.table.insert_count() - self.known_received_count;
+        if increment > 0 {
+            DecoderInstruction::InsertCountIncrement(increment)
+                .encode(&mut self.instructions);
+            self.known_received_count = self.table.insert_count();
+        }
+        self.instructions.split()
+    }
+
+    // stream reset or abandoned before its sections were decoded
+    pub fn cancel_stream(&mut self, stream_id: u64) {
+        self.blocked.remove(&stream_id);
+        // not needed without a dynamic table, RFC 9204 4.4.2
+        if self.max_table_capacity > 0 {
+            DecoderInstruction::StreamCancellation(stream_id)
+                .encode(&mut self.instructions);
+        }
+    }
+
+    /* Reads the instructions of the encoder stream, returns the number of
+     * bytes read. A truncated instruction is left for the next call.
+     *
+     * Error:
+     *      Capacity        when above the maximum capacity.
+     *      EntryTooLarge   when an entry is larger than the capacity.
+     *      StaticIndex     when a name is not in the static table.
+     *      DynamicIndex    when a name or a duplicate is not in the dynamic
+     *                      table.
+     *      Hpack           when a literal name has uppercase, and errors of
+     *                      the integer and string representations.
+     */
+    pub fn on_encoder_stream(
+        &mut self,
+        buf: &[u8],
+    ) -> Result<usize, QpackError> {
+        let mut rest = buf;
+        loop {
+            match EncoderInstruction::decode(&mut rest) {
+                Ok(instruction) => self.on_instruction(instruction)?,
+                Err(QpackError::Truncated) => break,
+                Err(e) => return Err(e),
+            }
+        }
+        let insert_count = self.table.insert_count();
+        self.blocked.retain(|_, required| *required > insert_count);
+        Ok(buf.len() - rest.len())
+    }
+
+    fn on_instruction(
+        &mut self,
+        instruction: EncoderInstruction,
+    ) -> Result<(), QpackError> {
+        let (name, value) = match instruction {
+            EncoderInstruction::SetCapacity(capacity) => {
+                if capacity > self.max_table_capacity {
+                    return Err(QpackError::Capacity(capacity));
+                }
+                self.table.set_max_size(capacity);
+                return Ok(());
+            }
+            EncoderInstruction::InsertWithNameRef {
+                is_static: true,
+                index,
+                value,
+            } => (static_entry(index)?.0, value),
+            EncoderInstruction::InsertWithNameRef {
+                is_static: false,
+                index,
+                value,
+            } => (self.relative_entry(index)?.0, value),
+            EncoderInstruction::InsertWithLiteralName {
+                name,
+                value,
+            } => (check_name(name)?, value),
+            EncoderInstruction::Duplicate(index) => {
+                self.relative_entry(index)?
+            }
+        };
+        let size = entry_size(&name, &value);
+        if size > self.table.max_size() {
+            return Err(QpackError::EntryTooLarge(size));
+        }
+        self.table.insert(name, value);
+        Ok(())
+    }
+
+    // relative to the insert count, RFC 9204 3.2.5
+    fn relative_entry(
+        &self,
+        index: usize,
+    ) -> Result<(Bytes, Bytes), QpackError> {
+        index
+            .checked_add(1)
+            .and_then(|offset| self.table.insert_count().checked_sub(offset))
+            .and_then(|index| self.table.get_absolute(index))
+            .cloned()
+            .ok_or(QpackError::DynamicIndex(index))
+    }
+
+    pub fn decode_request(
+        &mut self,
+        stream_id: u64,
+        block: &[u8],
+    ) -> Result<Option<(RequestLine, HeaderMap)>, QpackError> {
+        let Some(fields) = self.decode_fields(stream_id, block)? else {
+            return Ok(None);
+        };
+        let (pseudo, map) = split_fields(fields)?;
+        Ok(Some((request_line(pseudo)?, map)))
+    }
+
+    pub fn decode_response(
+        &mut self,
+        stream_id: u64,
+        block: &[u8],
+    ) -> Result<Option<(ResponseLine, HeaderMap)>, QpackError> {
+        let Some(fields) = self.decode_fields(stream_id, block)? else {
+            return Ok(None);
+        };
+        let (pseudo, map) = split_fields(fields)?;
+        Ok(Some((response_line(pseudo)?, map)))
+    }
+
+    pub fn decode_trailers(
+        &mut self,
+        stream_id: u64,
+        block: &[u8],
+    ) -> Result<Option<HeaderMap>, QpackError> {
+        let Some(fields) = self.decode_fields(stream_id, block)? else {
+            return Ok(None);
+        };
+        let (pseudo, map) = split_fields(fields)?;
+        check_trailers(pseudo)?;
+        Ok(Some(map))
+    }
+
+    /* Fields of the section in order, pseudo-headers included. None when
+     * the stream is blocked on the encoder stream.
+     *
+     * Error:
+     *      RequiredInsertCount     when the count is invalid or is not the
+     *                              largest reference plus one.
+     *      Base                    when the base is below 0.
+     *      BlockedStreams          when the stream would block one stream
+     *                              too many.
+     *      StaticIndex             when an index is not in the static table.
+     *      DynamicIndex            when an index is not in the dynamic table
+     *                              or not below the required insert count.
+     *      Hpack                   when a literal name has uppercase, and
+     *                              errors of the integer and string
+     *                              representations.
+     */
+    pub fn decode_fields(
+        &mut self,
+        stream_id: u64,
+        block: &[u8],
+    ) -> Result<Option<Vec<Field>>, QpackError> {
+        let mut buf = block;
+        let (required, base) = decode_prefix(
+            &mut buf,
+            max_entries(self.max_table_capacity),
+            self.table.insert_count(),
+        )?;
+        if required > self.table.insert_count() {
+            if !self.is_blocked(stream_id)
+                && self.blocked.len() >= self.max_blocked_streams
+            {
+                return Err(QpackError::BlockedStreams);
+            }
+            self.blocked.insert(stream_id, required);
+            return Ok(None);
+        }
+        self.blocked.remove(&stream_id);
+        let mut section = Section {
+            table: &self.table,
+            required,
+            base,
+            largest: None,
+        };
+        let mut fields = Vec::new();
+        while let Some(&first) = buf.first() {
+            let field = if first & 0x80 != 0 {
+                // indexed field line
+                let index = decode_int(&mut buf, 6)?;
+                let (name, value) = if first & 0x40 != 0 {
+                    static_entry(index)?
+                } else {
+                    section.pre_base(index)?
+                };
+                Field::new(name, value)
+            } else if first & 0x40 != 0 {
+                // literal field line with name reference
+                let index = decode_int(&mut buf, 4)?;
+                let name = if first & 0x10 != 0 {
+                    static_entry(index)?.0
+                } else {
+                    section.pre_base(index)?.0
+                };
+                literal(name, decode_string(&mut buf, 7)?, first & 0x20)
+            } else if first & 0x20 != 0 {
+                // literal field line with literal name
+                let name = check_name(decode_string(&mut buf, 3)?)?;
+                literal(name, decode_string(&mut buf, 7)?, first & 0x10)
+            } else if first & 0x10 != 0 {
+                // indexed field line with post-base index
+                let (name, value) =
+                    section.post_base(decode_int(&mut buf, 4)?)?;
+                Field::new(name, value)
+            } else {
+                // literal field line with post-base name reference
+                let name = section.post_base(decode_int(&mut buf, 3)?)?.0;
+                literal(name, decode_string(&mut buf, 7)?, first & 0x08)
+            };
+            fields.push(field);
+        }
+        if section.largest.map_or(0, |index| index + 1) != required {
+            return Err(QpackError::RequiredInsertCount(required));
+        }
+        if required > 0 {
+            DecoderInstruction::SectionAck(stream_id)
+                .encode(&mut self.instructions);
+            self.known_received_count =
+                self.known_received_count.max(required);
+        }
+        Ok(Some(fields))
+    }
+}
+
+// dynamic table as seen by a section
+struct Section<'a> {
+    table: &'a DynamicTable,
+    required: usize,
+    base: usize,
+    // largest absolute index referenced
+    largest: Option<usize>,
+}
+
+impl Section<'_> {
+    fn pre_base(
+        &mut self,
+        index: usize,
+    ) -> Result<(Bytes, Bytes), QpackError> {
+        let absolute = index
+            .checked_add(1)
+            .and_then(|offset| self.base.checked_sub(offset))
+            .ok_or(QpackError::DynamicIndex(index))?;
+        self.get(absolute)
+    }
+
+    fn post_base(
+        &mut self,
+        index: usize,
+    ) -> Result<(Bytes, Bytes), QpackError> {
+        let absolute = self
+            .base
+            .checked_add(index)
+            .ok_or(QpackError::DynamicIndex(index))?;
+        self.get(absolute)
+    }
+
+    fn get(&mut self, absolute: usize) -> Result<(Bytes, Bytes), QpackError> {
+        let entry = Some(absolute)
+            .filter(|absolute| *absolute < self.required)
+            .and_then(|absolute| self.table.get_absolute(absolute))
+            .cloned()
+            .ok_or(QpackError::DynamicIndex(absolute))?;
+        self.largest = self.largest.max(Some(absolute));
+        Ok(entry)
+    }
+}
+
+fn static_entry(index: usize) -> Result<(Bytes, Bytes), QpackError> {
+    let (name, value) =
+        STATIC_TABLE.get(index).ok_or(QpackError::StaticIndex(index))?;
+    Ok((
+        Bytes::from_static(name.as_bytes()),
+        Bytes::from_static(value.as_bytes()),
+    ))
+}
+
+fn check_name(name: Bytes) -> Result<Bytes, QpackError> {
+    if name.iter().any(u8::is_ascii_uppercase) {
+        return Err(HpackError::UppercaseName.into());
+    }
+    Ok(name)
+}
+
+// the N bit, the field is never indexed by intermediaries
+fn literal(name: Bytes, value: Bytes, never_indexed: u8) -> Field {
+    if never_indexed != 0 {
+        Field::never_indexed(name, value)
+    } else {
+        Field::new(name, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hpack::primitive::encode_int;
+
+    fn with_capacity(capacity: usize, max_blocked_streams: usize) -> Decoder {
+        let mut decoder = Decoder::new(capacity, max_blocked_streams);
+        let mut instructions = BytesMut::new();
+        EncoderInstruction::SetCapacity(capacity)
+            .encode(false, &mut instructions);
+        decoder.on_encoder_stream(&instructions).unwrap();
+        decoder
+    }
+
+    fn insert(decoder: &mut Decoder, name: &'static str, value: &'static str) {
+        let mut instructions = BytesMut::new();
+        EncoderInstruction::InsertWithLiteralName {
+            name: Bytes::from_static(name.as_bytes()),
+            value: Bytes::from_static(value.as_bytes()),
+        }
+        .encode(false, &mut instructions);
+        decoder.on_encoder_stream(&instructions).unwrap();
+    }
+
+    #[test]
+    fn test_decoder_static() {
+        let mut decoder = Decoder::default();
+        let fields = decoder.decode_fields(0, b"\x00\x00\xd1").unwrap();
+        assert_eq!(
+            fields,
+            Some(vec![Field::new(
+                Bytes::from_static(b":method"),
+                Bytes::from_static(b"GET")
+            )])
+        );
+        // without a reference to the dynamic table nothing is sent
+        assert!(decoder.take_instructions().is_empty());
+        assert_eq!(
+            decoder.decode_fields(0, b"\x00\x00\xff\x24"),
+            Err(QpackError::StaticIndex(99))
+        );
+    }
+
+    #[test]
+    fn test_decoder_never_indexed() {
+        let mut decoder = Decoder::default();
+        let fields = decoder
+            .decode_fields(0, b"\x00\x00\x7f\x45\x06secret\x31a\x01b")
+            .unwrap()
+            .unwrap();
+        let sensitive: Vec<bool> =
+            fields.iter().map(Field::is_sensitive).collect();
+        assert_eq!(sensitive, [true, true]);
+        assert_eq!(fields[0].name(), b"authorization");
+    }
+
+    #[test]
+    fn test_decoder_uppercase_name() {
+        let mut decoder = Decoder::default();
+        assert_eq!(
+            decoder.decode_fields(0, b"\x00\x00\x21A\x01b"),
+            Err(QpackError::Hpack(HpackError::UppercaseName))
+        );
+        let mut decoder = with_capacity(100, 0);
+        assert_eq!(
+            decoder.on_encoder_stream(b"\x41A\x01b"),
+            Err(QpackError::Hpack(HpackError::UppercaseName))
+        );
+    }
+
+    #[test]
+    fn test_decoder_post_base() {
+        let mut decoder = with_capacity(100, 0);
+        insert(&mut decoder, "x-one", "1");
+        insert(&mut decoder, "x-two", "2");
+        // base 0, post-base index and post-base name reference
+        let fields = decoder
+            .decode_fields(0, b"\x03\x81\x11\x00\x01b")
+            .unwrap()
+            .unwrap();
+        let pairs: Vec<(&[u8], &[u8])> =
+            fields.iter().map(|field| (field.name(), field.value())).collect();
+        assert_eq!(pairs, [(&b"x-two"[..], &b"2"[..]), (b"x-one", b"b")]);
+        assert_eq!(&decoder.take_instructions()[..], [0x80]);
+    }
+
+    #[test]
+    fn test_decoder_required_insert_count() {
+        let mut decoder = with_capacity(100, 0);
+        insert(&mut decoder, "x-one", "1");
+        insert(&mut decoder, "x-two", "2");
+        // count of 2 but only the first entry is referenced
+        assert_eq!(
+            decoder.decode_fields(0, b"\x03\x81\x10"),
+            Err(QpackError::RequiredInsertCount(2))
+        );
+        // reference at the required insert count
+        assert_eq!(
+            decoder.decode_fields(0, b"\x02\x80\x11"),
+            Err(QpackError::DynamicIndex(1))
+        );
+        // reference to the dynamic table without a count
+        assert_eq!(
+            decoder.decode_fields(0, b"\x00\x00\x80"),
+            Err(QpackError::DynamicIndex(0))
+        );
+    }
+
+    #[test]
+    fn test_decoder_blocked_streams_limit() {
+        let mut decoder = with_capacity(100, 1);
+        assert_eq!(decoder.decode_fields(0, b"\x02\x80\x10"), Ok(None));
+        // the same stream may block again
+        assert_eq!(decoder.decode_fields(0, b"\x02\x80\x10"), Ok(None));
+        assert_eq!(
+            decoder.decode_fields(4, b"\x02\x80\x10"),
+            Err(QpackError::BlockedStreams)
+        );
+        insert(&mut decoder, "x-one", "1");
+        assert!(!decoder.is_blocked(0));
+        assert!(decoder.decode_fields(0, b"\x02\x80\x10").unwrap().is_some());
+        // the acknowledgment covers the insert
+        assert_eq!(&decoder.take_instructions()[..], [0x80]);
+    }
+
+    // index + 1 does not fit in a usize
+    #[test]
+    fn test_decoder_oversized_index() {
+        let mut decoder = Decoder::new(4096, 16);
+        let mut instructions = BytesMut::new();
+        EncoderInstruction::Duplicate(usize::MAX)
+            .encode(false, &mut instructions);
+        assert_eq!(
+            decoder.on_encoder_stream(&instructions),
+            Err(QpackError::DynamicIndex(usize::MAX))
+        );
+        let mut decoder = with_capacity(100, 0);
+        insert(&mut decoder, "x-one", "1");
+        // count of 1, base 1, pre-base index
+        let mut section = BytesMut::from(&b"\x02\x00"[..]);
+        encode_int(usize::MAX, 6, 0x80, &mut section);
+        assert_eq!(
+            decoder.decode_fields(0, &section),
+            Err(QpackError::DynamicIndex(usize::MAX))
+        );
+    }
+
+    #[test]
+    fn test_decoder_encoder_stream_errors() {
+        let mut decoder = with_capacity(100, 0);
+        assert_eq!(
+            decoder.on_encoder_stream(b"\x3f\x46"),
+            Err(QpackError::Capacity(101))
+        );
+        assert_eq!(
+            decoder.on_encoder_stream(b"\xff\x40\x00"),
+            Err(QpackError::StaticIndex(127))
+        );
+        assert_eq!(
+            decoder.on_encoder_stream(b"\x00"),
+            Err(QpackError::DynamicIndex(0))
+        );
+        let mut instructions = BytesMut::new();
+        EncoderInstruction::InsertWithLiteralName {
+            name: Bytes::from_static(b"x-large"),
+            value: Bytes::from(vec![b'a'; 62]),
+        }
+        .encode(false, &mut instructions);
+        assert_eq!(
+            decoder.on_encoder_stream(&instructions),
+            Err(QpackError::EntryTooLarge(101))
+        );
+        // truncated instruction is left unread
+        assert_eq!(decoder.on_encoder_stream(b"\x20\x45x-o"), Ok(1));
+    }
+
+    #[test]
+    fn test_decoder_cancel_stream() {
+        // no dynamic table, no instruction
+        let mut decoder = Decoder::default();
+        decoder.cancel_stream(4);
+        assert!(decoder.take_instructions().is_empty());
+    }
+}
@@ -0,0 +1,636 @@
+use std::collections::{HashMap, VecDeque};
+
+use bytes::{Bytes, BytesMut};
+
+use crate::hpack::Field;
+use crate::hpack::primitive::{encode_int, encode_string};
+use crate::hpack::pseudo::{request_fields, response_fields};
+use crate::hpack::table::{DynamicTable, TableMatch, entry_size, find_static};
+use crate::hpack::{is_sensitive, lowercase};
+use crate::message_head::header_map::Hmap;
+use crate::qpack::instruction::{DecoderInstruction, EncoderInstruction};
+use crate::qpack::static_table::STATIC_TABLE;
+use crate::qpack::{QpackError, encode_prefix, max_entries};
+use crate::{HeaderMap, RequestLine, ResponseLine};
+
+const NONE: usize = usize::MAX;
+
+/* QPACK encoder of field sections, RFC 9204. Fields are inserted in the
+ * dynamic table when the entries to evict are not referenced by a section
+ * waiting for its acknowledgment. A section references entries the decoder
+ * has not received only when it may block its stream, ie. the stream
+ * already blocks or fewer streams than the maximum do.
+ *
+ * Entries inserted while encoding a section are referenced after its base,
+ * the insert count when the section starts.
+ */
+#[derive(Debug)]
+pub struct Encoder {
+    table: DynamicTable,
+    // SETTINGS_QPACK_MAX_TABLE_CAPACITY of the peer
+    max_table_capacity: usize,
+    // SETTINGS_QPACK_BLOCKED_STREAMS of the peer
+    max_blocked_streams: usize,
+    known_received_count: usize,
+    // sections with a required insert count, in order per stream
+    unacked: HashMap<u64, VecDeque<Section>>,
+    instructions: BytesMut,
+    huffman: bool,
+}
+
+// section waiting for its acknowledgment
+#[derive(Clone, Copy, Debug)]
+struct Section {
+    required_insert_count: usize,
+    // smallest absolute index referenced
+    min_index: usize,
+}
+
+// section being encoded
+struct SectionState {
+    stream_id: u64,
+    base: usize,
+    required_insert_count: usize,
+    min_index: usize,
+    can_block: bool,
+    fields: BytesMut,
+}
+
+impl SectionState {
+    fn reference(&mut self, index: usize) {
+        self.required_insert_count = self.required_insert_count.max(index + 1);
+        self.min_index = self.min_index.min(index);
+    }
+}
+
+// without SETTINGS the peer has no dynamic table, RFC 9204 5
+impl Default for Encoder {
+    fn default() -> Self {
+        Encoder::new(0, 0)
+    }
+}
+
+impl Encoder {
+    // the settings of the peer, the table capacity starts at 0
+    pub fn new(max_table_capacity: usize, max_blocked_streams: usize) -> Self {
+        Encoder {
+            table: DynamicTable::new(0),
+            max_table_capacity,
+            max_blocked_streams,
+            known_received_count: 0,
+            unacked: HashMap::new(),
+            instructions: BytesMut::new(),
+            huffman: true,
+        }
+    }
+
+    pub fn set_huffman(&mut self, huffman: bool) {
+        self.huffman = huffman;
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.table.max_size()
+    }
+
+    pub fn table_size(&self) -> usize {
+        self.table.size()
+    }
+
+    pub fn insert_count(&self) -> usize {
+        self.table.insert_count()
+    }
+
+    pub fn known_received_count(&self) -> usize {
+        self.known_received_count
+    }
+
+    // streams with a section referencing entries not yet received
+    pub fn blocked_streams(&self) -> usize {
+        self.unacked
+            .values()
+            .filter(|sections| self.is_blocking(sections))
+            .count()
+    }
+
+    fn is_blocking(&self, sections: &VecDeque<Section>) -> bool {
+        sections.iter().any(|section| {
+            section.required_insert_count > self.known_received_count
+        })
+    }
+
+    /* Error:
+     *      Capacity    when above the maximum of the peer, or when entries
+     *                  still referenced would be evicted.
+     */
+    pub fn set_capacity(&mut self, capacity: usize) -> Result<(), QpackError> {
+        if capacity > self.max_table_capacity {
+            return Err(QpackError::Capacity(capacity));
+        }
+        let evicted = self.table.evictions(capacity, 0).unwrap_or(NONE);
+        if !self.can_evict(evicted, NONE) {
+            return Err(QpackError::Capacity(capacity));
+        }
+        self.table.set_max_size(capacity);
+        EncoderInstruction::SetCapacity(capacity)
+            .encode(self.huffman, &mut self.instructions);
+        Ok(())
+    }
+
+    // encoder stream data to send
+    pub fn take_instructions(&mut self) -> BytesMut {
+        self.instructions.split()
+    }
+
+    /* Reads the instructions of the decoder stream, returns the number of
+     * bytes read. A truncated instruction is left for the next call.
+     *
+     * Error:
+     *      UnknownStream           when a stream has no section to
+     *                              acknowledge.
+     *      InsertCountIncrement    when the increment is 0 or above the
+     *                              insert count.
+     */
+    pub fn on_decoder_stream(
+        &mut self,
+        buf: &[u8],
+    ) -> Result<usize, QpackError> {
+        let mut rest = buf;
+        loop {
+            match DecoderInstruction::decode(&mut rest) {
+                Ok(instruction) => self.on_instruction(instruction)?,
+                Err(QpackError::Truncated) => break,
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(buf.len() - rest.len())
+    }
+
+    fn on_instruction(
+        &mut self,
+        instruction: DecoderInstruction,
+    ) -> Result<(), QpackError> {
+        match instruction {
+            DecoderInstruction::SectionAck(stream_id) => {
+                let sections = self
+                    .unacked
+                    .get_mut(&stream_id)
+                    .ok_or(QpackError::UnknownStream(stream_id))?;
+                let section = sections
+                    .pop_front()
+                    .ok_or(QpackError::UnknownStream(stream_id))?;
+                if sections.is_empty() {
+                    self.unacked.remove(&stream_id);
+                }
+                self.known_received_count = self
+                    .known_received_count
+                    .max(section.required_insert_count);
+            }
+            DecoderInstruction::StreamCancellation(stream_id) => {
+                self.unacked.remove(&stream_id);
+            }
+            DecoderInstruction::InsertCountIncrement(increment) => {
+                self.known_received_count = self
+                    .known_received_count
+                    .checked_add(increment)
+                    .filter(|count| {
+                        increment > 0 && *count <= self.table.insert_count()
+                    })
+                    .ok_or(QpackError::InsertCountIncrement(increment))?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn encode_request(
+        &mut self,
+        stream_id: u64,
+        line: &RequestLine,
+        headers: &HeaderMap,
+        dst: &mut BytesMut,
+    ) {
+        let mut section = self.start_section(stream_id);
+        for (name, value) in request_fields(line) {
+            self.encode_field(&mut section, name, value, false);
+        }
+        self.encode_map(&mut section, headers);
+        self.finish_section(section, dst);
+    }
+
+    pub fn encode_response(
+        &mut self,
+        stream_id: u64,
+        line: &ResponseLine,
+        headers: &HeaderMap,
+        dst: &mut BytesMut,
+    ) {
+        let mut section = self.start_section(stream_id);
+        for (name, value) in response_fields(line) {
+            self.encode_field(&mut section, name, value, false);
+        }
+        self.encode_map(&mut section, headers);
+        self.finish_section(section, dst);
+    }
+
+    // section without pseudo-headers, ie. trailers
+    pub fn encode_headers(
+        &mut self,
+        stream_id: u64,
+        headers: &HeaderMap,
+        dst: &mut BytesMut,
+    ) {
+        let mut section = self.start_section(stream_id);
+        self.encode_map(&mut section, headers);
+        self.finish_section(section, dst);
+    }
+
+    // fields are encoded as is, pseudo-headers included
+    pub fn encode_fields<'a, I>(
+        &mut self,
+        stream_id: u64,
+        fields: I,
+        dst: &mut BytesMut,
+    ) where
+        I: IntoIterator<Item = &'a Field>,
+    {
+        let mut section = self.start_section(stream_id);
+        for field in fields {
+            let name = lowercase(field.name());
+            self.encode_field(
+                &mut section,
+                &name,
+                field.value(),
+                field.is_sensitive(),
+            );
+        }
+        self.finish_section(section, dst);
+    }
+
+    fn start_section(&self, stream_id: u64) -> SectionState {
+        let can_block = self
+            .unacked
+            .get(&stream_id)
+            .is_some_and(|sections| self.is_blocking(sections))
+            || self.blocked_streams() < self.max_blocked_streams;
+        SectionState {
+            stream_id,
+            base: self.table.insert_count(),
+            required_insert_count: 0,
+            min_index: NONE,
+            can_block,
+            fields: BytesMut::new(),
+        }
+    }
+
+    fn finish_section(&mut self, section: SectionState, dst: &mut BytesMut) {
+        let max_entries = max_entries(self.max_table_capacity);
+        // the base is unused without dynamic references
+        let base = if section.required_insert_count > 0 {
+            section.base
+        } else {
+            0
+        };
+        encode_prefix(section.required_insert_count, base, max_entries, dst);
+        dst.extend_from_slice(&section.fields);
+        if section.required_insert_count > 0 {
+            self.unacked.entry(section.stream_id).or_default().push_back(
+                Section {
+                    required_insert_count: section.required_insert_count,
+                    min_index: section.min_index,
+                },
+            );
+        }
+    }
+
+    // removed headers are skipped
+    fn encode_map(&mut self, section: &mut SectionState, headers: &HeaderMap) {
+        for header in headers.iter().filter(|header| !header.is_empty()) {
+            let name = lowercase(header.key_as_ref());
+            self.encode_field(section, &name, header.value_as_ref(), false);
+        }
+    }
+
+    fn encode_field(
+        &mut self,
+        section: &mut SectionState,
+        name: &[u8],
+        value: &[u8],
+        sensitive: bool,
+    ) {
+        let found_static = find_static(&STATIC_TABLE, name, value);
+        if let Some(TableMatch::Full(index)) = found_static {
+            // indexed field line, static
+            encode_int(index, 6, 0xc0, &mut section.fields);
+            return;
+        }
+        let sensitive = sensitive || is_sensitive(name, value);
+        let found = self.find_dynamic(name, value);
+        let mut usable = found.filter(|found| {
+            section.can_block || index_of(*found) < self.known_received_count
+        });
+        if !sensitive {
+            if let Some(TableMatch::Full(index)) = usable {
+                self.encode_indexed(section, index);
+                return;
+            }
+            // an entry not yet received is not inserted again
+            let inserted = match found {
+                Some(TableMatch::Full(_)) => None,
+                _ => {
+                    self.try_insert(section, name, value, found_static, found)
+                }
+            };
+            if let Some(index) = inserted {
+                if section.can_block {
+                    self.encode_indexed(section, index);
+                    return;
+                }
+                // the insert may have evicted the name
+                usable = None;
+            }
+        }
+        self.encode_literal(
+            section,
+            name,
+            value,
+            sensitive,
+            found_static,
+            usable,
+        );
+    }
+
+    // absolute index of the best match
+    fn find_dynamic(&self, name: &[u8], value: &[u8]) -> Option<TableMatch> {
+        let last = self.table.insert_count().checked_sub(1)?;
+        self.table.find(name, value).map(|found| match found {
+            TableMatch::Full(index) => TableMatch::Full(last - index),
+            TableMatch::Name(index) => TableMatch::Name(last - index),
+        })
+    }
+
+    /* Inserts the field when the entries to evict are not referenced,
+     * returns its absolute index.
+     */
+    fn try_insert(
+        &mut self,
+        section: &SectionState,
+        name: &[u8],
+        value: &[u8],
+        found_static: Option<TableMatch>,
+        found: Option<TableMatch>,
+    ) -> Option<usize> {
+        let size = entry_size(name, value);
+        let evicted = self.table.evictions(self.table.max_size(), size)?;
+        if !self.can_evict(evicted, section.min_index) {
+            return None;
+        }
+        let value = Bytes::copy_from_slice(value);
+        let instruction = match (found_static, found) {
+            (Some(found), _) => EncoderInstruction::InsertWithNameRef {
+                is_static: true,
+                index: index_of(found),
+                value: value.clone(),
+            },
+            (None, Some(found)) => EncoderInstruction::InsertWithNameRef {
+                is_static: false,
+                index: self.table.insert_count() - index_of(found) - 1,
+                value: value.clone(),
+            },
+            (None, None) => EncoderInstruction::InsertWithLiteralName {
+                name: Bytes::copy_from_slice(name),
+                value: value.clone(),
+            },
+        };
+        instruction.encode(self.huffman, &mut self.instructions);
+        self.table.insert(Bytes::copy_from_slice(name), value);
+        Some(self.table.insert_count() - 1)
+    }
+
+    // the count oldest entries are not referenced by a section
+    fn can_evict(&self, count: usize, section_min_index: usize) -> bool {
+        if count == 0 {
+            return true;
+        }
+        if count > self.table.len() {
+            return false;
+        }
+        let oldest = self.table.insert_count() - self.table.len();
+        let min_index = self
+            .unacked
+            .values()
+            .flatten()
+            .map(|section| section.min_index)
+            .fold(section_min_index, usize::min);
+        oldest + count <= min_index
+    }
+
+    fn encode_indexed(&self, section: &mut SectionState, index: usize) {
+        section.reference(index);
+        if index < section.base {
+            encode_int(section.base - index - 1, 6, 0x80, &mut section.fields);
+        } else {
+            encode_int(index - section.base, 4, 0x10, &mut section.fields);
+        }
+    }
+
+    fn encode_literal(
+        &self,
+        section: &mut SectionState,
+        name: &[u8],
+        value: &[u8],
+        sensitive: bool,
+        found_static: Option<TableMatch>,
+        found: Option<TableMatch>,
+    ) {
+        let never_indexed = if sensitive {
+            0x20
+        } else {
+            0
+        };
+        match (found_static, found) {
+            (Some(found), _) => encode_int(
+                index_of(found),
+                4,
+                0x50 | never_indexed,
+                &mut section.fields,
+            ),
+            (None, Some(found)) => {
+                let index = index_of(found);
+                section.reference(index);
+                if index < section.base {
+                    encode_int(
+                        section.base - index - 1,
+                        4,
+                        0x40 | never_indexed,
+                        &mut section.fields,
+                    );
+                } else {
+                    encode_int(
+                        index - section.base,
+                        3,
+                        never_indexed >> 2,
+                        &mut section.fields,
+                    );
+                }
+            }
+            (None, None) => encode_string(
+                name,
+                3,
+                0x20 | never_indexed >> 1,
+                self.huffman,
+                &mut section.fields,
+            ),
+        }
+        encode_string(value, 7, 0, self.huffman, &mut section.fields);
+    }
+}
+
+fn index_of(found: TableMatch) -> usize {
+    match found {
+        TableMatch::Full(index) | TableMatch::Name(index) => index,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(name: &'static str, value: &'static str) -> Field {
+        Field::new(
+            Bytes::from_static(name.as_bytes()),
+            Bytes::from_static(value.as_bytes()),
+        )
+    }
+
+    fn encode(
+        encoder: &mut Encoder,
+        stream_id: u64,
+        fields: &[Field],
+    ) -> BytesMut {
+        let mut dst = BytesMut::new();
+        encoder.encode_fields(stream_id, fields, &mut dst);
+        dst
+    }
+
+    fn encoder(capacity: usize, max_blocked_streams: usize) -> Encoder {
+        let mut encoder = Encoder::new(capacity, max_blocked_streams);
+        encoder.set_huffman(false);
+        encoder.set_capacity(capacity).unwrap();
+        encoder.take_instructions();
+        encoder
+    }
+
+    #[test]
+    fn test_encoder_static_only() {
+        let mut encoder = Encoder::default();
+        encoder.set_huffman(false);
+        let dst = encode(&mut encoder, 0, &[field(":method", "GET")]);
+        assert_eq!(&dst[..], [0x00, 0x00, 0xd1]);
+        let dst = encode(&mut encoder, 0, &[field("x-custom", "a")]);
+        assert_eq!(&dst[..], b"\x00\x00\x27\x01x-custom\x01a");
+        assert!(encoder.take_instructions().is_empty());
+        assert_eq!(encoder.set_capacity(1), Err(QpackError::Capacity(1)));
+    }
+
+    #[test]
+    fn test_encoder_sensitive() {
+        let mut encoder = encoder(4096, 1);
+        let dst = encode(&mut encoder, 0, &[field("authorization", "secret")]);
+        // never indexed, static name 84
+        assert_eq!(&dst[..], b"\x00\x00\x7f\x45\x06secret");
+        let password = Field::never_indexed(
+            Bytes::from_static(b"password"),
+            Bytes::from_static(b"secret"),
+        );
+        let dst = encode(&mut encoder, 0, &[password]);
+        assert_eq!(&dst[..], b"\x00\x00\x37\x01password\x06secret");
+        assert!(encoder.take_instructions().is_empty());
+    }
+
+    #[test]
+    fn test_encoder_not_blocking() {
+        // entries are inserted but not referenced until acknowledged
+        let mut encoder = encoder(4096, 0);
+        let dst = encode(&mut encoder, 0, &[field("x-custom", "a")]);
+        assert_eq!(&dst[..], b"\x00\x00\x27\x01x-custom\x01a");
+        assert_eq!(encoder.insert_count(), 1);
+        assert_eq!(encoder.blocked_streams(), 0);
+        encoder.on_decoder_stream(&[0x01]).unwrap();
+        let dst = encode(&mut encoder, 0, &[field("x-custom", "a")]);
+        assert_eq!(&dst[..], [0x02, 0x00, 0x80]);
+    }
+
+    #[test]
+    fn test_encoder_blocked_streams_limit() {
+        let mut encoder = encoder(4096, 1);
+        encode(&mut encoder, 0, &[field("x-one", "1")]);
+        assert_eq!(encoder.blocked_streams(), 1);
+        // the stream already blocks
+        let dst = encode(&mut encoder, 0, &[field("x-two", "2")]);
+        assert_eq!(&dst[..], [0x03, 0x80, 0x10]);
+        // another stream can not block
+        let dst = encode(&mut encoder, 4, &[field("x-one", "1")]);
+        assert_eq!(&dst[..], b"\x00\x00\x25x-one\x011");
+        assert_eq!(encoder.blocked_streams(), 1);
+        encoder.on_decoder_stream(&[0x80]).unwrap();
+        assert_eq!(encoder.blocked_streams(), 1);
+        encoder.on_decoder_stream(&[0x80]).unwrap();
+        assert_eq!(encoder.blocked_streams(), 0);
+    }
+
+    #[test]
+    fn test_encoder_referenced_not_evicted() {
+        // room for two entries of 40 bytes
+        let mut encoder = encoder(80, 2);
+        encode(&mut encoder, 0, &[field("x-one", "1"), field("x-two", "2")]);
+        assert_eq!(encoder.insert_count(), 2);
+        // the entries are referenced by the section of stream 0
+        let dst = encode(&mut encoder, 4, &[field("x-thr", "3")]);
+        assert_eq!(&dst[..], b"\x00\x00\x25x-thr\x013");
+        assert_eq!(encoder.insert_count(), 2);
+        assert_eq!(encoder.set_capacity(40), Err(QpackError::Capacity(40)));
+        // stream 0 is cancelled
+        encoder.on_decoder_stream(&[0x40]).unwrap();
+        encode(&mut encoder, 4, &[field("x-thr", "3")]);
+        assert_eq!(encoder.insert_count(), 3);
+        assert_eq!(encoder.table_size(), 76);
+    }
+
+    #[test]
+    fn test_encoder_dynamic_name_reference() {
+        let mut encoder = encoder(4096, 1);
+        encode(&mut encoder, 0, &[field("x-custom", "a")]);
+        encoder.on_decoder_stream(&[0x80]).unwrap();
+        encoder.take_instructions();
+        let dst = encode(&mut encoder, 0, &[field("x-custom", "b")]);
+        // inserted with the name of entry 0, referenced after the base
+        assert_eq!(&encoder.take_instructions()[..], b"\x80\x01b");
+        assert_eq!(&dst[..], [0x03, 0x80, 0x10]);
+        // sensitive value, name of the acknowledged entry
+        let password = Field::never_indexed(
+            Bytes::from_static(b"x-custom"),
+            Bytes::from_static(b"c"),
+        );
+        encoder.on_decoder_stream(&[0x80]).unwrap();
+        let dst = encode(&mut encoder, 0, &[password]);
+        assert_eq!(&dst[..], b"\x03\x00\x60\x01c");
+    }
+
+    #[test]
+    fn test_encoder_decoder_stream_errors() {
+        let mut encoder = encoder(4096, 1);
+        assert_eq!(
+            encoder.on_decoder_stream(&[0x84]),
+            Err(QpackError::UnknownStream(4))
+        );
+        assert_eq!(
+            encoder.on_decoder_stream(&[0x00]),
+            Err(QpackError::InsertCountIncrement(0))
+        );
+        assert_eq!(
+            encoder.on_decoder_stream(&[0x01]),
+            Err(QpackError::InsertCountIncrement(1))
+        );
+        // truncated instruction is left
+        assert_eq!(encoder.on_decoder_stream(&[0x48, 0xff]), Ok(1));
+    }
+}
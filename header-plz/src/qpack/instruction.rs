@@ -0,0 +1,198 @@
+// Instructions of the encoder and decoder streams, RFC 9204 4.3 and 4.4
+
+use bytes::{Bytes, BytesMut};
+
+use crate::hpack::primitive::{
+    decode_int, decode_string, encode_int, encode_string,
+};
+use crate::qpack::QpackError;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum EncoderInstruction {
+    SetCapacity(usize),
+    // static index, or relative index in the dynamic table
+    InsertWithNameRef {
+        is_static: bool,
+        index: usize,
+        value: Bytes,
+    },
+    InsertWithLiteralName {
+        name: Bytes,
+        value: Bytes,
+    },
+    // relative index
+    Duplicate(usize),
+}
+
+impl EncoderInstruction {
+    pub(crate) fn encode(&self, huffman: bool, dst: &mut BytesMut) {
+        match self {
+            EncoderInstruction::SetCapacity(capacity) => {
+                encode_int(*capacity, 5, 0x20, dst)
+            }
+            EncoderInstruction::InsertWithNameRef {
+                is_static,
+                index,
+                value,
+            } => {
+                let flags = if *is_static {
+                    0xc0
+                } else {
+                    0x80
+                };
+                encode_int(*index, 6, flags, dst);
+                encode_string(value, 7, 0, huffman, dst);
+            }
+            EncoderInstruction::InsertWithLiteralName {
+                name,
+                value,
+            } => {
+                encode_string(name, 5, 0x40, huffman, dst);
+                encode_string(value, 7, 0, huffman, dst);
+            }
+            EncoderInstruction::Duplicate(index) => {
+                encode_int(*index, 5, 0x00, dst)
+            }
+        }
+    }
+
+    // buf is only advanced past a complete instruction
+    pub(crate) fn decode(buf: &mut &[u8]) -> Result<Self, QpackError> {
+        let mut rest = *buf;
+        let Some(&first) = rest.first() else {
+            return Err(QpackError::Truncated);
+        };
+        let instruction = if first & 0x80 != 0 {
+            let index = decode_int(&mut rest, 6)?;
+            EncoderInstruction::InsertWithNameRef {
+                is_static: first & 0x40 != 0,
+                index,
+                value: decode_string(&mut rest, 7)?,
+            }
+        } else if first & 0x40 != 0 {
+            let name = decode_string(&mut rest, 5)?;
+            EncoderInstruction::InsertWithLiteralName {
+                name,
+                value: decode_string(&mut rest, 7)?,
+            }
+        } else if first & 0x20 != 0 {
+            EncoderInstruction::SetCapacity(decode_int(&mut rest, 5)?)
+        } else {
+            EncoderInstruction::Duplicate(decode_int(&mut rest, 5)?)
+        };
+        *buf = rest;
+        Ok(instruction)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum DecoderInstruction {
+    SectionAck(u64),
+    StreamCancellation(u64),
+    InsertCountIncrement(usize),
+}
+
+impl DecoderInstruction {
+    pub(crate) fn encode(&self, dst: &mut BytesMut) {
+        match self {
+            DecoderInstruction::SectionAck(stream_id) => {
+                encode_int(*stream_id as usize, 7, 0x80, dst)
+            }
+            DecoderInstruction::StreamCancellation(stream_id) => {
+                encode_int(*stream_id as usize, 6, 0x40, dst)
+            }
+            DecoderInstruction::InsertCountIncrement(increment) => {
+                encode_int(*increment, 6, 0x00, dst)
+            }
+        }
+    }
+
+    // buf is only advanced past a complete instruction
+    pub(crate) fn decode(buf: &mut &[u8]) -> Result<Self, QpackError> {
+        let mut rest = *buf;
+        let Some(&first) = rest.first() else {
+            return Err(QpackError::Truncated);
+        };
+        let instruction = if first & 0x80 != 0 {
+            DecoderInstruction::SectionAck(decode_int(&mut rest, 7)? as u64)
+        } else if first & 0x40 != 0 {
+            DecoderInstruction::StreamCancellation(
+                decode_int(&mut rest, 6)? as u64
+            )
+        } else {
+            DecoderInstruction::InsertCountIncrement(decode_int(&mut rest, 6)?)
+        };
+        *buf = rest;
+        Ok(instruction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip_encoder(instruction: EncoderInstruction) {
+        for huffman in [false, true] {
+            let mut dst = BytesMut::new();
+            instruction.encode(huffman, &mut dst);
+            let mut buf = &dst[..];
+            assert_eq!(
+                EncoderInstruction::decode(&mut buf),
+                Ok(instruction.clone())
+            );
+            assert!(buf.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_encoder_instruction_round_trip() {
+        round_trip_encoder(EncoderInstruction::SetCapacity(220));
+        round_trip_encoder(EncoderInstruction::InsertWithNameRef {
+            is_static: true,
+            index: 98,
+            value: Bytes::from("sameorigin"),
+        });
+        round_trip_encoder(EncoderInstruction::InsertWithNameRef {
+            is_static: false,
+            index: 0,
+            value: Bytes::new(),
+        });
+        round_trip_encoder(EncoderInstruction::InsertWithLiteralName {
+            name: Bytes::from("custom-key"),
+            value: Bytes::from("custom-value"),
+        });
+        round_trip_encoder(EncoderInstruction::Duplicate(40));
+    }
+
+    #[test]
+    fn test_decoder_instruction_round_trip() {
+        for instruction in [
+            DecoderInstruction::SectionAck(4),
+            DecoderInstruction::SectionAck(1 << 40),
+            DecoderInstruction::StreamCancellation(8),
+            DecoderInstruction::InsertCountIncrement(1),
+        ] {
+            let mut dst = BytesMut::new();
+            instruction.encode(&mut dst);
+            let mut buf = &dst[..];
+            assert_eq!(DecoderInstruction::decode(&mut buf), Ok(instruction));
+            assert!(buf.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_instruction_incomplete() {
+        // Insert With Literal Name missing its value
+        let mut buf = &b"\x4acustom-key"[..];
+        assert_eq!(
+            EncoderInstruction::decode(&mut buf),
+            Err(QpackError::Truncated)
+        );
+        assert_eq!(buf.len(), 11);
+        let mut buf = &[0xff][..];
+        assert_eq!(
+            DecoderInstruction::decode(&mut buf),
+            Err(QpackError::Truncated)
+        );
+    }
+}
@@ -0,0 +1,402 @@
+use bytes::BytesMut;
+use thiserror::Error;
+
+mod decoder;
+mod encoder;
+mod instruction;
+mod static_table;
+
+pub use decoder::Decoder;
+pub use encoder::Encoder;
+
+use crate::hpack::primitive::{decode_int, encode_int};
+use crate::hpack::table::ENTRY_OVERHEAD;
+use crate::hpack::{HpackError, PseudoHeaderError};
+
+/* QPACK, RFC 9204. Field sections are encoded per stream, the encoder and
+ * decoder streams are exchanged as bytes by the caller:
+ *
+ *      Encoder::take_instructions  ->  Decoder::on_encoder_stream
+ *      Decoder::take_instructions  ->  Encoder::on_decoder_stream
+ *
+ * Both instruction readers consume complete instructions only and return
+ * the number of bytes read, the rest is passed again with more data.
+ */
+
+#[derive(Debug, Error, PartialEq)]
+pub enum QpackError {
+    #[error("truncated")]
+    Truncated,
+    #[error("hpack| {0}")]
+    Hpack(HpackError),
+    #[error("invalid static index| {0}")]
+    StaticIndex(usize),
+    #[error("invalid dynamic index| {0}")]
+    DynamicIndex(usize),
+    #[error("invalid required insert count| {0}")]
+    RequiredInsertCount(usize),
+    #[error("invalid base")]
+    Base,
+    #[error("capacity above maximum| {0}")]
+    Capacity(usize),
+    #[error("entry larger than capacity| {0}")]
+    EntryTooLarge(usize),
+    #[error("too many blocked streams")]
+    BlockedStreams,
+    #[error("no section to acknowledge| {0}")]
+    UnknownStream(u64),
+    #[error("invalid insert count increment| {0}")]
+    InsertCountIncrement(usize),
+    #[error("pseudo header| {0}")]
+    Pseudo(#[from] PseudoHeaderError),
+}
+
+// truncated input of a stream is not an error of the representation
+impl From<HpackError> for QpackError {
+    fn from(e: HpackError) -> Self {
+        match e {
+            HpackError::Truncated => QpackError::Truncated,
+            e => QpackError::Hpack(e),
+        }
+    }
+}
+
+// RFC 9204 3.2.2
+fn max_entries(max_table_capacity: usize) -> usize {
+    max_table_capacity / ENTRY_OVERHEAD
+}
+
+// RFC 9204 4.5.1.1
+fn encode_insert_count(required: usize, max_entries: usize) -> usize {
+    if required == 0 {
+        0
+    } else {
+        required % (2 * max_entries) + 1
+    }
+}
+
+/* Error:
+ *      RequiredInsertCount     when the encoded value can not be a count
+ *                              of the table.
+ */
+fn decode_insert_count(
+    encoded: usize,
+    max_entries: usize,
+    total_inserts: usize,
+) -> Result<usize, QpackError> {
+    if encoded == 0 {
+        return Ok(0);
+    }
+    let full_range = 2 * max_entries;
+    if encoded > full_range {
+        return Err(QpackError::RequiredInsertCount(encoded));
+    }
+    let max_value = total_inserts + max_entries;
+    let max_wrapped = max_value / full_range * full_range;
+    let mut required = max_wrapped + encoded - 1;
+    if required > max_value {
+        if required <= full_range {
+            return Err(QpackError::RequiredInsertCount(encoded));
+        }
+        required -= full_range;
+    }
+    if required == 0 {
+        return Err(QpackError::RequiredInsertCount(encoded));
+    }
+    Ok(required)
+}
+
+// Required Insert Count and signed Delta Base, RFC 9204 4.5.1
+fn encode_prefix(
+    required: usize,
+    base: usize,
+    max_entries: usize,
+    dst: &mut BytesMut,
+) {
+    encode_int(encode_insert_count(required, max_entries), 8, 0, dst);
+    if base >= required {
+        encode_int(base - required, 7, 0, dst);
+    } else {
+        encode_int(required - base - 1, 7, 0x80, dst);
+    }
+}
+
+// (required insert count, base)
+fn decode_prefix(
+    buf: &mut &[u8],
+    max_entries: usize,
+    total_inserts: usize,
+) -> Result<(usize, usize), QpackError> {
+    let required =
+        decode_insert_count(decode_int(buf, 8)?, max_entries, total_inserts)?;
+    let negative = buf.first().is_some_and(|b| b & 0x80 != 0);
+    let delta = decode_int(buf, 7)?;
+    let base = if negative {
+        delta.checked_add(1).and_then(|delta| required.checked_sub(delta))
+    } else {
+        required.checked_add(delta)
+    };
+    Ok((required, base.ok_or(QpackError::Base)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+
+    use super::*;
+    use crate::hpack::Field;
+    use crate::{HeaderMap, Method, RequestLine, ResponseLine, StatusCode};
+
+    fn hex(src: &str) -> Vec<u8> {
+        (0..src.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&src[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn field(name: &'static str, value: &'static str) -> Field {
+        Field::new(
+            Bytes::from_static(name.as_bytes()),
+            Bytes::from_static(value.as_bytes()),
+        )
+    }
+
+    fn decode(
+        decoder: &mut Decoder,
+        stream_id: u64,
+        block: &str,
+    ) -> Vec<Field> {
+        decoder.decode_fields(stream_id, &hex(block)).unwrap().unwrap()
+    }
+
+    fn on_encoder_stream(decoder: &mut Decoder, instructions: &str) {
+        let instructions = hex(instructions);
+        assert_eq!(
+            decoder.on_encoder_stream(&instructions),
+            Ok(instructions.len())
+        );
+    }
+
+    // B.2 to B.5
+    const SET_CAPACITY_AND_INSERTS: &str = "3fbd01c00f7777772e6578616d706c652e\
+                                            636f6dc10c2f73616d706c652f70617468";
+    const SPECULATIVE_INSERT: &str = "4a637573746f6d2d6b65790c637573746f6d2d\
+                                      76616c7565";
+    const INSERT_EVICTION: &str = "810d637573746f6d2d76616c756532";
+
+    // RFC 9204 Appendix B, with a maximum capacity of 220
+    #[test]
+    fn test_qpack_rfc_decoder() {
+        let mut decoder = Decoder::new(220, 1);
+
+        // B.1, Literal Field Line with Name Reference
+        let fields = decode(&mut decoder, 0, "0000510b2f696e6465782e68746d6c");
+        assert_eq!(fields, [field(":path", "/index.html")]);
+        assert!(decoder.take_instructions().is_empty());
+
+        // B.2, Dynamic Table
+        on_encoder_stream(&mut decoder, SET_CAPACITY_AND_INSERTS);
+        assert_eq!(decoder.table_size(), 106);
+        let fields = decode(&mut decoder, 4, "03811011");
+        assert_eq!(
+            fields,
+            [
+                field(":authority", "www.example.com"),
+                field(":path", "/sample/path")
+            ]
+        );
+        assert_eq!(&decoder.take_instructions()[..], [0x84]);
+
+        // B.3, Speculative Insert
+        on_encoder_stream(&mut decoder, SPECULATIVE_INSERT);
+        assert_eq!(decoder.table_size(), 160);
+        assert_eq!(&decoder.take_instructions()[..], [0x01]);
+
+        // B.4, Duplicate Instruction, Stream Cancellation. The duplicate is
+        // delayed, the section blocks until the stream is cancelled.
+        assert_eq!(decoder.decode_fields(8, &hex("050080c181")), Ok(None));
+        assert!(decoder.is_blocked(8));
+        decoder.cancel_stream(8);
+        assert_eq!(decoder.blocked_streams(), 0);
+        assert_eq!(&decoder.take_instructions()[..], [0x48]);
+        on_encoder_stream(&mut decoder, "02");
+        assert_eq!(decoder.table_size(), 217);
+        assert_eq!(&decoder.take_instructions()[..], [0x01]);
+        // the section decodes once the duplicate is received
+        let fields = decode(&mut decoder, 12, "050080c181");
+        assert_eq!(
+            fields,
+            [
+                field(":authority", "www.example.com"),
+                field(":path", "/"),
+                field("custom-key", "custom-value")
+            ]
+        );
+        assert_eq!(&decoder.take_instructions()[..], [0x8c]);
+
+        // B.5, Dynamic Table Insert, Eviction
+        on_encoder_stream(&mut decoder, INSERT_EVICTION);
+        assert_eq!(decoder.table_size(), 215);
+        assert_eq!(&decoder.take_instructions()[..], [0x01]);
+        assert_eq!(decoder.insert_count(), 5);
+        // the oldest entry was evicted
+        assert_eq!(
+            decoder.decode_fields(16, &hex("020080")),
+            Err(QpackError::DynamicIndex(0))
+        );
+    }
+
+    #[test]
+    fn test_qpack_rfc_encoder() {
+        let mut encoder = Encoder::new(220, 1);
+        encoder.set_huffman(false);
+
+        // B.1, no dynamic table yet
+        let mut dst = BytesMut::new();
+        encoder.encode_fields(0, &[field(":path", "/index.html")], &mut dst);
+        assert_eq!(&dst[..], hex("0000510b2f696e6465782e68746d6c"));
+        assert!(encoder.take_instructions().is_empty());
+
+        // B.2
+        encoder.set_capacity(220).unwrap();
+        let fields = [
+            field(":authority", "www.example.com"),
+            field(":path", "/sample/path"),
+        ];
+        let mut dst = BytesMut::new();
+        encoder.encode_fields(4, &fields, &mut dst);
+        assert_eq!(&dst[..], hex("03811011"));
+        assert_eq!(
+            &encoder.take_instructions()[..],
+            hex(SET_CAPACITY_AND_INSERTS)
+        );
+        assert_eq!(encoder.blocked_streams(), 1);
+        assert_eq!(encoder.on_decoder_stream(&[0x84]), Ok(1));
+        assert_eq!(encoder.blocked_streams(), 0);
+        assert_eq!(encoder.known_received_count(), 2);
+
+        // acknowledged entries are referenced before the base
+        let mut dst = BytesMut::new();
+        encoder.encode_fields(8, &fields, &mut dst);
+        assert_eq!(&dst[..], [0x03, 0x00, 0x81, 0x80]);
+        assert!(encoder.take_instructions().is_empty());
+    }
+
+    fn exchange(encoder: &mut Encoder, decoder: &mut Decoder) {
+        let instructions = encoder.take_instructions();
+        assert_eq!(
+            decoder.on_encoder_stream(&instructions),
+            Ok(instructions.len())
+        );
+        let instructions = decoder.take_instructions();
+        assert_eq!(
+            encoder.on_decoder_stream(&instructions),
+            Ok(instructions.len())
+        );
+    }
+
+    #[test]
+    fn test_qpack_request_response_round_trip() {
+        let mut encoder = Encoder::new(4096, 16);
+        encoder.set_capacity(4096).unwrap();
+        let mut decoder = Decoder::new(4096, 16);
+        let uri = crate::Uri::builder()
+            .scheme("https")
+            .authority("example.com")
+            .path("/index.html?a=b")
+            .build()
+            .unwrap();
+        let line = RequestLine::new(Method::POST, uri);
+        let mut headers = HeaderMap::new();
+        headers.insert("user-agent", "plz");
+        headers.insert("x-custom", "1");
+        headers.insert("authorization", "Bearer token");
+        for stream_id in [0, 4, 8] {
+            let mut dst = BytesMut::new();
+            encoder.encode_request(stream_id, &line, &headers, &mut dst);
+            exchange(&mut encoder, &mut decoder);
+            let (decoded_line, decoded_headers) =
+                decoder.decode_request(stream_id, &dst).unwrap().unwrap();
+            assert_eq!(decoded_line, line);
+            assert_eq!(decoded_headers, headers);
+            exchange(&mut encoder, &mut decoder);
+        }
+        assert_eq!(encoder.blocked_streams(), 0);
+        // all entries acknowledged
+        assert_eq!(encoder.known_received_count(), encoder.insert_count());
+
+        let line = ResponseLine::new(StatusCode::from_u16(201).unwrap());
+        let mut dst = BytesMut::new();
+        encoder.encode_response(0, &line, &headers, &mut dst);
+        exchange(&mut encoder, &mut decoder);
+        let (decoded_line, decoded_headers) =
+            decoder.decode_response(0, &dst).unwrap().unwrap();
+        assert_eq!(decoded_line, line);
+        assert_eq!(decoded_headers, headers);
+    }
+
+    #[test]
+    fn test_qpack_blocked_round_trip() {
+        let mut encoder = Encoder::new(4096, 1);
+        encoder.set_capacity(4096).unwrap();
+        let mut decoder = Decoder::new(4096, 1);
+        let fields = [field("x-custom", "value")];
+        let mut dst = BytesMut::new();
+        encoder.encode_fields(0, &fields, &mut dst);
+        // section before the encoder stream
+        assert_eq!(decoder.decode_fields(0, &dst), Ok(None));
+        assert_eq!(decoder.blocked_streams(), 1);
+        let instructions = encoder.take_instructions();
+        decoder.on_encoder_stream(&instructions).unwrap();
+        assert!(!decoder.is_blocked(0));
+        assert_eq!(decoder.decode_fields(0, &dst), Ok(Some(fields.to_vec())));
+        assert_eq!(decoder.blocked_streams(), 0);
+        // only the acknowledgment is sent, it covers the insert
+        assert_eq!(&decoder.take_instructions()[..], [0x80]);
+    }
+
+    #[test]
+    fn test_qpack_insert_count_rfc() {
+        // 4.5.1.1 with a maximum capacity of 100 bytes, 3 entries
+        assert_eq!(max_entries(100), 3);
+        assert_eq!(encode_insert_count(0, 3), 0);
+        assert_eq!(encode_insert_count(9, 3), 4);
+        assert_eq!(decode_insert_count(4, 3, 10), Ok(9));
+        assert_eq!(decode_insert_count(4, 3, 7), Ok(9));
+        assert_eq!(decode_insert_count(1, 3, 5), Ok(6));
+        assert_eq!(
+            decode_insert_count(7, 3, 10),
+            Err(QpackError::RequiredInsertCount(7))
+        );
+        // a count of 0 is encoded as 0
+        assert_eq!(
+            decode_insert_count(1, 3, 0),
+            Err(QpackError::RequiredInsertCount(1))
+        );
+        for total in 0usize..50 {
+            for required in total.saturating_sub(2).max(1)..=total + 3 {
+                let encoded = encode_insert_count(required, 3);
+                assert_eq!(
+                    decode_insert_count(encoded, 3, total),
+                    Ok(required)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_qpack_prefix() {
+        for (required, base) in [(0, 0), (2, 0), (4, 4), (4, 9), (9, 2)] {
+            let mut dst = BytesMut::new();
+            encode_prefix(required, base, 6, &mut dst);
+            let mut buf = &dst[..];
+            assert_eq!(decode_prefix(&mut buf, 6, 3), Ok((required, base)));
+            assert!(buf.is_empty());
+        }
+        // base below 0
+        assert_eq!(
+            decode_prefix(&mut &[0x02, 0x81][..], 6, 1),
+            Err(QpackError::Base)
+        );
+    }
+}
@@ -0,0 +1,672 @@
+// Structured Field Values for HTTP
+// https://www.rfc-editor.org/rfc/rfc8941.html
+
+use std::fmt::Write;
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum StructuredFieldError {
+    #[error("unexpected end of input")]
+    UnexpectedEnd,
+    #[error("unexpected character| {0}")]
+    UnexpectedChar(char),
+    #[error("invalid number")]
+    InvalidNumber,
+    #[error("invalid string")]
+    InvalidString,
+    #[error("invalid byte sequence")]
+    InvalidByteSequence,
+    #[error("trailing characters")]
+    TrailingCharacters,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum BareItem {
+    Integer(i64),
+    Decimal(f64),
+    String(String),
+    Token(String),
+    ByteSequence(Vec<u8>),
+    Boolean(bool),
+}
+
+impl BareItem {
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            BareItem::String(s) | BareItem::Token(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            BareItem::ByteSequence(b) => Some(b),
+            _ => None,
+        }
+    }
+
+    pub fn as_integer(&self) -> Option<i64> {
+        match self {
+            BareItem::Integer(i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            BareItem::Boolean(b) => Some(*b),
+            _ => None,
+        }
+    }
+}
+
+pub type Parameters = Vec<(String, BareItem)>;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Item {
+    pub bare_item: BareItem,
+    pub params: Parameters,
+}
+
+impl Item {
+    pub fn new(bare_item: BareItem) -> Self {
+        Item {
+            bare_item,
+            params: Vec::new(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct InnerList {
+    pub items: Vec<Item>,
+    pub params: Parameters,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ListEntry {
+    Item(Item),
+    InnerList(InnerList),
+}
+
+impl ListEntry {
+    pub fn as_item(&self) -> Option<&Item> {
+        match self {
+            ListEntry::Item(item) => Some(item),
+            ListEntry::InnerList(_) => None,
+        }
+    }
+
+    pub fn as_inner_list(&self) -> Option<&InnerList> {
+        match self {
+            ListEntry::InnerList(list) => Some(list),
+            ListEntry::Item(_) => None,
+        }
+    }
+}
+
+pub type List = Vec<ListEntry>;
+pub type Dictionary = Vec<(String, ListEntry)>;
+
+pub fn parse_item(input: &str) -> Result<Item, StructuredFieldError> {
+    let mut parser = Parser::new(input);
+    let item = parser.parse_item()?;
+    parser.finish()?;
+    Ok(item)
+}
+
+pub fn parse_list(input: &str) -> Result<List, StructuredFieldError> {
+    let mut parser = Parser::new(input);
+    let mut list = Vec::new();
+    while !parser.is_empty() {
+        list.push(parser.parse_list_entry()?);
+        if !parser.next_member()? {
+            break;
+        }
+    }
+    parser.finish()?;
+    Ok(list)
+}
+
+pub fn parse_dictionary(
+    input: &str,
+) -> Result<Dictionary, StructuredFieldError> {
+    let mut parser = Parser::new(input);
+    let mut dict: Dictionary = Vec::new();
+    while !parser.is_empty() {
+        let key = parser.parse_key()?;
+        let entry = if parser.peek() == Some(b'=') {
+            parser.pos += 1;
+            parser.parse_list_entry()?
+        } else {
+            ListEntry::Item(Item {
+                bare_item: BareItem::Boolean(true),
+                params: parser.parse_parameters()?,
+            })
+        };
+        // last value wins for duplicate keys
+        if let Some(existing) = dict.iter_mut().find(|(k, _)| *k == key) {
+            existing.1 = entry;
+        } else {
+            dict.push((key, entry));
+        }
+        if !parser.next_member()? {
+            break;
+        }
+    }
+    parser.finish()?;
+    Ok(dict)
+}
+
+struct Parser<'a> {
+    input: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        let input = input.as_bytes().trim_ascii();
+        Parser {
+            input,
+            pos: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Result<u8, StructuredFieldError> {
+        let b = self.peek().ok_or(StructuredFieldError::UnexpectedEnd)?;
+        self.pos += 1;
+        Ok(b)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pos >= self.input.len()
+    }
+
+    fn skip_sp(&mut self) {
+        while self.peek() == Some(b' ') {
+            self.pos += 1;
+        }
+    }
+
+    fn skip_ows(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t')) {
+            self.pos += 1;
+        }
+    }
+
+    fn finish(&self) -> Result<(), StructuredFieldError> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(StructuredFieldError::TrailingCharacters)
+        }
+    }
+
+    // Consume the separator between list or dictionary members. Returns
+    // false at the end of input.
+    fn next_member(&mut self) -> Result<bool, StructuredFieldError> {
+        self.skip_ows();
+        if self.is_empty() {
+            return Ok(false);
+        }
+        match self.next()? {
+            b',' => {
+                self.skip_ows();
+                if self.is_empty() {
+                    return Err(StructuredFieldError::UnexpectedEnd);
+                }
+                Ok(true)
+            }
+            c => Err(StructuredFieldError::UnexpectedChar(c as char)),
+        }
+    }
+
+    fn parse_list_entry(&mut self) -> Result<ListEntry, StructuredFieldError> {
+        if self.peek() == Some(b'(') {
+            self.parse_inner_list().map(ListEntry::InnerList)
+        } else {
+            self.parse_item().map(ListEntry::Item)
+        }
+    }
+
+    fn parse_inner_list(&mut self) -> Result<InnerList, StructuredFieldError> {
+        self.pos += 1;
+        let mut items = Vec::new();
+        loop {
+            self.skip_sp();
+            match self.peek() {
+                Some(b')') => {
+                    self.pos += 1;
+                    let params = self.parse_parameters()?;
+                    return Ok(InnerList {
+                        items,
+                        params,
+                    });
+                }
+                Some(_) => {
+                    items.push(self.parse_item()?);
+                    match self.peek() {
+                        Some(b' ' | b')') => {}
+                        Some(c) => {
+                            return Err(StructuredFieldError::UnexpectedChar(
+                                c as char,
+                            ));
+                        }
+                        None => {
+                            return Err(StructuredFieldError::UnexpectedEnd);
+                        }
+                    }
+                }
+                None => return Err(StructuredFieldError::UnexpectedEnd),
+            }
+        }
+    }
+
+    fn parse_item(&mut self) -> Result<Item, StructuredFieldError> {
+        let bare_item = self.parse_bare_item()?;
+        let params = self.parse_parameters()?;
+        Ok(Item {
+            bare_item,
+            params,
+        })
+    }
+
+    fn parse_parameters(
+        &mut self,
+    ) -> Result<Parameters, StructuredFieldError> {
+        let mut params: Parameters = Vec::new();
+        while self.peek() == Some(b';') {
+            self.pos += 1;
+            self.skip_sp();
+            let key = self.parse_key()?;
+            let value = if self.peek() == Some(b'=') {
+                self.pos += 1;
+                self.parse_bare_item()?
+            } else {
+                BareItem::Boolean(true)
+            };
+            if let Some(existing) = params.iter_mut().find(|(k, _)| *k == key)
+            {
+                existing.1 = value;
+            } else {
+                params.push((key, value));
+            }
+        }
+        Ok(params)
+    }
+
+    fn parse_key(&mut self) -> Result<String, StructuredFieldError> {
+        let start = self.pos;
+        match self.peek() {
+            Some(b'a'..=b'z' | b'*') => self.pos += 1,
+            Some(c) => {
+                return Err(StructuredFieldError::UnexpectedChar(c as char));
+            }
+            None => return Err(StructuredFieldError::UnexpectedEnd),
+        }
+        while matches!(
+            self.peek(),
+            Some(b'a'..=b'z' | b'0'..=b'9' | b'_' | b'-' | b'.' | b'*')
+        ) {
+            self.pos += 1;
+        }
+        Ok(String::from_utf8_lossy(&self.input[start..self.pos]).into_owned())
+    }
+
+    fn parse_bare_item(&mut self) -> Result<BareItem, StructuredFieldError> {
+        match self.peek() {
+            Some(b'-' | b'0'..=b'9') => self.parse_number(),
+            Some(b'"') => self.parse_string().map(BareItem::String),
+            Some(b':') => {
+                self.parse_byte_sequence().map(BareItem::ByteSequence)
+            }
+            Some(b'?') => self.parse_boolean().map(BareItem::Boolean),
+            Some(c) if c.is_ascii_alphabetic() || c == b'*' => {
+                Ok(BareItem::Token(self.parse_token()))
+            }
+            Some(c) => Err(StructuredFieldError::UnexpectedChar(c as char)),
+            None => Err(StructuredFieldError::UnexpectedEnd),
+        }
+    }
+
+    fn parse_number(&mut self) -> Result<BareItem, StructuredFieldError> {
+        let start = self.pos;
+        if self.peek() == Some(b'-') {
+            self.pos += 1;
+        }
+        let mut is_decimal = false;
+        while let Some(c) = self.peek() {
+            match c {
+                b'0'..=b'9' => self.pos += 1,
+                b'.' if !is_decimal => {
+                    is_decimal = true;
+                    self.pos += 1;
+                }
+                _ => break,
+            }
+        }
+        // Invariant: only ascii digits, '-' and '.' consumed
+        let num = str::from_utf8(&self.input[start..self.pos])
+            .map_err(|_| StructuredFieldError::InvalidNumber)?;
+        if is_decimal {
+            if num.ends_with('.') {
+                return Err(StructuredFieldError::InvalidNumber);
+            }
+            num.parse::<f64>()
+                .map(BareItem::Decimal)
+                .map_err(|_| StructuredFieldError::InvalidNumber)
+        } else {
+            num.parse::<i64>()
+                .ok()
+                .filter(|n| n.unsigned_abs() <= 999_999_999_999_999)
+                .map(BareItem::Integer)
+                .ok_or(StructuredFieldError::InvalidNumber)
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String, StructuredFieldError> {
+        self.pos += 1;
+        let mut out = String::new();
+        loop {
+            match self.next()? {
+                b'"' => return Ok(out),
+                b'\\' => match self.next()? {
+                    c @ (b'"' | b'\\') => out.push(c as char),
+                    _ => return Err(StructuredFieldError::InvalidString),
+                },
+                c @ 0x20..=0x7e => out.push(c as char),
+                _ => return Err(StructuredFieldError::InvalidString),
+            }
+        }
+    }
+
+    fn parse_token(&mut self) -> String {
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            if is_tchar(c) || c == b':' || c == b'/' {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+        String::from_utf8_lossy(&self.input[start..self.pos]).into_owned()
+    }
+
+    fn parse_byte_sequence(
+        &mut self,
+    ) -> Result<Vec<u8>, StructuredFieldError> {
+        self.pos += 1;
+        let start = self.pos;
+        let end = self.input[start..]
+            .iter()
+            .position(|b| *b == b':')
+            .ok_or(StructuredFieldError::UnexpectedEnd)?;
+        self.pos = start + end + 1;
+        STANDARD
+            .decode(&self.input[start..start + end])
+            .map_err(|_| StructuredFieldError::InvalidByteSequence)
+    }
+
+    fn parse_boolean(&mut self) -> Result<bool, StructuredFieldError> {
+        self.pos += 1;
+        match self.next()? {
+            b'1' => Ok(true),
+            b'0' => Ok(false),
+            c => Err(StructuredFieldError::UnexpectedChar(c as char)),
+        }
+    }
+}
+
+#[inline]
+pub fn is_tchar(c: u8) -> bool {
+    c.is_ascii_alphanumeric()
+        || matches!(
+            c,
+            b'!' | b'#'
+                | b'$'
+                | b'%'
+                | b'&'
+                | b'\''
+                | b'*'
+                | b'+'
+                | b'-'
+                | b'.'
+                | b'^'
+                | b'_'
+                | b'`'
+                | b'|'
+                | b'~'
+        )
+}
+
+// ---------- Serialize
+pub fn serialize_bare_item(item: &BareItem, out: &mut String) {
+    match item {
+        BareItem::Integer(i) => {
+            let _ = write!(out, "{i}");
+        }
+        BareItem::Decimal(d) => {
+            let rounded = (d * 1000.0).round() / 1000.0;
+            if rounded.fract() == 0.0 {
+                let _ = write!(out, "{rounded:.1}");
+            } else {
+                let _ = write!(out, "{rounded}");
+            }
+        }
+        BareItem::String(s) => {
+            out.push('"');
+            for c in s.chars() {
+                if c == '"' || c == '\\' {
+                    out.push('\\');
+                }
+                out.push(c);
+            }
+            out.push('"');
+        }
+        BareItem::Token(t) => out.push_str(t),
+        BareItem::ByteSequence(b) => {
+            out.push(':');
+            out.push_str(&STANDARD.encode(b));
+            out.push(':');
+        }
+        BareItem::Boolean(b) => out.push_str(if *b {
+            "?1"
+        } else {
+            "?0"
+        }),
+    }
+}
+
+pub fn serialize_parameters(params: &Parameters, out: &mut String) {
+    for (key, value) in params {
+        out.push(';');
+        out.push_str(key);
+        if *value != BareItem::Boolean(true) {
+            out.push('=');
+            serialize_bare_item(value, out);
+        }
+    }
+}
+
+pub fn serialize_item(item: &Item, out: &mut String) {
+    serialize_bare_item(&item.bare_item, out);
+    serialize_parameters(&item.params, out);
+}
+
+pub fn serialize_list_entry(entry: &ListEntry, out: &mut String) {
+    match entry {
+        ListEntry::Item(item) => serialize_item(item, out),
+        ListEntry::InnerList(list) => {
+            out.push('(');
+            for (index, item) in list.items.iter().enumerate() {
+                if index > 0 {
+                    out.push(' ');
+                }
+                serialize_item(item, out);
+            }
+            out.push(')');
+            serialize_parameters(&list.params, out);
+        }
+    }
+}
+
+pub fn serialize_list(list: &List) -> String {
+    let mut out = String::new();
+    for (index, entry) in list.iter().enumerate() {
+        if index > 0 {
+            out.push_str(", ");
+        }
+        serialize_list_entry(entry, &mut out);
+    }
+    out
+}
+
+pub fn serialize_dictionary(dict: &Dictionary) -> String {
+    let mut out = String::new();
+    for (index, (key, entry)) in dict.iter().enumerate() {
+        if index > 0 {
+            out.push_str(", ");
+        }
+        out.push_str(key);
+        match entry {
+            ListEntry::Item(Item {
+                bare_item: BareItem::Boolean(true),
+                params,
+            }) => serialize_parameters(params, &mut out),
+            _ => {
+                out.push('=');
+                serialize_list_entry(entry, &mut out);
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sf_parse_item_integer() {
+        let item = parse_item("42").unwrap();
+        assert_eq!(item.bare_item, BareItem::Integer(42));
+        let item = parse_item("-42").unwrap();
+        assert_eq!(item.bare_item, BareItem::Integer(-42));
+    }
+
+    #[test]
+    fn test_sf_parse_item_decimal() {
+        let item = parse_item("4.5").unwrap();
+        assert_eq!(item.bare_item, BareItem::Decimal(4.5));
+        assert!(parse_item("4.").is_err());
+    }
+
+    #[test]
+    fn test_sf_parse_item_string() {
+        let item = parse_item(r#""hello \"world\"""#).unwrap();
+        assert_eq!(
+            item.bare_item,
+            BareItem::String(r#"hello "world""#.into())
+        );
+        assert!(parse_item(r#""unterminated"#).is_err());
+    }
+
+    #[test]
+    fn test_sf_parse_item_token_with_params() {
+        let item = parse_item("raw;a=1;b").unwrap();
+        assert_eq!(item.bare_item, BareItem::Token("raw".into()));
+        assert_eq!(
+            item.params,
+            vec![
+                ("a".to_string(), BareItem::Integer(1)),
+                ("b".to_string(), BareItem::Boolean(true))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sf_parse_item_byte_sequence() {
+        let item = parse_item(":aGVsbG8=:").unwrap();
+        assert_eq!(item.bare_item, BareItem::ByteSequence(b"hello".to_vec()));
+        assert_eq!(
+            parse_item(":!!:"),
+            Err(StructuredFieldError::InvalidByteSequence)
+        );
+    }
+
+    #[test]
+    fn test_sf_parse_item_boolean() {
+        assert_eq!(
+            parse_item("?1").unwrap().bare_item,
+            BareItem::Boolean(true)
+        );
+        assert_eq!(
+            parse_item("?0").unwrap().bare_item,
+            BareItem::Boolean(false)
+        );
+    }
+
+    #[test]
+    fn test_sf_parse_item_trailing() {
+        assert_eq!(
+            parse_item("1 2"),
+            Err(StructuredFieldError::TrailingCharacters)
+        );
+    }
+
+    #[test]
+    fn test_sf_parse_list() {
+        let list = parse_list("a, (b \"c\");x=1, 3").unwrap();
+        assert_eq!(list.len(), 3);
+        let inner = list[1].as_inner_list().unwrap();
+        assert_eq!(inner.items.len(), 2);
+        assert_eq!(inner.params[0].1, BareItem::Integer(1));
+        assert!(parse_list("a,").is_err());
+    }
+
+    #[test]
+    fn test_sf_parse_dictionary() {
+        let dict = parse_dictionary(
+            r#"match="/app/*", match-dest=("document" "frame"), id="v1", type=raw, flag"#,
+        )
+        .unwrap();
+        assert_eq!(dict.len(), 5);
+        assert_eq!(dict[0].0, "match");
+        assert_eq!(
+            dict[0].1.as_item().unwrap().bare_item.as_str(),
+            Some("/app/*")
+        );
+        assert_eq!(dict[1].1.as_inner_list().unwrap().items.len(), 2);
+        assert_eq!(
+            dict[4].1.as_item().unwrap().bare_item,
+            BareItem::Boolean(true)
+        );
+    }
+
+    #[test]
+    fn test_sf_parse_dictionary_duplicate_last_wins() {
+        let dict = parse_dictionary("a=1, b=2, a=3").unwrap();
+        assert_eq!(dict.len(), 2);
+        assert_eq!(
+            dict[0].1.as_item().unwrap().bare_item,
+            BareItem::Integer(3)
+        );
+    }
+
+    #[test]
+    fn test_sf_serialize_roundtrip() {
+        let input = r#"sha-256=:aGVsbG8=:, id="a\"b", d=("x" y);q=0.5, f"#;
+        let dict = parse_dictionary(input).unwrap();
+        assert_eq!(serialize_dictionary(&dict), input);
+
+        let input = "1, 2.5, ?0";
+        let list = parse_list(input).unwrap();
+        assert_eq!(serialize_list(&list), input);
+    }
+}
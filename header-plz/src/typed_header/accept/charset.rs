@@ -0,0 +1,89 @@
+// Accept-Charset
+// https://www.rfc-editor.org/rfc/rfc9110.html#section-12.5.2
+
+use std::fmt::{self, Display};
+
+use crate::const_headers::ACCEPT_CHARSET;
+use crate::typed_header::accept::{
+    AcceptError, QValue, QualityItem, WILDCARD, fmt_quality_list, negotiate,
+    parse_quality_list,
+};
+use crate::typed_header::impl_typed_header;
+use crate::typed_header::list::is_token;
+
+/* Charsets acceptable in textual responses, names compare case-insensitive.
+ * A charset not listed is not acceptable unless "*" is present.
+ */
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AcceptCharset {
+    items: Vec<QualityItem>,
+}
+
+impl AcceptCharset {
+    pub fn new(items: Vec<QualityItem>) -> Self {
+        AcceptCharset {
+            items,
+        }
+    }
+
+    // absent header
+    pub fn any() -> Self {
+        AcceptCharset::new(vec![QualityItem::new(WILDCARD, QValue::ONE)])
+    }
+
+    pub fn parse(value: &[u8]) -> Result<Self, AcceptError> {
+        parse_quality_list(value, is_token).map(AcceptCharset::new)
+    }
+
+    pub fn items(&self) -> &[QualityItem] {
+        &self.items
+    }
+
+    pub fn quality(&self, charset: &str) -> QValue {
+        let charset = charset.to_ascii_lowercase();
+        let find =
+            |value: &str| self.items.iter().find(|item| item.value == value);
+        find(&charset)
+            .or_else(|| find(WILDCARD))
+            .map_or(QValue::ZERO, |item| item.q)
+    }
+
+    pub fn negotiate<'a, T>(&self, available: &'a [T]) -> Option<&'a T>
+    where
+        T: AsRef<str>,
+    {
+        negotiate(available, |charset| self.quality(charset.as_ref()))
+    }
+}
+
+impl Display for AcceptCharset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_quality_list(&self.items, f)
+    }
+}
+
+impl_typed_header!(AcceptCharset, ACCEPT_CHARSET, AcceptError);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accept_charset() {
+        let ac =
+            AcceptCharset::parse(b"iso-8859-5, unicode-1-1;q=0.8").unwrap();
+        assert_eq!(ac.quality("ISO-8859-5"), QValue::ONE);
+        assert_eq!(ac.quality("unicode-1-1").as_thousandths(), 800);
+        assert!(ac.quality("utf-8").is_zero());
+        let available = ["utf-8", "unicode-1-1"];
+        assert_eq!(ac.negotiate(&available), Some(&"unicode-1-1"));
+    }
+
+    #[test]
+    fn test_accept_charset_wildcard() {
+        let ac = AcceptCharset::parse(b"utf-8;q=0, *;q=0.1").unwrap();
+        assert!(ac.quality("utf-8").is_zero());
+        assert_eq!(ac.quality("latin1").as_thousandths(), 100);
+        assert_eq!(ac.to_string(), "utf-8;q=0, *;q=0.1");
+    }
+}
@@ -0,0 +1,161 @@
+// Accept-Encoding
+// https://www.rfc-editor.org/rfc/rfc9110.html#section-12.5.3
+
+use std::fmt::{self, Display};
+
+use crate::body_headers::content_encoding::ContentEncoding;
+use crate::const_headers::ACCEPT_ENCODING;
+use crate::typed_header::accept::{
+    AcceptError, QValue, QualityItem, WILDCARD, fmt_quality_list, negotiate,
+    parse_quality_list,
+};
+use crate::typed_header::impl_typed_header;
+use crate::typed_header::list::is_token;
+
+/* Content codings acceptable in the response.
+ *
+ * An empty field value accepts only identity, an absent field accepts any
+ * coding, see any(). Identity is acceptable unless refused by "identity;q=0"
+ * or by "*;q=0" without an identity member. When not listed it has the
+ * lowest weight, so that a listed coding is preferred.
+ */
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AcceptEncoding {
+    items: Vec<QualityItem>,
+}
+
+impl AcceptEncoding {
+    pub fn new(items: Vec<QualityItem>) -> Self {
+        AcceptEncoding {
+            items,
+        }
+    }
+
+    // absent header
+    pub fn any() -> Self {
+        AcceptEncoding::new(vec![QualityItem::new(WILDCARD, QValue::ONE)])
+    }
+
+    pub fn parse(value: &[u8]) -> Result<Self, AcceptError> {
+        parse_quality_list(value, is_token).map(AcceptEncoding::new)
+    }
+
+    pub fn items(&self) -> &[QualityItem] {
+        &self.items
+    }
+
+    fn find(&self, coding: &str) -> Option<QValue> {
+        self.items
+            .iter()
+            .find(|item| {
+                item.value == coding
+                    || item.value.strip_prefix("x-") == Some(coding)
+            })
+            .map(|item| item.q)
+    }
+
+    pub fn quality(&self, encoding: &ContentEncoding) -> QValue {
+        let coding = encoding.as_ref().to_ascii_lowercase();
+        if let Some(q) = self.find(&coding) {
+            return q;
+        }
+        if let Some(q) = self.find(WILDCARD) {
+            return q;
+        }
+        if *encoding == ContentEncoding::Identity {
+            // lowest weight that is still acceptable
+            return QValue::from_thousandths(1).unwrap_or(QValue::ONE);
+        }
+        QValue::ZERO
+    }
+
+    pub fn is_acceptable(&self, encoding: &ContentEncoding) -> bool {
+        !self.quality(encoding).is_zero()
+    }
+
+    /* Best coding of available, in order of the server preference. None
+     * when none is acceptable, ie. a 415 or an unencoded response.
+     */
+    pub fn negotiate<'a>(
+        &self,
+        available: &'a [ContentEncoding],
+    ) -> Option<&'a ContentEncoding> {
+        negotiate(available, |encoding| self.quality(encoding))
+    }
+}
+
+impl Display for AcceptEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_quality_list(&self.items, f)
+    }
+}
+
+impl_typed_header!(AcceptEncoding, ACCEPT_ENCODING, AcceptError);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OneHeaderMap;
+    use crate::typed_header::TypedHeader;
+    use ContentEncoding::*;
+    use bytes::BytesMut;
+
+    fn accept(value: &str) -> AcceptEncoding {
+        AcceptEncoding::parse(value.as_bytes()).unwrap()
+    }
+
+    fn q(accept: &AcceptEncoding, encoding: ContentEncoding) -> u16 {
+        accept.quality(&encoding).as_thousandths()
+    }
+
+    #[test]
+    fn test_accept_encoding_quality() {
+        let ae = accept("gzip;q=0.8, br, *;q=0.1");
+        assert_eq!(q(&ae, Brotli), 1000);
+        assert_eq!(q(&ae, Gzip), 800);
+        assert_eq!(q(&ae, Zstd), 100);
+        assert_eq!(q(&ae, Identity), 100);
+    }
+
+    #[test]
+    fn test_accept_encoding_identity() {
+        let ae = accept("gzip");
+        assert!(ae.is_acceptable(&Identity));
+        assert!(!ae.is_acceptable(&Brotli));
+        assert!(!accept("gzip, identity;q=0").is_acceptable(&Identity));
+        assert!(!accept("*;q=0").is_acceptable(&Identity));
+        assert!(accept("*;q=0, identity").is_acceptable(&Identity));
+        // empty value, only identity
+        let ae = accept("");
+        assert!(ae.is_acceptable(&Identity));
+        assert!(!ae.is_acceptable(&Gzip));
+    }
+
+    #[test]
+    fn test_accept_encoding_x_prefix() {
+        let ae = accept("x-gzip");
+        assert!(ae.is_acceptable(&Gzip));
+    }
+
+    #[test]
+    fn test_accept_encoding_negotiate() {
+        let available = [Zstd, Brotli, Gzip, Identity];
+        assert_eq!(accept("gzip, br").negotiate(&available), Some(&Brotli));
+        assert_eq!(
+            accept("gzip, br;q=0.9").negotiate(&available),
+            Some(&Gzip)
+        );
+        assert_eq!(accept("deflate").negotiate(&available), Some(&Identity));
+        assert_eq!(accept("*;q=0").negotiate(&available), None);
+        assert_eq!(AcceptEncoding::any().negotiate(&available), Some(&Zstd));
+    }
+
+    #[test]
+    fn test_accept_encoding_typed_header() {
+        let input = "Accept-Encoding: gzip;q=1.0, deflate;q=0.50\r\n\
+                     Accept-Encoding: BR\r\n\r\n";
+        let map = OneHeaderMap::from(BytesMut::from(input));
+        let ae = AcceptEncoding::decode(&map).unwrap().unwrap();
+        assert_eq!(ae.to_string(), "gzip, deflate;q=0.5, br");
+    }
+}
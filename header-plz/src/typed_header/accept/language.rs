@@ -0,0 +1,155 @@
+// Accept-Language
+// https://www.rfc-editor.org/rfc/rfc9110.html#section-12.5.4
+
+use std::fmt::{self, Display};
+
+use crate::const_headers::ACCEPT_LANGUAGE;
+use crate::typed_header::accept::{
+    AcceptError, QValue, QualityItem, WILDCARD, fmt_quality_list, negotiate,
+    parse_quality_list,
+};
+use crate::typed_header::impl_typed_header;
+
+/* Natural languages preferred in the response.
+ *
+ * Tags are matched with basic filtering (RFC 4647 3.3.1), ie. "en" matches
+ * "en" and "en-US", and the most specific matching range gives the weight.
+ */
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AcceptLanguage {
+    items: Vec<QualityItem>,
+}
+
+impl AcceptLanguage {
+    pub fn new(items: Vec<QualityItem>) -> Self {
+        AcceptLanguage {
+            items,
+        }
+    }
+
+    // absent header
+    pub fn any() -> Self {
+        AcceptLanguage::new(vec![QualityItem::new(WILDCARD, QValue::ONE)])
+    }
+
+    pub fn parse(value: &[u8]) -> Result<Self, AcceptError> {
+        parse_quality_list(value, is_language_range).map(AcceptLanguage::new)
+    }
+
+    pub fn items(&self) -> &[QualityItem] {
+        &self.items
+    }
+
+    pub fn quality(&self, tag: &str) -> QValue {
+        let tag = tag.to_ascii_lowercase();
+        self.items
+            .iter()
+            .filter(|item| range_matches(&item.value, &tag))
+            .max_by_key(|item| {
+                // wildcard is the least specific
+                (!item.is_wildcard(), item.value.len())
+            })
+            .map_or(QValue::ZERO, |item| item.q)
+    }
+
+    // best of the supported tags, ties go to the earlier supported tag
+    pub fn negotiate<'a, T>(&self, supported: &'a [T]) -> Option<&'a T>
+    where
+        T: AsRef<str>,
+    {
+        negotiate(supported, |tag| self.quality(tag.as_ref()))
+    }
+}
+
+// language-range = (1*8ALPHA *("-" 1*8alphanum)) / "*"
+fn is_language_range(value: &[u8]) -> bool {
+    if value == WILDCARD.as_bytes() {
+        return true;
+    }
+    let mut subtags = value.split(|&b| b == b'-');
+    let valid = |subtag: &[u8], alpha: bool| {
+        (1..=8).contains(&subtag.len())
+            && subtag.iter().all(|b| {
+                if alpha {
+                    b.is_ascii_alphabetic()
+                } else {
+                    b.is_ascii_alphanumeric()
+                }
+            })
+    };
+    subtags.next().is_some_and(|primary| valid(primary, true))
+        && subtags.all(|subtag| valid(subtag, false))
+}
+
+// range and tag are lowercase
+fn range_matches(range: &str, tag: &str) -> bool {
+    range == WILDCARD
+        || tag
+            .strip_prefix(range)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('-'))
+}
+
+impl Display for AcceptLanguage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_quality_list(&self.items, f)
+    }
+}
+
+impl_typed_header!(AcceptLanguage, ACCEPT_LANGUAGE, AcceptError);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn accept(value: &str) -> AcceptLanguage {
+        AcceptLanguage::parse(value.as_bytes()).unwrap()
+    }
+
+    fn q(accept: &AcceptLanguage, tag: &str) -> u16 {
+        accept.quality(tag).as_thousandths()
+    }
+
+    #[test]
+    fn test_accept_language_quality() {
+        // https://www.rfc-editor.org/rfc/rfc9110.html#section-12.5.4
+        let al = accept("da, en-gb;q=0.8, en;q=0.7");
+        assert_eq!(q(&al, "da"), 1000);
+        assert_eq!(q(&al, "en-GB"), 800);
+        assert_eq!(q(&al, "en-US"), 700);
+        assert_eq!(q(&al, "en"), 700);
+        assert_eq!(q(&al, "fr"), 0);
+        // not a prefix on a subtag boundary
+        assert_eq!(q(&accept("en"), "eng"), 0);
+    }
+
+    #[test]
+    fn test_accept_language_wildcard() {
+        let al = accept("fr-CH, fr;q=0.9, *;q=0.5, de;q=0");
+        assert_eq!(q(&al, "fr-ch"), 1000);
+        assert_eq!(q(&al, "fr-FR"), 900);
+        assert_eq!(q(&al, "en"), 500);
+        assert_eq!(q(&al, "de-AT"), 0);
+    }
+
+    #[test]
+    fn test_accept_language_negotiate() {
+        let supported = ["en-US", "fr", "de"];
+        let al = accept("fr-CH, fr;q=0.9, en;q=0.8");
+        assert_eq!(al.negotiate(&supported), Some(&"fr"));
+        assert_eq!(accept("ja").negotiate(&supported), None);
+        assert_eq!(
+            AcceptLanguage::any().negotiate(&supported),
+            Some(&"en-US")
+        );
+    }
+
+    #[test]
+    fn test_accept_language_invalid() {
+        assert_eq!(
+            AcceptLanguage::parse(b"en_US"),
+            Err(AcceptError::InvalidMember("en_US".into()))
+        );
+        assert!(AcceptLanguage::parse(b"toolongprimary").is_err());
+        assert!(AcceptLanguage::parse(b"zh-Hant-2021").is_ok());
+    }
+}
@@ -0,0 +1,99 @@
+// Accept, media ranges are parsed by mime_plz
+// https://www.rfc-editor.org/rfc/rfc9110.html#section-12.5.1
+
+pub use mime_plz::{Accept, MediaRange};
+use mime_plz::{MediaType, is_acceptable_content_type};
+
+use crate::const_headers::{ACCEPT, CONTENT_TYPE};
+use crate::message_head::header_map::{HMap, Hmap};
+use crate::typed_header::TypedHeader;
+use crate::typed_header::accept::AcceptError;
+use crate::typed_header::list::join_lines;
+
+impl TypedHeader for Accept {
+    type Error = AcceptError;
+
+    const NAME: &'static [u8] = ACCEPT;
+
+    fn decode_values<'a, I>(values: I) -> Result<Self, Self::Error>
+    where
+        I: Iterator<Item = &'a [u8]>,
+    {
+        let joined = join_lines(values);
+        let value =
+            str::from_utf8(&joined).map_err(|_| AcceptError::NotUtf8)?;
+        Ok(Accept::parse(value)?)
+    }
+
+    fn encode_value(&self) -> String {
+        self.to_string()
+    }
+}
+
+/* Whether the Content-Type of a response satisfies the Accept of its
+ * request, see mime_plz::is_acceptable_content_type.
+ */
+pub fn response_satisfies_accept<T, U>(
+    request_map: &HMap<T>,
+    response_map: &HMap<U>,
+) -> bool
+where
+    T: Hmap,
+    U: Hmap,
+{
+    let Some(content_type) = response_map
+        .values_of_key(CONTENT_TYPE)
+        .next()
+        .and_then(|value| str::from_utf8(value).ok())
+    else {
+        return false;
+    };
+    match Accept::decode(request_map) {
+        Some(Ok(accept)) => MediaType::parse(content_type)
+            .is_ok_and(|media_type| accept.is_acceptable(&media_type)),
+        _ => is_acceptable_content_type(None, content_type),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OneHeaderMap;
+    use bytes::BytesMut;
+
+    fn map(input: &str) -> OneHeaderMap {
+        OneHeaderMap::from(BytesMut::from(input))
+    }
+
+    #[test]
+    fn test_accept_typed_header() {
+        let input = "Accept: text/html;q=0.9\r\n\
+                     Accept: application/json\r\n\r\n";
+        let accept = Accept::decode(&map(input)).unwrap().unwrap();
+        assert_eq!(accept.ranges().len(), 2);
+        assert_eq!(accept.to_string(), "text/html; q=0.9, application/json");
+        assert!(matches!(
+            Accept::decode(&map("Accept: */html\r\n\r\n")),
+            Some(Err(AcceptError::MediaRange(_)))
+        ));
+    }
+
+    #[test]
+    fn test_response_satisfies_accept() {
+        let request = map("Accept: text/html, application/*;q=0.5\r\n\r\n");
+        let html = map("Content-Type: text/html; charset=utf-8\r\n\r\n");
+        let json = map("Content-Type: application/json\r\n\r\n");
+        let image = map("Content-Type: image/png\r\n\r\n");
+        assert!(response_satisfies_accept(&request, &html));
+        assert!(response_satisfies_accept(&request, &json));
+        assert!(!response_satisfies_accept(&request, &image));
+        // no content type
+        assert!(!response_satisfies_accept(&request, &map("A: b\r\n\r\n")));
+        // absent or invalid accept
+        assert!(response_satisfies_accept(&map("A: b\r\n\r\n"), &image));
+        assert!(response_satisfies_accept(
+            &map("Accept: text\r\n\r\n"),
+            &image
+        ));
+    }
+}
@@ -0,0 +1,185 @@
+// Proactive negotiation fields with weights
+// https://www.rfc-editor.org/rfc/rfc9110.html#section-12.4
+
+use std::fmt::{self, Display};
+
+use mime_plz::MediaTypeError;
+pub use mime_plz::QValue;
+use thiserror::Error;
+
+use crate::typed_header::list::{split_list, split_unquoted, trim_ows};
+
+pub mod charset;
+pub mod encoding;
+pub mod language;
+pub mod media;
+
+pub use charset::AcceptCharset;
+pub use encoding::AcceptEncoding;
+pub use language::AcceptLanguage;
+pub use media::{Accept, MediaRange};
+
+pub const WILDCARD: &str = "*";
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum AcceptError {
+    #[error("not utf8")]
+    NotUtf8,
+    #[error("invalid qvalue| {0}")]
+    InvalidQValue(String),
+    #[error("invalid member| {0}")]
+    InvalidMember(String),
+    #[error("media range| {0}")]
+    MediaRange(#[from] MediaTypeError),
+}
+
+/* Member of a weighted list, value is lowercase. Parameters other than the
+ * weight are not used by these fields and are dropped.
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QualityItem {
+    pub value: String,
+    pub q: QValue,
+}
+
+impl QualityItem {
+    pub fn new(value: impl Into<String>, q: QValue) -> Self {
+        QualityItem {
+            value: value.into().to_ascii_lowercase(),
+            q,
+        }
+    }
+
+    pub fn is_wildcard(&self) -> bool {
+        self.value == WILDCARD
+    }
+}
+
+impl Display for QualityItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.value)?;
+        if self.q != QValue::ONE {
+            write!(f, ";q={}", self.q)?;
+        }
+        Ok(())
+    }
+}
+
+/* #( value weight ), members are validated by is_valid. The order of the
+ * members is kept.
+ */
+pub(crate) fn parse_quality_list<F>(
+    value: &[u8],
+    is_valid: F,
+) -> Result<Vec<QualityItem>, AcceptError>
+where
+    F: Fn(&[u8]) -> bool,
+{
+    let mut items = Vec::new();
+    for member in split_list(value) {
+        let mut parts = split_unquoted(member, b';');
+        let item = parts.next().unwrap_or_default();
+        if !is_valid(item) {
+            return Err(AcceptError::InvalidMember(
+                String::from_utf8_lossy(member).into_owned(),
+            ));
+        }
+        let mut q = QValue::ONE;
+        for param in parts {
+            let (name, value) = match param.iter().position(|&b| b == b'=') {
+                Some(pos) => (&param[..pos], &param[pos + 1..]),
+                None => (param, &[][..]),
+            };
+            if trim_ows(name).eq_ignore_ascii_case(b"q") {
+                let value = trim_ows(value);
+                q = QValue::parse(value).ok_or_else(|| {
+                    AcceptError::InvalidQValue(
+                        String::from_utf8_lossy(value).into_owned(),
+                    )
+                })?;
+            }
+        }
+        let item = str::from_utf8(item).map_err(|_| AcceptError::NotUtf8)?;
+        items.push(QualityItem::new(item, q));
+    }
+    Ok(items)
+}
+
+pub(crate) fn fmt_quality_list(
+    items: &[QualityItem],
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    for (index, item) in items.iter().enumerate() {
+        if index > 0 {
+            f.write_str(", ")?;
+        }
+        item.fmt(f)?;
+    }
+    Ok(())
+}
+
+/* Best of the available values, the highest weight wins and ties go to the
+ * earlier available value, ie. the server preference. Values with weight 0
+ * are never chosen.
+ */
+pub(crate) fn negotiate<T, F>(available: &[T], quality: F) -> Option<&T>
+where
+    F: Fn(&T) -> QValue,
+{
+    let mut best: Option<(&T, QValue)> = None;
+    for candidate in available {
+        let q = quality(candidate);
+        if !q.is_zero() && best.is_none_or(|(_, best_q)| q > best_q) {
+            best = Some((candidate, q));
+        }
+    }
+    best.map(|(candidate, _)| candidate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn q(thousandths: u16) -> QValue {
+        QValue::from_thousandths(thousandths).unwrap()
+    }
+
+    #[test]
+    fn test_parse_quality_list() {
+        let items =
+            parse_quality_list(b"GZIP;q=0.5, br ; Q=1, *;q=0", |_| true)
+                .unwrap();
+        assert_eq!(
+            items,
+            vec![
+                QualityItem::new("gzip", q(500)),
+                QualityItem::new("br", QValue::ONE),
+                QualityItem::new("*", QValue::ZERO),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_quality_list_invalid() {
+        assert_eq!(
+            parse_quality_list(b"gzip;q=2", |_| true),
+            Err(AcceptError::InvalidQValue("2".into()))
+        );
+        assert_eq!(
+            parse_quality_list(b"a b;q=1", |_| false),
+            Err(AcceptError::InvalidMember("a b;q=1".into()))
+        );
+    }
+
+    #[test]
+    fn test_negotiate_ties_server_order() {
+        let available = ["a", "b", "c"];
+        let quality = |v: &&str| match *v {
+            "a" => q(500),
+            "b" | "c" => q(800),
+            _ => QValue::ZERO,
+        };
+        assert_eq!(negotiate(&available, quality), Some(&"b"));
+        assert_eq!(negotiate(&available, |_| QValue::ZERO), None);
+    }
+}
@@ -0,0 +1,239 @@
+// Age, Date, Expires, Pragma and Vary
+// https://www.rfc-editor.org/rfc/rfc9111.html#section-5
+
+use std::fmt::{self, Display};
+use std::time::SystemTime;
+
+use crate::const_headers::{AGE, DATE, EXPIRES, PRAGMA, VARY};
+use crate::typed_header::cache::{CacheHeaderError, parse_delta_seconds};
+use crate::typed_header::date::{format_http_date, parse_http_date};
+use crate::typed_header::list::{is_token, split_list};
+use crate::typed_header::{impl_first_line_header, impl_typed_header};
+
+// seconds the response has been in caches
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Age(pub u64);
+
+impl Age {
+    pub fn parse(value: &[u8]) -> Result<Self, CacheHeaderError> {
+        let value = value.trim_ascii();
+        parse_delta_seconds(value).map(Age).ok_or_else(|| {
+            CacheHeaderError::InvalidDeltaSeconds(
+                String::from_utf8_lossy(value).into_owned(),
+            )
+        })
+    }
+}
+
+impl Display for Age {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+// origination time of the message
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Date(pub SystemTime);
+
+impl Date {
+    pub fn parse(value: &[u8]) -> Result<Self, CacheHeaderError> {
+        parse_http_date(value).map(Date).ok_or(CacheHeaderError::InvalidDate)
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&format_http_date(self.0))
+    }
+}
+
+/* An invalid date, especially "0", is a time in the past and is kept as
+ * None. Serialized as "0".
+ */
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Expires(pub Option<SystemTime>);
+
+impl Expires {
+    pub fn parse(value: &[u8]) -> Result<Self, CacheHeaderError> {
+        Ok(Expires(parse_http_date(value)))
+    }
+}
+
+impl Display for Expires {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(time) => f.write_str(&format_http_date(time)),
+            None => f.write_str("0"),
+        }
+    }
+}
+
+/* Deprecated request header, "no-cache" is honoured only when the request
+ * has no Cache-Control.
+ */
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Pragma {
+    pub no_cache: bool,
+    pub extensions: Vec<String>,
+}
+
+impl Pragma {
+    pub fn parse(value: &[u8]) -> Result<Self, CacheHeaderError> {
+        let mut pragma = Pragma::default();
+        for member in split_list(value) {
+            let member = str::from_utf8(member)
+                .map_err(|_| CacheHeaderError::NotUtf8)?;
+            if member.eq_ignore_ascii_case("no-cache") {
+                pragma.no_cache = true;
+            } else {
+                pragma.extensions.push(member.to_string());
+            }
+        }
+        Ok(pragma)
+    }
+}
+
+impl Display for Pragma {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let no_cache = self.no_cache.then_some("no-cache");
+        let members = no_cache
+            .into_iter()
+            .chain(self.extensions.iter().map(String::as_str));
+        for (index, member) in members.enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(member)?;
+        }
+        Ok(())
+    }
+}
+
+// request fields the response varies on, names are lowercase
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Vary {
+    Any,
+    Fields(Vec<String>),
+}
+
+impl Vary {
+    pub fn parse(value: &[u8]) -> Result<Self, CacheHeaderError> {
+        let mut fields = Vec::new();
+        for member in split_list(value) {
+            if member == b"*" {
+                return Ok(Vary::Any);
+            }
+            if !is_token(member) {
+                return Err(CacheHeaderError::InvalidFieldName(
+                    String::from_utf8_lossy(member).into_owned(),
+                ));
+            }
+            let name = String::from_utf8_lossy(member).to_ascii_lowercase();
+            if !fields.contains(&name) {
+                fields.push(name);
+            }
+        }
+        Ok(Vary::Fields(fields))
+    }
+
+    pub fn is_any(&self) -> bool {
+        matches!(self, Vary::Any)
+    }
+
+    // field names, empty for "*"
+    pub fn fields(&self) -> &[String] {
+        match self {
+            Vary::Any => &[],
+            Vary::Fields(fields) => fields,
+        }
+    }
+}
+
+impl Display for Vary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Vary::Any => f.write_str("*"),
+            Vary::Fields(fields) => f.write_str(&fields.join(", ")),
+        }
+    }
+}
+
+impl_first_line_header!(Age, AGE, CacheHeaderError);
+impl_first_line_header!(Date, DATE, CacheHeaderError);
+impl_first_line_header!(Expires, EXPIRES, CacheHeaderError);
+impl_typed_header!(Pragma, PRAGMA, CacheHeaderError);
+impl_typed_header!(Vary, VARY, CacheHeaderError);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OneHeaderMap;
+    use crate::typed_header::TypedHeader;
+    use crate::typed_header::date::from_unix_secs;
+    use bytes::BytesMut;
+
+    const RFC_EXAMPLE: &str = "Sun, 06 Nov 1994 08:49:37 GMT";
+
+    fn one(input: &str) -> OneHeaderMap {
+        OneHeaderMap::from(BytesMut::from(input))
+    }
+
+    #[test]
+    fn test_age() {
+        assert_eq!(Age::parse(b" 60 "), Ok(Age(60)));
+        assert_eq!(
+            Age::parse(b"-1"),
+            Err(CacheHeaderError::InvalidDeltaSeconds("-1".into()))
+        );
+        // first line wins
+        let map = one("Age: 10\r\nAge: 20\r\n\r\n");
+        assert_eq!(Age::decode(&map), Some(Ok(Age(10))));
+    }
+
+    #[test]
+    fn test_date_first_line() {
+        let input = format!("Date: {RFC_EXAMPLE}\r\nDate: 0\r\n\r\n");
+        let date = Date::decode(&one(&input)).unwrap().unwrap();
+        assert_eq!(date, Date(from_unix_secs(784_111_777)));
+        assert_eq!(date.to_string(), RFC_EXAMPLE);
+        assert_eq!(
+            Date::parse(b"yesterday"),
+            Err(CacheHeaderError::InvalidDate)
+        );
+    }
+
+    #[test]
+    fn test_expires() {
+        let expires = Expires::parse(RFC_EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(expires, Expires(Some(from_unix_secs(784_111_777))));
+        assert_eq!(expires.to_string(), RFC_EXAMPLE);
+        assert_eq!(Expires::parse(b"0"), Ok(Expires(None)));
+        assert_eq!(Expires(None).to_string(), "0");
+    }
+
+    #[test]
+    fn test_pragma() {
+        let pragma = Pragma::parse(b"No-Cache, x-foo=1").unwrap();
+        assert!(pragma.no_cache);
+        assert_eq!(pragma.extensions, vec!["x-foo=1"]);
+        assert_eq!(pragma.to_string(), "no-cache, x-foo=1");
+    }
+
+    #[test]
+    fn test_vary() {
+        let map = one("Vary: Accept-Encoding, Origin\r\n\
+                       Vary: accept-encoding\r\n\r\n");
+        let vary = Vary::decode(&map).unwrap().unwrap();
+        assert_eq!(
+            vary,
+            Vary::Fields(vec!["accept-encoding".into(), "origin".into()])
+        );
+        assert_eq!(vary.to_string(), "accept-encoding, origin");
+        assert_eq!(Vary::parse(b"Accept, *"), Ok(Vary::Any));
+        assert!(Vary::Any.fields().is_empty());
+        assert_eq!(
+            Vary::parse(b"a b"),
+            Err(CacheHeaderError::InvalidFieldName("a b".into()))
+        );
+    }
+}
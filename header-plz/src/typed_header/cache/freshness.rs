@@ -0,0 +1,659 @@
+// Storing responses and freshness
+// https://www.rfc-editor.org/rfc/rfc9111.html#section-3
+// https://www.rfc-editor.org/rfc/rfc9111.html#section-4.2
+
+use std::time::{Duration, SystemTime};
+
+use thiserror::Error;
+
+use crate::const_headers::{AUTHORIZATION, IF_MODIFIED_SINCE, IF_NONE_MATCH};
+use crate::message_head::header_map::{HMap, Hmap};
+use crate::typed_header::TypedHeader;
+use crate::typed_header::cache::{
+    Age, CacheControl, Date, ETag, EntityTag, Expires, LastModified, Pragma,
+    Vary,
+};
+use crate::typed_header::date::format_http_date;
+use crate::{Method, StatusCode};
+
+// https://www.rfc-editor.org/rfc/rfc9110.html#section-15.1
+const HEURISTICALLY_CACHEABLE: [u16; 12] =
+    [200, 203, 204, 206, 300, 301, 308, 404, 405, 410, 414, 501];
+
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum NotStorable {
+    #[error("method| {0}")]
+    Method(String),
+    #[error("status| {0}")]
+    Status(u16),
+    #[error("no-store")]
+    NoStore,
+    #[error("private in shared cache")]
+    Private,
+    #[error("authorization in shared cache")]
+    Authorization,
+    #[error("vary *")]
+    VaryAny,
+    #[error("no explicit or heuristic freshness")]
+    NoFreshness,
+}
+
+/* Cache the evaluation is done for.
+ *
+ *      shared              - proxy cache, s-maxage, proxy-revalidate and
+ *                            private apply
+ *      heuristic_percent   - fraction of the time since Last-Modified used
+ *                            as heuristic freshness lifetime, 0 to 100
+ */
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CachePolicy {
+    shared: bool,
+    heuristic_percent: u32,
+}
+
+impl Default for CachePolicy {
+    fn default() -> Self {
+        CachePolicy::shared()
+    }
+}
+
+impl CachePolicy {
+    pub fn shared() -> Self {
+        CachePolicy {
+            shared: true,
+            heuristic_percent: 10,
+        }
+    }
+
+    pub fn private() -> Self {
+        CachePolicy {
+            shared: false,
+            ..CachePolicy::shared()
+        }
+    }
+
+    // percent larger than 100 is clamped to 100
+    pub fn with_heuristic_percent(mut self, percent: u32) -> Self {
+        self.heuristic_percent = percent.min(100);
+        self
+    }
+
+    pub fn is_shared(&self) -> bool {
+        self.shared
+    }
+
+    /* Evaluate a response to a request.
+     *
+     *      request_time    - time the request was sent
+     *      response_time   - time the response was received
+     */
+    pub fn evaluate<Q, R>(
+        &self,
+        method: &Method,
+        request: &HMap<Q>,
+        status: StatusCode,
+        response: &HMap<R>,
+        request_time: SystemTime,
+        response_time: SystemTime,
+    ) -> CacheEvaluation
+    where
+        Q: Hmap,
+        R: Hmap,
+    {
+        let request_cc = typed::<CacheControl, _>(request);
+        let pragma_no_cache = request_cc.is_none()
+            && typed::<Pragma, _>(request).is_some_and(|p| p.no_cache);
+        let request_cc = request_cc.unwrap_or_default();
+        let response_cc =
+            typed::<CacheControl, _>(response).unwrap_or_default();
+        let expires = typed::<Expires, _>(response);
+        let date = typed::<Date, _>(response).map_or(response_time, |d| d.0);
+        let age = typed::<Age, _>(response).map_or(0, |age| age.0);
+        let last_modified = typed::<LastModified, _>(response).map(|l| l.0);
+        let vary = typed::<Vary, _>(response);
+
+        // 4.2.1 freshness lifetime
+        let mut heuristic = false;
+        let freshness_lifetime =
+            match (response_cc.s_maxage, response_cc.max_age) {
+                (Some(s_maxage), _) if self.shared => secs(s_maxage),
+                (_, Some(max_age)) => secs(max_age),
+                _ => match expires {
+                    Some(Expires(Some(expires))) => since(expires, date),
+                    Some(Expires(None)) => Duration::ZERO,
+                    None => {
+                        heuristic = true;
+                        self.heuristic_lifetime(
+                            status,
+                            &response_cc,
+                            date,
+                            last_modified,
+                        )
+                    }
+                },
+            };
+
+        // 4.2.3 age
+        let apparent_age = since(response_time, date);
+        let response_delay = since(response_time, request_time);
+        let corrected_age_value = secs(age) + response_delay;
+        let corrected_initial_age = apparent_age.max(corrected_age_value);
+
+        let storable = self.storable(
+            method,
+            request,
+            status,
+            &request_cc,
+            &response_cc,
+            expires.is_some(),
+            vary.as_ref(),
+        );
+
+        CacheEvaluation {
+            storable,
+            freshness_lifetime,
+            heuristic,
+            corrected_initial_age,
+            response_time,
+            no_cache: response_cc.is_no_cache()
+                || request_cc.is_no_cache()
+                || pragma_no_cache,
+            must_revalidate: response_cc.must_revalidate
+                || (self.shared
+                    && (response_cc.proxy_revalidate
+                        || response_cc.s_maxage.is_some())),
+            max_age: request_cc.max_age,
+            min_fresh: request_cc.min_fresh,
+            max_stale: request_cc.max_stale,
+            etag: typed::<ETag, _>(response).map(|etag| etag.0),
+            last_modified,
+            vary,
+        }
+    }
+
+    // 3. Storing responses in caches
+    #[allow(clippy::too_many_arguments)]
+    fn storable<Q>(
+        &self,
+        method: &Method,
+        request: &HMap<Q>,
+        status: StatusCode,
+        request_cc: &CacheControl,
+        response_cc: &CacheControl,
+        has_expires: bool,
+        vary: Option<&Vary>,
+    ) -> Result<(), NotStorable>
+    where
+        Q: Hmap,
+    {
+        if !matches!(method, Method::GET | Method::HEAD) {
+            return Err(NotStorable::Method(method.as_str().to_string()));
+        }
+        // partial content and not modified only update a stored response
+        let code = status.as_u16();
+        if status.is_informational() || code == 206 || code == 304 {
+            return Err(NotStorable::Status(code));
+        }
+        if request_cc.no_store || response_cc.no_store {
+            return Err(NotStorable::NoStore);
+        }
+        if self.shared && response_cc.is_private() {
+            return Err(NotStorable::Private);
+        }
+        if self.shared
+            && request.has_key(AUTHORIZATION)
+            && !(response_cc.must_revalidate
+                || response_cc.public
+                || response_cc.s_maxage.is_some())
+        {
+            return Err(NotStorable::Authorization);
+        }
+        if vary.is_some_and(Vary::is_any) {
+            return Err(NotStorable::VaryAny);
+        }
+        if response_cc.public
+            || (!self.shared && response_cc.private.is_some())
+            || has_expires
+            || response_cc.max_age.is_some()
+            || (self.shared && response_cc.s_maxage.is_some())
+            || HEURISTICALLY_CACHEABLE.contains(&code)
+        {
+            Ok(())
+        } else {
+            Err(NotStorable::NoFreshness)
+        }
+    }
+
+    // 4.2.2 calculating heuristic freshness
+    fn heuristic_lifetime(
+        &self,
+        status: StatusCode,
+        response_cc: &CacheControl,
+        date: SystemTime,
+        last_modified: Option<SystemTime>,
+    ) -> Duration {
+        if !(response_cc.public
+            || HEURISTICALLY_CACHEABLE.contains(&status.as_u16()))
+        {
+            return Duration::ZERO;
+        }
+        last_modified.map_or(Duration::ZERO, |last_modified| {
+            let since = since(date, last_modified);
+            // divide first when the product overflows, less precise
+            since
+                .checked_mul(self.heuristic_percent)
+                .map_or(since / 100 * self.heuristic_percent, |d| d / 100)
+        })
+    }
+}
+
+/* Result of CachePolicy::evaluate.
+ *
+ *      freshness_lifetime      - explicit or heuristic lifetime
+ *      corrected_initial_age   - age of the response when received
+ *      no_cache                - a stored response must be validated before
+ *                                every use
+ *      must_revalidate         - a stale response must not be used without
+ *                                validation
+ *      max_age, min_fresh,
+ *      max_stale               - request constraints on a stored response
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CacheEvaluation {
+    pub storable: Result<(), NotStorable>,
+    pub freshness_lifetime: Duration,
+    pub heuristic: bool,
+    pub corrected_initial_age: Duration,
+    pub response_time: SystemTime,
+    pub no_cache: bool,
+    pub must_revalidate: bool,
+    pub max_age: Option<u64>,
+    pub min_fresh: Option<u64>,
+    pub max_stale: Option<Option<u64>>,
+    pub etag: Option<EntityTag>,
+    pub last_modified: Option<SystemTime>,
+    pub vary: Option<Vary>,
+}
+
+impl CacheEvaluation {
+    pub fn is_storable(&self) -> bool {
+        self.storable.is_ok()
+    }
+
+    // corrected_initial_age + resident time
+    pub fn current_age(&self, now: SystemTime) -> Duration {
+        self.corrected_initial_age + since(now, self.response_time)
+    }
+
+    pub fn is_fresh(&self, now: SystemTime) -> bool {
+        self.freshness_lifetime > self.current_age(now)
+    }
+
+    pub fn staleness(&self, now: SystemTime) -> Duration {
+        self.current_age(now).saturating_sub(self.freshness_lifetime)
+    }
+
+    /* Stored response can be served without contacting the origin, ie. it
+     * is fresh for the request or the request accepts its staleness.
+     * https://www.rfc-editor.org/rfc/rfc9111.html#section-4
+     */
+    pub fn is_usable_without_validation(&self, now: SystemTime) -> bool {
+        if !self.is_storable() || self.no_cache {
+            return false;
+        }
+        let age = self.current_age(now);
+        if self.max_age.is_some_and(|max_age| age > secs(max_age)) {
+            return false;
+        }
+        if let Some(min_fresh) = self.min_fresh {
+            return self.freshness_lifetime >= age + secs(min_fresh);
+        }
+        if self.is_fresh(now) {
+            return true;
+        }
+        !self.must_revalidate
+            && match self.max_stale {
+                Some(None) => true,
+                Some(Some(max_stale)) => {
+                    self.staleness(now) <= secs(max_stale)
+                }
+                None => false,
+            }
+    }
+
+    /* Header fields of the conditional request that validates the stored
+     * response, empty when it has no validator.
+     * https://www.rfc-editor.org/rfc/rfc9111.html#section-4.3.1
+     */
+    pub fn conditional_headers(&self) -> Vec<(&'static [u8], String)> {
+        let mut headers = Vec::new();
+        if let Some(etag) = &self.etag {
+            headers.push((IF_NONE_MATCH, etag.to_string()));
+        }
+        if let Some(last_modified) = self.last_modified {
+            headers.push((IF_MODIFIED_SINCE, format_http_date(last_modified)));
+        }
+        headers
+    }
+
+    // set the conditional_headers on a request, false without validator
+    pub fn apply_conditional<T>(&self, header_map: &mut HMap<T>) -> bool
+    where
+        T: Hmap + for<'a> From<(&'a [u8], &'a [u8])>,
+    {
+        let headers = self.conditional_headers();
+        for (name, value) in &headers {
+            match header_map.header_key_position(*name) {
+                Some(pos) => {
+                    header_map.update_header_value_on_position(pos, value)
+                }
+                None => header_map.insert(*name, value.as_bytes()),
+            }
+        }
+        !headers.is_empty()
+    }
+}
+
+// valid typed header, invalid values are ignored
+fn typed<H, T>(header_map: &HMap<T>) -> Option<H>
+where
+    H: TypedHeader,
+    T: Hmap,
+{
+    H::decode(header_map).and_then(Result::ok)
+}
+
+fn secs(secs: u64) -> Duration {
+    Duration::from_secs(secs)
+}
+
+// later - earlier, zero when negative
+fn since(later: SystemTime, earlier: SystemTime) -> Duration {
+    later.duration_since(earlier).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OneHeaderMap;
+    use crate::typed_header::date::from_unix_secs;
+    use bytes::BytesMut;
+
+    // Date of the responses
+    const NOW: i64 = 1_700_000_000;
+    const DATE: &str = "Tue, 14 Nov 2023 22:13:20 GMT";
+
+    fn one(input: &str) -> OneHeaderMap {
+        OneHeaderMap::from(BytesMut::from(input))
+    }
+
+    fn at(offset: i64) -> SystemTime {
+        from_unix_secs(NOW + offset)
+    }
+
+    fn evaluate_with(
+        policy: CachePolicy,
+        method: Method,
+        request: &str,
+        status: u16,
+        response: &str,
+    ) -> CacheEvaluation {
+        let request = one(&format!("{request}\r\n"));
+        let response = one(&format!("Date: {DATE}\r\n{response}\r\n"));
+        let status = StatusCode::from_u16(status).ok().unwrap();
+        policy.evaluate(&method, &request, status, &response, at(0), at(0))
+    }
+
+    fn evaluate(response: &str) -> CacheEvaluation {
+        evaluate_with(CachePolicy::shared(), Method::GET, "", 200, response)
+    }
+
+    #[test]
+    fn test_freshness_max_age() {
+        let eval = evaluate("Cache-Control: max-age=60\r\n");
+        assert!(eval.is_storable());
+        assert_eq!(eval.freshness_lifetime, secs(60));
+        assert!(!eval.heuristic);
+        assert!(eval.is_fresh(at(59)));
+        assert!(!eval.is_fresh(at(60)));
+        assert_eq!(eval.staleness(at(70)), secs(10));
+    }
+
+    #[test]
+    fn test_freshness_s_maxage_shared_only() {
+        let response = "Cache-Control: max-age=60, s-maxage=600\r\n";
+        let eval = evaluate(response);
+        assert_eq!(eval.freshness_lifetime, secs(600));
+        assert!(eval.must_revalidate);
+        let eval = evaluate_with(
+            CachePolicy::private(),
+            Method::GET,
+            "",
+            200,
+            response,
+        );
+        assert_eq!(eval.freshness_lifetime, secs(60));
+        assert!(!eval.must_revalidate);
+    }
+
+    #[test]
+    fn test_freshness_expires() {
+        let eval = evaluate("Expires: Tue, 14 Nov 2023 23:13:20 GMT\r\n");
+        assert_eq!(eval.freshness_lifetime, secs(3600));
+        // max-age wins over Expires
+        let eval = evaluate(
+            "Expires: Tue, 14 Nov 2023 23:13:20 GMT\r\n\
+             Cache-Control: max-age=5\r\n",
+        );
+        assert_eq!(eval.freshness_lifetime, secs(5));
+        // invalid is in the past
+        let eval = evaluate("Expires: 0\r\n");
+        assert!(eval.is_storable());
+        assert_eq!(eval.freshness_lifetime, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_freshness_heuristic() {
+        // ten days since Last-Modified
+        let eval =
+            evaluate("Last-Modified: Sat, 04 Nov 2023 22:13:20 GMT\r\n");
+        assert!(eval.heuristic);
+        assert_eq!(eval.freshness_lifetime, secs(86_400));
+        let eval = evaluate_with(
+            CachePolicy::shared().with_heuristic_percent(50),
+            Method::GET,
+            "",
+            200,
+            "Last-Modified: Sat, 04 Nov 2023 22:13:20 GMT\r\n",
+        );
+        assert_eq!(eval.freshness_lifetime, secs(5 * 86_400));
+        // clamped to 100 percent
+        let eval = evaluate_with(
+            CachePolicy::shared().with_heuristic_percent(u32::MAX),
+            Method::GET,
+            "",
+            200,
+            "Last-Modified: Sat, 04 Nov 2023 22:13:20 GMT\r\n",
+        );
+        assert_eq!(eval.freshness_lifetime, secs(10 * 86_400));
+        // not heuristically cacheable
+        let eval = evaluate_with(
+            CachePolicy::shared(),
+            Method::GET,
+            "",
+            302,
+            "Last-Modified: Sat, 04 Nov 2023 22:13:20 GMT\r\n",
+        );
+        assert_eq!(eval.storable, Err(NotStorable::NoFreshness));
+        assert_eq!(eval.freshness_lifetime, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_freshness_heuristic_overflow() {
+        let date = from_unix_secs(i64::MAX);
+        let lifetime = CachePolicy::shared().heuristic_lifetime(
+            StatusCode::OK,
+            &CacheControl::default(),
+            date,
+            Some(from_unix_secs(0)),
+        );
+        assert_eq!(lifetime, secs(i64::MAX as u64) / 100 * 10);
+    }
+
+    #[test]
+    fn test_age_calculation() {
+        let request = one("\r\n");
+        let response = one(&format!(
+            "Date: {DATE}\r\nAge: 30\r\nCache-Control: max-age=100\r\n\r\n"
+        ));
+        let status = StatusCode::from_u16(200).ok().unwrap();
+        // sent at +0, received at +5
+        let eval = CachePolicy::shared().evaluate(
+            &Method::GET,
+            &request,
+            status,
+            &response,
+            at(0),
+            at(5),
+        );
+        // max(apparent 5, 30 + delay 5)
+        assert_eq!(eval.corrected_initial_age, secs(35));
+        assert_eq!(eval.current_age(at(15)), secs(45));
+        assert!(eval.is_fresh(at(69)));
+        assert!(!eval.is_fresh(at(70)));
+    }
+
+    #[test]
+    fn test_age_apparent_wins() {
+        let request = one("\r\n");
+        let response = one(&format!("Date: {DATE}\r\n\r\n"));
+        let status = StatusCode::from_u16(200).ok().unwrap();
+        let eval = CachePolicy::shared().evaluate(
+            &Method::GET,
+            &request,
+            status,
+            &response,
+            at(100),
+            at(120),
+        );
+        assert_eq!(eval.corrected_initial_age, secs(120));
+    }
+
+    #[test]
+    fn test_not_storable() {
+        let cases = [
+            (Method::POST, "", 200, "Cache-Control: max-age=60\r\n"),
+            (Method::GET, "", 206, "Cache-Control: max-age=60\r\n"),
+            (Method::GET, "", 200, "Cache-Control: no-store\r\n"),
+            (Method::GET, "Cache-Control: no-store\r\n", 200, ""),
+            (Method::GET, "", 200, "Cache-Control: private\r\n"),
+            (Method::GET, "Authorization: Basic a\r\n", 200, ""),
+            (Method::GET, "", 200, "Vary: *\r\n"),
+            (Method::GET, "", 500, ""),
+        ];
+        let verify = [
+            NotStorable::Method("POST".into()),
+            NotStorable::Status(206),
+            NotStorable::NoStore,
+            NotStorable::NoStore,
+            NotStorable::Private,
+            NotStorable::Authorization,
+            NotStorable::VaryAny,
+            NotStorable::NoFreshness,
+        ];
+        for ((method, request, status, response), verify) in
+            cases.into_iter().zip(verify)
+        {
+            let eval = evaluate_with(
+                CachePolicy::shared(),
+                method,
+                request,
+                status,
+                response,
+            );
+            assert_eq!(eval.storable, Err(verify));
+        }
+    }
+
+    #[test]
+    fn test_storable_private_cache() {
+        let eval = evaluate_with(
+            CachePolicy::private(),
+            Method::GET,
+            "Authorization: Basic a\r\n",
+            500,
+            "Cache-Control: private, max-age=10\r\n",
+        );
+        assert!(eval.is_storable());
+        let eval = evaluate_with(
+            CachePolicy::shared(),
+            Method::GET,
+            "Authorization: Basic a\r\n",
+            200,
+            "Cache-Control: public\r\n",
+        );
+        assert!(eval.is_storable());
+    }
+
+    #[test]
+    fn test_usable_without_validation() {
+        let eval = evaluate("Cache-Control: max-age=60\r\n");
+        assert!(eval.is_usable_without_validation(at(30)));
+        assert!(!eval.is_usable_without_validation(at(90)));
+
+        let eval = evaluate("Cache-Control: max-age=60, no-cache\r\n");
+        assert!(!eval.is_usable_without_validation(at(0)));
+
+        let request = |request| {
+            evaluate_with(
+                CachePolicy::shared(),
+                Method::GET,
+                request,
+                200,
+                "Cache-Control: max-age=60\r\n",
+            )
+        };
+        let eval = request("Cache-Control: max-stale=60\r\n");
+        assert!(eval.is_usable_without_validation(at(100)));
+        assert!(!eval.is_usable_without_validation(at(121)));
+        let eval = request("Cache-Control: max-stale\r\n");
+        assert!(eval.is_usable_without_validation(at(10_000)));
+        let eval = request("Cache-Control: min-fresh=30\r\n");
+        assert!(eval.is_usable_without_validation(at(30)));
+        assert!(!eval.is_usable_without_validation(at(31)));
+        let eval = request("Cache-Control: max-age=10\r\n");
+        assert!(!eval.is_usable_without_validation(at(11)));
+        let eval = request("Pragma: no-cache\r\n");
+        assert!(!eval.is_usable_without_validation(at(0)));
+        // Pragma ignored with Cache-Control
+        let eval = request("Pragma: no-cache\r\nCache-Control: max-stale\r\n");
+        assert!(eval.is_usable_without_validation(at(0)));
+
+        let eval = evaluate_with(
+            CachePolicy::shared(),
+            Method::GET,
+            "Cache-Control: max-stale\r\n",
+            200,
+            "Cache-Control: max-age=60, must-revalidate\r\n",
+        );
+        assert!(!eval.is_usable_without_validation(at(61)));
+    }
+
+    #[test]
+    fn test_conditional_request() {
+        let eval = evaluate(
+            "ETag: \"v1\"\r\n\
+             Last-Modified: Sat, 04 Nov 2023 22:13:20 GMT\r\n",
+        );
+        let mut request =
+            one("Host: example.com\r\nIf-None-Match: \"v0\"\r\n");
+        assert!(eval.apply_conditional(&mut request));
+        let verify = "Host: example.com\r\n\
+                      If-None-Match: \"v1\"\r\n\
+                      if-modified-since: Sat, 04 Nov 2023 22:13:20 GMT\r\n";
+        assert_eq!(request.into_bytes(), verify);
+
+        let eval = evaluate("Cache-Control: max-age=60\r\n");
+        assert!(eval.conditional_headers().is_empty());
+    }
+}
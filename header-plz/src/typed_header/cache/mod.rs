@@ -0,0 +1,379 @@
+// HTTP caching header fields
+// https://www.rfc-editor.org/rfc/rfc9111.html#section-5
+
+use std::fmt::{self, Display};
+
+use thiserror::Error;
+
+use crate::const_headers::CACHE_CONTROL;
+use crate::typed_header::impl_typed_header;
+use crate::typed_header::list::{quote_if_needed, split_list, unquote};
+
+pub mod fields;
+pub mod freshness;
+pub mod validator;
+
+pub use fields::{Age, Date, Expires, Pragma, Vary};
+pub use freshness::{CacheEvaluation, CachePolicy, NotStorable};
+pub use validator::{ETag, EntityTag, LastModified};
+
+// delta-seconds larger than this are sent as this, ie. 2^31
+pub const MAX_DELTA_SECONDS: u64 = 2_147_483_648;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum CacheHeaderError {
+    #[error("not utf8")]
+    NotUtf8,
+    #[error("invalid date")]
+    InvalidDate,
+    #[error("invalid delta-seconds| {0}")]
+    InvalidDeltaSeconds(String),
+    #[error("invalid entity-tag| {0}")]
+    InvalidEntityTag(String),
+    #[error("invalid field name| {0}")]
+    InvalidFieldName(String),
+}
+
+/* delta-seconds = 1*DIGIT, saturated at MAX_DELTA_SECONDS.
+ * https://www.rfc-editor.org/rfc/rfc9111.html#section-1.2.2
+ */
+pub fn parse_delta_seconds(value: &[u8]) -> Option<u64> {
+    if value.is_empty() || !value.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+    let secs = value.iter().try_fold(0u64, |acc, b| {
+        acc.checked_mul(10)?.checked_add((b - b'0') as u64)
+    });
+    Some(secs.map_or(MAX_DELTA_SECONDS, |secs| secs.min(MAX_DELTA_SECONDS)))
+}
+
+/* Cache-Control directives of a request or a response.
+ *
+ *      delta-seconds directives    - an invalid value is read as 0, ie.
+ *                                    stale, as recommended for invalid
+ *                                    freshness information
+ *      no_cache / private          - Some(empty) when unqualified, else the
+ *                                    lowercase field names of the argument
+ *      max_stale                   - Some(None) without argument, any
+ *                                    staleness is accepted
+ *      extensions                  - unknown directives, lowercase name and
+ *                                    unquoted argument
+ *
+ * When a directive is repeated the first occurrence is used.
+ */
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CacheControl {
+    pub max_age: Option<u64>,
+    pub s_maxage: Option<u64>,
+    pub max_stale: Option<Option<u64>>,
+    pub min_fresh: Option<u64>,
+    pub stale_while_revalidate: Option<u64>,
+    pub stale_if_error: Option<u64>,
+    pub no_cache: Option<Vec<String>>,
+    pub private: Option<Vec<String>>,
+    pub no_store: bool,
+    pub no_transform: bool,
+    pub only_if_cached: bool,
+    pub must_revalidate: bool,
+    pub proxy_revalidate: bool,
+    pub must_understand: bool,
+    pub public: bool,
+    pub immutable: bool,
+    pub extensions: Vec<(String, Option<String>)>,
+}
+
+impl CacheControl {
+    pub fn new() -> Self {
+        CacheControl::default()
+    }
+
+    pub fn parse(value: &[u8]) -> Result<Self, CacheHeaderError> {
+        let mut cache_control = CacheControl::default();
+        for directive in split_list(value) {
+            let directive = str::from_utf8(directive)
+                .map_err(|_| CacheHeaderError::NotUtf8)?;
+            let (name, argument) = match directive.split_once('=') {
+                Some((name, argument)) => (name.trim(), Some(argument.trim())),
+                None => (directive, None),
+            };
+            let argument = argument.map(|argument| {
+                String::from_utf8_lossy(&unquote(argument.as_bytes()))
+                    .into_owned()
+            });
+            cache_control.add_directive(&name.to_ascii_lowercase(), argument);
+        }
+        Ok(cache_control)
+    }
+
+    fn add_directive(&mut self, name: &str, argument: Option<String>) {
+        let seconds = || {
+            Some(
+                argument
+                    .as_deref()
+                    .and_then(|arg| parse_delta_seconds(arg.as_bytes()))
+                    .unwrap_or(0),
+            )
+        };
+        let field_names = || {
+            Some(argument.as_deref().map_or_else(Vec::new, |arg| {
+                split_list(arg.as_bytes())
+                    .map(|name| {
+                        String::from_utf8_lossy(name).to_ascii_lowercase()
+                    })
+                    .collect()
+            }))
+        };
+        match name {
+            "max-age" if self.max_age.is_none() => self.max_age = seconds(),
+            "s-maxage" if self.s_maxage.is_none() => self.s_maxage = seconds(),
+            "max-stale" if self.max_stale.is_none() => {
+                self.max_stale = Some(argument.as_deref().map(|arg| {
+                    parse_delta_seconds(arg.as_bytes()).unwrap_or(0)
+                }))
+            }
+            "min-fresh" if self.min_fresh.is_none() => {
+                self.min_fresh = seconds()
+            }
+            "stale-while-revalidate"
+                if self.stale_while_revalidate.is_none() =>
+            {
+                self.stale_while_revalidate = seconds()
+            }
+            "stale-if-error" if self.stale_if_error.is_none() => {
+                self.stale_if_error = seconds()
+            }
+            "no-cache" if self.no_cache.is_none() => {
+                self.no_cache = field_names()
+            }
+            "private" if self.private.is_none() => {
+                self.private = field_names()
+            }
+            "no-store" => self.no_store = true,
+            "no-transform" => self.no_transform = true,
+            "only-if-cached" => self.only_if_cached = true,
+            "must-revalidate" => self.must_revalidate = true,
+            "proxy-revalidate" => self.proxy_revalidate = true,
+            "must-understand" => self.must_understand = true,
+            "public" => self.public = true,
+            "immutable" => self.immutable = true,
+            "max-age"
+            | "s-maxage"
+            | "max-stale"
+            | "min-fresh"
+            | "stale-while-revalidate"
+            | "stale-if-error"
+            | "no-cache"
+            | "private" => (),
+            _ => self.extensions.push((name.to_string(), argument)),
+        }
+    }
+
+    // no-cache without field names
+    pub fn is_no_cache(&self) -> bool {
+        self.no_cache.as_ref().is_some_and(Vec::is_empty)
+    }
+
+    // private without field names
+    pub fn is_private(&self) -> bool {
+        self.private.as_ref().is_some_and(Vec::is_empty)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == CacheControl::default()
+    }
+}
+
+struct Directives<'a, 'b> {
+    f: &'a mut fmt::Formatter<'b>,
+    first: bool,
+}
+
+impl Directives<'_, '_> {
+    fn write(&mut self, directive: fmt::Arguments<'_>) -> fmt::Result {
+        if !self.first {
+            self.f.write_str(", ")?;
+        }
+        self.first = false;
+        self.f.write_fmt(directive)
+    }
+
+    fn flag(&mut self, name: &str, set: bool) -> fmt::Result {
+        if set {
+            self.write(format_args!("{name}"))?;
+        }
+        Ok(())
+    }
+
+    fn seconds(&mut self, name: &str, secs: Option<u64>) -> fmt::Result {
+        if let Some(secs) = secs {
+            self.write(format_args!("{name}={secs}"))?;
+        }
+        Ok(())
+    }
+
+    // field names are always quoted
+    fn field_names(
+        &mut self,
+        name: &str,
+        names: &Option<Vec<String>>,
+    ) -> fmt::Result {
+        match names {
+            Some(names) if names.is_empty() => {
+                self.write(format_args!("{name}"))
+            }
+            Some(names) => {
+                self.write(format_args!("{name}=\"{}\"", names.join(", ")))
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+impl Display for CacheControl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut d = Directives {
+            f,
+            first: true,
+        };
+        d.flag("public", self.public)?;
+        d.field_names("private", &self.private)?;
+        d.field_names("no-cache", &self.no_cache)?;
+        d.flag("no-store", self.no_store)?;
+        d.seconds("max-age", self.max_age)?;
+        d.seconds("s-maxage", self.s_maxage)?;
+        match self.max_stale {
+            Some(Some(secs)) => d.seconds("max-stale", Some(secs))?,
+            Some(None) => d.flag("max-stale", true)?,
+            None => (),
+        }
+        d.seconds("min-fresh", self.min_fresh)?;
+        d.flag("must-revalidate", self.must_revalidate)?;
+        d.flag("proxy-revalidate", self.proxy_revalidate)?;
+        d.flag("must-understand", self.must_understand)?;
+        d.flag("no-transform", self.no_transform)?;
+        d.flag("only-if-cached", self.only_if_cached)?;
+        d.flag("immutable", self.immutable)?;
+        d.seconds("stale-while-revalidate", self.stale_while_revalidate)?;
+        d.seconds("stale-if-error", self.stale_if_error)?;
+        for (name, argument) in &self.extensions {
+            match argument {
+                Some(arg) => {
+                    d.write(format_args!("{name}={}", quote_if_needed(arg)))?
+                }
+                None => d.write(format_args!("{name}"))?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl_typed_header!(CacheControl, CACHE_CONTROL, CacheHeaderError);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OneHeaderMap;
+    use crate::typed_header::TypedHeader;
+    use bytes::BytesMut;
+
+    #[test]
+    fn test_parse_delta_seconds() {
+        assert_eq!(parse_delta_seconds(b"0"), Some(0));
+        assert_eq!(parse_delta_seconds(b"3600"), Some(3600));
+        assert_eq!(
+            parse_delta_seconds(b"99999999999999999999999"),
+            Some(MAX_DELTA_SECONDS)
+        );
+        assert_eq!(parse_delta_seconds(b""), None);
+        assert_eq!(parse_delta_seconds(b"-1"), None);
+        assert_eq!(parse_delta_seconds(b"1.5"), None);
+    }
+
+    #[test]
+    fn test_cache_control_parse_response() {
+        let cc = CacheControl::parse(
+            br#"public, Max-Age=60, s-maxage="120", must-revalidate,
+                private="Set-Cookie, x-id", no-cache, immutable"#,
+        )
+        .unwrap();
+        let verify = CacheControl {
+            max_age: Some(60),
+            s_maxage: Some(120),
+            no_cache: Some(vec![]),
+            private: Some(vec!["set-cookie".into(), "x-id".into()]),
+            must_revalidate: true,
+            public: true,
+            immutable: true,
+            ..Default::default()
+        };
+        assert_eq!(cc, verify);
+        assert!(cc.is_no_cache());
+        assert!(!cc.is_private());
+    }
+
+    #[test]
+    fn test_cache_control_parse_request() {
+        let cc =
+            CacheControl::parse(b"max-stale, min-fresh=10, only-if-cached")
+                .unwrap();
+        assert_eq!(cc.max_stale, Some(None));
+        assert_eq!(cc.min_fresh, Some(10));
+        assert!(cc.only_if_cached);
+        let cc = CacheControl::parse(b"max-stale=30").unwrap();
+        assert_eq!(cc.max_stale, Some(Some(30)));
+    }
+
+    #[test]
+    fn test_cache_control_invalid_and_repeated() {
+        let cc =
+            CacheControl::parse(b"max-age=abc, max-age=60, s-maxage").unwrap();
+        assert_eq!(cc.max_age, Some(0));
+        assert_eq!(cc.s_maxage, Some(0));
+        let cc = CacheControl::parse(b"max-age=10, max-age=60").unwrap();
+        assert_eq!(cc.max_age, Some(10));
+    }
+
+    #[test]
+    fn test_cache_control_extensions() {
+        let cc =
+            CacheControl::parse(br#"community="UCI", Foo, bar=1"#).unwrap();
+        assert_eq!(
+            cc.extensions,
+            vec![
+                ("community".into(), Some("UCI".into())),
+                ("foo".into(), None),
+                ("bar".into(), Some("1".into())),
+            ]
+        );
+        assert_eq!(cc.to_string(), "community=UCI, foo, bar=1");
+    }
+
+    #[test]
+    fn test_cache_control_display() {
+        let cc = CacheControl {
+            max_age: Some(0),
+            no_cache: Some(vec!["set-cookie".into()]),
+            no_store: true,
+            must_revalidate: true,
+            ..Default::default()
+        };
+        let value = cc.to_string();
+        assert_eq!(
+            value,
+            r#"no-cache="set-cookie", no-store, max-age=0, must-revalidate"#
+        );
+        assert_eq!(CacheControl::parse(value.as_bytes()).unwrap(), cc);
+        assert_eq!(CacheControl::new().to_string(), "");
+    }
+
+    #[test]
+    fn test_cache_control_typed_header_lines() {
+        let input = "Cache-Control: max-age=60\r\n\
+                     Host: example.com\r\n\
+                     Cache-Control: no-transform\r\n\r\n";
+        let map = OneHeaderMap::from(BytesMut::from(input));
+        let cc = CacheControl::decode(&map).unwrap().unwrap();
+        assert_eq!(cc.max_age, Some(60));
+        assert!(cc.no_transform);
+    }
+}
@@ -0,0 +1,166 @@
+// Validator fields
+// https://www.rfc-editor.org/rfc/rfc9110.html#section-8.8
+
+use std::fmt::{self, Display};
+use std::time::SystemTime;
+
+use crate::const_headers::{ETAG, LAST_MODIFIED};
+use crate::typed_header::cache::CacheHeaderError;
+use crate::typed_header::date::{format_http_date, parse_http_date};
+use crate::typed_header::impl_first_line_header;
+
+/* entity-tag = [ weak ] opaque-tag, tag is kept without the quotes.
+ * https://www.rfc-editor.org/rfc/rfc9110.html#section-8.8.3
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EntityTag {
+    pub weak: bool,
+    pub tag: String,
+}
+
+impl EntityTag {
+    pub fn strong(tag: impl Into<String>) -> Self {
+        EntityTag {
+            weak: false,
+            tag: tag.into(),
+        }
+    }
+
+    pub fn weak(tag: impl Into<String>) -> Self {
+        EntityTag {
+            weak: true,
+            tag: tag.into(),
+        }
+    }
+
+    pub fn parse(value: &[u8]) -> Result<Self, CacheHeaderError> {
+        let value = value.trim_ascii();
+        let invalid = || {
+            CacheHeaderError::InvalidEntityTag(
+                String::from_utf8_lossy(value).into_owned(),
+            )
+        };
+        let (weak, opaque) = match value.strip_prefix(b"W/") {
+            Some(opaque) => (true, opaque),
+            None => (false, value),
+        };
+        let tag = opaque
+            .strip_prefix(b"\"")
+            .and_then(|tag| tag.strip_suffix(b"\""))
+            .ok_or_else(invalid)?;
+        // etagc = %x21 / %x23-7E / obs-text
+        if !tag.iter().all(|&b| b == 0x21 || (0x23..=0x7E).contains(&b)) {
+            return Err(invalid());
+        }
+        Ok(EntityTag {
+            weak,
+            // only ascii after the check
+            tag: String::from_utf8_lossy(tag).into_owned(),
+        })
+    }
+
+    // both strong and character by character equal
+    pub fn strong_eq(&self, other: &EntityTag) -> bool {
+        !self.weak && !other.weak && self.tag == other.tag
+    }
+
+    // opaque tags equal, either may be weak
+    pub fn weak_eq(&self, other: &EntityTag) -> bool {
+        self.tag == other.tag
+    }
+}
+
+impl Display for EntityTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.weak {
+            f.write_str("W/")?;
+        }
+        write!(f, "\"{}\"", self.tag)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ETag(pub EntityTag);
+
+impl ETag {
+    pub fn parse(value: &[u8]) -> Result<Self, CacheHeaderError> {
+        EntityTag::parse(value).map(ETag)
+    }
+}
+
+impl Display for ETag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LastModified(pub SystemTime);
+
+impl LastModified {
+    pub fn parse(value: &[u8]) -> Result<Self, CacheHeaderError> {
+        parse_http_date(value)
+            .map(LastModified)
+            .ok_or(CacheHeaderError::InvalidDate)
+    }
+}
+
+impl Display for LastModified {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&format_http_date(self.0))
+    }
+}
+
+impl_first_line_header!(ETag, ETAG, CacheHeaderError);
+impl_first_line_header!(LastModified, LAST_MODIFIED, CacheHeaderError);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OneHeaderMap;
+    use crate::typed_header::TypedHeader;
+    use crate::typed_header::date::from_unix_secs;
+    use bytes::BytesMut;
+
+    #[test]
+    fn test_entity_tag_parse() {
+        assert_eq!(
+            EntityTag::parse(br#""xyzzy""#),
+            Ok(EntityTag::strong("xyzzy"))
+        );
+        assert_eq!(
+            EntityTag::parse(br#"W/"xyzzy""#),
+            Ok(EntityTag::weak("xyzzy"))
+        );
+        assert_eq!(EntityTag::parse(br#""""#), Ok(EntityTag::strong("")));
+        for invalid in [&b"xyzzy"[..], br#"w/"a""#, br#""a"b""#, br#""a b""#] {
+            assert!(EntityTag::parse(invalid).is_err());
+        }
+    }
+
+    // https://www.rfc-editor.org/rfc/rfc9110.html#section-8.8.3.2
+    #[test]
+    fn test_entity_tag_comparison() {
+        let w1 = EntityTag::weak("1");
+        let w2 = EntityTag::weak("2");
+        let s1 = EntityTag::strong("1");
+        assert!(!w1.strong_eq(&w1));
+        assert!(w1.weak_eq(&w1));
+        assert!(!w1.strong_eq(&w2));
+        assert!(!w1.weak_eq(&w2));
+        assert!(!w1.strong_eq(&s1));
+        assert!(w1.weak_eq(&s1));
+        assert!(s1.strong_eq(&s1));
+    }
+
+    #[test]
+    fn test_etag_last_modified_typed_header() {
+        let input = "ETag: W/\"abc\"\r\n\
+                     Last-Modified: Sun, 06 Nov 1994 08:49:37 GMT\r\n\r\n";
+        let map = OneHeaderMap::from(BytesMut::from(input));
+        let etag = ETag::decode(&map).unwrap().unwrap();
+        assert_eq!(etag.to_string(), r#"W/"abc""#);
+        let last_modified = LastModified::decode(&map).unwrap().unwrap();
+        assert_eq!(last_modified, LastModified(from_unix_secs(784_111_777)));
+    }
+}
@@ -0,0 +1,662 @@
+// Cookie storage and retrieval
+// https://datatracker.ietf.org/doc/html/draft-ietf-httpbis-rfc6265bis#section-5.7
+
+use std::net::IpAddr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use thiserror::Error;
+
+use crate::Uri;
+use crate::message_head::header_map::{HMap, Hmap};
+use crate::typed_header::cookie::set_cookie::MAX_COOKIE_AGE;
+use crate::typed_header::cookie::{
+    Cookie, CookieError, CookiePair, SameSite, SetCookie,
+};
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum CookieRejected {
+    #[error("invalid| {0}")]
+    Invalid(#[from] CookieError),
+    #[error("uri without host")]
+    NoHost,
+    #[error("domain does not match host| {0}")]
+    DomainMismatch(String),
+    #[error("domain is a public suffix| {0}")]
+    PublicSuffix(String),
+    #[error("secure cookie from insecure origin")]
+    SecureFromInsecureOrigin,
+    #[error("samesite none without secure")]
+    SameSiteNoneWithoutSecure,
+    #[error("__Secure- or __Host- prefix requirements not met")]
+    InvalidPrefix,
+    #[error("overwrites a secure cookie from insecure origin")]
+    OverwritesSecure,
+}
+
+/* Relation between the request and the site for cookies, decided by the
+ * caller.
+ *      SameSite            - every cookie is sent
+ *      CrossSiteNavigation - cross-site top-level navigation with a safe
+ *                            method, Lax cookies are sent
+ *      CrossSite           - only SameSite=None and unset cookies are sent
+ *
+ * Cookies without SameSite are treated as None, as legacy user agents do.
+ */
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum SiteContext {
+    #[default]
+    SameSite,
+    CrossSiteNavigation,
+    CrossSite,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StoredCookie {
+    pub name: String,
+    pub value: String,
+    pub domain: String,
+    pub host_only: bool,
+    pub path: String,
+    // None for a session cookie
+    pub expiry: Option<SystemTime>,
+    pub secure: bool,
+    pub http_only: bool,
+    pub same_site: Option<SameSite>,
+    pub partitioned: bool,
+    pub creation_time: SystemTime,
+    pub last_access_time: SystemTime,
+}
+
+impl StoredCookie {
+    pub fn is_expired(&self, now: SystemTime) -> bool {
+        self.expiry.is_some_and(|expiry| expiry <= now)
+    }
+
+    pub fn is_session(&self) -> bool {
+        self.expiry.is_none()
+    }
+
+    fn matches_host(&self, host: &str) -> bool {
+        if self.host_only {
+            self.domain == host
+        } else {
+            domain_match(host, &self.domain)
+        }
+    }
+
+    fn is_sent_in(&self, context: SiteContext) -> bool {
+        matches!(
+            (self.same_site, context),
+            (_, SiteContext::SameSite)
+                | (None | Some(SameSite::None), _)
+                | (Some(SameSite::Lax), SiteContext::CrossSiteNavigation)
+        )
+    }
+
+    fn pair(&self) -> CookiePair {
+        CookiePair::new(self.name.as_str(), self.value.as_str())
+    }
+}
+
+/* Cookie jar following the user agent storage model of RFC 6265bis.
+ *
+ * There is no public suffix list, a single label domain attribute such as
+ * "com" is treated as a public suffix. The current time is passed in by the
+ * caller.
+ */
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CookieJar {
+    cookies: Vec<StoredCookie>,
+}
+
+impl CookieJar {
+    pub fn new() -> Self {
+        CookieJar::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cookies.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cookies.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, StoredCookie> {
+        self.cookies.iter()
+    }
+
+    pub fn clear(&mut self) {
+        self.cookies.clear()
+    }
+
+    pub fn remove_expired(&mut self, now: SystemTime) {
+        self.cookies.retain(|cookie| !cookie.is_expired(now))
+    }
+
+    // end of the session, session cookies are removed
+    pub fn clear_session_cookies(&mut self) {
+        self.cookies.retain(|cookie| !cookie.is_session())
+    }
+
+    /* Store the cookie received in the response to uri. An expired cookie
+     * removes the stored one with the same name, domain and path.
+     */
+    pub fn store(
+        &mut self,
+        set_cookie: SetCookie,
+        uri: &Uri,
+        now: SystemTime,
+    ) -> Result<(), CookieRejected> {
+        let host = request_host(uri).ok_or(CookieRejected::NoHost)?;
+        let secure_origin = is_secure_origin(uri);
+
+        // capped at MAX_COOKIE_AGE, a session cookie when now + cap does
+        // not fit in SystemTime
+        let latest = now.checked_add(Duration::from_secs(MAX_COOKIE_AGE));
+        let expiry = match (set_cookie.max_age, set_cookie.expires) {
+            (Some(max_age), _) if max_age <= 0 => Some(UNIX_EPOCH),
+            (Some(max_age), _) => now.checked_add(Duration::from_secs(
+                (max_age as u64).min(MAX_COOKIE_AGE),
+            )),
+            (None, Some(expires)) => latest.map(|latest| expires.min(latest)),
+            (None, None) => None,
+        };
+
+        let (domain, host_only) = match set_cookie.domain.clone() {
+            Some(domain) if domain == host => (host.clone(), false),
+            Some(domain) if !domain.contains('.') => {
+                return Err(CookieRejected::PublicSuffix(domain));
+            }
+            Some(domain) if domain_match(&host, &domain) => (domain, false),
+            Some(domain) => {
+                return Err(CookieRejected::DomainMismatch(domain));
+            }
+            None => (host.clone(), true),
+        };
+
+        let path = set_cookie
+            .path
+            .clone()
+            .unwrap_or_else(|| default_path(uri.path()));
+
+        if set_cookie.secure && !secure_origin {
+            return Err(CookieRejected::SecureFromInsecureOrigin);
+        }
+        if set_cookie.same_site == Some(SameSite::None) && !set_cookie.secure {
+            return Err(CookieRejected::SameSiteNoneWithoutSecure);
+        }
+        if !prefix_requirements_met(&set_cookie, host_only, &path) {
+            return Err(CookieRejected::InvalidPrefix);
+        }
+        if !set_cookie.secure
+            && !secure_origin
+            && self.cookies.iter().any(|stored| {
+                stored.secure
+                    && stored.name == set_cookie.name
+                    && (domain_match(&domain, &stored.domain)
+                        || domain_match(&stored.domain, &domain))
+                    && path_match(&path, &stored.path)
+            })
+        {
+            return Err(CookieRejected::OverwritesSecure);
+        }
+
+        let mut creation_time = now;
+        if let Some(pos) = self.cookies.iter().position(|stored| {
+            stored.name == set_cookie.name
+                && stored.domain == domain
+                && stored.host_only == host_only
+                && stored.path == path
+        }) {
+            creation_time = self.cookies.remove(pos).creation_time;
+        }
+        if expiry.is_some_and(|expiry| expiry <= now) {
+            return Ok(());
+        }
+        self.cookies.push(StoredCookie {
+            name: set_cookie.name,
+            value: set_cookie.value,
+            domain,
+            host_only,
+            path,
+            expiry,
+            secure: set_cookie.secure,
+            http_only: set_cookie.http_only,
+            same_site: set_cookie.same_site,
+            partitioned: set_cookie.partitioned,
+            creation_time,
+            last_access_time: now,
+        });
+        Ok(())
+    }
+
+    // every Set-Cookie of a response, the rejected cookies are returned
+    pub fn store_response<T>(
+        &mut self,
+        header_map: &HMap<T>,
+        uri: &Uri,
+        now: SystemTime,
+    ) -> Vec<CookieRejected>
+    where
+        T: Hmap,
+    {
+        SetCookie::from_header_map(header_map)
+            .filter_map(|set_cookie| {
+                set_cookie
+                    .map_err(CookieRejected::from)
+                    .and_then(|set_cookie| self.store(set_cookie, uri, now))
+                    .err()
+            })
+            .collect()
+    }
+
+    /* Cookie header for a request to uri, None when no cookie applies.
+     * Longer paths first, then older cookies first.
+     */
+    pub fn cookie_header(
+        &mut self,
+        uri: &Uri,
+        now: SystemTime,
+        context: SiteContext,
+    ) -> Option<Cookie> {
+        self.remove_expired(now);
+        let host = request_host(uri)?;
+        let secure_origin = is_secure_origin(uri);
+        let path = match uri.path() {
+            "" => "/",
+            path => path,
+        };
+        let mut selected: Vec<&mut StoredCookie> = self
+            .cookies
+            .iter_mut()
+            .filter(|cookie| {
+                cookie.matches_host(&host)
+                    && path_match(path, &cookie.path)
+                    && (!cookie.secure || secure_origin)
+                    && cookie.is_sent_in(context)
+            })
+            .collect();
+        if selected.is_empty() {
+            return None;
+        }
+        selected.sort_by(|a, b| {
+            b.path
+                .len()
+                .cmp(&a.path.len())
+                .then(a.creation_time.cmp(&b.creation_time))
+        });
+        Some(
+            selected
+                .into_iter()
+                .map(|cookie| {
+                    cookie.last_access_time = now;
+                    cookie.pair()
+                })
+                .collect(),
+        )
+    }
+}
+
+// lowercase host of the authority, without userinfo, port and brackets
+pub fn request_host(uri: &Uri) -> Option<String> {
+    let authority = uri.authority()?;
+    let host = authority.rsplit_once('@').map_or(authority, |(_, host)| host);
+    let host = match host.strip_prefix('[') {
+        Some(ipv6) => ipv6.split_once(']')?.0,
+        None => host.split_once(':').map_or(host, |(host, _)| host),
+    };
+    let host = host.trim_end_matches('.');
+    (!host.is_empty()).then(|| host.to_ascii_lowercase())
+}
+
+fn is_secure_origin(uri: &Uri) -> bool {
+    uri.scheme().is_some_and(|scheme| {
+        scheme.as_str().eq_ignore_ascii_case("https")
+            || scheme.as_str().eq_ignore_ascii_case("wss")
+    })
+}
+
+// host is domain or a subdomain of it, never for an ip address
+pub fn domain_match(host: &str, domain: &str) -> bool {
+    if host == domain {
+        return true;
+    }
+    host.parse::<IpAddr>().is_err()
+        && host
+            .strip_suffix(domain)
+            .is_some_and(|prefix| prefix.ends_with('.'))
+}
+
+// directory of the request path
+pub fn default_path(path: &str) -> String {
+    if !path.starts_with('/') {
+        return "/".to_string();
+    }
+    match path.rfind('/') {
+        Some(0) | None => "/".to_string(),
+        Some(pos) => path[..pos].to_string(),
+    }
+}
+
+pub fn path_match(request_path: &str, cookie_path: &str) -> bool {
+    request_path == cookie_path
+        || request_path.strip_prefix(cookie_path).is_some_and(|rest| {
+            cookie_path.ends_with('/') || rest.starts_with('/')
+        })
+}
+
+fn prefix_requirements_met(
+    set_cookie: &SetCookie,
+    host_only: bool,
+    path: &str,
+) -> bool {
+    if set_cookie.name.is_empty() {
+        let value = SetCookie::new(set_cookie.value.as_str(), "");
+        return !value.is_secure_prefixed() && !value.is_host_prefixed();
+    }
+    if set_cookie.is_secure_prefixed() && !set_cookie.secure {
+        return false;
+    }
+    if set_cookie.is_host_prefixed() {
+        return set_cookie.secure
+            && host_only
+            && set_cookie.domain.is_none()
+            && path == "/";
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OneHeaderMap;
+    use crate::typed_header::date::from_unix_secs;
+    use bytes::BytesMut;
+
+    const NOW: i64 = 1_700_000_000;
+
+    fn now() -> SystemTime {
+        from_unix_secs(NOW)
+    }
+
+    fn uri(value: &str) -> Uri {
+        Uri::try_from(value).unwrap()
+    }
+
+    fn store(jar: &mut CookieJar, set_cookie: &str, to: &str) {
+        let set_cookie = SetCookie::parse(set_cookie.as_bytes()).unwrap();
+        jar.store(set_cookie, &uri(to), now()).unwrap();
+    }
+
+    fn rejected(set_cookie: &str, to: &str) -> CookieRejected {
+        let set_cookie = SetCookie::parse(set_cookie.as_bytes()).unwrap();
+        CookieJar::new().store(set_cookie, &uri(to), now()).unwrap_err()
+    }
+
+    fn header(jar: &mut CookieJar, to: &str) -> Option<String> {
+        jar.cookie_header(&uri(to), now(), SiteContext::SameSite)
+            .map(|cookie| cookie.to_string())
+    }
+
+    #[test]
+    fn test_request_host() {
+        let host = |value| request_host(&uri(value));
+        assert_eq!(
+            host("http://Example.COM/a").as_deref(),
+            Some("example.com")
+        );
+        assert_eq!(
+            host("https://user:pw@www.example.com:8443/").as_deref(),
+            Some("www.example.com")
+        );
+        assert_eq!(host("http://[::1]:8080/").as_deref(), Some("::1"));
+        assert_eq!(host("/path"), None);
+    }
+
+    #[test]
+    fn test_domain_match() {
+        assert!(domain_match("example.com", "example.com"));
+        assert!(domain_match("www.example.com", "example.com"));
+        assert!(!domain_match("wwwexample.com", "example.com"));
+        assert!(!domain_match("example.com", "www.example.com"));
+        assert!(!domain_match("1.2.3.4", "2.3.4"));
+    }
+
+    #[test]
+    fn test_default_path_and_path_match() {
+        assert_eq!(default_path(""), "/");
+        assert_eq!(default_path("/"), "/");
+        assert_eq!(default_path("/a"), "/");
+        assert_eq!(default_path("/a/b/c"), "/a/b");
+        assert!(path_match("/a/b", "/a"));
+        assert!(path_match("/a/b", "/a/"));
+        assert!(path_match("/a", "/a"));
+        assert!(!path_match("/ab", "/a"));
+        assert!(!path_match("/", "/a"));
+    }
+
+    #[test]
+    fn test_jar_host_only_and_domain() {
+        let mut jar = CookieJar::new();
+        store(&mut jar, "host=1", "http://example.com/");
+        store(&mut jar, "dom=2; Domain=example.com", "http://example.com/");
+        assert_eq!(
+            header(&mut jar, "http://example.com/").as_deref(),
+            Some("host=1; dom=2")
+        );
+        assert_eq!(
+            header(&mut jar, "http://www.example.com/").as_deref(),
+            Some("dom=2")
+        );
+        assert_eq!(header(&mut jar, "http://example.org/"), None);
+    }
+
+    #[test]
+    fn test_jar_path_order() {
+        let mut jar = CookieJar::new();
+        store(&mut jar, "a=1; Path=/", "http://example.com/");
+        store(&mut jar, "b=2; Path=/docs", "http://example.com/");
+        store(&mut jar, "c=3", "http://example.com/docs/page");
+        assert_eq!(
+            header(&mut jar, "http://example.com/docs/page").as_deref(),
+            Some("b=2; c=3; a=1")
+        );
+        assert_eq!(
+            header(&mut jar, "http://example.com/other").as_deref(),
+            Some("a=1")
+        );
+    }
+
+    #[test]
+    fn test_jar_replace_keeps_creation_time() {
+        let mut jar = CookieJar::new();
+        store(&mut jar, "a=1", "http://example.com/");
+        let later = from_unix_secs(NOW + 10);
+        let set_cookie = SetCookie::parse(b"a=2").unwrap();
+        jar.store(set_cookie, &uri("http://example.com/"), later).unwrap();
+        assert_eq!(jar.len(), 1);
+        let cookie = jar.iter().next().unwrap();
+        assert_eq!(cookie.value, "2");
+        assert_eq!(cookie.creation_time, now());
+    }
+
+    #[test]
+    fn test_jar_expiry() {
+        let mut jar = CookieJar::new();
+        store(&mut jar, "a=1; Max-Age=60", "http://example.com/");
+        store(
+            &mut jar,
+            "b=2; Expires=Thu, 01 Jan 2099 00:00:00 GMT",
+            "http://example.com/",
+        );
+        store(&mut jar, "c=3", "http://example.com/");
+        assert_eq!(jar.len(), 3);
+        // max-age wins over expires, delete
+        store(
+            &mut jar,
+            "b=2; Max-Age=0; Expires=Thu, 01 Jan 2099 00:00:00 GMT",
+            "http://example.com/",
+        );
+        store(
+            &mut jar,
+            "c=; Expires=Thu, 01 Jan 1970 00:00:00 GMT",
+            "http://example.com/",
+        );
+        assert_eq!(jar.len(), 1);
+        let later = from_unix_secs(NOW + 61);
+        let cookie = jar.cookie_header(
+            &uri("http://example.com/"),
+            later,
+            SiteContext::SameSite,
+        );
+        assert!(cookie.is_none());
+        assert!(jar.is_empty());
+    }
+
+    #[test]
+    fn test_jar_expiry_capped() {
+        let mut jar = CookieJar::new();
+        store(
+            &mut jar,
+            "a=1; Expires=Thu, 01 Jan 2099 00:00:00 GMT",
+            "http://example.com/",
+        );
+        // not parsed, Max-Age is not capped yet
+        let set_cookie = SetCookie {
+            max_age: Some(i64::MAX),
+            ..SetCookie::new("b", "2")
+        };
+        jar.store(set_cookie, &uri("http://example.com/"), now()).unwrap();
+        let latest = from_unix_secs(NOW + MAX_COOKIE_AGE as i64);
+        assert_eq!(jar.len(), 2);
+        assert!(jar.iter().all(|cookie| cookie.expiry == Some(latest)));
+    }
+
+    #[test]
+    fn test_jar_secure() {
+        let mut jar = CookieJar::new();
+        store(&mut jar, "s=1; Secure", "https://example.com/");
+        store(&mut jar, "p=2", "http://example.com/");
+        assert_eq!(
+            header(&mut jar, "http://example.com/").as_deref(),
+            Some("p=2")
+        );
+        assert_eq!(
+            header(&mut jar, "https://example.com/").as_deref(),
+            Some("s=1; p=2")
+        );
+        // insecure origin cannot shadow the secure cookie
+        let set_cookie = SetCookie::parse(b"s=evil").unwrap();
+        assert_eq!(
+            jar.store(set_cookie, &uri("http://example.com/"), now()),
+            Err(CookieRejected::OverwritesSecure)
+        );
+    }
+
+    #[test]
+    fn test_jar_rejected() {
+        assert_eq!(
+            rejected("a=1; Domain=other.com", "http://example.com/"),
+            CookieRejected::DomainMismatch("other.com".into())
+        );
+        assert_eq!(
+            rejected("a=1; Domain=com", "http://example.com/"),
+            CookieRejected::PublicSuffix("com".into())
+        );
+        assert_eq!(
+            rejected("a=1; Secure", "http://example.com/"),
+            CookieRejected::SecureFromInsecureOrigin
+        );
+        assert_eq!(
+            rejected("a=1; SameSite=None", "https://example.com/"),
+            CookieRejected::SameSiteNoneWithoutSecure
+        );
+        assert_eq!(
+            rejected("__Secure-a=1", "https://example.com/"),
+            CookieRejected::InvalidPrefix
+        );
+        assert_eq!(
+            rejected("__Host-a=1; Secure; Path=/app", "https://example.com/"),
+            CookieRejected::InvalidPrefix
+        );
+        assert_eq!(
+            rejected(
+                "__Host-a=1; Secure; Path=/; Domain=example.com",
+                "https://example.com/"
+            ),
+            CookieRejected::InvalidPrefix
+        );
+        assert_eq!(
+            rejected("__Host-a; Secure", "https://example.com/"),
+            CookieRejected::InvalidPrefix
+        );
+        assert_eq!(rejected("a=1", "/relative"), CookieRejected::NoHost);
+    }
+
+    #[test]
+    fn test_jar_host_prefix_accepted() {
+        let mut jar = CookieJar::new();
+        store(
+            &mut jar,
+            "__Host-id=1; Secure; Path=/",
+            "https://example.com/a",
+        );
+        assert_eq!(
+            header(&mut jar, "https://example.com/b").as_deref(),
+            Some("__Host-id=1")
+        );
+    }
+
+    #[test]
+    fn test_jar_same_site() {
+        let mut jar = CookieJar::new();
+        store(&mut jar, "strict=1; SameSite=Strict", "https://example.com/");
+        store(&mut jar, "lax=2; SameSite=Lax", "https://example.com/");
+        store(
+            &mut jar,
+            "none=3; SameSite=None; Secure",
+            "https://example.com/",
+        );
+        store(&mut jar, "unset=4", "https://example.com/");
+        let to = uri("https://example.com/");
+        let names = |jar: &mut CookieJar, context| {
+            jar.cookie_header(&to, now(), context)
+                .map(|cookie| cookie.to_string())
+        };
+        assert_eq!(
+            names(&mut jar, SiteContext::SameSite).as_deref(),
+            Some("strict=1; lax=2; none=3; unset=4")
+        );
+        assert_eq!(
+            names(&mut jar, SiteContext::CrossSiteNavigation).as_deref(),
+            Some("lax=2; none=3; unset=4")
+        );
+        assert_eq!(
+            names(&mut jar, SiteContext::CrossSite).as_deref(),
+            Some("none=3; unset=4")
+        );
+    }
+
+    #[test]
+    fn test_jar_store_response() {
+        let input = "Set-Cookie: a=1\r\n\
+                     Set-Cookie: b=2; Secure\r\n\
+                     Set-Cookie: =\r\n\r\n";
+        let map = OneHeaderMap::from(BytesMut::from(input));
+        let mut jar = CookieJar::new();
+        let rejected =
+            jar.store_response(&map, &uri("http://example.com/"), now());
+        assert_eq!(
+            rejected,
+            vec![
+                CookieRejected::SecureFromInsecureOrigin,
+                CookieRejected::Invalid(CookieError::Empty)
+            ]
+        );
+        assert_eq!(
+            header(&mut jar, "http://example.com/").as_deref(),
+            Some("a=1")
+        );
+    }
+}
@@ -0,0 +1,284 @@
+// Cookie request header
+// https://datatracker.ietf.org/doc/html/draft-ietf-httpbis-rfc6265bis#section-4.2
+
+use std::fmt::{self, Display};
+
+use thiserror::Error;
+
+use crate::const_headers::COOKIE;
+use crate::typed_header::TypedHeader;
+
+pub mod jar;
+pub mod set_cookie;
+
+pub use jar::CookieJar;
+pub use set_cookie::{SameSite, SetCookie};
+
+// name + value, larger cookies are ignored
+pub const MAX_COOKIE_LEN: usize = 4096;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum CookieError {
+    #[error("not utf8")]
+    NotUtf8,
+    #[error("empty name and value")]
+    Empty,
+    #[error("too large| {0}")]
+    TooLarge(usize),
+    #[error("control character")]
+    ControlCharacter,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CookiePair {
+    pub name: String,
+    pub value: String,
+}
+
+impl CookiePair {
+    pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
+        CookiePair {
+            name: name.into(),
+            value: value.into(),
+        }
+    }
+}
+
+// name=value, a pair without name is serialized as the value alone
+impl Display for CookiePair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.name.is_empty() {
+            f.write_str(&self.value)
+        } else {
+            write!(f, "{}={}", self.name, self.value)
+        }
+    }
+}
+
+/* Cookie header, pairs in order of the header.
+ *
+ * Values are kept as sent, including the quotes. The original value is
+ * serialized as is until the pairs are edited.
+ */
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Cookie {
+    pairs: Vec<CookiePair>,
+    raw: Option<String>,
+}
+
+impl Cookie {
+    pub fn new() -> Self {
+        Cookie::default()
+    }
+
+    pub fn parse(value: &[u8]) -> Result<Self, CookieError> {
+        let value = str::from_utf8(value).map_err(|_| CookieError::NotUtf8)?;
+        let pairs = value
+            .split(';')
+            .map(str::trim)
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| match pair.split_once('=') {
+                Some((name, value)) => {
+                    CookiePair::new(name.trim(), value.trim())
+                }
+                None => CookiePair::new("", pair),
+            })
+            .collect();
+        Ok(Cookie {
+            pairs,
+            raw: Some(value.to_string()),
+        })
+    }
+
+    pub fn pairs(&self) -> &[CookiePair] {
+        &self.pairs
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, CookiePair> {
+        self.pairs.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.pairs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+
+    // value of the first pair with name, names are case-sensitive
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.pairs
+            .iter()
+            .find(|pair| pair.name == name)
+            .map(|pair| pair.value.as_str())
+    }
+
+    pub fn get_all<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = &'a str> + 'a {
+        self.pairs
+            .iter()
+            .filter(move |pair| pair.name == name)
+            .map(|pair| pair.value.as_str())
+    }
+
+    // value of the first pair with name is replaced, appended when absent
+    pub fn set(&mut self, name: &str, value: &str) {
+        self.raw = None;
+        match self.pairs.iter_mut().find(|pair| pair.name == name) {
+            Some(pair) => pair.value = value.to_string(),
+            None => self.pairs.push(CookiePair::new(name, value)),
+        }
+    }
+
+    pub fn push(&mut self, pair: CookiePair) {
+        self.raw = None;
+        self.pairs.push(pair);
+    }
+
+    // every pair with name
+    pub fn remove(&mut self, name: &str) -> bool {
+        let len = self.pairs.len();
+        self.pairs.retain(|pair| pair.name != name);
+        let removed = self.pairs.len() != len;
+        if removed {
+            self.raw = None;
+        }
+        removed
+    }
+
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&CookiePair) -> bool,
+    {
+        self.raw = None;
+        self.pairs.retain(f);
+    }
+}
+
+impl Display for Cookie {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(raw) = &self.raw {
+            return f.write_str(raw);
+        }
+        for (index, pair) in self.pairs.iter().enumerate() {
+            if index > 0 {
+                f.write_str("; ")?;
+            }
+            pair.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromIterator<CookiePair> for Cookie {
+    fn from_iter<I: IntoIterator<Item = CookiePair>>(iter: I) -> Self {
+        Cookie {
+            pairs: iter.into_iter().collect(),
+            raw: None,
+        }
+    }
+}
+
+/* h2 and h3 may split the Cookie header into several field lines, they are
+ * joined with "; " (RFC 9113 8.2.3).
+ */
+impl TypedHeader for Cookie {
+    type Error = CookieError;
+
+    const NAME: &'static [u8] = COOKIE;
+
+    fn decode_values<'a, I>(values: I) -> Result<Self, Self::Error>
+    where
+        I: Iterator<Item = &'a [u8]>,
+    {
+        let mut joined = Vec::new();
+        for value in values {
+            if !joined.is_empty() {
+                joined.extend_from_slice(b"; ");
+            }
+            joined.extend_from_slice(value);
+        }
+        Self::parse(&joined)
+    }
+
+    fn encode_value(&self) -> String {
+        self.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{HeaderMap, OneHeaderMap};
+    use bytes::BytesMut;
+
+    #[test]
+    fn test_cookie_parse() {
+        let cookie =
+            Cookie::parse(b"SID=31d4d96e407aad42; lang=en-US").unwrap();
+        assert_eq!(
+            cookie.pairs(),
+            &[
+                CookiePair::new("SID", "31d4d96e407aad42"),
+                CookiePair::new("lang", "en-US")
+            ]
+        );
+        assert_eq!(cookie.get("lang"), Some("en-US"));
+        assert_eq!(cookie.get("LANG"), None);
+    }
+
+    #[test]
+    fn test_cookie_parse_lenient() {
+        let cookie =
+            Cookie::parse(br#"a=1;b = "x y" ;; nameless; c=d=e; d="#).unwrap();
+        assert_eq!(
+            cookie.pairs(),
+            &[
+                CookiePair::new("a", "1"),
+                CookiePair::new("b", r#""x y""#),
+                CookiePair::new("", "nameless"),
+                CookiePair::new("c", "d=e"),
+                CookiePair::new("d", ""),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cookie_lossless() {
+        let input = "a=1;b = 2 ;;  nameless";
+        let cookie = Cookie::parse(input.as_bytes()).unwrap();
+        assert_eq!(cookie.to_string(), input);
+    }
+
+    #[test]
+    fn test_cookie_edit() {
+        let mut cookie = Cookie::parse(b"a=1;b=2; a=3").unwrap();
+        assert_eq!(cookie.get_all("a").collect::<Vec<_>>(), vec!["1", "3"]);
+        cookie.set("b", "20");
+        cookie.set("c", "30");
+        assert_eq!(cookie.to_string(), "a=1; b=20; a=3; c=30");
+        assert!(cookie.remove("a"));
+        assert!(!cookie.remove("x"));
+        assert_eq!(cookie.to_string(), "b=20; c=30");
+        cookie.push(CookiePair::new("", "flag"));
+        assert_eq!(cookie.to_string(), "b=20; c=30; flag");
+    }
+
+    #[test]
+    fn test_cookie_typed_header_two() {
+        let input = "cookie: a=1\r\nhost: example.com\r\ncookie: b=2\r\n\r\n";
+        let mut map =
+            HeaderMap::from(OneHeaderMap::from(BytesMut::from(input)));
+        let mut cookie = Cookie::decode(&map).unwrap().unwrap();
+        assert_eq!(cookie.to_string(), "a=1; b=2");
+        cookie.remove("b");
+        cookie.encode(&mut map);
+        assert_eq!(
+            map.values_of_key(COOKIE).collect::<Vec<_>>(),
+            vec![b"a=1"]
+        );
+    }
+}
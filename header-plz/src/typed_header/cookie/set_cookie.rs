@@ -0,0 +1,330 @@
+// Set-Cookie response header
+// https://datatracker.ietf.org/doc/html/draft-ietf-httpbis-rfc6265bis#section-5.6
+
+use std::fmt::{self, Display};
+use std::time::SystemTime;
+
+use crate::const_headers::SET_COOKIE;
+use crate::message_head::header_map::{HMap, Hmap};
+use crate::typed_header::cookie::{CookieError, MAX_COOKIE_LEN};
+use crate::typed_header::date::{format_http_date, parse_cookie_date};
+
+// attribute values larger than this are ignored
+pub const MAX_ATTRIBUTE_VALUE_LEN: usize = 1024;
+
+// Max-Age and Expires are capped at 400 days in the future, in seconds
+pub const MAX_COOKIE_AGE: u64 = 400 * 24 * 60 * 60;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SameSite {
+    Strict,
+    Lax,
+    None,
+}
+
+impl SameSite {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Strict => "Strict",
+            Self::Lax => "Lax",
+            Self::None => "None",
+        }
+    }
+
+    // unknown values leave the attribute unset
+    fn parse(value: &str) -> Option<Self> {
+        if value.eq_ignore_ascii_case("strict") {
+            Some(Self::Strict)
+        } else if value.eq_ignore_ascii_case("lax") {
+            Some(Self::Lax)
+        } else if value.eq_ignore_ascii_case("none") {
+            Some(Self::None)
+        } else {
+            None
+        }
+    }
+}
+
+/* Set-Cookie header. Attributes follow the user agent parsing rules, ie.
+ * invalid attribute values are ignored and the last occurrence of an
+ * attribute wins.
+ *
+ *      expires     - None when absent or not a valid cookie-date
+ *      max_age     - seconds, may be negative
+ *      domain      - leading "." removed, lowercase
+ *      path        - None when absent or not starting with "/"
+ *      extensions  - unknown attributes, as sent
+ */
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SetCookie {
+    pub name: String,
+    pub value: String,
+    pub expires: Option<SystemTime>,
+    pub max_age: Option<i64>,
+    pub domain: Option<String>,
+    pub path: Option<String>,
+    pub secure: bool,
+    pub http_only: bool,
+    pub same_site: Option<SameSite>,
+    pub partitioned: bool,
+    pub extensions: Vec<String>,
+}
+
+impl SetCookie {
+    pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
+        SetCookie {
+            name: name.into(),
+            value: value.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn parse(value: &[u8]) -> Result<Self, CookieError> {
+        let value = str::from_utf8(value).map_err(|_| CookieError::NotUtf8)?;
+        if value.bytes().any(|b| b.is_ascii_control() && b != b'\t') {
+            return Err(CookieError::ControlCharacter);
+        }
+        let (pair, attributes) = value.split_once(';').unwrap_or((value, ""));
+        // a pair without "=" is a cookie with an empty name
+        let (name, value) = match pair.split_once('=') {
+            Some((name, value)) => (name.trim(), value.trim()),
+            None => ("", pair.trim()),
+        };
+        if name.is_empty() && value.is_empty() {
+            return Err(CookieError::Empty);
+        }
+        if name.len() + value.len() > MAX_COOKIE_LEN {
+            return Err(CookieError::TooLarge(name.len() + value.len()));
+        }
+        let mut set_cookie = SetCookie::new(name, value);
+        for attribute in attributes.split(';') {
+            set_cookie.parse_attribute(attribute);
+        }
+        Ok(set_cookie)
+    }
+
+    fn parse_attribute(&mut self, attribute: &str) {
+        let (name, value) = match attribute.split_once('=') {
+            Some((name, value)) => (name.trim(), value.trim()),
+            None => (attribute.trim(), ""),
+        };
+        if name.is_empty() {
+            return;
+        }
+        if value.len() > MAX_ATTRIBUTE_VALUE_LEN {
+            return;
+        }
+        match name.to_ascii_lowercase().as_str() {
+            "expires" => {
+                if let Some(expires) = parse_cookie_date(value.as_bytes()) {
+                    self.expires = Some(expires)
+                }
+            }
+            "max-age" => {
+                if let Some(max_age) = parse_max_age(value) {
+                    self.max_age = Some(max_age)
+                }
+            }
+            "domain" => {
+                let domain = value.strip_prefix('.').unwrap_or(value);
+                self.domain =
+                    (!domain.is_empty()).then(|| domain.to_ascii_lowercase());
+            }
+            "path" => {
+                self.path = value.starts_with('/').then(|| value.to_string());
+            }
+            "secure" => self.secure = true,
+            "httponly" => self.http_only = true,
+            "samesite" => self.same_site = SameSite::parse(value),
+            "partitioned" => self.partitioned = true,
+            _ => self.extensions.push(attribute.trim().to_string()),
+        }
+    }
+
+    // every Set-Cookie field line, never combined
+    pub fn from_header_map<T>(
+        header_map: &HMap<T>,
+    ) -> impl Iterator<Item = Result<Self, CookieError>> + '_
+    where
+        T: Hmap,
+    {
+        header_map.values_of_key(SET_COOKIE).map(Self::parse)
+    }
+
+    pub fn is_host_prefixed(&self) -> bool {
+        starts_with_ignore_case(&self.name, "__Host-")
+    }
+
+    pub fn is_secure_prefixed(&self) -> bool {
+        starts_with_ignore_case(&self.name, "__Secure-")
+    }
+}
+
+fn starts_with_ignore_case(name: &str, prefix: &str) -> bool {
+    name.get(..prefix.len())
+        .is_some_and(|start| start.eq_ignore_ascii_case(prefix))
+}
+
+// optional "-" followed by digits, capped at MAX_COOKIE_AGE
+fn parse_max_age(value: &str) -> Option<i64> {
+    let (negative, digits) = match value.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, value),
+    };
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let max_age =
+        digits.parse::<i64>().unwrap_or(i64::MAX).min(MAX_COOKIE_AGE as i64);
+    Some(if negative {
+        -max_age
+    } else {
+        max_age
+    })
+}
+
+impl Display for SetCookie {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.name.is_empty() {
+            f.write_str(&self.value)?;
+        } else {
+            write!(f, "{}={}", self.name, self.value)?;
+        }
+        if let Some(expires) = self.expires {
+            write!(f, "; Expires={}", format_http_date(expires))?;
+        }
+        if let Some(max_age) = self.max_age {
+            write!(f, "; Max-Age={max_age}")?;
+        }
+        if let Some(domain) = &self.domain {
+            write!(f, "; Domain={domain}")?;
+        }
+        if let Some(path) = &self.path {
+            write!(f, "; Path={path}")?;
+        }
+        if self.secure {
+            f.write_str("; Secure")?;
+        }
+        if self.http_only {
+            f.write_str("; HttpOnly")?;
+        }
+        if let Some(same_site) = self.same_site {
+            write!(f, "; SameSite={}", same_site.as_str())?;
+        }
+        if self.partitioned {
+            f.write_str("; Partitioned")?;
+        }
+        for extension in &self.extensions {
+            write!(f, "; {extension}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OneHeaderMap;
+    use crate::typed_header::date::from_unix_secs;
+    use bytes::BytesMut;
+
+    #[test]
+    fn test_set_cookie_parse_all_attributes() {
+        let input = "SID=31d4d96e407aad42; Expires=Wed, 09 Jun 2021 10:18:14 GMT; \
+                     Max-Age=3600; Domain=.Example.com; Path=/app; Secure; \
+                     HttpOnly; SameSite=Lax; Partitioned; Priority=High";
+        let cookie = SetCookie::parse(input.as_bytes()).unwrap();
+        let verify = SetCookie {
+            name: "SID".into(),
+            value: "31d4d96e407aad42".into(),
+            expires: Some(from_unix_secs(1_623_233_894)),
+            max_age: Some(3600),
+            domain: Some("example.com".into()),
+            path: Some("/app".into()),
+            secure: true,
+            http_only: true,
+            same_site: Some(SameSite::Lax),
+            partitioned: true,
+            extensions: vec!["Priority=High".into()],
+        };
+        assert_eq!(cookie, verify);
+    }
+
+    #[test]
+    fn test_set_cookie_invalid_attributes_ignored() {
+        let input = "a=b; Expires=never; Max-Age=1a; Path=relative; \
+                     Domain=; SameSite=Bogus; ; =x";
+        let cookie = SetCookie::parse(input.as_bytes()).unwrap();
+        assert_eq!(cookie, SetCookie::new("a", "b"));
+    }
+
+    #[test]
+    fn test_set_cookie_last_attribute_wins() {
+        let input = "a=b; Max-Age=10; max-age=-5; path=/a; PATH=/b";
+        let cookie = SetCookie::parse(input.as_bytes()).unwrap();
+        assert_eq!(cookie.max_age, Some(-5));
+        assert_eq!(cookie.path.as_deref(), Some("/b"));
+    }
+
+    #[test]
+    fn test_set_cookie_max_age_capped() {
+        let input = "a=b; Max-Age=99999999999999999999999";
+        let cookie = SetCookie::parse(input.as_bytes()).unwrap();
+        assert_eq!(cookie.max_age, Some(MAX_COOKIE_AGE as i64));
+        let input = "a=b; Max-Age=-99999999999999999999999";
+        let cookie = SetCookie::parse(input.as_bytes()).unwrap();
+        assert_eq!(cookie.max_age, Some(-(MAX_COOKIE_AGE as i64)));
+    }
+
+    #[test]
+    fn test_set_cookie_nameless_and_errors() {
+        let cookie = SetCookie::parse(b"token; Secure").unwrap();
+        assert_eq!(cookie.name, "");
+        assert_eq!(cookie.value, "token");
+        assert_eq!(SetCookie::parse(b"=; Secure"), Err(CookieError::Empty));
+        assert_eq!(
+            SetCookie::parse(b"a=b\x00"),
+            Err(CookieError::ControlCharacter)
+        );
+        let large = format!("a={}", "x".repeat(MAX_COOKIE_LEN));
+        assert_eq!(
+            SetCookie::parse(large.as_bytes()),
+            Err(CookieError::TooLarge(MAX_COOKIE_LEN + 1))
+        );
+    }
+
+    #[test]
+    fn test_set_cookie_prefixes() {
+        assert!(SetCookie::new("__Host-id", "1").is_host_prefixed());
+        assert!(SetCookie::new("__secure-id", "1").is_secure_prefixed());
+        assert!(!SetCookie::new("id", "1").is_secure_prefixed());
+    }
+
+    #[test]
+    fn test_set_cookie_display() {
+        let mut cookie = SetCookie::new("id", "a3fWa");
+        cookie.expires = Some(from_unix_secs(1_623_233_894));
+        cookie.path = Some("/".into());
+        cookie.secure = true;
+        cookie.same_site = Some(SameSite::Strict);
+        let verify = "id=a3fWa; Expires=Wed, 09 Jun 2021 10:18:14 GMT; \
+                      Path=/; Secure; SameSite=Strict";
+        assert_eq!(cookie.to_string(), verify);
+        assert_eq!(SetCookie::parse(verify.as_bytes()).unwrap(), cookie);
+    }
+
+    #[test]
+    fn test_set_cookie_from_header_map() {
+        let input = "Set-Cookie: a=1, b=2; Path=/\r\n\
+                     Host: example.com\r\n\
+                     Set-Cookie: c=3\r\n\r\n";
+        let map = OneHeaderMap::from(BytesMut::from(input));
+        let cookies = SetCookie::from_header_map(&map)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(cookies.len(), 2);
+        // not a list, the comma is part of the value
+        assert_eq!(cookies[0].value, "1, b=2");
+        assert_eq!(cookies[1].name, "c");
+    }
+}
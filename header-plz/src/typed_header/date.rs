@@ -0,0 +1,266 @@
+// Dates in header values
+// HTTP-date    - https://www.rfc-editor.org/rfc/rfc9110.html#section-5.6.7
+// cookie-date  - https://www.rfc-editor.org/rfc/rfc6265.html#section-5.1.1
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const SECS_PER_DAY: i64 = 86_400;
+
+const DAY_NAMES: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+const MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct",
+    "Nov", "Dec",
+];
+
+/* HTTP-date in any of the IMF-fixdate, rfc850 and asctime formats.
+ *
+ * Recipients should be lenient, the cookie-date algorithm accepts all three
+ * formats and the common variants.
+ */
+pub fn parse_http_date(value: &[u8]) -> Option<SystemTime> {
+    parse_cookie_date(value)
+}
+
+// IMF-fixdate, eg. Sun, 06 Nov 1994 08:49:37 GMT
+pub fn format_http_date(time: SystemTime) -> String {
+    let secs = to_unix_secs(time);
+    let days = secs.div_euclid(SECS_PER_DAY);
+    let day_secs = secs.rem_euclid(SECS_PER_DAY);
+    let (year, month, day) = civil_from_days(days);
+    // 1970-01-01 was a Thursday
+    let weekday = (days + 4).rem_euclid(7) as usize;
+    format!(
+        "{}, {:02} {} {:04} {:02}:{:02}:{:02} GMT",
+        DAY_NAMES[weekday],
+        day,
+        MONTH_NAMES[month as usize - 1],
+        year,
+        day_secs / 3600,
+        day_secs % 3600 / 60,
+        day_secs % 60
+    )
+}
+
+// Cookie date parsing algorithm, also used for Expires
+pub fn parse_cookie_date(value: &[u8]) -> Option<SystemTime> {
+    let mut time = None;
+    let mut day = None;
+    let mut month = None;
+    let mut year = None;
+    for token in value.split(|&b| is_delimiter(b)).filter(|t| !t.is_empty()) {
+        if time.is_none()
+            && let Some(t) = parse_time(token)
+        {
+            time = Some(t);
+        } else if day.is_none()
+            && let Some(d) = leading_digits(token, 1, 2)
+        {
+            day = Some(d);
+        } else if month.is_none()
+            && let Some(m) = parse_month(token)
+        {
+            month = Some(m);
+        } else if year.is_none()
+            && let Some(y) = leading_digits(token, 2, 4)
+        {
+            year = Some(y);
+        }
+    }
+    let (hour, minute, second) = time?;
+    let day = day?;
+    let month = month?;
+    let year = match year? {
+        y @ 70..=99 => y + 1900,
+        y @ 0..=69 => y + 2000,
+        y => y,
+    };
+    if !(1..=31).contains(&day)
+        || year < 1601
+        || hour > 23
+        || minute > 59
+        || second > 59
+        || day > days_in_month(year, month)
+    {
+        return None;
+    }
+    let days = days_from_civil(year as i64, month, day);
+    let secs = days * SECS_PER_DAY
+        + hour as i64 * 3600
+        + minute as i64 * 60
+        + second as i64;
+    Some(from_unix_secs(secs))
+}
+
+pub fn to_unix_secs(time: SystemTime) -> i64 {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(d) => d.as_secs() as i64,
+        Err(e) => -(e.duration().as_secs() as i64),
+    }
+}
+
+pub fn from_unix_secs(secs: i64) -> SystemTime {
+    if secs >= 0 {
+        UNIX_EPOCH + Duration::from_secs(secs as u64)
+    } else {
+        UNIX_EPOCH - Duration::from_secs(secs.unsigned_abs())
+    }
+}
+
+// %x09 / %x20-2F / %x3B-40 / %x5B-60 / %x7B-7E
+fn is_delimiter(b: u8) -> bool {
+    matches!(b, 0x09 | 0x20..=0x2F | 0x3B..=0x40 | 0x5B..=0x60 | 0x7B..=0x7E)
+}
+
+/* min to max digits, followed by nothing or a non digit. Trailing octets are
+ * ignored as the algorithm requires.
+ */
+fn leading_digits(token: &[u8], min: usize, max: usize) -> Option<u32> {
+    let len = token.iter().take_while(|b| b.is_ascii_digit()).count();
+    if len < min || len > max {
+        return None;
+    }
+    token[..len]
+        .iter()
+        .try_fold(0u32, |acc, b| Some(acc * 10 + (b - b'0') as u32))
+}
+
+// hms-time = time-field ":" time-field ":" time-field
+fn parse_time(token: &[u8]) -> Option<(u32, u32, u32)> {
+    let mut fields = token.splitn(3, |&b| b == b':');
+    let hour = fields.next()?;
+    let minute = fields.next()?;
+    let second = fields.next()?;
+    let exact = |f: &[u8]| {
+        leading_digits(f, 1, 2).filter(|_| f.iter().all(u8::is_ascii_digit))
+    };
+    Some((exact(hour)?, exact(minute)?, leading_digits(second, 1, 2)?))
+}
+
+fn parse_month(token: &[u8]) -> Option<u32> {
+    let prefix = token.get(..3)?;
+    MONTH_NAMES
+        .iter()
+        .position(|m| m.as_bytes().eq_ignore_ascii_case(prefix))
+        .map(|pos| pos as u32 + 1)
+}
+
+fn is_leap_year(year: u32) -> bool {
+    (year.is_multiple_of(4) && !year.is_multiple_of(100))
+        || year.is_multiple_of(400)
+}
+
+fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// days since 1970-01-01 of a proleptic gregorian date
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 {
+        year - 1
+    } else {
+        year
+    };
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let month = month as i64;
+    let doy = (153
+        * (month
+            + if month > 2 {
+                -3
+            } else {
+                9
+            })
+        + 2)
+        / 5
+        + day as i64
+        - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 {
+        mp + 3
+    } else {
+        mp - 9
+    } as u32;
+    let year = yoe
+        + era * 400
+        + if month <= 2 {
+            1
+        } else {
+            0
+        };
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Sun, 06 Nov 1994 08:49:37 GMT
+    const RFC_EXAMPLE: i64 = 784_111_777;
+
+    fn parse(value: &str) -> Option<i64> {
+        parse_http_date(value.as_bytes()).map(to_unix_secs)
+    }
+
+    #[test]
+    fn test_parse_http_date_formats() {
+        assert_eq!(parse("Sun, 06 Nov 1994 08:49:37 GMT"), Some(RFC_EXAMPLE));
+        assert_eq!(parse("Sunday, 06-Nov-94 08:49:37 GMT"), Some(RFC_EXAMPLE));
+        assert_eq!(parse("Sun Nov  6 08:49:37 1994"), Some(RFC_EXAMPLE));
+    }
+
+    #[test]
+    fn test_parse_cookie_date_variants() {
+        assert_eq!(parse("06 nov 1994 08:49:37"), Some(RFC_EXAMPLE));
+        assert_eq!(
+            parse("Wed, 09 Jun 2021 10:18:14 GMT"),
+            Some(1_623_233_894)
+        );
+        assert_eq!(parse("Thu, 01 Jan 1970 00:00:00 GMT"), Some(0));
+        assert_eq!(parse("Fri, 31 Dec 1999 23:59:59 GMT"), Some(946_684_799));
+        assert_eq!(parse("Tue, 29 Feb 2000 00:00:00 GMT"), Some(951_782_400));
+    }
+
+    #[test]
+    fn test_parse_cookie_date_invalid() {
+        assert_eq!(parse(""), None);
+        assert_eq!(parse("Sun, 06 Nov 1994"), None);
+        assert_eq!(parse("Sun, 32 Nov 1994 08:49:37 GMT"), None);
+        assert_eq!(parse("Sun, 06 Nov 1994 24:49:37 GMT"), None);
+        assert_eq!(parse("Sun, 06 Foo 1994 08:49:37 GMT"), None);
+        assert_eq!(parse("Mon, 29 Feb 2021 00:00:00 GMT"), None);
+        assert_eq!(parse("Mon, 01 Jan 1600 00:00:00 GMT"), None);
+    }
+
+    #[test]
+    fn test_format_http_date() {
+        assert_eq!(
+            format_http_date(from_unix_secs(RFC_EXAMPLE)),
+            "Sun, 06 Nov 1994 08:49:37 GMT"
+        );
+        assert_eq!(
+            format_http_date(from_unix_secs(0)),
+            "Thu, 01 Jan 1970 00:00:00 GMT"
+        );
+        assert_eq!(
+            format_http_date(from_unix_secs(-1)),
+            "Wed, 31 Dec 1969 23:59:59 GMT"
+        );
+    }
+}
@@ -0,0 +1,222 @@
+// Field value lists
+// https://www.rfc-editor.org/rfc/rfc9110.html#section-5.6.1
+
+use std::borrow::Cow;
+
+const DQUOTE: u8 = b'"';
+const BACKSLASH: u8 = b'\\';
+
+/* Iterator over the members of value separated by delimiter.
+ *
+ * Delimiters inside a quoted-string, including escaped quotes, do not split.
+ * Members are trimmed of optional whitespace and empty members are skipped,
+ * as recipients must accept "a, , b" as "a, b".
+ */
+#[derive(Clone, Debug)]
+pub struct SplitUnquoted<'a> {
+    value: &'a [u8],
+    delimiter: u8,
+}
+
+impl<'a> Iterator for SplitUnquoted<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.value.is_empty() {
+            let end = find_unquoted(self.value, self.delimiter)
+                .unwrap_or(self.value.len());
+            let member = trim_ows(&self.value[..end]);
+            self.value = self.value.get(end + 1..).unwrap_or_default();
+            if !member.is_empty() {
+                return Some(member);
+            }
+        }
+        None
+    }
+}
+
+pub fn split_unquoted(value: &[u8], delimiter: u8) -> SplitUnquoted<'_> {
+    SplitUnquoted {
+        value,
+        delimiter,
+    }
+}
+
+// comma separated list
+pub fn split_list(value: &[u8]) -> SplitUnquoted<'_> {
+    split_unquoted(value, b',')
+}
+
+// members of every field line, a list may span several lines
+pub fn split_lines<'a, I>(values: I) -> impl Iterator<Item = &'a [u8]>
+where
+    I: IntoIterator<Item = &'a [u8]>,
+{
+    values.into_iter().flat_map(split_list)
+}
+
+// field lines combined into one value, as a recipient may do
+pub fn join_lines<'a, I>(values: I) -> Vec<u8>
+where
+    I: IntoIterator<Item = &'a [u8]>,
+{
+    let mut joined = Vec::new();
+    for value in values {
+        if !joined.is_empty() {
+            joined.extend_from_slice(b", ");
+        }
+        joined.extend_from_slice(value);
+    }
+    joined
+}
+
+// position of the first delimiter outside a quoted-string
+pub fn find_unquoted(value: &[u8], delimiter: u8) -> Option<usize> {
+    let mut quoted = false;
+    let mut escaped = false;
+    for (pos, &byte) in value.iter().enumerate() {
+        if escaped {
+            escaped = false;
+        } else if quoted && byte == BACKSLASH {
+            escaped = true;
+        } else if byte == DQUOTE {
+            quoted = !quoted;
+        } else if !quoted && byte == delimiter {
+            return Some(pos);
+        }
+    }
+    None
+}
+
+pub fn trim_ows(value: &[u8]) -> &[u8] {
+    value.trim_ascii()
+}
+
+/* Content of a quoted-string with the escapes removed, value is returned as
+ * is when not quoted.
+ */
+pub fn unquote(value: &[u8]) -> Cow<'_, [u8]> {
+    let Some(inner) =
+        value.strip_prefix(&[DQUOTE]).and_then(|v| v.strip_suffix(&[DQUOTE]))
+    else {
+        return Cow::Borrowed(value);
+    };
+    if !inner.contains(&BACKSLASH) {
+        return Cow::Borrowed(inner);
+    }
+    let mut unescaped = Vec::with_capacity(inner.len());
+    let mut escaped = false;
+    for &byte in inner {
+        if !escaped && byte == BACKSLASH {
+            escaped = true;
+        } else {
+            escaped = false;
+            unescaped.push(byte);
+        }
+    }
+    Cow::Owned(unescaped)
+}
+
+// tchar, https://www.rfc-editor.org/rfc/rfc9110.html#section-5.6.2
+pub fn is_tchar(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&byte)
+}
+
+pub fn is_token(value: &[u8]) -> bool {
+    !value.is_empty() && value.iter().all(|&b| is_tchar(b))
+}
+
+// value as token when possible, quoted-string otherwise
+pub fn quote_if_needed(value: &str) -> Cow<'_, str> {
+    if is_token(value.as_bytes()) {
+        return Cow::Borrowed(value);
+    }
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        if c == '"' || c == '\\' {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('"');
+    Cow::Owned(quoted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(value: &str) -> Vec<&str> {
+        split_list(value.as_bytes())
+            .map(|m| str::from_utf8(m).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_split_list() {
+        assert_eq!(split("gzip, br,deflate"), vec!["gzip", "br", "deflate"]);
+    }
+
+    #[test]
+    fn test_split_list_empty_members() {
+        assert_eq!(split(" , a,, b ,\t,"), vec!["a", "b"]);
+        assert!(split("").is_empty());
+        assert!(split(" , ").is_empty());
+    }
+
+    #[test]
+    fn test_split_list_quoted() {
+        assert_eq!(
+            split(r#"a="x, y", b, private="set-cookie, vary""#),
+            vec![r#"a="x, y""#, "b", r#"private="set-cookie, vary""#]
+        );
+    }
+
+    #[test]
+    fn test_split_list_escaped_quote() {
+        assert_eq!(split(r#"a="x\", y", b"#), vec![r#"a="x\", y""#, "b"]);
+        assert_eq!(split(r#"a="\\", b"#), vec![r#"a="\\""#, "b"]);
+    }
+
+    #[test]
+    fn test_split_list_unterminated_quote() {
+        assert_eq!(split(r#"a="x, y"#), vec![r#"a="x, y"#]);
+    }
+
+    #[test]
+    fn test_split_unquoted_params() {
+        let members: Vec<&[u8]> =
+            split_unquoted(br#"text/html; q=0.5; a="b;c""#, b';').collect();
+        assert_eq!(members, vec![&b"text/html"[..], b"q=0.5", br#"a="b;c""#]);
+    }
+
+    #[test]
+    fn test_split_lines() {
+        let lines: [&[u8]; 3] = [b"a, b", b"", b"c"];
+        let members: Vec<&[u8]> = split_lines(lines).collect();
+        assert_eq!(members, vec![&b"a"[..], b"b", b"c"]);
+    }
+
+    #[test]
+    fn test_join_lines() {
+        let lines: [&[u8]; 2] = [b"a", b"b, c"];
+        assert_eq!(join_lines(lines), b"a, b, c");
+    }
+
+    #[test]
+    fn test_unquote() {
+        assert_eq!(unquote(b"token"), &b"token"[..]);
+        assert_eq!(unquote(br#""a, b""#), &b"a, b"[..]);
+        assert_eq!(unquote(br#""a \"b\" \\""#), &br#"a "b" \"#[..]);
+        assert_eq!(unquote(br#"""#), &br#"""#[..]);
+    }
+
+    #[test]
+    fn test_quote_if_needed() {
+        assert_eq!(quote_if_needed("no-cache"), "no-cache");
+        assert_eq!(quote_if_needed("a, b"), r#""a, b""#);
+        assert_eq!(quote_if_needed(r#"a"b\"#), r#""a\"b\\""#);
+        assert_eq!(quote_if_needed(""), r#""""#);
+    }
+}
@@ -0,0 +1,196 @@
+use crate::message_head::header_map::{HMap, Hmap};
+
+pub mod accept;
+pub mod cache;
+pub mod cookie;
+pub mod date;
+pub mod list;
+
+/* Header with a typed value, decoded from and encoded into a header map of
+ * either version.
+ *
+ *      NAME            - lowercase field name
+ *      decode_values   - value from the field lines with NAME, in order. A
+ *                        list may span several lines, see list::split_lines
+ *      encode_value    - field value
+ */
+pub trait TypedHeader: Sized {
+    type Error;
+
+    const NAME: &'static [u8];
+
+    fn decode_values<'a, I>(values: I) -> Result<Self, Self::Error>
+    where
+        I: Iterator<Item = &'a [u8]>;
+
+    fn encode_value(&self) -> String;
+
+    // None when the header is absent
+    fn decode<T>(header_map: &HMap<T>) -> Option<Result<Self, Self::Error>>
+    where
+        T: Hmap,
+    {
+        let mut values = header_map.values_of_key(Self::NAME).peekable();
+        values.peek()?;
+        Some(Self::decode_values(values))
+    }
+
+    /* Value is set on the first field line with NAME and the others are
+     * removed. Inserted at the end when absent.
+     */
+    fn encode<T>(&self, header_map: &mut HMap<T>)
+    where
+        T: Hmap + for<'a> From<(&'a [u8], &'a [u8])>,
+    {
+        let value = self.encode_value();
+        let mut positions = header_map
+            .header_key_positions(Self::NAME)
+            .collect::<Vec<usize>>()
+            .into_iter();
+        match positions.next() {
+            Some(first) => {
+                header_map.update_header_value_on_position(first, &value);
+                header_map.remove_header_multiple_positions(positions);
+            }
+            None => header_map.insert(Self::NAME, value.as_bytes()),
+        }
+    }
+}
+
+/* TypedHeader for a singleton header with parse(&[u8]) and Display. Field
+ * lines are combined before parsing, as for structured fields.
+ */
+macro_rules! impl_typed_header {
+    ($typ:ty, $name:expr, $error:ty) => {
+        impl $crate::typed_header::TypedHeader for $typ {
+            type Error = $error;
+
+            const NAME: &'static [u8] = $name;
+
+            fn decode_values<'a, I>(values: I) -> Result<Self, Self::Error>
+            where
+                I: Iterator<Item = &'a [u8]>,
+            {
+                Self::parse(&$crate::typed_header::list::join_lines(values))
+            }
+
+            fn encode_value(&self) -> String {
+                self.to_string()
+            }
+        }
+    };
+}
+
+/* TypedHeader for a singleton header whose value may contain commas, eg. a
+ * date. Only the first field line is used.
+ */
+macro_rules! impl_first_line_header {
+    ($typ:ty, $name:expr, $error:ty) => {
+        impl $crate::typed_header::TypedHeader for $typ {
+            type Error = $error;
+
+            const NAME: &'static [u8] = $name;
+
+            fn decode_values<'a, I>(mut values: I) -> Result<Self, Self::Error>
+            where
+                I: Iterator<Item = &'a [u8]>,
+            {
+                Self::parse(values.next().unwrap_or_default())
+            }
+
+            fn encode_value(&self) -> String {
+                self.to_string()
+            }
+        }
+    };
+}
+
+pub(crate) use impl_first_line_header;
+pub(crate) use impl_typed_header;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{HeaderMap, OneHeaderMap};
+    use bytes::BytesMut;
+
+    // list of tokens
+    #[derive(Debug, PartialEq)]
+    struct Vary(Vec<String>);
+
+    impl TypedHeader for Vary {
+        type Error = std::str::Utf8Error;
+
+        const NAME: &'static [u8] = b"vary";
+
+        fn decode_values<'a, I>(values: I) -> Result<Self, Self::Error>
+        where
+            I: Iterator<Item = &'a [u8]>,
+        {
+            list::split_lines(values)
+                .map(|m| str::from_utf8(m).map(str::to_string))
+                .collect::<Result<_, _>>()
+                .map(Vary)
+        }
+
+        fn encode_value(&self) -> String {
+            self.0.join(", ")
+        }
+    }
+
+    fn one(input: &str) -> OneHeaderMap {
+        OneHeaderMap::from(BytesMut::from(input))
+    }
+
+    fn vary(members: &[&str]) -> Vary {
+        Vary(members.iter().map(|m| m.to_string()).collect())
+    }
+
+    #[test]
+    fn test_typed_header_decode_absent() {
+        let map = one("Host: example.com\r\n\r\n");
+        assert!(Vary::decode(&map).is_none());
+        assert!(Vary::decode(&HeaderMap::from(map)).is_none());
+    }
+
+    #[test]
+    fn test_typed_header_decode_multiple_lines() {
+        let map = one("Vary: Accept, ,Origin\r\n\
+                       Host: example.com\r\n\
+                       vary: Cookie\r\n\r\n");
+        let verify = vary(&["Accept", "Origin", "Cookie"]);
+        assert_eq!(Vary::decode(&map), Some(Ok(verify)));
+        let verify = vary(&["Accept", "Origin", "Cookie"]);
+        assert_eq!(Vary::decode(&HeaderMap::from(map)), Some(Ok(verify)));
+    }
+
+    #[test]
+    fn test_typed_header_encode_one() {
+        let mut map = one("Vary: Accept\r\n\
+                           Host: example.com\r\n\
+                           Vary: Cookie\r\n\r\n");
+        vary(&["Origin"]).encode(&mut map);
+        let verify = "Vary: Origin\r\n\
+                      Host: example.com\r\n\r\n";
+        assert_eq!(map.into_bytes(), verify);
+
+        let mut map = one("Host: example.com\r\n");
+        vary(&["Accept", "Cookie"]).encode(&mut map);
+        let verify = "Host: example.com\r\n\
+                      vary: Accept, Cookie\r\n";
+        assert_eq!(map.into_bytes(), verify);
+    }
+
+    #[test]
+    fn test_typed_header_encode_two() {
+        let mut map = HeaderMap::from(one("Host: example.com\r\n\
+                                           Vary: Accept\r\n\
+                                           Vary: Cookie\r\n\r\n"));
+        vary(&["Origin"]).encode(&mut map);
+        assert_eq!(Vary::decode(&map), Some(Ok(vary(&["Origin"]))));
+
+        let mut map = HeaderMap::new();
+        vary(&["Accept"]).encode(&mut map);
+        assert_eq!(map.value_of_key("vary"), Some(&b"Accept"[..]));
+    }
+}
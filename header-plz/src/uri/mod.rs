@@ -1,4 +1,5 @@
 use bytes::Bytes;
+use mime_plz::{MediaType, MimeRecord, lookup_path};
 use thiserror::Error;
 
 use crate::{
@@ -83,6 +84,11 @@ impl Uri {
         self.path_and_query.query()
     }
 
+    // from the extension of the last path segment, see mime_plz::lookup_path
+    pub fn media_type_from_path(&self) -> Option<MediaType> {
+        lookup_path(self.path()).map(MimeRecord::media_type)
+    }
+
     pub fn authority(&self) -> Option<&str> {
         if self.authority.is_empty() {
             None
@@ -532,4 +538,16 @@ mod tests {
         assert_eq!(uri.scheme().unwrap().as_str(), "mailto");
         assert!(uri.authority().unwrap().contains("user@example.com"));
     }
+
+    #[test]
+    fn test_uri_media_type_from_path() {
+        let uri = Uri::from_shared(Bytes::from(
+            "http://example.com/assets/app.js?v=1.2",
+        ))
+        .unwrap();
+        let media_type = uri.media_type_from_path().unwrap();
+        assert_eq!(media_type.essence(), "text/javascript");
+        let uri = Uri::from_shared(Bytes::from("/v1.2/users")).unwrap();
+        assert!(uri.media_type_from_path().is_none());
+    }
 }
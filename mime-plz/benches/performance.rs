@@ -1,11 +1,67 @@
+use std::collections::HashMap;
+use std::hint::black_box;
+use std::sync::LazyLock;
+
 use criterion::{Criterion, criterion_group, criterion_main};
-use mime_plz::from_extension::EXTENSION_MAP;
+use mime_plz::ContentType;
+#[allow(deprecated)]
+use mime_plz::from_extension::{
+    EXT_APP, EXT_AUDIO, EXT_FONT, EXT_IMAGE, EXT_MESSAGE, EXT_MODEL, EXT_TEXT,
+    EXT_VIDEO,
+};
+use mime_plz::from_extension::{EXTENSION_MAP, content_type};
+
+const EXTENSIONS: [&str; 6] = ["wvx", "png", "json", "html", "woff2", "nope"];
+
+// the previous lazy_static map, for comparison
+#[allow(deprecated)]
+fn hashmap() -> HashMap<&'static str, ContentType> {
+    let mut m = HashMap::new();
+    for (ct, exts) in [
+        (ContentType::Application, &EXT_APP[..]),
+        (ContentType::Audio, &EXT_AUDIO[..]),
+        (ContentType::Font, &EXT_FONT[..]),
+        (ContentType::Image, &EXT_IMAGE[..]),
+        (ContentType::Message, &EXT_MESSAGE[..]),
+        (ContentType::Model, &EXT_MODEL[..]),
+        (ContentType::Text, &EXT_TEXT[..]),
+        (ContentType::Video, &EXT_VIDEO[..]),
+    ]
+    .iter()
+    {
+        for &ext in *exts {
+            m.insert(ext, *ct);
+        }
+    }
+    m
+}
+
+static HASHMAP: LazyLock<HashMap<&'static str, ContentType>> =
+    LazyLock::new(hashmap);
 
 pub fn criterion_benchmark_hashmap(c: &mut Criterion) {
     let mut group = c.benchmark_group("content_type");
     group.bench_function("hashmap", |b| {
-        b.iter(|| EXTENSION_MAP.get("wvx").copied());
+        b.iter(|| {
+            EXTENSIONS
+                .iter()
+                .filter_map(|ext| HASHMAP.get(black_box(ext)).copied())
+                .count()
+        });
+    });
+    group.bench_function("hashmap_init", |b| b.iter(hashmap));
+    group.bench_function("phf", |b| {
+        b.iter(|| {
+            EXTENSIONS
+                .iter()
+                .filter_map(|ext| EXTENSION_MAP.get(black_box(ext)).copied())
+                .count()
+        });
+    });
+    group.bench_function("phf_case_insensitive", |b| {
+        b.iter(|| content_type(black_box("WOFF2")));
     });
+    group.finish();
 }
 
 criterion_group!(benches, criterion_benchmark_hashmap,);
@@ -1,6 +1,6 @@
 // https://raw.githubusercontent.com/jshttp/mime-db/master/db.json
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fs::{
     File, {self},
 };
@@ -8,8 +8,19 @@ use std::io::Write;
 
 use serde_json::Value;
 
-const LABELS: [&str; 8] =
-    ["APP", "AUDIO", "FONT", "IMAGE", "MESSAGE", "MODEL", "TEXT", "VIDEO"];
+/* Main types with a ContentType variant, the variant and the label of the
+ * deprecated EXT_ array.
+ */
+const CATEGORIES: [(&str, &str, &str); 8] = [
+    ("application", "Application", "APP"),
+    ("audio", "Audio", "AUDIO"),
+    ("font", "Font", "FONT"),
+    ("image", "Image", "IMAGE"),
+    ("message", "Message", "MESSAGE"),
+    ("model", "Model", "MODEL"),
+    ("text", "Text", "TEXT"),
+    ("video", "Video", "VIDEO"),
+];
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("cargo:rerun-if-changed=./artifacts/db.json");
@@ -17,111 +28,205 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let file_content =
         fs::read_to_string("./artifacts/db.json").expect("error reading file");
     let result: Value = serde_json::from_str::<Value>(&file_content)?;
-    let mut app_vec: Vec<&str> = Vec::new();
-    let mut audio_vec: Vec<&str> = Vec::new();
-    let mut font_vec: Vec<&str> = Vec::new();
-    let mut image_vec: Vec<&str> = Vec::new();
-    let mut message_vec: Vec<&str> = Vec::new();
-    let mut model_vec: Vec<&str> = Vec::new();
-    let mut text_vec: Vec<&str> = Vec::new();
-    let mut video_vec: Vec<&str> = Vec::new();
 
     if let Value::Object(map) = result {
-        for entry in map.iter() {
-            let extension = &entry.1["extensions"];
-            if !extension.is_null()
-                && let Value::Array(arr) = extension
-            {
-                let result: Vec<&str> = arr
-                    .iter()
-                    .filter_map(|x| {
-                        let s = x.as_str();
-                        if s.is_none() {
-                            eprintln!("non-string element in array| {:?}", x);
-                        }
-                        s
-                    })
-                    .collect();
-                if let Some((category, _)) = entry.0.split_once('/') {
-                    match category {
-                        "application" => app_vec.extend(result),
-                        "audio" => audio_vec.extend(result),
-                        "font" => font_vec.extend(result),
-                        "image" => image_vec.extend(result),
-                        "message" => message_vec.extend(result),
-                        "model" => model_vec.extend(result),
-                        "text" => text_vec.extend(result),
-                        "video" => video_vec.extend(result),
-                        _ => eprintln!("Unknown MIME category: {}", category),
-                    }
-                } else {
-                    eprintln!(
-                        "Malformed MIME type (missing '/'): {}",
-                        entry.0
-                    );
-                }
-            }
+        let mut records: Vec<(&String, &Value)> = map.iter().collect();
+        records.sort_by(|one, two| one.0.cmp(two.0));
+        let extensions = preferred_extensions(&records);
+        if let Err(e) = write_file_at(
+            "./src/mime_db/db.rs",
+            db_to_string(&records, &extensions),
+        ) {
+            eprintln!("error writing file| db| {e}");
         }
-
-        // https://github.com/jshttp/mime-db/issues/207
-        // remove mp4 from application
-        app_vec.retain(|&x| x != "mp4");
-
-        // unique across vectors
-        let mut vectors = vec![
-            app_vec,
-            audio_vec,
-            font_vec,
-            image_vec,
-            message_vec,
-            model_vec,
-            text_vec,
-            video_vec,
-        ];
-
-        // sort and remove duplicates
-        vectors.iter_mut().for_each(|v| {
-            v.sort();
-            v.dedup();
-        });
-
-        vectors = unique_across_vectors(vectors);
-
-        for (label, vec) in LABELS.iter().zip(vectors) {
-            let string = vec_to_string(label, vec);
-            if let Err(e) = write_file(label.to_lowercase().as_str(), string) {
-                eprintln!("error writing file| {label}| {e}");
-            }
+        if let Err(e) = write_file_at(
+            "./src/mime_type/extension_map.rs",
+            extension_map_to_string(&records),
+        ) {
+            eprintln!("error writing file| extension map| {e}");
         }
     }
     Ok(())
 }
 
-fn vec_to_string(content_type: &str, vec: Vec<&str>) -> String {
+// index in CATEGORIES of the main type of essence
+fn category(essence: &str) -> Option<usize> {
+    let (main_type, _) = essence.split_once('/')?;
+    CATEGORIES.iter().position(|(name, ..)| *name == main_type)
+}
+
+/* Perfect hash map of lowercase extension to content type, and the
+ * deprecated EXT_ arrays with the same data. An extension listed under
+ * several main types goes to the first one in CATEGORIES.
+ */
+fn extension_map_to_string(records: &[(&String, &Value)]) -> String {
+    let mut vectors: Vec<Vec<&str>> = vec![Vec::new(); CATEGORIES.len()];
+    for (essence, record) in records {
+        if let Some(category) = category(essence) {
+            vectors[category].extend(record_extensions(record));
+        }
+    }
+
+    // https://github.com/jshttp/mime-db/issues/207
+    // remove mp4 from application
+    vectors[0].retain(|&x| x != "mp4");
+
+    // sort and remove duplicates
+    vectors.iter_mut().for_each(|v| {
+        v.sort();
+        v.dedup();
+    });
+
+    let vectors = unique_across_vectors(vectors);
+    let mut map = phf_codegen::Map::new();
+    for ((_, variant, _), vec) in CATEGORIES.iter().zip(&vectors) {
+        for ext in vec {
+            map.entry(*ext, &format!("ContentType::{variant}"));
+        }
+    }
+    let mut data = format!(
+        "// generated by build.rs from artifacts/db.json\n\n\
+         use crate::ContentType;\n\n\
+         #[rustfmt::skip]\n\
+         pub static EXTENSION_MAP: phf::Map<&'static str, ContentType> = {};\n",
+        map.build()
+    );
+    for ((.., label), vec) in CATEGORIES.iter().zip(vectors) {
+        data.push_str(&vec_to_string(label, vec));
+    }
+    data.pop();
+    data
+}
+
+fn unique_across_vectors(vectors: Vec<Vec<&str>>) -> Vec<Vec<&str>> {
+    let mut seen = HashSet::new();
+    vectors
+        .into_iter()
+        .map(|vec| vec.into_iter().filter(|&item| seen.insert(item)).collect())
+        .collect()
+}
+
+fn vec_to_string(label: &str, vec: Vec<&str>) -> String {
     let len = vec.len();
     let mut ct_string = format!(
-        "#[rustfmt::skip]\npub const EXT_{content_type}: [&str; {len}] = ["
+        "\n#[rustfmt::skip]\n\
+         #[deprecated(note = \"use from_extension::content_type\")]\n\
+         pub const EXT_{label}: [&str; {len}] = ["
     );
     for ext in vec {
         ct_string.push_str(&format!(r#""{ext}","#));
     }
     ct_string.pop();
-    ct_string.push_str("];");
+    ct_string.push_str("];\n");
     ct_string
 }
 
-fn write_file(name: &str, data: String) -> Result<(), std::io::Error> {
-    let path = format!("./src/mime_type/{name}.rs");
+fn write_file_at(path: &str, data: String) -> Result<(), std::io::Error> {
     let mut file = File::create(path)?;
     file.write_all(data.as_bytes())?;
     file.write_all("\n".as_bytes())?;
     Ok(())
 }
 
-fn unique_across_vectors(vectors: Vec<Vec<&str>>) -> Vec<Vec<&str>> {
-    let mut seen = HashSet::new();
-    vectors
-        .into_iter()
-        .map(|vec| vec.into_iter().filter(|&item| seen.insert(item)).collect())
-        .collect()
+// Extensions sorted with the index of their preferred record
+fn preferred_extensions<'a>(
+    records: &[(&String, &'a Value)],
+) -> Vec<(&'a str, usize)> {
+    let mut preferred: HashMap<&str, usize> = HashMap::new();
+    for (index, (essence, record)) in records.iter().enumerate() {
+        for ext in record_extensions(record) {
+            // https://github.com/jshttp/mime-db/issues/207
+            if essence.as_str() == "application/mp4" && ext == "mp4" {
+                continue;
+            }
+            if let Some(&current) = preferred.get(ext) {
+                let (current_essence, current_record) = records[current];
+                let from = source_rank(&current_record["source"]);
+                let to = source_rank(&record["source"]);
+                if current_essence != "application/octet-stream"
+                    && (from > to
+                        || (from == to
+                            && current_essence.starts_with("application/")))
+                {
+                    continue;
+                }
+            }
+            preferred.insert(ext, index);
+        }
+    }
+    let mut extensions: Vec<(&str, usize)> = preferred.into_iter().collect();
+    extensions.sort();
+    extensions
+}
+
+fn record_extensions(record: &Value) -> Vec<&str> {
+    record["extensions"]
+        .as_array()
+        .map(|arr| arr.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default()
+}
+
+/* Full records sorted by media type, and extensions sorted with the index
+ * of their preferred record.
+ */
+fn db_to_string(
+    records: &[(&String, &Value)],
+    extensions: &[(&str, usize)],
+) -> String {
+    let mut db = format!(
+        "// generated by build.rs from artifacts/db.json\n\n\
+         use crate::mime_db::{{MimeRecord, MimeSource}};\n\n\
+         #[rustfmt::skip]\n\
+         pub(crate) static MIME_DB: [MimeRecord; {}] = [\n",
+        records.len()
+    );
+    for (essence, record) in records {
+        let source = match record["source"].as_str() {
+            Some("iana") => "Some(MimeSource::Iana)",
+            Some("apache") => "Some(MimeSource::Apache)",
+            Some("nginx") => "Some(MimeSource::Nginx)",
+            _ => "None",
+        };
+        let compressible = match record["compressible"].as_bool() {
+            Some(value) => format!("Some({value})"),
+            None => "None".to_string(),
+        };
+        let charset = match record["charset"].as_str() {
+            Some(value) => format!(r#"Some("{value}")"#),
+            None => "None".to_string(),
+        };
+        let quoted: Vec<String> = record_extensions(record)
+            .iter()
+            .map(|ext| format!(r#""{ext}""#))
+            .collect();
+        db.push_str(&format!(
+            "    MimeRecord {{ essence: \"{essence}\", source: {source}, \
+             compressible: {compressible}, charset: {charset}, \
+             extensions: &[{}] }},\n",
+            quoted.join(", ")
+        ));
+    }
+    db.push_str("];\n");
+
+    db.push_str(&format!(
+        "\n#[rustfmt::skip]\n\
+         pub(crate) static EXTENSION_DB: [(&str, usize); {}] = [\n",
+        extensions.len()
+    ));
+    for (ext, index) in extensions {
+        db.push_str(&format!("    (\"{ext}\", {index}),\n"));
+    }
+    db.push_str("];");
+    db
+}
+
+// preference of a source when an extension is in several records, as in
+// the mime-types package
+fn source_rank(source: &Value) -> u8 {
+    match source.as_str() {
+        Some("nginx") => 0,
+        Some("apache") => 1,
+        Some("iana") => 3,
+        _ => 2,
+    }
 }
@@ -1,31 +1,24 @@
-use std::collections::HashMap;
-
-use lazy_static::lazy_static;
+use std::borrow::Cow;
 
 use crate::ContentType;
-use crate::mime_type::*;
-
-lazy_static! {
-    pub static ref EXTENSION_MAP: HashMap<&'static str, ContentType> = {
-        let mut m = HashMap::new();
-        for (ct, exts) in [
-            (ContentType::Application, &EXT_APP[..]),
-            (ContentType::Audio, &EXT_AUDIO[..]),
-            (ContentType::Font, &EXT_FONT[..]),
-            (ContentType::Image, &EXT_IMAGE[..]),
-            (ContentType::Message, &EXT_MESSAGE[..]),
-            (ContentType::Model, &EXT_MODEL[..]),
-            (ContentType::Text, &EXT_TEXT[..]),
-            (ContentType::Video, &EXT_VIDEO[..]),
-        ]
-        .iter()
-        {
-            for &ext in *exts {
-                m.insert(ext, *ct);
-            }
-        }
-        m
+pub use crate::mime_type::EXTENSION_MAP;
+#[allow(deprecated)]
+pub use crate::mime_type::{
+    EXT_APP, EXT_AUDIO, EXT_FONT, EXT_IMAGE, EXT_MESSAGE, EXT_MODEL, EXT_TEXT,
+    EXT_VIDEO,
+};
+
+/* Content type of an extension without the leading dot, case-insensitive.
+ * Keys of EXTENSION_MAP are lowercase, only an extension with uppercase
+ * characters is copied.
+ */
+pub fn content_type(ext: &str) -> Option<ContentType> {
+    let ext = if ext.bytes().any(|b| b.is_ascii_uppercase()) {
+        Cow::Owned(ext.to_ascii_lowercase())
+    } else {
+        Cow::Borrowed(ext)
     };
+    EXTENSION_MAP.get(ext.as_ref()).copied()
 }
 
 #[cfg(test)]
@@ -46,4 +39,56 @@ mod test {
 
         assert!(EXTENSION_MAP.get("").copied().is_none());
     }
+
+    #[test]
+    fn test_content_type_case_insensitive() {
+        assert_eq!(content_type("PNG"), Some(ContentType::Image));
+        assert_eq!(content_type("Json"), Some(ContentType::Application));
+        assert_eq!(content_type("woff2"), Some(ContentType::Font));
+        assert_eq!(content_type("unknown-ext"), None);
+    }
+
+    // an extension under several main types keeps the first one
+    #[test]
+    fn test_content_type_first_main_type() {
+        assert_eq!(content_type("js"), Some(ContentType::Application));
+        assert_eq!(content_type("3gpp"), Some(ContentType::Audio));
+        assert_eq!(content_type("sub"), Some(ContentType::Image));
+        assert_eq!(content_type("mts"), Some(ContentType::Model));
+        assert_eq!(content_type("emf"), Some(ContentType::Application));
+        assert_eq!(content_type("stl"), Some(ContentType::Application));
+        assert_eq!(content_type("mp4"), Some(ContentType::Video));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_ext_arrays() {
+        let arrays: [&[&str]; 8] = [
+            &EXT_APP,
+            &EXT_AUDIO,
+            &EXT_FONT,
+            &EXT_IMAGE,
+            &EXT_MESSAGE,
+            &EXT_MODEL,
+            &EXT_TEXT,
+            &EXT_VIDEO,
+        ];
+        let variants = [
+            ContentType::Application,
+            ContentType::Audio,
+            ContentType::Font,
+            ContentType::Image,
+            ContentType::Message,
+            ContentType::Model,
+            ContentType::Text,
+            ContentType::Video,
+        ];
+        let len: usize = arrays.iter().map(|exts| exts.len()).sum();
+        assert_eq!(len, EXTENSION_MAP.len());
+        for (exts, variant) in arrays.iter().zip(variants) {
+            for ext in exts.iter() {
+                assert_eq!(content_type(ext), Some(variant), "{ext}");
+            }
+        }
+    }
 }
@@ -1,3 +1,10 @@
 mod content_type;
 pub use content_type::*;
+mod media_type;
+pub use media_type::*;
+mod mime_db;
+pub use mime_db::*;
 mod mime_type;
+mod qvalue;
+pub use qvalue::*;
+pub mod sniff;
@@ -0,0 +1,424 @@
+// Media type with parameters
+// https://www.rfc-editor.org/rfc/rfc9110.html#section-8.3.1
+
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use crate::ContentType;
+
+mod range;
+pub use range::*;
+
+pub const CHARSET: &str = "charset";
+pub const BOUNDARY: &str = "boundary";
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum MediaTypeError {
+    #[error("missing subtype| {0}")]
+    MissingSubtype(String),
+    #[error("invalid type| {0}")]
+    InvalidType(String),
+    #[error("invalid parameter| {0}")]
+    InvalidParameter(String),
+    #[error("unterminated quoted-string| {0}")]
+    UnterminatedQuote(String),
+    #[error("invalid media range| {0}")]
+    InvalidRange(String),
+    #[error("invalid qvalue| {0}")]
+    InvalidQValue(String),
+}
+
+/* type "/" subtype ["+" suffix] *( OWS ";" OWS parameter )
+ *
+ * Type, subtype, suffix and parameter names compare case-insensitive, so
+ * does the charset value. Other parameter values are case-sensitive.
+ * Parameters keep their order and case, values are stored unquoted.
+ */
+#[derive(Clone, Debug)]
+pub struct MediaType {
+    main_type: String,
+    subtype: String,
+    suffix: Option<String>,
+    params: Vec<(String, String)>,
+}
+
+impl MediaType {
+    // subtype may carry a structured syntax suffix, eg. "ld+json"
+    pub fn new(main_type: impl Into<String>, subtype: &str) -> Self {
+        let (subtype, suffix) = split_suffix(subtype);
+        MediaType {
+            main_type: main_type.into(),
+            subtype: subtype.to_string(),
+            suffix: suffix.map(str::to_string),
+            params: Vec::new(),
+        }
+    }
+
+    pub fn with_param(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        self.params.push((name.into(), value.into()));
+        self
+    }
+
+    /* Steps:
+     *      1. Split the essence at the first ";" and the type at "/".
+     *      2. Split the structured syntax suffix at the last "+".
+     *      3. Parse each parameter as token "=" ( token / quoted-string ),
+     *         empty parameters are skipped.
+     */
+    pub fn parse(value: &str) -> Result<Self, MediaTypeError> {
+        let value = value.trim_matches(is_ows);
+        let (essence, mut rest) =
+            value.split_at(value.find(';').unwrap_or(value.len()));
+        let essence = essence.trim_end_matches(is_ows);
+        let (main_type, subtype) = essence
+            .split_once('/')
+            .ok_or_else(|| MediaTypeError::MissingSubtype(essence.into()))?;
+        if !is_token(main_type) || !is_token(subtype) {
+            return Err(MediaTypeError::InvalidType(essence.into()));
+        }
+        let mut media_type = MediaType::new(main_type, subtype);
+        while let Some(stripped) = rest.strip_prefix(';') {
+            rest = stripped.trim_start_matches(is_ows);
+            if rest.is_empty() || rest.starts_with(';') {
+                continue;
+            }
+            let (name, value, remaining) = parse_param(rest)?;
+            media_type.params.push((name.to_string(), value));
+            rest = remaining.trim_start_matches(is_ows);
+        }
+        Ok(media_type)
+    }
+
+    pub fn main_type(&self) -> &str {
+        &self.main_type
+    }
+
+    // without the suffix
+    pub fn subtype(&self) -> &str {
+        &self.subtype
+    }
+
+    pub fn suffix(&self) -> Option<&str> {
+        self.suffix.as_deref()
+    }
+
+    // lowercase type/subtype[+suffix], without parameters
+    pub fn essence(&self) -> String {
+        let mut essence = format!("{}/{}", self.main_type, self.subtype);
+        if let Some(suffix) = &self.suffix {
+            essence.push('+');
+            essence.push_str(suffix);
+        }
+        essence.to_ascii_lowercase()
+    }
+
+    pub fn params(&self) -> &[(String, String)] {
+        &self.params
+    }
+
+    // first parameter with name
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    // replaces the first parameter with name or appends it
+    pub fn set_param(&mut self, name: &str, value: impl Into<String>) {
+        let value = value.into();
+        match self
+            .params
+            .iter_mut()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        {
+            Some((_, current)) => *current = value,
+            None => self.params.push((name.to_string(), value)),
+        }
+    }
+
+    pub fn remove_param(&mut self, name: &str) {
+        self.params.retain(|(key, _)| !key.eq_ignore_ascii_case(name));
+    }
+
+    pub fn charset(&self) -> Option<&str> {
+        self.param(CHARSET)
+    }
+
+    pub fn boundary(&self) -> Option<&str> {
+        self.param(BOUNDARY)
+    }
+
+    pub fn content_type(&self) -> ContentType {
+        ContentType::from(self.main_type.to_ascii_lowercase().as_str())
+    }
+}
+
+// name "=" ( token / quoted-string ), returns the unparsed rest
+fn parse_param(input: &str) -> Result<(&str, String, &str), MediaTypeError> {
+    let invalid = || {
+        let end = input.find(';').unwrap_or(input.len());
+        MediaTypeError::InvalidParameter(input[..end].into())
+    };
+    let name_end =
+        input.bytes().position(|b| !is_tchar(b)).unwrap_or(input.len());
+    let name = &input[..name_end];
+    let Some(value) = input[name_end..].strip_prefix('=') else {
+        return Err(invalid());
+    };
+    if name.is_empty() {
+        return Err(invalid());
+    }
+    let (value, rest) = if value.starts_with('"') {
+        parse_quoted_string(value)?
+    } else {
+        let end = value.find(';').unwrap_or(value.len());
+        let token = value[..end].trim_end_matches(is_ows);
+        if !is_token(token) {
+            return Err(invalid());
+        }
+        (token.to_string(), &value[end..])
+    };
+    if !rest.trim_start_matches(is_ows).is_empty()
+        && !rest.trim_start_matches(is_ows).starts_with(';')
+    {
+        return Err(invalid());
+    }
+    Ok((name, value, rest))
+}
+
+// DQUOTE *( qdtext / quoted-pair ) DQUOTE, returns the unquoted value
+fn parse_quoted_string(input: &str) -> Result<(String, &str), MediaTypeError> {
+    let mut value = String::new();
+    let mut chars = input.char_indices().skip(1);
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '"' => return Ok((value, &input[index + 1..])),
+            '\\' => match chars.next() {
+                Some((_, escaped)) => value.push(escaped),
+                None => break,
+            },
+            c => value.push(c),
+        }
+    }
+    Err(MediaTypeError::UnterminatedQuote(input.into()))
+}
+
+// last "+" with a non empty subtype and suffix
+fn split_suffix(subtype: &str) -> (&str, Option<&str>) {
+    match subtype.rsplit_once('+') {
+        Some((subtype, suffix))
+            if !subtype.is_empty() && !suffix.is_empty() =>
+        {
+            (subtype, Some(suffix))
+        }
+        _ => (subtype, None),
+    }
+}
+
+fn is_ows(c: char) -> bool {
+    c == ' ' || c == '\t'
+}
+
+fn is_tchar(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&byte)
+}
+
+fn is_token(value: &str) -> bool {
+    !value.is_empty() && value.bytes().all(is_tchar)
+}
+
+fn param_value_eq(name: &str, one: &str, two: &str) -> bool {
+    if name.eq_ignore_ascii_case(CHARSET) {
+        one.eq_ignore_ascii_case(two)
+    } else {
+        one == two
+    }
+}
+
+// parameter order is not significant
+impl PartialEq for MediaType {
+    fn eq(&self, other: &Self) -> bool {
+        let same_suffix = match (&self.suffix, &other.suffix) {
+            (Some(one), Some(two)) => one.eq_ignore_ascii_case(two),
+            (None, None) => true,
+            _ => false,
+        };
+        self.main_type.eq_ignore_ascii_case(&other.main_type)
+            && self.subtype.eq_ignore_ascii_case(&other.subtype)
+            && same_suffix
+            && self.params.len() == other.params.len()
+            && self.params.iter().all(|(name, value)| {
+                other.params.iter().any(|(other_name, other_value)| {
+                    name.eq_ignore_ascii_case(other_name)
+                        && param_value_eq(name, value, other_value)
+                })
+            })
+    }
+}
+
+impl Eq for MediaType {}
+
+// value as token when possible, quoted-string otherwise
+impl Display for MediaType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.main_type, self.subtype)?;
+        if let Some(suffix) = &self.suffix {
+            write!(f, "+{suffix}")?;
+        }
+        for (name, value) in &self.params {
+            write!(f, "; {name}=")?;
+            if is_token(value) {
+                f.write_str(value)?;
+            } else {
+                f.write_str("\"")?;
+                for c in value.chars() {
+                    if c == '"' || c == '\\' {
+                        f.write_str("\\")?;
+                    }
+                    write!(f, "{c}")?;
+                }
+                f.write_str("\"")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for MediaType {
+    type Err = MediaTypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        MediaType::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn media_type(value: &str) -> MediaType {
+        MediaType::parse(value).unwrap()
+    }
+
+    #[test]
+    fn test_media_type_parse() {
+        let mt = media_type("text/html;charset=utf-8");
+        assert_eq!(mt.main_type(), "text");
+        assert_eq!(mt.subtype(), "html");
+        assert_eq!(mt.suffix(), None);
+        assert_eq!(mt.charset(), Some("utf-8"));
+        assert_eq!(mt.content_type(), ContentType::Text);
+    }
+
+    #[test]
+    fn test_media_type_suffix() {
+        let mt = media_type("application/vnd.api+json");
+        assert_eq!(mt.subtype(), "vnd.api");
+        assert_eq!(mt.suffix(), Some("json"));
+        assert_eq!(mt.essence(), "application/vnd.api+json");
+        // not a suffix
+        assert_eq!(media_type("application/+json").suffix(), None);
+        assert_eq!(media_type("application/json+").suffix(), None);
+        let mt = media_type("application/a+b+xml");
+        assert_eq!((mt.subtype(), mt.suffix()), ("a+b", Some("xml")));
+    }
+
+    #[test]
+    fn test_media_type_params_ordered() {
+        let mt = media_type(
+            "multipart/form-data ; boundary=\"a;b \\\"c\\\"\" ;; x=1; X=2",
+        );
+        assert_eq!(mt.boundary(), Some("a;b \"c\""));
+        assert_eq!(
+            mt.params(),
+            &[
+                ("boundary".into(), "a;b \"c\"".into()),
+                ("x".into(), "1".into()),
+                ("X".into(), "2".into())
+            ]
+        );
+        assert_eq!(mt.param("x"), Some("1"));
+        assert_eq!(mt.content_type(), ContentType::Multipart);
+    }
+
+    #[test]
+    fn test_media_type_eq_case_insensitive() {
+        assert_eq!(
+            media_type("Text/HTML; Charset=\"UTF-8\""),
+            media_type("text/html;charset=utf-8")
+        );
+        assert_eq!(media_type("a/b; x=1; y=2"), media_type("a/b; y=2; x=1"));
+        assert_ne!(media_type("a/b; x=A"), media_type("a/b; x=a"));
+        assert_ne!(media_type("a/b+json"), media_type("a/b"));
+        assert_ne!(media_type("a/b; x=1"), media_type("a/b"));
+    }
+
+    #[test]
+    fn test_media_type_round_trip() {
+        for input in [
+            "text/html",
+            "Text/HTML; Charset=UTF-8",
+            "application/ld+json; profile=\"http://www.w3.org/ns/json-ld\"",
+            "multipart/mixed; boundary=\"\"",
+            "a/b; x=\"q\\\"uote\\\\\"",
+        ] {
+            let mt = media_type(input);
+            assert_eq!(mt.to_string(), input);
+            assert_eq!(media_type(&mt.to_string()), mt);
+        }
+        // token values are written unquoted
+        assert_eq!(media_type("a/b; c=\"d\"").to_string(), "a/b; c=d");
+    }
+
+    #[test]
+    fn test_media_type_build() {
+        let mut mt = MediaType::new("application", "problem+json")
+            .with_param(CHARSET, "utf-8");
+        assert_eq!(mt.to_string(), "application/problem+json; charset=utf-8");
+        mt.set_param("Charset", "latin1");
+        mt.set_param("q", "a b");
+        assert_eq!(
+            mt.to_string(),
+            "application/problem+json; charset=latin1; q=\"a b\""
+        );
+        mt.remove_param("q");
+        assert_eq!(mt, media_type("application/problem+json;charset=LATIN1"));
+    }
+
+    #[test]
+    fn test_media_type_invalid() {
+        assert_eq!(
+            MediaType::parse("text"),
+            Err(MediaTypeError::MissingSubtype("text".into()))
+        );
+        assert_eq!(
+            MediaType::parse("text/ html"),
+            Err(MediaTypeError::InvalidType("text/ html".into()))
+        );
+        assert_eq!(
+            MediaType::parse("text/html; charset"),
+            Err(MediaTypeError::InvalidParameter("charset".into()))
+        );
+        assert_eq!(
+            MediaType::parse("text/html; a = b"),
+            Err(MediaTypeError::InvalidParameter("a = b".into()))
+        );
+        assert_eq!(
+            MediaType::parse("text/html; a=b c"),
+            Err(MediaTypeError::InvalidParameter("a=b c".into()))
+        );
+        assert_eq!(
+            MediaType::parse("text/html; a=\"b"),
+            Err(MediaTypeError::UnterminatedQuote("\"b".into()))
+        );
+        assert!(MediaType::parse("text/html; a=\"b\"c").is_err());
+        assert!("/html".parse::<MediaType>().is_err());
+    }
+}
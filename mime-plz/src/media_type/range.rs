@@ -0,0 +1,372 @@
+// Accept
+// https://www.rfc-editor.org/rfc/rfc9110.html#section-12.5.1
+
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use crate::QValue;
+use crate::media_type::{MediaType, MediaTypeError, is_ows, param_value_eq};
+
+const WILDCARD: &str = "*";
+
+/* media-range [ weight ]
+ *
+ * Parameters before the weight belong to the range, accept extensions after
+ * it are dropped.
+ */
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MediaRange {
+    range: MediaType,
+    q: QValue,
+}
+
+impl MediaRange {
+    pub fn new(range: MediaType, q: QValue) -> Self {
+        MediaRange {
+            range,
+            q,
+        }
+    }
+
+    /* Steps:
+     *      1. Parse the member as a media type, "*" is a token.
+     *      2. Split the parameters at the first "q".
+     *      3. A wildcard type requires a wildcard subtype.
+     */
+    pub fn parse(value: &str) -> Result<Self, MediaTypeError> {
+        let mut range = MediaType::parse(value)?;
+        let mut q = QValue::ONE;
+        if let Some(pos) = range
+            .params
+            .iter()
+            .position(|(name, _)| name.eq_ignore_ascii_case("q"))
+        {
+            let weight = &range.params[pos].1;
+            q = QValue::parse(weight.as_bytes()).ok_or_else(|| {
+                MediaTypeError::InvalidQValue(weight.clone())
+            })?;
+            range.params.truncate(pos);
+        }
+        if range.main_type == WILDCARD
+            && (range.subtype != WILDCARD || range.suffix.is_some())
+        {
+            return Err(MediaTypeError::InvalidRange(range.essence()));
+        }
+        Ok(MediaRange::new(range, q))
+    }
+
+    pub fn range(&self) -> &MediaType {
+        &self.range
+    }
+
+    pub fn q(&self) -> QValue {
+        self.q
+    }
+
+    // 0 for any type, 1 for any subtype, 2 otherwise, plus the parameters
+    pub fn specificity(&self) -> usize {
+        let level = if self.range.main_type == WILDCARD {
+            0
+        } else if self.range.subtype == WILDCARD && self.range.suffix.is_none()
+        {
+            1
+        } else {
+            2
+        };
+        level + self.range.params.len()
+    }
+
+    // every parameter of the range must be present in media_type
+    pub fn matches(&self, media_type: &MediaType) -> bool {
+        let range = &self.range;
+        let essence_matches = match self.specificity() - range.params.len() {
+            0 => true,
+            1 => range.main_type.eq_ignore_ascii_case(&media_type.main_type),
+            _ => range.essence() == media_type.essence(),
+        };
+        essence_matches
+            && range.params.iter().all(|(name, value)| {
+                media_type
+                    .param(name)
+                    .is_some_and(|other| param_value_eq(name, value, other))
+            })
+    }
+}
+
+impl Display for MediaRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.range.fmt(f)?;
+        if self.q != QValue::ONE {
+            write!(f, "; q={}", self.q)?;
+        }
+        Ok(())
+    }
+}
+
+/* Media types acceptable in the response.
+ *
+ * The most specific range that matches a media type gives its weight. An
+ * absent or empty field value accepts any media type, see any().
+ */
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Accept {
+    ranges: Vec<MediaRange>,
+}
+
+impl Accept {
+    pub fn new(ranges: Vec<MediaRange>) -> Self {
+        Accept {
+            ranges,
+        }
+    }
+
+    // absent header
+    pub fn any() -> Self {
+        Accept::new(vec![MediaRange::new(
+            MediaType::new(WILDCARD, WILDCARD),
+            QValue::ONE,
+        )])
+    }
+
+    pub fn parse(value: &str) -> Result<Self, MediaTypeError> {
+        let ranges = split_list(value)
+            .map(MediaRange::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+        if ranges.is_empty() {
+            return Ok(Accept::any());
+        }
+        Ok(Accept::new(ranges))
+    }
+
+    pub fn ranges(&self) -> &[MediaRange] {
+        &self.ranges
+    }
+
+    // most specific matching range, ties go to the earlier range
+    fn best_match(&self, media_type: &MediaType) -> Option<&MediaRange> {
+        self.ranges
+            .iter()
+            .filter(|range| range.matches(media_type))
+            .rev()
+            .max_by_key(|range| range.specificity())
+    }
+
+    pub fn quality(&self, media_type: &MediaType) -> QValue {
+        self.best_match(media_type).map_or(QValue::ZERO, |range| range.q)
+    }
+
+    pub fn is_acceptable(&self, media_type: &MediaType) -> bool {
+        !self.quality(media_type).is_zero()
+    }
+
+    /* Acceptable candidates, by weight and then by the specificity of the
+     * matching range. Ties keep the order of candidates, ie. the server
+     * preference.
+     */
+    pub fn rank<'a>(&self, candidates: &'a [MediaType]) -> Vec<&'a MediaType> {
+        let mut ranked: Vec<_> = candidates
+            .iter()
+            .filter_map(|candidate| {
+                let range = self.best_match(candidate)?;
+                (!range.q.is_zero()).then_some((
+                    candidate,
+                    range.q,
+                    range.specificity(),
+                ))
+            })
+            .collect();
+        ranked.sort_by(|(_, q1, s1), (_, q2, s2)| (q2, s2).cmp(&(q1, s1)));
+        ranked.into_iter().map(|(candidate, ..)| candidate).collect()
+    }
+
+    pub fn negotiate<'a>(
+        &self,
+        candidates: &'a [MediaType],
+    ) -> Option<&'a MediaType> {
+        self.rank(candidates).into_iter().next()
+    }
+}
+
+/* Whether a response with content_type satisfies the Accept of the request.
+ * An absent or invalid Accept accepts any media type, as a server may
+ * ignore it, an invalid Content-Type is never acceptable.
+ */
+pub fn is_acceptable_content_type(
+    accept: Option<&str>,
+    content_type: &str,
+) -> bool {
+    let Ok(media_type) = MediaType::parse(content_type) else {
+        return false;
+    };
+    accept
+        .and_then(|accept| Accept::parse(accept).ok())
+        .is_none_or(|accept| accept.is_acceptable(&media_type))
+}
+
+// members separated by "," outside of quoted-strings, empty members skipped
+fn split_list(value: &str) -> impl Iterator<Item = &str> {
+    let mut quoted = false;
+    let mut escaped = false;
+    value
+        .split(move |c| {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' if quoted => escaped = true,
+                '"' => quoted = !quoted,
+                ',' if !quoted => return true,
+                _ => {}
+            }
+            false
+        })
+        .map(|member| member.trim_matches(is_ows))
+        .filter(|member| !member.is_empty())
+}
+
+impl Display for Accept {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, range) in self.ranges.iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            range.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Accept {
+    type Err = MediaTypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Accept::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn accept(value: &str) -> Accept {
+        Accept::parse(value).unwrap()
+    }
+
+    fn media_type(value: &str) -> MediaType {
+        MediaType::parse(value).unwrap()
+    }
+
+    fn q(accept: &Accept, value: &str) -> u16 {
+        accept.quality(&media_type(value)).as_thousandths()
+    }
+
+    #[test]
+    fn test_accept_parse() {
+        let accept = accept(
+            "text/html, application/xhtml+xml, application/xml;q=0.9, \
+             */*;q=0.8",
+        );
+        assert_eq!(accept.ranges().len(), 4);
+        assert_eq!(accept.ranges()[1].range().suffix(), Some("xml"));
+        assert_eq!(accept.ranges()[2].q().as_thousandths(), 900);
+        assert_eq!(accept.ranges()[3].specificity(), 0);
+    }
+
+    #[test]
+    fn test_accept_quality_specificity() {
+        // https://www.rfc-editor.org/rfc/rfc9110.html#section-12.5.1
+        let accept = accept(
+            "text/*;q=0.3, text/plain;q=0.7, text/plain;format=flowed, \
+             text/plain;format=fixed;q=0.4, */*;q=0.5",
+        );
+        assert_eq!(q(&accept, "text/plain;format=flowed"), 1000);
+        assert_eq!(q(&accept, "text/plain"), 700);
+        assert_eq!(q(&accept, "text/html"), 300);
+        assert_eq!(q(&accept, "image/jpeg"), 500);
+        assert_eq!(q(&accept, "text/plain;format=fixed"), 400);
+        assert_eq!(q(&accept, "text/html;level=3"), 300);
+    }
+
+    #[test]
+    fn test_accept_case_insensitive() {
+        let accept = accept("Text/HTML; Charset=UTF-8");
+        assert_eq!(q(&accept, "text/html;charset=utf-8"), 1000);
+        assert_eq!(q(&accept, "text/html"), 0);
+    }
+
+    #[test]
+    fn test_accept_params_before_weight() {
+        let range =
+            MediaRange::parse("text/html;level=1;q=0.5;ext=\"a,b\"").unwrap();
+        assert_eq!(range.range().param("level"), Some("1"));
+        assert_eq!(range.range().param("ext"), None);
+        assert_eq!(range.q().as_thousandths(), 500);
+        assert_eq!(range.to_string(), "text/html; level=1; q=0.5");
+        let accept = accept("a/b;x=\"1,2\";q=0.1, c/d");
+        assert_eq!(accept.ranges().len(), 2);
+        assert_eq!(accept.ranges()[0].range().param("x"), Some("1,2"));
+    }
+
+    #[test]
+    fn test_accept_rank() {
+        let candidates = [
+            media_type("application/json"),
+            media_type("text/html"),
+            media_type("application/xml"),
+            media_type("image/png"),
+        ];
+        let ranges = accept("text/*;q=0.9, application/xml, */*;q=0.1");
+        let ranked = ranges.rank(&candidates);
+        assert_eq!(
+            ranked,
+            vec![
+                &candidates[2],
+                &candidates[1],
+                &candidates[0],
+                &candidates[3]
+            ]
+        );
+        // equal weight, the more specific range first
+        let ranges = accept("application/*, application/xml");
+        assert_eq!(ranges.negotiate(&candidates), Some(&candidates[2]));
+        assert_eq!(accept("image/*;q=0").negotiate(&candidates), None);
+        assert_eq!(Accept::any().negotiate(&candidates), Some(&candidates[0]));
+    }
+
+    #[test]
+    fn test_accept_empty() {
+        assert_eq!(accept(""), Accept::any());
+        assert_eq!(accept(" , ,"), Accept::any());
+    }
+
+    #[test]
+    fn test_accept_invalid() {
+        assert_eq!(
+            Accept::parse("*/html"),
+            Err(MediaTypeError::InvalidRange("*/html".into()))
+        );
+        assert_eq!(
+            Accept::parse("text/html;q=2"),
+            Err(MediaTypeError::InvalidQValue("2".into()))
+        );
+        assert!(Accept::parse("text").is_err());
+    }
+
+    #[test]
+    fn test_accept_display() {
+        let input = "text/html, application/xml; q=0.9, */*; q=0.8";
+        assert_eq!(accept(input).to_string(), input);
+    }
+
+    #[test]
+    fn test_is_acceptable_content_type() {
+        let accept = Some("text/html, application/xhtml+xml");
+        assert!(is_acceptable_content_type(
+            accept,
+            "text/html; charset=utf-8"
+        ));
+        assert!(is_acceptable_content_type(accept, "Application/XHTML+XML"));
+        assert!(!is_acceptable_content_type(accept, "application/json"));
+        assert!(!is_acceptable_content_type(accept, "invalid"));
+        assert!(is_acceptable_content_type(None, "application/json"));
+        assert!(is_acceptable_content_type(Some("*/x"), "application/json"));
+    }
+}
@@ -0,0 +1,3665 @@
+// generated by build.rs from artifacts/db.json
+
+use crate::mime_db::{MimeRecord, MimeSource};
+
+#[rustfmt::skip]
+pub(crate) static MIME_DB: [MimeRecord; 2446] = [
+    MimeRecord { essence: "application/1d-interleaved-parityfec", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/3gpdash-qoe-report+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: Some("UTF-8"), extensions: &[] },
+    MimeRecord { essence: "application/3gpp-ims+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/3gpphal+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/3gpphalforms+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/a2l", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/ace+cbor", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/ace+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/ace-groupcomm+cbor", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/activemessage", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/activity+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/aif+cbor", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/aif+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/alto-cdni+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/alto-cdnifilter+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/alto-costmap+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/alto-costmapfilter+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/alto-directory+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/alto-endpointcost+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/alto-endpointcostparams+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/alto-endpointprop+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/alto-endpointpropparams+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/alto-error+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/alto-networkmap+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/alto-networkmapfilter+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/alto-propmap+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/alto-propmapparams+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/alto-tips+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/alto-tipsparams+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/alto-updatestreamcontrol+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/alto-updatestreamparams+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/aml", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/andrew-inset", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ez"] },
+    MimeRecord { essence: "application/appinstaller", source: None, compressible: Some(false), charset: None, extensions: &["appinstaller"] },
+    MimeRecord { essence: "application/applefile", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/applixware", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["aw"] },
+    MimeRecord { essence: "application/appx", source: None, compressible: Some(false), charset: None, extensions: &["appx"] },
+    MimeRecord { essence: "application/appxbundle", source: None, compressible: Some(false), charset: None, extensions: &["appxbundle"] },
+    MimeRecord { essence: "application/at+jwt", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/atf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/atfx", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/atom+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["atom"] },
+    MimeRecord { essence: "application/atomcat+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["atomcat"] },
+    MimeRecord { essence: "application/atomdeleted+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["atomdeleted"] },
+    MimeRecord { essence: "application/atomicmail", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/atomsvc+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["atomsvc"] },
+    MimeRecord { essence: "application/atsc-dwd+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["dwd"] },
+    MimeRecord { essence: "application/atsc-dynamic-event-message", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/atsc-held+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["held"] },
+    MimeRecord { essence: "application/atsc-rdt+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/atsc-rsat+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["rsat"] },
+    MimeRecord { essence: "application/atxml", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/auth-policy+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/automationml-aml+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["aml"] },
+    MimeRecord { essence: "application/automationml-amlx+zip", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["amlx"] },
+    MimeRecord { essence: "application/bacnet-xdd+zip", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/batch-smtp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/bdoc", source: None, compressible: Some(false), charset: None, extensions: &["bdoc"] },
+    MimeRecord { essence: "application/beep+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: Some("UTF-8"), extensions: &[] },
+    MimeRecord { essence: "application/bufr", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/c2pa", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/calendar+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/calendar+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["xcs"] },
+    MimeRecord { essence: "application/call-completion", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/cals-1840", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/captive+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/cbor", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/cbor-seq", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/cccex", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/ccmp+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/ccxml+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["ccxml"] },
+    MimeRecord { essence: "application/cda+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: Some("UTF-8"), extensions: &[] },
+    MimeRecord { essence: "application/cdfx+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["cdfx"] },
+    MimeRecord { essence: "application/cdmi-capability", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["cdmia"] },
+    MimeRecord { essence: "application/cdmi-container", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["cdmic"] },
+    MimeRecord { essence: "application/cdmi-domain", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["cdmid"] },
+    MimeRecord { essence: "application/cdmi-object", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["cdmio"] },
+    MimeRecord { essence: "application/cdmi-queue", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["cdmiq"] },
+    MimeRecord { essence: "application/cdni", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/cea", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/cea-2018+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/cellml+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/cfw", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/cid-edhoc+cbor-seq", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/city+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/clr", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/clue+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/clue_info+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/cms", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/cnrp+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/coap-group+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/coap-payload", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/commonground", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/concise-problem-details+cbor", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/conference-info+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/cose", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/cose-key", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/cose-key-set", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/cose-x509", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/cpl+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["cpl"] },
+    MimeRecord { essence: "application/csrattrs", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/csta+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/cstadata+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/csvm+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/cu-seeme", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["cu"] },
+    MimeRecord { essence: "application/cwl", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["cwl"] },
+    MimeRecord { essence: "application/cwl+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/cwl+yaml", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/cwt", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/cybercash", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/dart", source: None, compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/dash+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["mpd"] },
+    MimeRecord { essence: "application/dash-patch+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["mpp"] },
+    MimeRecord { essence: "application/dashdelta", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/davmount+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["davmount"] },
+    MimeRecord { essence: "application/dca-rft", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/dcd", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/dec-dx", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/dialog-info+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/dicom", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/dicom+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/dicom+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/dii", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/dit", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/dns", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/dns+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/dns-message", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/docbook+xml", source: Some(MimeSource::Apache), compressible: Some(true), charset: None, extensions: &["dbk"] },
+    MimeRecord { essence: "application/dots+cbor", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/dpop+jwt", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/dskpp+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/dssc+der", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["dssc"] },
+    MimeRecord { essence: "application/dssc+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["xdssc"] },
+    MimeRecord { essence: "application/dvcs", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/ecmascript", source: Some(MimeSource::Apache), compressible: Some(true), charset: None, extensions: &["ecma"] },
+    MimeRecord { essence: "application/edhoc+cbor-seq", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/edi-consent", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/edi-x12", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/edifact", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/efi", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/elm+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: Some("UTF-8"), extensions: &[] },
+    MimeRecord { essence: "application/elm+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/emergencycalldata.cap+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: Some("UTF-8"), extensions: &[] },
+    MimeRecord { essence: "application/emergencycalldata.comment+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/emergencycalldata.control+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/emergencycalldata.deviceinfo+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/emergencycalldata.ecall.msd", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/emergencycalldata.legacyesn+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/emergencycalldata.providerinfo+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/emergencycalldata.serviceinfo+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/emergencycalldata.subscriberinfo+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/emergencycalldata.veds+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/emma+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["emma"] },
+    MimeRecord { essence: "application/emotionml+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["emotionml"] },
+    MimeRecord { essence: "application/encaprtp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/epp+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/epub+zip", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["epub"] },
+    MimeRecord { essence: "application/eshop", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/exi", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["exi"] },
+    MimeRecord { essence: "application/expect-ct-report+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/express", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["exp"] },
+    MimeRecord { essence: "application/fastinfoset", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/fastsoap", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/fdf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["fdf"] },
+    MimeRecord { essence: "application/fdt+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["fdt"] },
+    MimeRecord { essence: "application/fhir+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: Some("UTF-8"), extensions: &[] },
+    MimeRecord { essence: "application/fhir+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: Some("UTF-8"), extensions: &[] },
+    MimeRecord { essence: "application/fido.trusted-apps+json", source: None, compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/fits", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/flexfec", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/font-sfnt", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/font-tdpfr", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["pfr"] },
+    MimeRecord { essence: "application/font-woff", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/framework-attributes+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/geo+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["geojson"] },
+    MimeRecord { essence: "application/geo+json-seq", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/geopackage+sqlite3", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/geoxacml+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/geoxacml+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/gltf-buffer", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/gml+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["gml"] },
+    MimeRecord { essence: "application/gnap-binding-jws", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/gnap-binding-jwsd", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/gnap-binding-rotation-jws", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/gnap-binding-rotation-jwsd", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/gpx+xml", source: Some(MimeSource::Apache), compressible: Some(true), charset: None, extensions: &["gpx"] },
+    MimeRecord { essence: "application/grib", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/gxf", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["gxf"] },
+    MimeRecord { essence: "application/gzip", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["gz"] },
+    MimeRecord { essence: "application/h224", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/held+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/hjson", source: None, compressible: None, charset: None, extensions: &["hjson"] },
+    MimeRecord { essence: "application/hl7v2+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: Some("UTF-8"), extensions: &[] },
+    MimeRecord { essence: "application/http", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/hyperstudio", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["stk"] },
+    MimeRecord { essence: "application/ibe-key-request+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/ibe-pkg-reply+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/ibe-pp-data", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/iges", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/im-iscomposing+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: Some("UTF-8"), extensions: &[] },
+    MimeRecord { essence: "application/index", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/index.cmd", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/index.obj", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/index.response", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/index.vnd", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/inkml+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["ink", "inkml"] },
+    MimeRecord { essence: "application/iotp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/ipfix", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ipfix"] },
+    MimeRecord { essence: "application/ipp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/isup", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/its+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["its"] },
+    MimeRecord { essence: "application/java-archive", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["jar", "war", "ear"] },
+    MimeRecord { essence: "application/java-serialized-object", source: Some(MimeSource::Apache), compressible: Some(false), charset: None, extensions: &["ser"] },
+    MimeRecord { essence: "application/java-vm", source: Some(MimeSource::Apache), compressible: Some(false), charset: None, extensions: &["class"] },
+    MimeRecord { essence: "application/javascript", source: Some(MimeSource::Apache), compressible: Some(true), charset: Some("UTF-8"), extensions: &["js"] },
+    MimeRecord { essence: "application/jf2feed+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/jose", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/jose+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/jrd+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/jscalendar+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/jscontact+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/json", source: Some(MimeSource::Iana), compressible: Some(true), charset: Some("UTF-8"), extensions: &["json", "map"] },
+    MimeRecord { essence: "application/json-patch+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/json-seq", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/json5", source: None, compressible: None, charset: None, extensions: &["json5"] },
+    MimeRecord { essence: "application/jsonml+json", source: Some(MimeSource::Apache), compressible: Some(true), charset: None, extensions: &["jsonml"] },
+    MimeRecord { essence: "application/jsonpath", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/jwk+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/jwk-set+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/jwt", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/kpml-request+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/kpml-response+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/ld+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["jsonld"] },
+    MimeRecord { essence: "application/lgr+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["lgr"] },
+    MimeRecord { essence: "application/link-format", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/linkset", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/linkset+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/load-control+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/logout+jwt", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/lost+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["lostxml"] },
+    MimeRecord { essence: "application/lostsync+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/lpf+zip", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/lxf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/mac-binhex40", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["hqx"] },
+    MimeRecord { essence: "application/mac-compactpro", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["cpt"] },
+    MimeRecord { essence: "application/macwriteii", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/mads+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["mads"] },
+    MimeRecord { essence: "application/manifest+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: Some("UTF-8"), extensions: &["webmanifest"] },
+    MimeRecord { essence: "application/marc", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["mrc"] },
+    MimeRecord { essence: "application/marcxml+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["mrcx"] },
+    MimeRecord { essence: "application/mathematica", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ma", "nb", "mb"] },
+    MimeRecord { essence: "application/mathml+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["mathml"] },
+    MimeRecord { essence: "application/mathml-content+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/mathml-presentation+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/mbms-associated-procedure-description+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/mbms-deregister+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/mbms-envelope+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/mbms-msk+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/mbms-msk-response+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/mbms-protection-description+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/mbms-reception-report+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/mbms-register+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/mbms-register-response+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/mbms-schedule+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/mbms-user-service-description+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/mbox", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["mbox"] },
+    MimeRecord { essence: "application/media-policy-dataset+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["mpf"] },
+    MimeRecord { essence: "application/media_control+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/mediaservercontrol+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["mscml"] },
+    MimeRecord { essence: "application/merge-patch+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/metalink+xml", source: Some(MimeSource::Apache), compressible: Some(true), charset: None, extensions: &["metalink"] },
+    MimeRecord { essence: "application/metalink4+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["meta4"] },
+    MimeRecord { essence: "application/mets+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["mets"] },
+    MimeRecord { essence: "application/mf4", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/mikey", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/mipc", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/missing-blocks+cbor-seq", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/mmt-aei+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["maei"] },
+    MimeRecord { essence: "application/mmt-usd+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["musd"] },
+    MimeRecord { essence: "application/mods+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["mods"] },
+    MimeRecord { essence: "application/moss-keys", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/moss-signature", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/mosskey-data", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/mosskey-request", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/mp21", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["m21", "mp21"] },
+    MimeRecord { essence: "application/mp4", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["mp4", "mpg4", "mp4s", "m4p"] },
+    MimeRecord { essence: "application/mpeg4-generic", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/mpeg4-iod", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/mpeg4-iod-xmt", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/mrb-consumer+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/mrb-publish+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/msc-ivr+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: Some("UTF-8"), extensions: &[] },
+    MimeRecord { essence: "application/msc-mixer+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: Some("UTF-8"), extensions: &[] },
+    MimeRecord { essence: "application/msix", source: None, compressible: Some(false), charset: None, extensions: &["msix"] },
+    MimeRecord { essence: "application/msixbundle", source: None, compressible: Some(false), charset: None, extensions: &["msixbundle"] },
+    MimeRecord { essence: "application/msword", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["doc", "dot"] },
+    MimeRecord { essence: "application/mud+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/multipart-core", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/mxf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["mxf"] },
+    MimeRecord { essence: "application/n-quads", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["nq"] },
+    MimeRecord { essence: "application/n-triples", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["nt"] },
+    MimeRecord { essence: "application/nasdata", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/news-checkgroups", source: Some(MimeSource::Iana), compressible: None, charset: Some("US-ASCII"), extensions: &[] },
+    MimeRecord { essence: "application/news-groupinfo", source: Some(MimeSource::Iana), compressible: None, charset: Some("US-ASCII"), extensions: &[] },
+    MimeRecord { essence: "application/news-transmission", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/nlsml+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/node", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["cjs"] },
+    MimeRecord { essence: "application/nss", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/oauth-authz-req+jwt", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/oblivious-dns-message", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/ocsp-request", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/ocsp-response", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/octet-stream", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["bin", "dms", "lrf", "mar", "so", "dist", "distz", "pkg", "bpk", "dump", "elc", "deploy", "exe", "dll", "deb", "dmg", "iso", "img", "msi", "msp", "msm", "buffer"] },
+    MimeRecord { essence: "application/oda", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["oda"] },
+    MimeRecord { essence: "application/odm+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/odx", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/oebps-package+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["opf"] },
+    MimeRecord { essence: "application/ogg", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["ogx"] },
+    MimeRecord { essence: "application/ohttp-keys", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/omdoc+xml", source: Some(MimeSource::Apache), compressible: Some(true), charset: None, extensions: &["omdoc"] },
+    MimeRecord { essence: "application/onenote", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["onetoc", "onetoc2", "onetmp", "onepkg"] },
+    MimeRecord { essence: "application/opc-nodeset+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/oscore", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/oxps", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["oxps"] },
+    MimeRecord { essence: "application/p21", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/p21+zip", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/p2p-overlay+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["relo"] },
+    MimeRecord { essence: "application/parityfec", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/passport", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/patch-ops-error+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["xer"] },
+    MimeRecord { essence: "application/pdf", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["pdf"] },
+    MimeRecord { essence: "application/pdx", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/pem-certificate-chain", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/pgp-encrypted", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["pgp"] },
+    MimeRecord { essence: "application/pgp-keys", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["asc"] },
+    MimeRecord { essence: "application/pgp-signature", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["sig", "asc"] },
+    MimeRecord { essence: "application/pics-rules", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["prf"] },
+    MimeRecord { essence: "application/pidf+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: Some("UTF-8"), extensions: &[] },
+    MimeRecord { essence: "application/pidf-diff+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: Some("UTF-8"), extensions: &[] },
+    MimeRecord { essence: "application/pkcs10", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["p10"] },
+    MimeRecord { essence: "application/pkcs12", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/pkcs7-mime", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["p7m", "p7c"] },
+    MimeRecord { essence: "application/pkcs7-signature", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["p7s"] },
+    MimeRecord { essence: "application/pkcs8", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["p8"] },
+    MimeRecord { essence: "application/pkcs8-encrypted", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/pkix-attr-cert", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ac"] },
+    MimeRecord { essence: "application/pkix-cert", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["cer"] },
+    MimeRecord { essence: "application/pkix-crl", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["crl"] },
+    MimeRecord { essence: "application/pkix-pkipath", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["pkipath"] },
+    MimeRecord { essence: "application/pkixcmp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["pki"] },
+    MimeRecord { essence: "application/pls+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["pls"] },
+    MimeRecord { essence: "application/poc-settings+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: Some("UTF-8"), extensions: &[] },
+    MimeRecord { essence: "application/postscript", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["ai", "eps", "ps"] },
+    MimeRecord { essence: "application/ppsp-tracker+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/private-token-issuer-directory", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/private-token-request", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/private-token-response", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/problem+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/problem+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/provenance+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["provx"] },
+    MimeRecord { essence: "application/prs.alvestrand.titrax-sheet", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/prs.cww", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["cww"] },
+    MimeRecord { essence: "application/prs.cyn", source: Some(MimeSource::Iana), compressible: None, charset: Some("7-BIT"), extensions: &[] },
+    MimeRecord { essence: "application/prs.hpub+zip", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/prs.implied-document+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/prs.implied-executable", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/prs.implied-object+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/prs.implied-object+json-seq", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/prs.implied-object+yaml", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/prs.implied-structure", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/prs.nprend", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/prs.plucker", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/prs.rdf-xml-crypt", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/prs.vcfbzip2", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/prs.xsf+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["xsf"] },
+    MimeRecord { essence: "application/pskc+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["pskcxml"] },
+    MimeRecord { essence: "application/pvd+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/qsig", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/raml+yaml", source: None, compressible: Some(true), charset: None, extensions: &["raml"] },
+    MimeRecord { essence: "application/raptorfec", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/rdap+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/rdf+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["rdf", "owl"] },
+    MimeRecord { essence: "application/reginfo+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["rif"] },
+    MimeRecord { essence: "application/relax-ng-compact-syntax", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["rnc"] },
+    MimeRecord { essence: "application/remote-printing", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/reputon+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/resource-lists+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["rl"] },
+    MimeRecord { essence: "application/resource-lists-diff+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["rld"] },
+    MimeRecord { essence: "application/rfc+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/riscos", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/rlmi+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/rls-services+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["rs"] },
+    MimeRecord { essence: "application/route-apd+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["rapd"] },
+    MimeRecord { essence: "application/route-s-tsid+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["sls"] },
+    MimeRecord { essence: "application/route-usd+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["rusd"] },
+    MimeRecord { essence: "application/rpki-checklist", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/rpki-ghostbusters", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["gbr"] },
+    MimeRecord { essence: "application/rpki-manifest", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["mft"] },
+    MimeRecord { essence: "application/rpki-publication", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/rpki-roa", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["roa"] },
+    MimeRecord { essence: "application/rpki-signed-tal", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/rpki-updown", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/rsd+xml", source: Some(MimeSource::Apache), compressible: Some(true), charset: None, extensions: &["rsd"] },
+    MimeRecord { essence: "application/rss+xml", source: Some(MimeSource::Apache), compressible: Some(true), charset: None, extensions: &["rss"] },
+    MimeRecord { essence: "application/rtf", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["rtf"] },
+    MimeRecord { essence: "application/rtploopback", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/rtx", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/samlassertion+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/samlmetadata+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/sarif+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/sarif-external-properties+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/sbe", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/sbml+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["sbml"] },
+    MimeRecord { essence: "application/scaip+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/scim+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/scvp-cv-request", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["scq"] },
+    MimeRecord { essence: "application/scvp-cv-response", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["scs"] },
+    MimeRecord { essence: "application/scvp-vp-request", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["spq"] },
+    MimeRecord { essence: "application/scvp-vp-response", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["spp"] },
+    MimeRecord { essence: "application/sdp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["sdp"] },
+    MimeRecord { essence: "application/secevent+jwt", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/senml+cbor", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/senml+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/senml+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["senmlx"] },
+    MimeRecord { essence: "application/senml-etch+cbor", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/senml-etch+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/senml-exi", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/sensml+cbor", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/sensml+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/sensml+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["sensmlx"] },
+    MimeRecord { essence: "application/sensml-exi", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/sep+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/sep-exi", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/session-info", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/set-payment", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/set-payment-initiation", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["setpay"] },
+    MimeRecord { essence: "application/set-registration", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/set-registration-initiation", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["setreg"] },
+    MimeRecord { essence: "application/sgml", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/sgml-open-catalog", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/shf+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["shf"] },
+    MimeRecord { essence: "application/sieve", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["siv", "sieve"] },
+    MimeRecord { essence: "application/simple-filter+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/simple-message-summary", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/simplesymbolcontainer", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/sipc", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/slate", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/smil", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/smil+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["smi", "smil"] },
+    MimeRecord { essence: "application/smpte336m", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/soap+fastinfoset", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/soap+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/sparql-query", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["rq"] },
+    MimeRecord { essence: "application/sparql-results+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["srx"] },
+    MimeRecord { essence: "application/spdx+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/spirits-event+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/sql", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["sql"] },
+    MimeRecord { essence: "application/srgs", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["gram"] },
+    MimeRecord { essence: "application/srgs+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["grxml"] },
+    MimeRecord { essence: "application/sru+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["sru"] },
+    MimeRecord { essence: "application/ssdl+xml", source: Some(MimeSource::Apache), compressible: Some(true), charset: None, extensions: &["ssdl"] },
+    MimeRecord { essence: "application/ssml+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["ssml"] },
+    MimeRecord { essence: "application/st2110-41", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/stix+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/stratum", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/swid+cbor", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/swid+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["swidtag"] },
+    MimeRecord { essence: "application/tamp-apex-update", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/tamp-apex-update-confirm", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/tamp-community-update", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/tamp-community-update-confirm", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/tamp-error", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/tamp-sequence-adjust", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/tamp-sequence-adjust-confirm", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/tamp-status-query", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/tamp-status-response", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/tamp-update", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/tamp-update-confirm", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/tar", source: None, compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/taxii+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/td+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/tei+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["tei", "teicorpus"] },
+    MimeRecord { essence: "application/tetra_isi", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/thraud+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["tfi"] },
+    MimeRecord { essence: "application/timestamp-query", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/timestamp-reply", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/timestamped-data", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["tsd"] },
+    MimeRecord { essence: "application/tlsrpt+gzip", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/tlsrpt+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/tm+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/tnauthlist", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/token-introspection+jwt", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/toml", source: None, compressible: Some(true), charset: None, extensions: &["toml"] },
+    MimeRecord { essence: "application/trickle-ice-sdpfrag", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/trig", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["trig"] },
+    MimeRecord { essence: "application/ttml+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["ttml"] },
+    MimeRecord { essence: "application/tve-trigger", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/tzif", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/tzif-leap", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/ubjson", source: None, compressible: Some(false), charset: None, extensions: &["ubj"] },
+    MimeRecord { essence: "application/ulpfec", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/urc-grpsheet+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/urc-ressheet+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["rsheet"] },
+    MimeRecord { essence: "application/urc-targetdesc+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["td"] },
+    MimeRecord { essence: "application/urc-uisocketdesc+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vc", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vcard+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vcard+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vemmi", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vividence.scriptfile", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.1000minds.decision-model+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["1km"] },
+    MimeRecord { essence: "application/vnd.1ob", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp-prose+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp-prose-pc3a+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp-prose-pc3ach+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp-prose-pc3ch+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp-prose-pc8+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp-v2x-local-service-information", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.5gnas", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.5gsa2x", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.5gsa2x-local-service-information", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.access-transfer-events+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.bsf+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.crs+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.current-location-discovery+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.gmop+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.gtpc", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.interworking-data", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.lpp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.mc-signalling-ear", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.mcdata-affiliation-command+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.mcdata-info+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.mcdata-msgstore-ctrl-request+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.mcdata-payload", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.mcdata-regroup+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.mcdata-service-config+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.mcdata-signalling", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.mcdata-ue-config+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.mcdata-user-profile+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.mcptt-affiliation-command+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.mcptt-floor-request+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.mcptt-info+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.mcptt-location-info+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.mcptt-mbms-usage-info+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.mcptt-regroup+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.mcptt-service-config+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.mcptt-signed+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.mcptt-ue-config+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.mcptt-ue-init-config+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.mcptt-user-profile+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.mcvideo-affiliation-command+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.mcvideo-info+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.mcvideo-location-info+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.mcvideo-mbms-usage-info+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.mcvideo-regroup+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.mcvideo-service-config+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.mcvideo-transmission-request+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.mcvideo-ue-config+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.mcvideo-user-profile+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.mid-call+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.ngap", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.pfcp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.pic-bw-large", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["plb"] },
+    MimeRecord { essence: "application/vnd.3gpp.pic-bw-small", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["psb"] },
+    MimeRecord { essence: "application/vnd.3gpp.pic-bw-var", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["pvb"] },
+    MimeRecord { essence: "application/vnd.3gpp.pinapp-info+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.s1ap", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.seal-group-doc+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.seal-info+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.seal-location-info+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.seal-mbms-usage-info+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.seal-network-qos-management-info+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.seal-ue-config-info+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.seal-unicast-info+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.seal-user-profile-info+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.sms", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.sms+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.srvcc-ext+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.srvcc-info+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.state-and-event-info+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.ussd+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.v2x", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp.vae-info+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp2.bcmcsinfo+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp2.sms", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3gpp2.tcap", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["tcap"] },
+    MimeRecord { essence: "application/vnd.3lightssoftware.imagescal", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.3m.post-it-notes", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["pwn"] },
+    MimeRecord { essence: "application/vnd.accpac.simply.aso", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["aso"] },
+    MimeRecord { essence: "application/vnd.accpac.simply.imp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["imp"] },
+    MimeRecord { essence: "application/vnd.acm.addressxfer+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.acm.chatbot+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.acucobol", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["acu"] },
+    MimeRecord { essence: "application/vnd.acucorp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["atc", "acutc"] },
+    MimeRecord { essence: "application/vnd.adobe.air-application-installer-package+zip", source: Some(MimeSource::Apache), compressible: Some(false), charset: None, extensions: &["air"] },
+    MimeRecord { essence: "application/vnd.adobe.flash.movie", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.adobe.formscentral.fcdt", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["fcdt"] },
+    MimeRecord { essence: "application/vnd.adobe.fxp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["fxp", "fxpl"] },
+    MimeRecord { essence: "application/vnd.adobe.partial-upload", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.adobe.xdp+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["xdp"] },
+    MimeRecord { essence: "application/vnd.adobe.xfdf", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["xfdf"] },
+    MimeRecord { essence: "application/vnd.aether.imp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.afpc.afplinedata", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.afpc.afplinedata-pagedef", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.afpc.cmoca-cmresource", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.afpc.foca-charset", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.afpc.foca-codedfont", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.afpc.foca-codepage", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.afpc.modca", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.afpc.modca-cmtable", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.afpc.modca-formdef", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.afpc.modca-mediummap", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.afpc.modca-objectcontainer", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.afpc.modca-overlay", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.afpc.modca-pagesegment", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.age", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["age"] },
+    MimeRecord { essence: "application/vnd.ah-barcode", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ahead.space", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ahead"] },
+    MimeRecord { essence: "application/vnd.airzip.filesecure.azf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["azf"] },
+    MimeRecord { essence: "application/vnd.airzip.filesecure.azs", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["azs"] },
+    MimeRecord { essence: "application/vnd.amadeus+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.amazon.ebook", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["azw"] },
+    MimeRecord { essence: "application/vnd.amazon.mobi8-ebook", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.americandynamics.acc", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["acc"] },
+    MimeRecord { essence: "application/vnd.amiga.ami", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ami"] },
+    MimeRecord { essence: "application/vnd.amundsen.maze+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.android.ota", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.android.package-archive", source: Some(MimeSource::Apache), compressible: Some(false), charset: None, extensions: &["apk"] },
+    MimeRecord { essence: "application/vnd.anki", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.anser-web-certificate-issue-initiation", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["cii"] },
+    MimeRecord { essence: "application/vnd.anser-web-funds-transfer-initiation", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["fti"] },
+    MimeRecord { essence: "application/vnd.antix.game-component", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["atx"] },
+    MimeRecord { essence: "application/vnd.apache.arrow.file", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.apache.arrow.stream", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.apache.parquet", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.apache.thrift.binary", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.apache.thrift.compact", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.apache.thrift.json", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.apexlang", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.api+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.aplextor.warrp+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.apothekende.reservation+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.apple.installer+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["mpkg"] },
+    MimeRecord { essence: "application/vnd.apple.keynote", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["key"] },
+    MimeRecord { essence: "application/vnd.apple.mpegurl", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["m3u8"] },
+    MimeRecord { essence: "application/vnd.apple.numbers", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["numbers"] },
+    MimeRecord { essence: "application/vnd.apple.pages", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["pages"] },
+    MimeRecord { essence: "application/vnd.apple.pkpass", source: None, compressible: Some(false), charset: None, extensions: &["pkpass"] },
+    MimeRecord { essence: "application/vnd.arastra.swi", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.aristanetworks.swi", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["swi"] },
+    MimeRecord { essence: "application/vnd.artisan+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.artsquare", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.astraea-software.iota", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["iota"] },
+    MimeRecord { essence: "application/vnd.audiograph", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["aep"] },
+    MimeRecord { essence: "application/vnd.autopackage", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.avalon+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.avistar+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.balsamiq.bmml+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["bmml"] },
+    MimeRecord { essence: "application/vnd.balsamiq.bmpr", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.banana-accounting", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.bbf.usp.error", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.bbf.usp.msg", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.bbf.usp.msg+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.bekitzur-stech+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.belightsoft.lhzd+zip", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.belightsoft.lhzl+zip", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.bint.med-content", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.biopax.rdf+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.blink-idb-value-wrapper", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.blueice.multipass", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["mpm"] },
+    MimeRecord { essence: "application/vnd.bluetooth.ep.oob", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.bluetooth.le.oob", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.bmi", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["bmi"] },
+    MimeRecord { essence: "application/vnd.bpf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.bpf3", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.businessobjects", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["rep"] },
+    MimeRecord { essence: "application/vnd.byu.uapi+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.bzip3", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.c3voc.schedule+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.cab-jscript", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.canon-cpdl", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.canon-lips", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.capasystems-pg+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.cendio.thinlinc.clientconf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.century-systems.tcp_stream", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.chemdraw+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["cdxml"] },
+    MimeRecord { essence: "application/vnd.chess-pgn", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.chipnuts.karaoke-mmd", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["mmd"] },
+    MimeRecord { essence: "application/vnd.ciedi", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.cinderella", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["cdy"] },
+    MimeRecord { essence: "application/vnd.cirpack.isdn-ext", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.citationstyles.style+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["csl"] },
+    MimeRecord { essence: "application/vnd.claymore", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["cla"] },
+    MimeRecord { essence: "application/vnd.cloanto.rp9", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["rp9"] },
+    MimeRecord { essence: "application/vnd.clonk.c4group", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["c4g", "c4d", "c4f", "c4p", "c4u"] },
+    MimeRecord { essence: "application/vnd.cluetrust.cartomobile-config", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["c11amc"] },
+    MimeRecord { essence: "application/vnd.cluetrust.cartomobile-config-pkg", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["c11amz"] },
+    MimeRecord { essence: "application/vnd.cncf.helm.chart.content.v1.tar+gzip", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.cncf.helm.chart.provenance.v1.prov", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.cncf.helm.config.v1+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.coffeescript", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.collabio.xodocuments.document", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.collabio.xodocuments.document-template", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.collabio.xodocuments.presentation", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.collabio.xodocuments.presentation-template", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.collabio.xodocuments.spreadsheet", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.collabio.xodocuments.spreadsheet-template", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.collection+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.collection.doc+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.collection.next+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.comicbook+zip", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.comicbook-rar", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.commerce-battelle", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.commonspace", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["csp"] },
+    MimeRecord { essence: "application/vnd.contact.cmsg", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["cdbcmsg"] },
+    MimeRecord { essence: "application/vnd.coreos.ignition+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.cosmocaller", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["cmc"] },
+    MimeRecord { essence: "application/vnd.crick.clicker", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["clkx"] },
+    MimeRecord { essence: "application/vnd.crick.clicker.keyboard", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["clkk"] },
+    MimeRecord { essence: "application/vnd.crick.clicker.palette", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["clkp"] },
+    MimeRecord { essence: "application/vnd.crick.clicker.template", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["clkt"] },
+    MimeRecord { essence: "application/vnd.crick.clicker.wordbank", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["clkw"] },
+    MimeRecord { essence: "application/vnd.criticaltools.wbs+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["wbs"] },
+    MimeRecord { essence: "application/vnd.cryptii.pipe+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.crypto-shade-file", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.cryptomator.encrypted", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.cryptomator.vault", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ctc-posml", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["pml"] },
+    MimeRecord { essence: "application/vnd.ctct.ws+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.cups-pdf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.cups-postscript", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.cups-ppd", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ppd"] },
+    MimeRecord { essence: "application/vnd.cups-raster", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.cups-raw", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.curl", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.curl.car", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["car"] },
+    MimeRecord { essence: "application/vnd.curl.pcurl", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["pcurl"] },
+    MimeRecord { essence: "application/vnd.cyan.dean.root+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.cybank", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.cyclonedx+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.cyclonedx+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.d2l.coursepackage1p0+zip", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.d3m-dataset", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.d3m-problem", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.dart", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["dart"] },
+    MimeRecord { essence: "application/vnd.data-vision.rdz", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["rdz"] },
+    MimeRecord { essence: "application/vnd.datalog", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.datapackage+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.dataresource+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.dbf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["dbf"] },
+    MimeRecord { essence: "application/vnd.debian.binary-package", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.dece.data", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["uvf", "uvvf", "uvd", "uvvd"] },
+    MimeRecord { essence: "application/vnd.dece.ttml+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["uvt", "uvvt"] },
+    MimeRecord { essence: "application/vnd.dece.unspecified", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["uvx", "uvvx"] },
+    MimeRecord { essence: "application/vnd.dece.zip", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["uvz", "uvvz"] },
+    MimeRecord { essence: "application/vnd.denovo.fcselayout-link", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["fe_launch"] },
+    MimeRecord { essence: "application/vnd.desmume.movie", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.dir-bi.plate-dl-nosuffix", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.dm.delegation+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.dna", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["dna"] },
+    MimeRecord { essence: "application/vnd.document+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.dolby.mlp", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["mlp"] },
+    MimeRecord { essence: "application/vnd.dolby.mobile.1", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.dolby.mobile.2", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.doremir.scorecloud-binary-document", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.dpgraph", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["dpg"] },
+    MimeRecord { essence: "application/vnd.dreamfactory", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["dfac"] },
+    MimeRecord { essence: "application/vnd.drive+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ds-keypoint", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["kpxx"] },
+    MimeRecord { essence: "application/vnd.dtg.local", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.dtg.local.flash", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.dtg.local.html", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.dvb.ait", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ait"] },
+    MimeRecord { essence: "application/vnd.dvb.dvbisl+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.dvb.dvbj", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.dvb.esgcontainer", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.dvb.ipdcdftnotifaccess", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.dvb.ipdcesgaccess", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.dvb.ipdcesgaccess2", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.dvb.ipdcesgpdd", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.dvb.ipdcroaming", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.dvb.iptv.alfec-base", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.dvb.iptv.alfec-enhancement", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.dvb.notif-aggregate-root+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.dvb.notif-container+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.dvb.notif-generic+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.dvb.notif-ia-msglist+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.dvb.notif-ia-registration-request+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.dvb.notif-ia-registration-response+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.dvb.notif-init+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.dvb.pfr", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.dvb.service", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["svc"] },
+    MimeRecord { essence: "application/vnd.dxr", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.dynageo", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["geo"] },
+    MimeRecord { essence: "application/vnd.dzr", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.easykaraoke.cdgdownload", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ecdis-update", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ecip.rlp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.eclipse.ditto+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ecowin.chart", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["mag"] },
+    MimeRecord { essence: "application/vnd.ecowin.filerequest", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ecowin.fileupdate", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ecowin.series", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ecowin.seriesrequest", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ecowin.seriesupdate", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.efi.img", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.efi.iso", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.eln+zip", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.emclient.accessrequest+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.enliven", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["nml"] },
+    MimeRecord { essence: "application/vnd.enphase.envoy", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.eprints.data+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.epson.esf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["esf"] },
+    MimeRecord { essence: "application/vnd.epson.msf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["msf"] },
+    MimeRecord { essence: "application/vnd.epson.quickanime", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["qam"] },
+    MimeRecord { essence: "application/vnd.epson.salt", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["slt"] },
+    MimeRecord { essence: "application/vnd.epson.ssf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ssf"] },
+    MimeRecord { essence: "application/vnd.ericsson.quickcall", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.erofs", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.espass-espass+zip", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.eszigno3+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["es3", "et3"] },
+    MimeRecord { essence: "application/vnd.etsi.aoc+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.etsi.asic-e+zip", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.etsi.asic-s+zip", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.etsi.cug+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.etsi.iptvcommand+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.etsi.iptvdiscovery+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.etsi.iptvprofile+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.etsi.iptvsad-bc+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.etsi.iptvsad-cod+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.etsi.iptvsad-npvr+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.etsi.iptvservice+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.etsi.iptvsync+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.etsi.iptvueprofile+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.etsi.mcid+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.etsi.mheg5", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.etsi.overload-control-policy-dataset+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.etsi.pstn+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.etsi.sci+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.etsi.simservs+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.etsi.timestamp-token", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.etsi.tsl+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.etsi.tsl.der", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.eu.kasparian.car+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.eudora.data", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.evolv.ecig.profile", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.evolv.ecig.settings", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.evolv.ecig.theme", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.exstream-empower+zip", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.exstream-package", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ezpix-album", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ez2"] },
+    MimeRecord { essence: "application/vnd.ezpix-package", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ez3"] },
+    MimeRecord { essence: "application/vnd.f-secure.mobile", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.familysearch.gedcom+zip", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.fastcopy-disk-image", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.fdf", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["fdf"] },
+    MimeRecord { essence: "application/vnd.fdsn.mseed", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["mseed"] },
+    MimeRecord { essence: "application/vnd.fdsn.seed", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["seed", "dataless"] },
+    MimeRecord { essence: "application/vnd.ffsns", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ficlab.flb+zip", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.filmit.zfc", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.fints", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.firemonkeys.cloudcell", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.flographit", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["gph"] },
+    MimeRecord { essence: "application/vnd.fluxtime.clip", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ftc"] },
+    MimeRecord { essence: "application/vnd.font-fontforge-sfd", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.framemaker", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["fm", "frame", "maker", "book"] },
+    MimeRecord { essence: "application/vnd.freelog.comic", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.frogans.fnc", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["fnc"] },
+    MimeRecord { essence: "application/vnd.frogans.ltf", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["ltf"] },
+    MimeRecord { essence: "application/vnd.fsc.weblaunch", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["fsc"] },
+    MimeRecord { essence: "application/vnd.fujifilm.fb.docuworks", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.fujifilm.fb.docuworks.binder", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.fujifilm.fb.docuworks.container", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.fujifilm.fb.jfi+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.fujitsu.oasys", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["oas"] },
+    MimeRecord { essence: "application/vnd.fujitsu.oasys2", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["oa2"] },
+    MimeRecord { essence: "application/vnd.fujitsu.oasys3", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["oa3"] },
+    MimeRecord { essence: "application/vnd.fujitsu.oasysgp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["fg5"] },
+    MimeRecord { essence: "application/vnd.fujitsu.oasysprs", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["bh2"] },
+    MimeRecord { essence: "application/vnd.fujixerox.art-ex", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.fujixerox.art4", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.fujixerox.ddd", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ddd"] },
+    MimeRecord { essence: "application/vnd.fujixerox.docuworks", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["xdw"] },
+    MimeRecord { essence: "application/vnd.fujixerox.docuworks.binder", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["xbd"] },
+    MimeRecord { essence: "application/vnd.fujixerox.docuworks.container", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.fujixerox.hbpl", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.fut-misnet", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.futoin+cbor", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.futoin+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.fuzzysheet", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["fzs"] },
+    MimeRecord { essence: "application/vnd.ga4gh.passport+jwt", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.genomatix.tuxedo", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["txd"] },
+    MimeRecord { essence: "application/vnd.genozip", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.gentics.grd+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.gentoo.catmetadata+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.gentoo.ebuild", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.gentoo.eclass", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.gentoo.gpkg", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.gentoo.manifest", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.gentoo.pkgmetadata+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.gentoo.xpak", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.geo+json", source: Some(MimeSource::Apache), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.geocube+xml", source: Some(MimeSource::Apache), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.geogebra.file", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ggb"] },
+    MimeRecord { essence: "application/vnd.geogebra.slides", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ggs"] },
+    MimeRecord { essence: "application/vnd.geogebra.tool", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ggt"] },
+    MimeRecord { essence: "application/vnd.geometry-explorer", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["gex", "gre"] },
+    MimeRecord { essence: "application/vnd.geonext", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["gxt"] },
+    MimeRecord { essence: "application/vnd.geoplan", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["g2w"] },
+    MimeRecord { essence: "application/vnd.geospace", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["g3w"] },
+    MimeRecord { essence: "application/vnd.gerber", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.globalplatform.card-content-mgt", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.globalplatform.card-content-mgt-response", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.gmx", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["gmx"] },
+    MimeRecord { essence: "application/vnd.gnu.taler.exchange+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.gnu.taler.merchant+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.google-apps.document", source: None, compressible: Some(false), charset: None, extensions: &["gdoc"] },
+    MimeRecord { essence: "application/vnd.google-apps.presentation", source: None, compressible: Some(false), charset: None, extensions: &["gslides"] },
+    MimeRecord { essence: "application/vnd.google-apps.spreadsheet", source: None, compressible: Some(false), charset: None, extensions: &["gsheet"] },
+    MimeRecord { essence: "application/vnd.google-earth.kml+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["kml"] },
+    MimeRecord { essence: "application/vnd.google-earth.kmz", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["kmz"] },
+    MimeRecord { essence: "application/vnd.gov.sk.e-form+xml", source: Some(MimeSource::Apache), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.gov.sk.e-form+zip", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.gov.sk.xmldatacontainer+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["xdcf"] },
+    MimeRecord { essence: "application/vnd.gpxsee.map+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.grafeq", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["gqf", "gqs"] },
+    MimeRecord { essence: "application/vnd.gridmp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.groove-account", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["gac"] },
+    MimeRecord { essence: "application/vnd.groove-help", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ghf"] },
+    MimeRecord { essence: "application/vnd.groove-identity-message", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["gim"] },
+    MimeRecord { essence: "application/vnd.groove-injector", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["grv"] },
+    MimeRecord { essence: "application/vnd.groove-tool-message", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["gtm"] },
+    MimeRecord { essence: "application/vnd.groove-tool-template", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["tpl"] },
+    MimeRecord { essence: "application/vnd.groove-vcard", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["vcg"] },
+    MimeRecord { essence: "application/vnd.hal+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.hal+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["hal"] },
+    MimeRecord { essence: "application/vnd.handheld-entertainment+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["zmm"] },
+    MimeRecord { essence: "application/vnd.hbci", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["hbci"] },
+    MimeRecord { essence: "application/vnd.hc+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.hcl-bireports", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.hdt", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.heroku+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.hhe.lesson-player", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["les"] },
+    MimeRecord { essence: "application/vnd.hp-hpgl", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["hpgl"] },
+    MimeRecord { essence: "application/vnd.hp-hpid", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["hpid"] },
+    MimeRecord { essence: "application/vnd.hp-hps", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["hps"] },
+    MimeRecord { essence: "application/vnd.hp-jlyt", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["jlt"] },
+    MimeRecord { essence: "application/vnd.hp-pcl", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["pcl"] },
+    MimeRecord { essence: "application/vnd.hp-pclxl", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["pclxl"] },
+    MimeRecord { essence: "application/vnd.hsl", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.httphone", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.hydrostatix.sof-data", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["sfd-hdstx"] },
+    MimeRecord { essence: "application/vnd.hyper+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.hyper-item+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.hyperdrive+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.hzn-3d-crossword", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ibm.afplinedata", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ibm.electronic-media", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ibm.minipay", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["mpy"] },
+    MimeRecord { essence: "application/vnd.ibm.modcap", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["afp", "listafp", "list3820"] },
+    MimeRecord { essence: "application/vnd.ibm.rights-management", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["irm"] },
+    MimeRecord { essence: "application/vnd.ibm.secure-container", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["sc"] },
+    MimeRecord { essence: "application/vnd.iccprofile", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["icc", "icm"] },
+    MimeRecord { essence: "application/vnd.ieee.1905", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.igloader", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["igl"] },
+    MimeRecord { essence: "application/vnd.imagemeter.folder+zip", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.imagemeter.image+zip", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.immervision-ivp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ivp"] },
+    MimeRecord { essence: "application/vnd.immervision-ivu", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ivu"] },
+    MimeRecord { essence: "application/vnd.ims.imsccv1p1", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ims.imsccv1p2", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ims.imsccv1p3", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ims.lis.v2.result+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ims.lti.v2.toolconsumerprofile+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ims.lti.v2.toolproxy+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ims.lti.v2.toolproxy.id+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ims.lti.v2.toolsettings+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ims.lti.v2.toolsettings.simple+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.informedcontrol.rms+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.informix-visionary", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.infotech.project", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.infotech.project+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.innopath.wamp.notification", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.insors.igm", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["igm"] },
+    MimeRecord { essence: "application/vnd.intercon.formnet", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["xpw", "xpx"] },
+    MimeRecord { essence: "application/vnd.intergeo", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["i2g"] },
+    MimeRecord { essence: "application/vnd.intertrust.digibox", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.intertrust.nncp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.intu.qbo", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["qbo"] },
+    MimeRecord { essence: "application/vnd.intu.qfx", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["qfx"] },
+    MimeRecord { essence: "application/vnd.ipfs.ipns-record", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ipld.car", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ipld.dag-cbor", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ipld.dag-json", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ipld.raw", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.iptc.g2.catalogitem+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.iptc.g2.conceptitem+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.iptc.g2.knowledgeitem+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.iptc.g2.newsitem+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.iptc.g2.newsmessage+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.iptc.g2.packageitem+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.iptc.g2.planningitem+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ipunplugged.rcprofile", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["rcprofile"] },
+    MimeRecord { essence: "application/vnd.irepository.package+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["irp"] },
+    MimeRecord { essence: "application/vnd.is-xpr", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["xpr"] },
+    MimeRecord { essence: "application/vnd.isac.fcs", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["fcs"] },
+    MimeRecord { essence: "application/vnd.iso11783-10+zip", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.jam", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["jam"] },
+    MimeRecord { essence: "application/vnd.japannet-directory-service", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.japannet-jpnstore-wakeup", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.japannet-payment-wakeup", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.japannet-registration", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.japannet-registration-wakeup", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.japannet-setstore-wakeup", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.japannet-verification", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.japannet-verification-wakeup", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.jcp.javame.midlet-rms", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["rms"] },
+    MimeRecord { essence: "application/vnd.jisp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["jisp"] },
+    MimeRecord { essence: "application/vnd.joost.joda-archive", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["joda"] },
+    MimeRecord { essence: "application/vnd.jsk.isdn-ngn", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.kahootz", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ktz", "ktr"] },
+    MimeRecord { essence: "application/vnd.kde.karbon", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["karbon"] },
+    MimeRecord { essence: "application/vnd.kde.kchart", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["chrt"] },
+    MimeRecord { essence: "application/vnd.kde.kformula", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["kfo"] },
+    MimeRecord { essence: "application/vnd.kde.kivio", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["flw"] },
+    MimeRecord { essence: "application/vnd.kde.kontour", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["kon"] },
+    MimeRecord { essence: "application/vnd.kde.kpresenter", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["kpr", "kpt"] },
+    MimeRecord { essence: "application/vnd.kde.kspread", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ksp"] },
+    MimeRecord { essence: "application/vnd.kde.kword", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["kwd", "kwt"] },
+    MimeRecord { essence: "application/vnd.kenameaapp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["htke"] },
+    MimeRecord { essence: "application/vnd.kidspiration", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["kia"] },
+    MimeRecord { essence: "application/vnd.kinar", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["kne", "knp"] },
+    MimeRecord { essence: "application/vnd.koan", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["skp", "skd", "skt", "skm"] },
+    MimeRecord { essence: "application/vnd.kodak-descriptor", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["sse"] },
+    MimeRecord { essence: "application/vnd.las", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.las.las+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.las.las+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["lasxml"] },
+    MimeRecord { essence: "application/vnd.laszip", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ldev.productlicensing", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.leap+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.liberty-request+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.llamagraphics.life-balance.desktop", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["lbd"] },
+    MimeRecord { essence: "application/vnd.llamagraphics.life-balance.exchange+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["lbe"] },
+    MimeRecord { essence: "application/vnd.logipipe.circuit+zip", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.loom", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.lotus-1-2-3", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["123"] },
+    MimeRecord { essence: "application/vnd.lotus-approach", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["apr"] },
+    MimeRecord { essence: "application/vnd.lotus-freelance", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["pre"] },
+    MimeRecord { essence: "application/vnd.lotus-notes", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["nsf"] },
+    MimeRecord { essence: "application/vnd.lotus-organizer", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["org"] },
+    MimeRecord { essence: "application/vnd.lotus-screencam", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["scm"] },
+    MimeRecord { essence: "application/vnd.lotus-wordpro", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["lwp"] },
+    MimeRecord { essence: "application/vnd.macports.portpkg", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["portpkg"] },
+    MimeRecord { essence: "application/vnd.mapbox-vector-tile", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["mvt"] },
+    MimeRecord { essence: "application/vnd.marlin.drm.actiontoken+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.marlin.drm.conftoken+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.marlin.drm.license+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.marlin.drm.mdcf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.mason+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.maxar.archive.3tz+zip", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.maxmind.maxmind-db", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.mcd", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["mcd"] },
+    MimeRecord { essence: "application/vnd.mdl", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.mdl-mbsdf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.medcalcdata", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["mc1"] },
+    MimeRecord { essence: "application/vnd.mediastation.cdkey", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["cdkey"] },
+    MimeRecord { essence: "application/vnd.medicalholodeck.recordxr", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.meridian-slingshot", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.mermaid", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.mfer", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["mwf"] },
+    MimeRecord { essence: "application/vnd.mfmp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["mfm"] },
+    MimeRecord { essence: "application/vnd.micro+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.micrografx.flo", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["flo"] },
+    MimeRecord { essence: "application/vnd.micrografx.igx", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["igx"] },
+    MimeRecord { essence: "application/vnd.microsoft.portable-executable", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.microsoft.windows.thumbnail-cache", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.miele+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.mif", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["mif"] },
+    MimeRecord { essence: "application/vnd.minisoft-hp3000-save", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.mitsubishi.misty-guard.trustweb", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.mobius.daf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["daf"] },
+    MimeRecord { essence: "application/vnd.mobius.dis", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["dis"] },
+    MimeRecord { essence: "application/vnd.mobius.mbk", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["mbk"] },
+    MimeRecord { essence: "application/vnd.mobius.mqy", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["mqy"] },
+    MimeRecord { essence: "application/vnd.mobius.msl", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["msl"] },
+    MimeRecord { essence: "application/vnd.mobius.plc", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["plc"] },
+    MimeRecord { essence: "application/vnd.mobius.txf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["txf"] },
+    MimeRecord { essence: "application/vnd.modl", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.mophun.application", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["mpn"] },
+    MimeRecord { essence: "application/vnd.mophun.certificate", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["mpc"] },
+    MimeRecord { essence: "application/vnd.motorola.flexsuite", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.motorola.flexsuite.adsi", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.motorola.flexsuite.fis", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.motorola.flexsuite.gotap", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.motorola.flexsuite.kmr", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.motorola.flexsuite.ttc", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.motorola.flexsuite.wem", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.motorola.iprm", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.mozilla.xul+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["xul"] },
+    MimeRecord { essence: "application/vnd.ms-3mfdocument", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ms-artgalry", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["cil"] },
+    MimeRecord { essence: "application/vnd.ms-asf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ms-cab-compressed", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["cab"] },
+    MimeRecord { essence: "application/vnd.ms-color.iccprofile", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ms-excel", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["xls", "xlm", "xla", "xlc", "xlt", "xlw"] },
+    MimeRecord { essence: "application/vnd.ms-excel.addin.macroenabled.12", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["xlam"] },
+    MimeRecord { essence: "application/vnd.ms-excel.sheet.binary.macroenabled.12", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["xlsb"] },
+    MimeRecord { essence: "application/vnd.ms-excel.sheet.macroenabled.12", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["xlsm"] },
+    MimeRecord { essence: "application/vnd.ms-excel.template.macroenabled.12", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["xltm"] },
+    MimeRecord { essence: "application/vnd.ms-fontobject", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["eot"] },
+    MimeRecord { essence: "application/vnd.ms-htmlhelp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["chm"] },
+    MimeRecord { essence: "application/vnd.ms-ims", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ims"] },
+    MimeRecord { essence: "application/vnd.ms-lrm", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["lrm"] },
+    MimeRecord { essence: "application/vnd.ms-office.activex+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ms-officetheme", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["thmx"] },
+    MimeRecord { essence: "application/vnd.ms-opentype", source: Some(MimeSource::Apache), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ms-outlook", source: None, compressible: Some(false), charset: None, extensions: &["msg"] },
+    MimeRecord { essence: "application/vnd.ms-package.obfuscated-opentype", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ms-pki.seccat", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["cat"] },
+    MimeRecord { essence: "application/vnd.ms-pki.stl", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["stl"] },
+    MimeRecord { essence: "application/vnd.ms-playready.initiator+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ms-powerpoint", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["ppt", "pps", "pot"] },
+    MimeRecord { essence: "application/vnd.ms-powerpoint.addin.macroenabled.12", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ppam"] },
+    MimeRecord { essence: "application/vnd.ms-powerpoint.presentation.macroenabled.12", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["pptm"] },
+    MimeRecord { essence: "application/vnd.ms-powerpoint.slide.macroenabled.12", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["sldm"] },
+    MimeRecord { essence: "application/vnd.ms-powerpoint.slideshow.macroenabled.12", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ppsm"] },
+    MimeRecord { essence: "application/vnd.ms-powerpoint.template.macroenabled.12", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["potm"] },
+    MimeRecord { essence: "application/vnd.ms-printdevicecapabilities+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ms-printing.printticket+xml", source: Some(MimeSource::Apache), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ms-printschematicket+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ms-project", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["mpp", "mpt"] },
+    MimeRecord { essence: "application/vnd.ms-tnef", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ms-windows.devicepairing", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ms-windows.nwprinting.oob", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ms-windows.printerpairing", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ms-windows.wsd.oob", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ms-wmdrm.lic-chlg-req", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ms-wmdrm.lic-resp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ms-wmdrm.meter-chlg-req", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ms-wmdrm.meter-resp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ms-word.document.macroenabled.12", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["docm"] },
+    MimeRecord { essence: "application/vnd.ms-word.template.macroenabled.12", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["dotm"] },
+    MimeRecord { essence: "application/vnd.ms-works", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["wps", "wks", "wcm", "wdb"] },
+    MimeRecord { essence: "application/vnd.ms-wpl", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["wpl"] },
+    MimeRecord { essence: "application/vnd.ms-xpsdocument", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["xps"] },
+    MimeRecord { essence: "application/vnd.msa-disk-image", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.mseq", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["mseq"] },
+    MimeRecord { essence: "application/vnd.msgpack", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.msign", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.multiad.creator", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.multiad.creator.cif", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.music-niff", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.musician", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["mus"] },
+    MimeRecord { essence: "application/vnd.muvee.style", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["msty"] },
+    MimeRecord { essence: "application/vnd.mynfc", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["taglet"] },
+    MimeRecord { essence: "application/vnd.nacamar.ybrid+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.nato.bindingdataobject+cbor", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.nato.bindingdataobject+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.nato.bindingdataobject+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["bdo"] },
+    MimeRecord { essence: "application/vnd.nato.openxmlformats-package.iepd+zip", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ncd.control", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ncd.reference", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.nearst.inv+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.nebumind.line", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.nervana", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.netfpx", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.neurolanguage.nlu", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["nlu"] },
+    MimeRecord { essence: "application/vnd.nimn", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.nintendo.nitro.rom", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.nintendo.snes.rom", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.nitf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ntf", "nitf"] },
+    MimeRecord { essence: "application/vnd.noblenet-directory", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["nnd"] },
+    MimeRecord { essence: "application/vnd.noblenet-sealer", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["nns"] },
+    MimeRecord { essence: "application/vnd.noblenet-web", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["nnw"] },
+    MimeRecord { essence: "application/vnd.nokia.catalogs", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.nokia.conml+wbxml", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.nokia.conml+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.nokia.iptv.config+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.nokia.isds-radio-presets", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.nokia.landmark+wbxml", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.nokia.landmark+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.nokia.landmarkcollection+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.nokia.n-gage.ac+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["ac"] },
+    MimeRecord { essence: "application/vnd.nokia.n-gage.data", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ngdat"] },
+    MimeRecord { essence: "application/vnd.nokia.n-gage.symbian.install", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["n-gage"] },
+    MimeRecord { essence: "application/vnd.nokia.ncd", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.nokia.pcd+wbxml", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.nokia.pcd+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.nokia.radio-preset", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["rpst"] },
+    MimeRecord { essence: "application/vnd.nokia.radio-presets", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["rpss"] },
+    MimeRecord { essence: "application/vnd.novadigm.edm", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["edm"] },
+    MimeRecord { essence: "application/vnd.novadigm.edx", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["edx"] },
+    MimeRecord { essence: "application/vnd.novadigm.ext", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ext"] },
+    MimeRecord { essence: "application/vnd.ntt-local.content-share", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ntt-local.file-transfer", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ntt-local.ogw_remote-access", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ntt-local.sip-ta_remote", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ntt-local.sip-ta_tcp_stream", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oai.workflows", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oai.workflows+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oai.workflows+yaml", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oasis.opendocument.base", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oasis.opendocument.chart", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["odc"] },
+    MimeRecord { essence: "application/vnd.oasis.opendocument.chart-template", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["otc"] },
+    MimeRecord { essence: "application/vnd.oasis.opendocument.database", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["odb"] },
+    MimeRecord { essence: "application/vnd.oasis.opendocument.formula", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["odf"] },
+    MimeRecord { essence: "application/vnd.oasis.opendocument.formula-template", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["odft"] },
+    MimeRecord { essence: "application/vnd.oasis.opendocument.graphics", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["odg"] },
+    MimeRecord { essence: "application/vnd.oasis.opendocument.graphics-template", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["otg"] },
+    MimeRecord { essence: "application/vnd.oasis.opendocument.image", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["odi"] },
+    MimeRecord { essence: "application/vnd.oasis.opendocument.image-template", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["oti"] },
+    MimeRecord { essence: "application/vnd.oasis.opendocument.presentation", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["odp"] },
+    MimeRecord { essence: "application/vnd.oasis.opendocument.presentation-template", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["otp"] },
+    MimeRecord { essence: "application/vnd.oasis.opendocument.spreadsheet", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["ods"] },
+    MimeRecord { essence: "application/vnd.oasis.opendocument.spreadsheet-template", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ots"] },
+    MimeRecord { essence: "application/vnd.oasis.opendocument.text", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["odt"] },
+    MimeRecord { essence: "application/vnd.oasis.opendocument.text-master", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["odm"] },
+    MimeRecord { essence: "application/vnd.oasis.opendocument.text-master-template", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oasis.opendocument.text-template", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ott"] },
+    MimeRecord { essence: "application/vnd.oasis.opendocument.text-web", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["oth"] },
+    MimeRecord { essence: "application/vnd.obn", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ocf+cbor", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oci.image.manifest.v1+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oftn.l10n+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oipf.contentaccessdownload+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oipf.contentaccessstreaming+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oipf.cspg-hexbinary", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oipf.dae.svg+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oipf.dae.xhtml+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oipf.mippvcontrolmessage+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oipf.pae.gem", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oipf.spdiscovery+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oipf.spdlist+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oipf.ueprofile+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oipf.userprofile+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.olpc-sugar", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["xo"] },
+    MimeRecord { essence: "application/vnd.oma-scws-config", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oma-scws-http-request", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oma-scws-http-response", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oma.bcast.associated-procedure-parameter+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oma.bcast.drm-trigger+xml", source: Some(MimeSource::Apache), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oma.bcast.imd+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oma.bcast.ltkm", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oma.bcast.notification+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oma.bcast.provisioningtrigger", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oma.bcast.sgboot", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oma.bcast.sgdd+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oma.bcast.sgdu", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oma.bcast.simple-symbol-container", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oma.bcast.smartcard-trigger+xml", source: Some(MimeSource::Apache), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oma.bcast.sprov+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oma.bcast.stkm", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oma.cab-address-book+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oma.cab-feature-handler+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oma.cab-pcc+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oma.cab-subs-invite+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oma.cab-user-prefs+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oma.dcd", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oma.dcdc", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oma.dd2+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["dd2"] },
+    MimeRecord { essence: "application/vnd.oma.drm.risd+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oma.group-usage-list+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oma.lwm2m+cbor", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oma.lwm2m+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oma.lwm2m+tlv", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oma.pal+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oma.poc.detailed-progress-report+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oma.poc.final-report+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oma.poc.groups+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oma.poc.invocation-descriptor+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oma.poc.optimized-progress-report+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oma.push", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oma.scidm.messages+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oma.xcap-directory+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.omads-email+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: Some("UTF-8"), extensions: &[] },
+    MimeRecord { essence: "application/vnd.omads-file+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: Some("UTF-8"), extensions: &[] },
+    MimeRecord { essence: "application/vnd.omads-folder+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: Some("UTF-8"), extensions: &[] },
+    MimeRecord { essence: "application/vnd.omaloc-supl-init", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.onepager", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.onepagertamp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.onepagertamx", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.onepagertat", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.onepagertatp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.onepagertatx", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.onvif.metadata", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openblox.game+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["obgx"] },
+    MimeRecord { essence: "application/vnd.openblox.game-binary", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openeye.oeb", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openofficeorg.extension", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["oxt"] },
+    MimeRecord { essence: "application/vnd.openstreetmap.data+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["osm"] },
+    MimeRecord { essence: "application/vnd.opentimestamps.ots", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.custom-properties+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.customxmlproperties+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.drawing+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.drawingml.chart+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.drawingml.chartshapes+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.drawingml.diagramcolors+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.drawingml.diagramdata+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.drawingml.diagramlayout+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.drawingml.diagramstyle+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.extended-properties+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.presentationml.commentauthors+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.presentationml.comments+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.presentationml.handoutmaster+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.presentationml.notesmaster+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.presentationml.notesslide+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.presentationml.presentation", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["pptx"] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.presentationml.presentation.main+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.presentationml.presprops+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.presentationml.slide", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["sldx"] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.presentationml.slide+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.presentationml.slidelayout+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.presentationml.slidemaster+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.presentationml.slideshow", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ppsx"] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.presentationml.slideshow.main+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.presentationml.slideupdateinfo+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.presentationml.tablestyles+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.presentationml.tags+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.presentationml.template", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["potx"] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.presentationml.template.main+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.presentationml.viewprops+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.spreadsheetml.calcchain+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.spreadsheetml.chartsheet+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.spreadsheetml.comments+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.spreadsheetml.connections+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.spreadsheetml.dialogsheet+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.spreadsheetml.externallink+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.spreadsheetml.pivotcachedefinition+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.spreadsheetml.pivotcacherecords+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.spreadsheetml.pivottable+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.spreadsheetml.querytable+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.spreadsheetml.revisionheaders+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.spreadsheetml.revisionlog+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.spreadsheetml.sharedstrings+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["xlsx"] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.spreadsheetml.sheetmetadata+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.spreadsheetml.styles+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.spreadsheetml.table+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.spreadsheetml.tablesinglecells+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.spreadsheetml.template", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["xltx"] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.spreadsheetml.template.main+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.spreadsheetml.usernames+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.spreadsheetml.volatiledependencies+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.theme+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.themeoverride+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.vmldrawing", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.wordprocessingml.comments+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.wordprocessingml.document", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["docx"] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.wordprocessingml.document.glossary+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.wordprocessingml.endnotes+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.wordprocessingml.fonttable+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.wordprocessingml.footer+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.wordprocessingml.footnotes+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.wordprocessingml.numbering+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.wordprocessingml.settings+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.wordprocessingml.styles+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.wordprocessingml.template", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["dotx"] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.wordprocessingml.template.main+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-officedocument.wordprocessingml.websettings+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-package.core-properties+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-package.digital-signature-xmlsignature+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.openxmlformats-package.relationships+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oracle.resource+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.orange.indata", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.osa.netdeploy", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.osgeo.mapguide.package", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["mgp"] },
+    MimeRecord { essence: "application/vnd.osgi.bundle", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.osgi.dp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["dp"] },
+    MimeRecord { essence: "application/vnd.osgi.subsystem", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["esa"] },
+    MimeRecord { essence: "application/vnd.otps.ct-kip+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.oxli.countgraph", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.pagerduty+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.palm", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["pdb", "pqa", "oprc"] },
+    MimeRecord { essence: "application/vnd.panoply", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.paos.xml", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.patentdive", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.patientecommsdoc", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.pawaafile", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["paw"] },
+    MimeRecord { essence: "application/vnd.pcos", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.pg.format", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["str"] },
+    MimeRecord { essence: "application/vnd.pg.osasli", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ei6"] },
+    MimeRecord { essence: "application/vnd.piaccess.application-licence", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.picsel", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["efif"] },
+    MimeRecord { essence: "application/vnd.pmi.widget", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["wg"] },
+    MimeRecord { essence: "application/vnd.poc.group-advertisement+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.pocketlearn", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["plf"] },
+    MimeRecord { essence: "application/vnd.powerbuilder6", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["pbd"] },
+    MimeRecord { essence: "application/vnd.powerbuilder6-s", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.powerbuilder7", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.powerbuilder7-s", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.powerbuilder75", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.powerbuilder75-s", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.preminet", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.previewsystems.box", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["box"] },
+    MimeRecord { essence: "application/vnd.proteus.magazine", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["mgz"] },
+    MimeRecord { essence: "application/vnd.psfs", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.pt.mundusmundi", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.publishare-delta-tree", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["qps"] },
+    MimeRecord { essence: "application/vnd.pvi.ptid1", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ptid"] },
+    MimeRecord { essence: "application/vnd.pwg-multiplexed", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.pwg-xhtml-print+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["xhtm"] },
+    MimeRecord { essence: "application/vnd.qualcomm.brew-app-res", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.quarantainenet", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.quark.quarkxpress", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["qxd", "qxt", "qwd", "qwt", "qxl", "qxb"] },
+    MimeRecord { essence: "application/vnd.quobject-quoxdocument", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.radisys.moml+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.radisys.msml+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.radisys.msml-audit+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.radisys.msml-audit-conf+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.radisys.msml-audit-conn+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.radisys.msml-audit-dialog+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.radisys.msml-audit-stream+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.radisys.msml-conf+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.radisys.msml-dialog+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.radisys.msml-dialog-base+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.radisys.msml-dialog-fax-detect+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.radisys.msml-dialog-fax-sendrecv+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.radisys.msml-dialog-group+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.radisys.msml-dialog-speech+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.radisys.msml-dialog-transform+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.rainstor.data", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.rapid", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.rar", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["rar"] },
+    MimeRecord { essence: "application/vnd.realvnc.bed", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["bed"] },
+    MimeRecord { essence: "application/vnd.recordare.musicxml", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["mxl"] },
+    MimeRecord { essence: "application/vnd.recordare.musicxml+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["musicxml"] },
+    MimeRecord { essence: "application/vnd.relpipe", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.renlearn.rlprint", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.resilient.logic", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.restful+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.rig.cryptonote", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["cryptonote"] },
+    MimeRecord { essence: "application/vnd.rim.cod", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["cod"] },
+    MimeRecord { essence: "application/vnd.rn-realmedia", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["rm"] },
+    MimeRecord { essence: "application/vnd.rn-realmedia-vbr", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["rmvb"] },
+    MimeRecord { essence: "application/vnd.route66.link66+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["link66"] },
+    MimeRecord { essence: "application/vnd.rs-274x", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ruckus.download", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.s3sms", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.sailingtracker.track", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["st"] },
+    MimeRecord { essence: "application/vnd.sar", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.sbm.cid", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.sbm.mid2", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.scribus", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.sealed.3df", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.sealed.csf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.sealed.doc", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.sealed.eml", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.sealed.mht", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.sealed.net", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.sealed.ppt", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.sealed.tiff", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.sealed.xls", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.sealedmedia.softseal.html", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.sealedmedia.softseal.pdf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.seemail", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["see"] },
+    MimeRecord { essence: "application/vnd.seis+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.sema", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["sema"] },
+    MimeRecord { essence: "application/vnd.semd", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["semd"] },
+    MimeRecord { essence: "application/vnd.semf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["semf"] },
+    MimeRecord { essence: "application/vnd.shade-save-file", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.shana.informed.formdata", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ifm"] },
+    MimeRecord { essence: "application/vnd.shana.informed.formtemplate", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["itp"] },
+    MimeRecord { essence: "application/vnd.shana.informed.interchange", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["iif"] },
+    MimeRecord { essence: "application/vnd.shana.informed.package", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ipk"] },
+    MimeRecord { essence: "application/vnd.shootproof+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.shopkick+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.shp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.shx", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.sigrok.session", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.simtech-mindmapper", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["twd", "twds"] },
+    MimeRecord { essence: "application/vnd.siren+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.smaf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["mmf"] },
+    MimeRecord { essence: "application/vnd.smart.notebook", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.smart.teacher", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["teacher"] },
+    MimeRecord { essence: "application/vnd.smintio.portals.archive", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.snesdev-page-table", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.software602.filler.form+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["fo"] },
+    MimeRecord { essence: "application/vnd.software602.filler.form-xml-zip", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.solent.sdkm+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["sdkm", "sdkd"] },
+    MimeRecord { essence: "application/vnd.spotfire.dxp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["dxp"] },
+    MimeRecord { essence: "application/vnd.spotfire.sfs", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["sfs"] },
+    MimeRecord { essence: "application/vnd.sqlite3", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.sss-cod", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.sss-dtf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.sss-ntf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.stardivision.calc", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["sdc"] },
+    MimeRecord { essence: "application/vnd.stardivision.draw", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["sda"] },
+    MimeRecord { essence: "application/vnd.stardivision.impress", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["sdd"] },
+    MimeRecord { essence: "application/vnd.stardivision.math", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["smf"] },
+    MimeRecord { essence: "application/vnd.stardivision.writer", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["sdw", "vor"] },
+    MimeRecord { essence: "application/vnd.stardivision.writer-global", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["sgl"] },
+    MimeRecord { essence: "application/vnd.stepmania.package", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["smzip"] },
+    MimeRecord { essence: "application/vnd.stepmania.stepchart", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["sm"] },
+    MimeRecord { essence: "application/vnd.street-stream", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.sun.wadl+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["wadl"] },
+    MimeRecord { essence: "application/vnd.sun.xml.calc", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["sxc"] },
+    MimeRecord { essence: "application/vnd.sun.xml.calc.template", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["stc"] },
+    MimeRecord { essence: "application/vnd.sun.xml.draw", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["sxd"] },
+    MimeRecord { essence: "application/vnd.sun.xml.draw.template", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["std"] },
+    MimeRecord { essence: "application/vnd.sun.xml.impress", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["sxi"] },
+    MimeRecord { essence: "application/vnd.sun.xml.impress.template", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["sti"] },
+    MimeRecord { essence: "application/vnd.sun.xml.math", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["sxm"] },
+    MimeRecord { essence: "application/vnd.sun.xml.writer", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["sxw"] },
+    MimeRecord { essence: "application/vnd.sun.xml.writer.global", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["sxg"] },
+    MimeRecord { essence: "application/vnd.sun.xml.writer.template", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["stw"] },
+    MimeRecord { essence: "application/vnd.sus-calendar", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["sus", "susp"] },
+    MimeRecord { essence: "application/vnd.svd", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["svd"] },
+    MimeRecord { essence: "application/vnd.swiftview-ics", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.sybyl.mol2", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.sycle+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.syft+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.symbian.install", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["sis", "sisx"] },
+    MimeRecord { essence: "application/vnd.syncml+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: Some("UTF-8"), extensions: &["xsm"] },
+    MimeRecord { essence: "application/vnd.syncml.dm+wbxml", source: Some(MimeSource::Iana), compressible: None, charset: Some("UTF-8"), extensions: &["bdm"] },
+    MimeRecord { essence: "application/vnd.syncml.dm+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: Some("UTF-8"), extensions: &["xdm"] },
+    MimeRecord { essence: "application/vnd.syncml.dm.notification", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.syncml.dmddf+wbxml", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.syncml.dmddf+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: Some("UTF-8"), extensions: &["ddf"] },
+    MimeRecord { essence: "application/vnd.syncml.dmtnds+wbxml", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.syncml.dmtnds+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: Some("UTF-8"), extensions: &[] },
+    MimeRecord { essence: "application/vnd.syncml.ds.notification", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.tableschema+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.tao.intent-module-archive", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["tao"] },
+    MimeRecord { essence: "application/vnd.tcpdump.pcap", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["pcap", "cap", "dmp"] },
+    MimeRecord { essence: "application/vnd.think-cell.ppttc+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.tmd.mediaflex.api+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.tml", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.tmobile-livetv", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["tmo"] },
+    MimeRecord { essence: "application/vnd.tri.onesource", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.trid.tpt", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["tpt"] },
+    MimeRecord { essence: "application/vnd.triscape.mxs", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["mxs"] },
+    MimeRecord { essence: "application/vnd.trueapp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["tra"] },
+    MimeRecord { essence: "application/vnd.truedoc", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ubisoft.webplayer", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ufdl", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ufd", "ufdl"] },
+    MimeRecord { essence: "application/vnd.uiq.theme", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["utz"] },
+    MimeRecord { essence: "application/vnd.umajin", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["umj"] },
+    MimeRecord { essence: "application/vnd.unity", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["unityweb"] },
+    MimeRecord { essence: "application/vnd.uoml+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["uoml", "uo"] },
+    MimeRecord { essence: "application/vnd.uplanet.alert", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.uplanet.alert-wbxml", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.uplanet.bearer-choice", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.uplanet.bearer-choice-wbxml", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.uplanet.cacheop", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.uplanet.cacheop-wbxml", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.uplanet.channel", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.uplanet.channel-wbxml", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.uplanet.list", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.uplanet.list-wbxml", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.uplanet.listcmd", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.uplanet.listcmd-wbxml", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.uplanet.signal", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.uri-map", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.valve.source.material", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.vcx", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["vcx"] },
+    MimeRecord { essence: "application/vnd.vd-study", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.vectorworks", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.vel+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.verimatrix.vcas", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.veritone.aion+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.veryant.thin", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.ves.encrypted", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.vidsoft.vidconference", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.visio", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["vsd", "vst", "vss", "vsw"] },
+    MimeRecord { essence: "application/vnd.visionary", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["vis"] },
+    MimeRecord { essence: "application/vnd.vividence.scriptfile", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.vsf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["vsf"] },
+    MimeRecord { essence: "application/vnd.wap.sic", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.wap.slc", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.wap.wbxml", source: Some(MimeSource::Iana), compressible: None, charset: Some("UTF-8"), extensions: &["wbxml"] },
+    MimeRecord { essence: "application/vnd.wap.wmlc", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["wmlc"] },
+    MimeRecord { essence: "application/vnd.wap.wmlscriptc", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["wmlsc"] },
+    MimeRecord { essence: "application/vnd.wasmflow.wafl", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.webturbo", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["wtb"] },
+    MimeRecord { essence: "application/vnd.wfa.dpp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.wfa.p2p", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.wfa.wsc", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.windows.devicepairing", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.wmc", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.wmf.bootstrap", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.wolfram.mathematica", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.wolfram.mathematica.package", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.wolfram.player", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["nbp"] },
+    MimeRecord { essence: "application/vnd.wordlift", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.wordperfect", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["wpd"] },
+    MimeRecord { essence: "application/vnd.wqd", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["wqd"] },
+    MimeRecord { essence: "application/vnd.wrq-hp3000-labelled", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.wt.stf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["stf"] },
+    MimeRecord { essence: "application/vnd.wv.csp+wbxml", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.wv.csp+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.wv.ssp+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.xacml+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.xara", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["xar"] },
+    MimeRecord { essence: "application/vnd.xecrets-encrypted", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.xfdl", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["xfdl"] },
+    MimeRecord { essence: "application/vnd.xfdl.webform", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.xmi+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.xmpie.cpkg", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.xmpie.dpkg", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.xmpie.plan", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.xmpie.ppkg", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.xmpie.xlim", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.yamaha.hv-dic", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["hvd"] },
+    MimeRecord { essence: "application/vnd.yamaha.hv-script", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["hvs"] },
+    MimeRecord { essence: "application/vnd.yamaha.hv-voice", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["hvp"] },
+    MimeRecord { essence: "application/vnd.yamaha.openscoreformat", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["osf"] },
+    MimeRecord { essence: "application/vnd.yamaha.openscoreformat.osfpvg+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["osfpvg"] },
+    MimeRecord { essence: "application/vnd.yamaha.remote-setup", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.yamaha.smaf-audio", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["saf"] },
+    MimeRecord { essence: "application/vnd.yamaha.smaf-phrase", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["spf"] },
+    MimeRecord { essence: "application/vnd.yamaha.through-ngn", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.yamaha.tunnel-udpencap", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.yaoweme", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vnd.yellowriver-custom-menu", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["cmp"] },
+    MimeRecord { essence: "application/vnd.zul", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["zir", "zirz"] },
+    MimeRecord { essence: "application/vnd.zzazz.deck+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["zaz"] },
+    MimeRecord { essence: "application/voicexml+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["vxml"] },
+    MimeRecord { essence: "application/voucher-cms+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/vq-rtcpxr", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/wasm", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["wasm"] },
+    MimeRecord { essence: "application/watcherinfo+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["wif"] },
+    MimeRecord { essence: "application/webpush-options+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/whoispp-query", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/whoispp-response", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/widget", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["wgt"] },
+    MimeRecord { essence: "application/winhlp", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["hlp"] },
+    MimeRecord { essence: "application/wita", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/wordperfect5.1", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/wsdl+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["wsdl"] },
+    MimeRecord { essence: "application/wspolicy+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["wspolicy"] },
+    MimeRecord { essence: "application/x-7z-compressed", source: Some(MimeSource::Apache), compressible: Some(false), charset: None, extensions: &["7z"] },
+    MimeRecord { essence: "application/x-abiword", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["abw"] },
+    MimeRecord { essence: "application/x-ace-compressed", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["ace"] },
+    MimeRecord { essence: "application/x-amf", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/x-apple-diskimage", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["dmg"] },
+    MimeRecord { essence: "application/x-arj", source: None, compressible: Some(false), charset: None, extensions: &["arj"] },
+    MimeRecord { essence: "application/x-authorware-bin", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["aab", "x32", "u32", "vox"] },
+    MimeRecord { essence: "application/x-authorware-map", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["aam"] },
+    MimeRecord { essence: "application/x-authorware-seg", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["aas"] },
+    MimeRecord { essence: "application/x-bcpio", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["bcpio"] },
+    MimeRecord { essence: "application/x-bdoc", source: None, compressible: Some(false), charset: None, extensions: &["bdoc"] },
+    MimeRecord { essence: "application/x-bittorrent", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["torrent"] },
+    MimeRecord { essence: "application/x-blorb", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["blb", "blorb"] },
+    MimeRecord { essence: "application/x-bzip", source: Some(MimeSource::Apache), compressible: Some(false), charset: None, extensions: &["bz"] },
+    MimeRecord { essence: "application/x-bzip2", source: Some(MimeSource::Apache), compressible: Some(false), charset: None, extensions: &["bz2", "boz"] },
+    MimeRecord { essence: "application/x-cbr", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["cbr", "cba", "cbt", "cbz", "cb7"] },
+    MimeRecord { essence: "application/x-cdlink", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["vcd"] },
+    MimeRecord { essence: "application/x-cfs-compressed", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["cfs"] },
+    MimeRecord { essence: "application/x-chat", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["chat"] },
+    MimeRecord { essence: "application/x-chess-pgn", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["pgn"] },
+    MimeRecord { essence: "application/x-chrome-extension", source: None, compressible: None, charset: None, extensions: &["crx"] },
+    MimeRecord { essence: "application/x-cocoa", source: Some(MimeSource::Nginx), compressible: None, charset: None, extensions: &["cco"] },
+    MimeRecord { essence: "application/x-compress", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/x-conference", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["nsc"] },
+    MimeRecord { essence: "application/x-cpio", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["cpio"] },
+    MimeRecord { essence: "application/x-csh", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["csh"] },
+    MimeRecord { essence: "application/x-deb", source: None, compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/x-debian-package", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["deb", "udeb"] },
+    MimeRecord { essence: "application/x-dgc-compressed", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["dgc"] },
+    MimeRecord { essence: "application/x-director", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["dir", "dcr", "dxr", "cst", "cct", "cxt", "w3d", "fgd", "swa"] },
+    MimeRecord { essence: "application/x-doom", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["wad"] },
+    MimeRecord { essence: "application/x-dtbncx+xml", source: Some(MimeSource::Apache), compressible: Some(true), charset: None, extensions: &["ncx"] },
+    MimeRecord { essence: "application/x-dtbook+xml", source: Some(MimeSource::Apache), compressible: Some(true), charset: None, extensions: &["dtb"] },
+    MimeRecord { essence: "application/x-dtbresource+xml", source: Some(MimeSource::Apache), compressible: Some(true), charset: None, extensions: &["res"] },
+    MimeRecord { essence: "application/x-dvi", source: Some(MimeSource::Apache), compressible: Some(false), charset: None, extensions: &["dvi"] },
+    MimeRecord { essence: "application/x-envoy", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["evy"] },
+    MimeRecord { essence: "application/x-eva", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["eva"] },
+    MimeRecord { essence: "application/x-font-bdf", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["bdf"] },
+    MimeRecord { essence: "application/x-font-dos", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/x-font-framemaker", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/x-font-ghostscript", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["gsf"] },
+    MimeRecord { essence: "application/x-font-libgrx", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/x-font-linux-psf", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["psf"] },
+    MimeRecord { essence: "application/x-font-pcf", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["pcf"] },
+    MimeRecord { essence: "application/x-font-snf", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["snf"] },
+    MimeRecord { essence: "application/x-font-speedo", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/x-font-sunos-news", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/x-font-type1", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["pfa", "pfb", "pfm", "afm"] },
+    MimeRecord { essence: "application/x-font-vfont", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/x-freearc", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["arc"] },
+    MimeRecord { essence: "application/x-futuresplash", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["spl"] },
+    MimeRecord { essence: "application/x-gca-compressed", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["gca"] },
+    MimeRecord { essence: "application/x-glulx", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["ulx"] },
+    MimeRecord { essence: "application/x-gnumeric", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["gnumeric"] },
+    MimeRecord { essence: "application/x-gramps-xml", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["gramps"] },
+    MimeRecord { essence: "application/x-gtar", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["gtar"] },
+    MimeRecord { essence: "application/x-gzip", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/x-hdf", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["hdf"] },
+    MimeRecord { essence: "application/x-httpd-php", source: None, compressible: Some(true), charset: None, extensions: &["php"] },
+    MimeRecord { essence: "application/x-install-instructions", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["install"] },
+    MimeRecord { essence: "application/x-iso9660-image", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["iso"] },
+    MimeRecord { essence: "application/x-iwork-keynote-sffkey", source: None, compressible: None, charset: None, extensions: &["key"] },
+    MimeRecord { essence: "application/x-iwork-numbers-sffnumbers", source: None, compressible: None, charset: None, extensions: &["numbers"] },
+    MimeRecord { essence: "application/x-iwork-pages-sffpages", source: None, compressible: None, charset: None, extensions: &["pages"] },
+    MimeRecord { essence: "application/x-java-archive-diff", source: Some(MimeSource::Nginx), compressible: None, charset: None, extensions: &["jardiff"] },
+    MimeRecord { essence: "application/x-java-jnlp-file", source: Some(MimeSource::Apache), compressible: Some(false), charset: None, extensions: &["jnlp"] },
+    MimeRecord { essence: "application/x-javascript", source: None, compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/x-keepass2", source: None, compressible: None, charset: None, extensions: &["kdbx"] },
+    MimeRecord { essence: "application/x-latex", source: Some(MimeSource::Apache), compressible: Some(false), charset: None, extensions: &["latex"] },
+    MimeRecord { essence: "application/x-lua-bytecode", source: None, compressible: None, charset: None, extensions: &["luac"] },
+    MimeRecord { essence: "application/x-lzh-compressed", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["lzh", "lha"] },
+    MimeRecord { essence: "application/x-makeself", source: Some(MimeSource::Nginx), compressible: None, charset: None, extensions: &["run"] },
+    MimeRecord { essence: "application/x-mie", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["mie"] },
+    MimeRecord { essence: "application/x-mobipocket-ebook", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["prc", "mobi"] },
+    MimeRecord { essence: "application/x-mpegurl", source: None, compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/x-ms-application", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["application"] },
+    MimeRecord { essence: "application/x-ms-shortcut", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["lnk"] },
+    MimeRecord { essence: "application/x-ms-wmd", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["wmd"] },
+    MimeRecord { essence: "application/x-ms-wmz", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["wmz"] },
+    MimeRecord { essence: "application/x-ms-xbap", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["xbap"] },
+    MimeRecord { essence: "application/x-msaccess", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["mdb"] },
+    MimeRecord { essence: "application/x-msbinder", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["obd"] },
+    MimeRecord { essence: "application/x-mscardfile", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["crd"] },
+    MimeRecord { essence: "application/x-msclip", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["clp"] },
+    MimeRecord { essence: "application/x-msdos-program", source: None, compressible: None, charset: None, extensions: &["exe"] },
+    MimeRecord { essence: "application/x-msdownload", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["exe", "dll", "com", "bat", "msi"] },
+    MimeRecord { essence: "application/x-msmediaview", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["mvb", "m13", "m14"] },
+    MimeRecord { essence: "application/x-msmetafile", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["wmf", "wmz", "emf", "emz"] },
+    MimeRecord { essence: "application/x-msmoney", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["mny"] },
+    MimeRecord { essence: "application/x-mspublisher", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["pub"] },
+    MimeRecord { essence: "application/x-msschedule", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["scd"] },
+    MimeRecord { essence: "application/x-msterminal", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["trm"] },
+    MimeRecord { essence: "application/x-mswrite", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["wri"] },
+    MimeRecord { essence: "application/x-netcdf", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["nc", "cdf"] },
+    MimeRecord { essence: "application/x-ns-proxy-autoconfig", source: None, compressible: Some(true), charset: None, extensions: &["pac"] },
+    MimeRecord { essence: "application/x-nzb", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["nzb"] },
+    MimeRecord { essence: "application/x-perl", source: Some(MimeSource::Nginx), compressible: None, charset: None, extensions: &["pl", "pm"] },
+    MimeRecord { essence: "application/x-pilot", source: Some(MimeSource::Nginx), compressible: None, charset: None, extensions: &["prc", "pdb"] },
+    MimeRecord { essence: "application/x-pkcs12", source: Some(MimeSource::Apache), compressible: Some(false), charset: None, extensions: &["p12", "pfx"] },
+    MimeRecord { essence: "application/x-pkcs7-certificates", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["p7b", "spc"] },
+    MimeRecord { essence: "application/x-pkcs7-certreqresp", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["p7r"] },
+    MimeRecord { essence: "application/x-pki-message", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/x-rar-compressed", source: Some(MimeSource::Apache), compressible: Some(false), charset: None, extensions: &["rar"] },
+    MimeRecord { essence: "application/x-redhat-package-manager", source: Some(MimeSource::Nginx), compressible: None, charset: None, extensions: &["rpm"] },
+    MimeRecord { essence: "application/x-research-info-systems", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["ris"] },
+    MimeRecord { essence: "application/x-sea", source: Some(MimeSource::Nginx), compressible: None, charset: None, extensions: &["sea"] },
+    MimeRecord { essence: "application/x-sh", source: Some(MimeSource::Apache), compressible: Some(true), charset: None, extensions: &["sh"] },
+    MimeRecord { essence: "application/x-shar", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["shar"] },
+    MimeRecord { essence: "application/x-shockwave-flash", source: Some(MimeSource::Apache), compressible: Some(false), charset: None, extensions: &["swf"] },
+    MimeRecord { essence: "application/x-silverlight-app", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["xap"] },
+    MimeRecord { essence: "application/x-sql", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["sql"] },
+    MimeRecord { essence: "application/x-stuffit", source: Some(MimeSource::Apache), compressible: Some(false), charset: None, extensions: &["sit"] },
+    MimeRecord { essence: "application/x-stuffitx", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["sitx"] },
+    MimeRecord { essence: "application/x-subrip", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["srt"] },
+    MimeRecord { essence: "application/x-sv4cpio", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["sv4cpio"] },
+    MimeRecord { essence: "application/x-sv4crc", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["sv4crc"] },
+    MimeRecord { essence: "application/x-t3vm-image", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["t3"] },
+    MimeRecord { essence: "application/x-tads", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["gam"] },
+    MimeRecord { essence: "application/x-tar", source: Some(MimeSource::Apache), compressible: Some(true), charset: None, extensions: &["tar"] },
+    MimeRecord { essence: "application/x-tcl", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["tcl", "tk"] },
+    MimeRecord { essence: "application/x-tex", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["tex"] },
+    MimeRecord { essence: "application/x-tex-tfm", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["tfm"] },
+    MimeRecord { essence: "application/x-texinfo", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["texinfo", "texi"] },
+    MimeRecord { essence: "application/x-tgif", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["obj"] },
+    MimeRecord { essence: "application/x-ustar", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["ustar"] },
+    MimeRecord { essence: "application/x-virtualbox-hdd", source: None, compressible: Some(true), charset: None, extensions: &["hdd"] },
+    MimeRecord { essence: "application/x-virtualbox-ova", source: None, compressible: Some(true), charset: None, extensions: &["ova"] },
+    MimeRecord { essence: "application/x-virtualbox-ovf", source: None, compressible: Some(true), charset: None, extensions: &["ovf"] },
+    MimeRecord { essence: "application/x-virtualbox-vbox", source: None, compressible: Some(true), charset: None, extensions: &["vbox"] },
+    MimeRecord { essence: "application/x-virtualbox-vbox-extpack", source: None, compressible: Some(false), charset: None, extensions: &["vbox-extpack"] },
+    MimeRecord { essence: "application/x-virtualbox-vdi", source: None, compressible: Some(true), charset: None, extensions: &["vdi"] },
+    MimeRecord { essence: "application/x-virtualbox-vhd", source: None, compressible: Some(true), charset: None, extensions: &["vhd"] },
+    MimeRecord { essence: "application/x-virtualbox-vmdk", source: None, compressible: Some(true), charset: None, extensions: &["vmdk"] },
+    MimeRecord { essence: "application/x-wais-source", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["src"] },
+    MimeRecord { essence: "application/x-web-app-manifest+json", source: None, compressible: Some(true), charset: None, extensions: &["webapp"] },
+    MimeRecord { essence: "application/x-www-form-urlencoded", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/x-x509-ca-cert", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["der", "crt", "pem"] },
+    MimeRecord { essence: "application/x-x509-ca-ra-cert", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/x-x509-next-ca-cert", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/x-xfig", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["fig"] },
+    MimeRecord { essence: "application/x-xliff+xml", source: Some(MimeSource::Apache), compressible: Some(true), charset: None, extensions: &["xlf"] },
+    MimeRecord { essence: "application/x-xpinstall", source: Some(MimeSource::Apache), compressible: Some(false), charset: None, extensions: &["xpi"] },
+    MimeRecord { essence: "application/x-xz", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["xz"] },
+    MimeRecord { essence: "application/x-zmachine", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["z1", "z2", "z3", "z4", "z5", "z6", "z7", "z8"] },
+    MimeRecord { essence: "application/x400-bp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/xacml+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/xaml+xml", source: Some(MimeSource::Apache), compressible: Some(true), charset: None, extensions: &["xaml"] },
+    MimeRecord { essence: "application/xcap-att+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["xav"] },
+    MimeRecord { essence: "application/xcap-caps+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["xca"] },
+    MimeRecord { essence: "application/xcap-diff+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["xdf"] },
+    MimeRecord { essence: "application/xcap-el+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["xel"] },
+    MimeRecord { essence: "application/xcap-error+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/xcap-ns+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["xns"] },
+    MimeRecord { essence: "application/xcon-conference-info+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/xcon-conference-info-diff+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/xenc+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["xenc"] },
+    MimeRecord { essence: "application/xfdf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["xfdf"] },
+    MimeRecord { essence: "application/xhtml+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["xhtml", "xht"] },
+    MimeRecord { essence: "application/xhtml-voice+xml", source: Some(MimeSource::Apache), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/xliff+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["xlf"] },
+    MimeRecord { essence: "application/xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["xml", "xsl", "xsd", "rng"] },
+    MimeRecord { essence: "application/xml-dtd", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["dtd"] },
+    MimeRecord { essence: "application/xml-external-parsed-entity", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/xml-patch+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/xmpp+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/xop+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["xop"] },
+    MimeRecord { essence: "application/xproc+xml", source: Some(MimeSource::Apache), compressible: Some(true), charset: None, extensions: &["xpl"] },
+    MimeRecord { essence: "application/xslt+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["xsl", "xslt"] },
+    MimeRecord { essence: "application/xspf+xml", source: Some(MimeSource::Apache), compressible: Some(true), charset: None, extensions: &["xspf"] },
+    MimeRecord { essence: "application/xv+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["mxml", "xhvml", "xvml", "xvm"] },
+    MimeRecord { essence: "application/yaml", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/yang", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["yang"] },
+    MimeRecord { essence: "application/yang-data+cbor", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/yang-data+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/yang-data+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/yang-patch+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/yang-patch+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/yang-sid+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "application/yin+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["yin"] },
+    MimeRecord { essence: "application/zip", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["zip"] },
+    MimeRecord { essence: "application/zlib", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "application/zstd", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/1d-interleaved-parityfec", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/32kadpcm", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/3gpp", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["3gpp"] },
+    MimeRecord { essence: "audio/3gpp2", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/aac", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["adts", "aac"] },
+    MimeRecord { essence: "audio/ac3", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/adpcm", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["adp"] },
+    MimeRecord { essence: "audio/amr", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["amr"] },
+    MimeRecord { essence: "audio/amr-wb", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/amr-wb+", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/aptx", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/asc", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/atrac-advanced-lossless", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/atrac-x", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/atrac3", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/basic", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["au", "snd"] },
+    MimeRecord { essence: "audio/bv16", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/bv32", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/clearmode", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/cn", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/dat12", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/dls", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/dsr-es201108", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/dsr-es202050", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/dsr-es202211", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/dsr-es202212", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/dv", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/dvi4", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/eac3", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/encaprtp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/evrc", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/evrc-qcp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/evrc0", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/evrc1", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/evrcb", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/evrcb0", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/evrcb1", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/evrcnw", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/evrcnw0", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/evrcnw1", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/evrcwb", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/evrcwb0", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/evrcwb1", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/evs", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/flac", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/flexfec", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/fwdred", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/g711-0", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/g719", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/g722", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/g7221", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/g723", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/g726-16", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/g726-24", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/g726-32", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/g726-40", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/g728", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/g729", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/g7291", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/g729d", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/g729e", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/gsm", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/gsm-efr", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/gsm-hr-08", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/ilbc", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/ip-mr_v2.5", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/isac", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/l16", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/l20", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/l24", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/l8", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/lpc", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/matroska", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/melp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/melp1200", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/melp2400", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/melp600", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/mhas", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/midi", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["mid", "midi", "kar", "rmi"] },
+    MimeRecord { essence: "audio/midi-clip", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/mobile-xmf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["mxmf"] },
+    MimeRecord { essence: "audio/mp3", source: None, compressible: Some(false), charset: None, extensions: &["mp3"] },
+    MimeRecord { essence: "audio/mp4", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["m4a", "mp4a"] },
+    MimeRecord { essence: "audio/mp4a-latm", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/mpa", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/mpa-robust", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/mpeg", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["mpga", "mp2", "mp2a", "mp3", "m2a", "m3a"] },
+    MimeRecord { essence: "audio/mpeg4-generic", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/musepack", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/ogg", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["oga", "ogg", "spx", "opus"] },
+    MimeRecord { essence: "audio/opus", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/parityfec", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/pcma", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/pcma-wb", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/pcmu", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/pcmu-wb", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/prs.sid", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/qcelp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/raptorfec", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/red", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/rtp-enc-aescm128", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/rtp-midi", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/rtploopback", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/rtx", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/s3m", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["s3m"] },
+    MimeRecord { essence: "audio/scip", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/silk", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["sil"] },
+    MimeRecord { essence: "audio/smv", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/smv-qcp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/smv0", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/sofa", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/sp-midi", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/speex", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/t140c", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/t38", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/telephone-event", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/tetra_acelp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/tetra_acelp_bb", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/tone", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/tsvcis", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/uemclip", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/ulpfec", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/usac", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/vdvi", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/vmr-wb", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/vnd.3gpp.iufp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/vnd.4sb", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/vnd.audiokoz", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/vnd.celp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/vnd.cisco.nse", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/vnd.cmles.radio-events", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/vnd.cns.anp1", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/vnd.cns.inf1", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/vnd.dece.audio", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["uva", "uvva"] },
+    MimeRecord { essence: "audio/vnd.digital-winds", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["eol"] },
+    MimeRecord { essence: "audio/vnd.dlna.adts", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/vnd.dolby.heaac.1", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/vnd.dolby.heaac.2", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/vnd.dolby.mlp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/vnd.dolby.mps", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/vnd.dolby.pl2", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/vnd.dolby.pl2x", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/vnd.dolby.pl2z", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/vnd.dolby.pulse.1", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/vnd.dra", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["dra"] },
+    MimeRecord { essence: "audio/vnd.dts", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["dts"] },
+    MimeRecord { essence: "audio/vnd.dts.hd", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["dtshd"] },
+    MimeRecord { essence: "audio/vnd.dts.uhd", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/vnd.dvb.file", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/vnd.everad.plj", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/vnd.hns.audio", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/vnd.lucent.voice", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["lvp"] },
+    MimeRecord { essence: "audio/vnd.ms-playready.media.pya", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["pya"] },
+    MimeRecord { essence: "audio/vnd.nokia.mobile-xmf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/vnd.nortel.vbk", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/vnd.nuera.ecelp4800", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ecelp4800"] },
+    MimeRecord { essence: "audio/vnd.nuera.ecelp7470", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ecelp7470"] },
+    MimeRecord { essence: "audio/vnd.nuera.ecelp9600", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ecelp9600"] },
+    MimeRecord { essence: "audio/vnd.octel.sbc", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/vnd.presonus.multitrack", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/vnd.qcelp", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/vnd.rhetorex.32kadpcm", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/vnd.rip", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["rip"] },
+    MimeRecord { essence: "audio/vnd.rn-realaudio", source: None, compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/vnd.sealedmedia.softseal.mpeg", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/vnd.vmx.cvsd", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/vnd.wave", source: None, compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/vorbis", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/vorbis-config", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/wav", source: None, compressible: Some(false), charset: None, extensions: &["wav"] },
+    MimeRecord { essence: "audio/wave", source: None, compressible: Some(false), charset: None, extensions: &["wav"] },
+    MimeRecord { essence: "audio/webm", source: Some(MimeSource::Apache), compressible: Some(false), charset: None, extensions: &["weba"] },
+    MimeRecord { essence: "audio/x-aac", source: Some(MimeSource::Apache), compressible: Some(false), charset: None, extensions: &["aac"] },
+    MimeRecord { essence: "audio/x-aiff", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["aif", "aiff", "aifc"] },
+    MimeRecord { essence: "audio/x-caf", source: Some(MimeSource::Apache), compressible: Some(false), charset: None, extensions: &["caf"] },
+    MimeRecord { essence: "audio/x-flac", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["flac"] },
+    MimeRecord { essence: "audio/x-m4a", source: Some(MimeSource::Nginx), compressible: None, charset: None, extensions: &["m4a"] },
+    MimeRecord { essence: "audio/x-matroska", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["mka"] },
+    MimeRecord { essence: "audio/x-mpegurl", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["m3u"] },
+    MimeRecord { essence: "audio/x-ms-wax", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["wax"] },
+    MimeRecord { essence: "audio/x-ms-wma", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["wma"] },
+    MimeRecord { essence: "audio/x-pn-realaudio", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["ram", "ra"] },
+    MimeRecord { essence: "audio/x-pn-realaudio-plugin", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["rmp"] },
+    MimeRecord { essence: "audio/x-realaudio", source: Some(MimeSource::Nginx), compressible: None, charset: None, extensions: &["ra"] },
+    MimeRecord { essence: "audio/x-tta", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "audio/x-wav", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["wav"] },
+    MimeRecord { essence: "audio/xm", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["xm"] },
+    MimeRecord { essence: "chemical/x-cdx", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["cdx"] },
+    MimeRecord { essence: "chemical/x-cif", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["cif"] },
+    MimeRecord { essence: "chemical/x-cmdf", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["cmdf"] },
+    MimeRecord { essence: "chemical/x-cml", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["cml"] },
+    MimeRecord { essence: "chemical/x-csml", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["csml"] },
+    MimeRecord { essence: "chemical/x-pdb", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "chemical/x-xyz", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["xyz"] },
+    MimeRecord { essence: "font/collection", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ttc"] },
+    MimeRecord { essence: "font/otf", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["otf"] },
+    MimeRecord { essence: "font/sfnt", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "font/ttf", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["ttf"] },
+    MimeRecord { essence: "font/woff", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["woff"] },
+    MimeRecord { essence: "font/woff2", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["woff2"] },
+    MimeRecord { essence: "image/aces", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["exr"] },
+    MimeRecord { essence: "image/apng", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["apng"] },
+    MimeRecord { essence: "image/avci", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["avci"] },
+    MimeRecord { essence: "image/avcs", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["avcs"] },
+    MimeRecord { essence: "image/avif", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["avif"] },
+    MimeRecord { essence: "image/bmp", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["bmp", "dib"] },
+    MimeRecord { essence: "image/cgm", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["cgm"] },
+    MimeRecord { essence: "image/dicom-rle", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["drle"] },
+    MimeRecord { essence: "image/dpx", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["dpx"] },
+    MimeRecord { essence: "image/emf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["emf"] },
+    MimeRecord { essence: "image/fits", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["fits"] },
+    MimeRecord { essence: "image/g3fax", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["g3"] },
+    MimeRecord { essence: "image/gif", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["gif"] },
+    MimeRecord { essence: "image/heic", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["heic"] },
+    MimeRecord { essence: "image/heic-sequence", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["heics"] },
+    MimeRecord { essence: "image/heif", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["heif"] },
+    MimeRecord { essence: "image/heif-sequence", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["heifs"] },
+    MimeRecord { essence: "image/hej2k", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["hej2"] },
+    MimeRecord { essence: "image/hsj2", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["hsj2"] },
+    MimeRecord { essence: "image/ief", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ief"] },
+    MimeRecord { essence: "image/j2c", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "image/jls", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["jls"] },
+    MimeRecord { essence: "image/jp2", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["jp2", "jpg2"] },
+    MimeRecord { essence: "image/jpeg", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["jpeg", "jpg", "jpe"] },
+    MimeRecord { essence: "image/jph", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["jph"] },
+    MimeRecord { essence: "image/jphc", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["jhc"] },
+    MimeRecord { essence: "image/jpm", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["jpm", "jpgm"] },
+    MimeRecord { essence: "image/jpx", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["jpx", "jpf"] },
+    MimeRecord { essence: "image/jxl", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["jxl"] },
+    MimeRecord { essence: "image/jxr", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["jxr"] },
+    MimeRecord { essence: "image/jxra", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["jxra"] },
+    MimeRecord { essence: "image/jxrs", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["jxrs"] },
+    MimeRecord { essence: "image/jxs", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["jxs"] },
+    MimeRecord { essence: "image/jxsc", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["jxsc"] },
+    MimeRecord { essence: "image/jxsi", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["jxsi"] },
+    MimeRecord { essence: "image/jxss", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["jxss"] },
+    MimeRecord { essence: "image/ktx", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ktx"] },
+    MimeRecord { essence: "image/ktx2", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ktx2"] },
+    MimeRecord { essence: "image/naplps", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "image/pjpeg", source: None, compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "image/png", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["png"] },
+    MimeRecord { essence: "image/prs.btif", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["btif", "btf"] },
+    MimeRecord { essence: "image/prs.pti", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["pti"] },
+    MimeRecord { essence: "image/pwg-raster", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "image/sgi", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["sgi"] },
+    MimeRecord { essence: "image/svg+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["svg", "svgz"] },
+    MimeRecord { essence: "image/t38", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["t38"] },
+    MimeRecord { essence: "image/tiff", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["tif", "tiff"] },
+    MimeRecord { essence: "image/tiff-fx", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["tfx"] },
+    MimeRecord { essence: "image/vnd.adobe.photoshop", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["psd"] },
+    MimeRecord { essence: "image/vnd.airzip.accelerator.azv", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["azv"] },
+    MimeRecord { essence: "image/vnd.cns.inf2", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "image/vnd.dece.graphic", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["uvi", "uvvi", "uvg", "uvvg"] },
+    MimeRecord { essence: "image/vnd.djvu", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["djvu", "djv"] },
+    MimeRecord { essence: "image/vnd.dvb.subtitle", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["sub"] },
+    MimeRecord { essence: "image/vnd.dwg", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["dwg"] },
+    MimeRecord { essence: "image/vnd.dxf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["dxf"] },
+    MimeRecord { essence: "image/vnd.fastbidsheet", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["fbs"] },
+    MimeRecord { essence: "image/vnd.fpx", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["fpx"] },
+    MimeRecord { essence: "image/vnd.fst", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["fst"] },
+    MimeRecord { essence: "image/vnd.fujixerox.edmics-mmr", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["mmr"] },
+    MimeRecord { essence: "image/vnd.fujixerox.edmics-rlc", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["rlc"] },
+    MimeRecord { essence: "image/vnd.globalgraphics.pgb", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "image/vnd.microsoft.icon", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["ico"] },
+    MimeRecord { essence: "image/vnd.mix", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "image/vnd.mozilla.apng", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "image/vnd.ms-dds", source: None, compressible: Some(true), charset: None, extensions: &["dds"] },
+    MimeRecord { essence: "image/vnd.ms-modi", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["mdi"] },
+    MimeRecord { essence: "image/vnd.ms-photo", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["wdp"] },
+    MimeRecord { essence: "image/vnd.net-fpx", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["npx"] },
+    MimeRecord { essence: "image/vnd.pco.b16", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["b16"] },
+    MimeRecord { essence: "image/vnd.radiance", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "image/vnd.sealed.png", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "image/vnd.sealedmedia.softseal.gif", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "image/vnd.sealedmedia.softseal.jpg", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "image/vnd.svf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "image/vnd.tencent.tap", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["tap"] },
+    MimeRecord { essence: "image/vnd.valve.source.texture", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["vtf"] },
+    MimeRecord { essence: "image/vnd.wap.wbmp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["wbmp"] },
+    MimeRecord { essence: "image/vnd.xiff", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["xif"] },
+    MimeRecord { essence: "image/vnd.zbrush.pcx", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["pcx"] },
+    MimeRecord { essence: "image/webp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["webp"] },
+    MimeRecord { essence: "image/wmf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["wmf"] },
+    MimeRecord { essence: "image/x-3ds", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["3ds"] },
+    MimeRecord { essence: "image/x-cmu-raster", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["ras"] },
+    MimeRecord { essence: "image/x-cmx", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["cmx"] },
+    MimeRecord { essence: "image/x-freehand", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["fh", "fhc", "fh4", "fh5", "fh7"] },
+    MimeRecord { essence: "image/x-icon", source: Some(MimeSource::Apache), compressible: Some(true), charset: None, extensions: &["ico"] },
+    MimeRecord { essence: "image/x-jng", source: Some(MimeSource::Nginx), compressible: None, charset: None, extensions: &["jng"] },
+    MimeRecord { essence: "image/x-mrsid-image", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["sid"] },
+    MimeRecord { essence: "image/x-ms-bmp", source: Some(MimeSource::Nginx), compressible: Some(true), charset: None, extensions: &["bmp"] },
+    MimeRecord { essence: "image/x-pcx", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["pcx"] },
+    MimeRecord { essence: "image/x-pict", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["pic", "pct"] },
+    MimeRecord { essence: "image/x-portable-anymap", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["pnm"] },
+    MimeRecord { essence: "image/x-portable-bitmap", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["pbm"] },
+    MimeRecord { essence: "image/x-portable-graymap", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["pgm"] },
+    MimeRecord { essence: "image/x-portable-pixmap", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["ppm"] },
+    MimeRecord { essence: "image/x-rgb", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["rgb"] },
+    MimeRecord { essence: "image/x-tga", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["tga"] },
+    MimeRecord { essence: "image/x-xbitmap", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["xbm"] },
+    MimeRecord { essence: "image/x-xcf", source: None, compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "image/x-xpixmap", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["xpm"] },
+    MimeRecord { essence: "image/x-xwindowdump", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["xwd"] },
+    MimeRecord { essence: "message/bhttp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "message/cpim", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "message/delivery-status", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "message/disposition-notification", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["disposition-notification"] },
+    MimeRecord { essence: "message/external-body", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "message/feedback-report", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "message/global", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["u8msg"] },
+    MimeRecord { essence: "message/global-delivery-status", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["u8dsn"] },
+    MimeRecord { essence: "message/global-disposition-notification", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["u8mdn"] },
+    MimeRecord { essence: "message/global-headers", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["u8hdr"] },
+    MimeRecord { essence: "message/http", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "message/imdn+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "message/mls", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "message/news", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "message/ohttp-req", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "message/ohttp-res", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "message/partial", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "message/rfc822", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["eml", "mime"] },
+    MimeRecord { essence: "message/s-http", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "message/sip", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "message/sipfrag", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "message/tracking-status", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "message/vnd.si.simp", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "message/vnd.wfa.wsc", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["wsc"] },
+    MimeRecord { essence: "model/3mf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["3mf"] },
+    MimeRecord { essence: "model/e57", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "model/gltf+json", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["gltf"] },
+    MimeRecord { essence: "model/gltf-binary", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["glb"] },
+    MimeRecord { essence: "model/iges", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["igs", "iges"] },
+    MimeRecord { essence: "model/jt", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["jt"] },
+    MimeRecord { essence: "model/mesh", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["msh", "mesh", "silo"] },
+    MimeRecord { essence: "model/mtl", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["mtl"] },
+    MimeRecord { essence: "model/obj", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["obj"] },
+    MimeRecord { essence: "model/prc", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["prc"] },
+    MimeRecord { essence: "model/step", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "model/step+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["stpx"] },
+    MimeRecord { essence: "model/step+zip", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["stpz"] },
+    MimeRecord { essence: "model/step-xml+zip", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["stpxz"] },
+    MimeRecord { essence: "model/stl", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["stl"] },
+    MimeRecord { essence: "model/u3d", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["u3d"] },
+    MimeRecord { essence: "model/vnd.bary", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["bary"] },
+    MimeRecord { essence: "model/vnd.cld", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["cld"] },
+    MimeRecord { essence: "model/vnd.collada+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["dae"] },
+    MimeRecord { essence: "model/vnd.dwf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["dwf"] },
+    MimeRecord { essence: "model/vnd.flatland.3dml", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "model/vnd.gdl", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["gdl"] },
+    MimeRecord { essence: "model/vnd.gs-gdl", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "model/vnd.gs.gdl", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "model/vnd.gtw", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["gtw"] },
+    MimeRecord { essence: "model/vnd.moml+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "model/vnd.mts", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["mts"] },
+    MimeRecord { essence: "model/vnd.opengex", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ogex"] },
+    MimeRecord { essence: "model/vnd.parasolid.transmit.binary", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["x_b"] },
+    MimeRecord { essence: "model/vnd.parasolid.transmit.text", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["x_t"] },
+    MimeRecord { essence: "model/vnd.pytha.pyox", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["pyo", "pyox"] },
+    MimeRecord { essence: "model/vnd.rosette.annotated-data-model", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "model/vnd.sap.vds", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["vds"] },
+    MimeRecord { essence: "model/vnd.usda", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["usda"] },
+    MimeRecord { essence: "model/vnd.usdz+zip", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["usdz"] },
+    MimeRecord { essence: "model/vnd.valve.source.compiled-map", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["bsp"] },
+    MimeRecord { essence: "model/vnd.vtu", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["vtu"] },
+    MimeRecord { essence: "model/vrml", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["wrl", "vrml"] },
+    MimeRecord { essence: "model/x3d+binary", source: Some(MimeSource::Apache), compressible: Some(false), charset: None, extensions: &["x3db", "x3dbz"] },
+    MimeRecord { essence: "model/x3d+fastinfoset", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["x3db"] },
+    MimeRecord { essence: "model/x3d+vrml", source: Some(MimeSource::Apache), compressible: Some(false), charset: None, extensions: &["x3dv", "x3dvz"] },
+    MimeRecord { essence: "model/x3d+xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["x3d", "x3dz"] },
+    MimeRecord { essence: "model/x3d-vrml", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["x3dv"] },
+    MimeRecord { essence: "multipart/alternative", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "multipart/appledouble", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "multipart/byteranges", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "multipart/digest", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "multipart/encrypted", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "multipart/form-data", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "multipart/header-set", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "multipart/mixed", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "multipart/multilingual", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "multipart/parallel", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "multipart/related", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "multipart/report", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "multipart/signed", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &[] },
+    MimeRecord { essence: "multipart/vnd.bint.med-plus", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "multipart/voice-message", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "multipart/x-mixed-replace", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/1d-interleaved-parityfec", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/cache-manifest", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["appcache", "manifest"] },
+    MimeRecord { essence: "text/calendar", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ics", "ifb"] },
+    MimeRecord { essence: "text/calender", source: None, compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "text/cmd", source: None, compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "text/coffeescript", source: None, compressible: None, charset: None, extensions: &["coffee", "litcoffee"] },
+    MimeRecord { essence: "text/cql", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/cql-expression", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/cql-identifier", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/css", source: Some(MimeSource::Iana), compressible: Some(true), charset: Some("UTF-8"), extensions: &["css"] },
+    MimeRecord { essence: "text/csv", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["csv"] },
+    MimeRecord { essence: "text/csv-schema", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/directory", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/dns", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/ecmascript", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/encaprtp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/enriched", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/fhirpath", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/flexfec", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/fwdred", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/gff3", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/grammar-ref-list", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/hl7v2", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/html", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["html", "htm", "shtml"] },
+    MimeRecord { essence: "text/jade", source: None, compressible: None, charset: None, extensions: &["jade"] },
+    MimeRecord { essence: "text/javascript", source: Some(MimeSource::Iana), compressible: Some(true), charset: Some("UTF-8"), extensions: &["js", "mjs"] },
+    MimeRecord { essence: "text/jcr-cnd", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/jsx", source: None, compressible: Some(true), charset: None, extensions: &["jsx"] },
+    MimeRecord { essence: "text/less", source: None, compressible: Some(true), charset: None, extensions: &["less"] },
+    MimeRecord { essence: "text/markdown", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["md", "markdown"] },
+    MimeRecord { essence: "text/mathml", source: Some(MimeSource::Nginx), compressible: None, charset: None, extensions: &["mml"] },
+    MimeRecord { essence: "text/mdx", source: None, compressible: Some(true), charset: None, extensions: &["mdx"] },
+    MimeRecord { essence: "text/mizar", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/n3", source: Some(MimeSource::Iana), compressible: Some(true), charset: Some("UTF-8"), extensions: &["n3"] },
+    MimeRecord { essence: "text/parameters", source: Some(MimeSource::Iana), compressible: None, charset: Some("UTF-8"), extensions: &[] },
+    MimeRecord { essence: "text/parityfec", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/plain", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["txt", "text", "conf", "def", "list", "log", "in", "ini"] },
+    MimeRecord { essence: "text/provenance-notation", source: Some(MimeSource::Iana), compressible: None, charset: Some("UTF-8"), extensions: &[] },
+    MimeRecord { essence: "text/prs.fallenstein.rst", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/prs.lines.tag", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["dsc"] },
+    MimeRecord { essence: "text/prs.prop.logic", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/prs.texi", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/raptorfec", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/red", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/rfc822-headers", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/richtext", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["rtx"] },
+    MimeRecord { essence: "text/rtf", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["rtf"] },
+    MimeRecord { essence: "text/rtp-enc-aescm128", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/rtploopback", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/rtx", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/sgml", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["sgml", "sgm"] },
+    MimeRecord { essence: "text/shaclc", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/shex", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["shex"] },
+    MimeRecord { essence: "text/slim", source: None, compressible: None, charset: None, extensions: &["slim", "slm"] },
+    MimeRecord { essence: "text/spdx", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["spdx"] },
+    MimeRecord { essence: "text/strings", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/stylus", source: None, compressible: None, charset: None, extensions: &["stylus", "styl"] },
+    MimeRecord { essence: "text/t140", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/tab-separated-values", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["tsv"] },
+    MimeRecord { essence: "text/troff", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["t", "tr", "roff", "man", "me", "ms"] },
+    MimeRecord { essence: "text/turtle", source: Some(MimeSource::Iana), compressible: None, charset: Some("UTF-8"), extensions: &["ttl"] },
+    MimeRecord { essence: "text/ulpfec", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/uri-list", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["uri", "uris", "urls"] },
+    MimeRecord { essence: "text/vcard", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["vcard"] },
+    MimeRecord { essence: "text/vnd.a", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/vnd.abc", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/vnd.ascii-art", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/vnd.curl", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["curl"] },
+    MimeRecord { essence: "text/vnd.curl.dcurl", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["dcurl"] },
+    MimeRecord { essence: "text/vnd.curl.mcurl", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["mcurl"] },
+    MimeRecord { essence: "text/vnd.curl.scurl", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["scurl"] },
+    MimeRecord { essence: "text/vnd.debian.copyright", source: Some(MimeSource::Iana), compressible: None, charset: Some("UTF-8"), extensions: &[] },
+    MimeRecord { essence: "text/vnd.dmclientscript", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/vnd.dvb.subtitle", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["sub"] },
+    MimeRecord { essence: "text/vnd.esmertec.theme-descriptor", source: Some(MimeSource::Iana), compressible: None, charset: Some("UTF-8"), extensions: &[] },
+    MimeRecord { essence: "text/vnd.exchangeable", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/vnd.familysearch.gedcom", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ged"] },
+    MimeRecord { essence: "text/vnd.ficlab.flt", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/vnd.fly", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["fly"] },
+    MimeRecord { essence: "text/vnd.fmi.flexstor", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["flx"] },
+    MimeRecord { essence: "text/vnd.gml", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/vnd.graphviz", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["gv"] },
+    MimeRecord { essence: "text/vnd.hans", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/vnd.hgl", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/vnd.in3d.3dml", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["3dml"] },
+    MimeRecord { essence: "text/vnd.in3d.spot", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["spot"] },
+    MimeRecord { essence: "text/vnd.iptc.newsml", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/vnd.iptc.nitf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/vnd.latex-z", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/vnd.motorola.reflex", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/vnd.ms-mediapackage", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/vnd.net2phone.commcenter.command", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/vnd.radisys.msml-basic-layout", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/vnd.senx.warpscript", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/vnd.si.uricatalogue", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/vnd.sosi", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/vnd.sun.j2me.app-descriptor", source: Some(MimeSource::Iana), compressible: None, charset: Some("UTF-8"), extensions: &["jad"] },
+    MimeRecord { essence: "text/vnd.trolltech.linguist", source: Some(MimeSource::Iana), compressible: None, charset: Some("UTF-8"), extensions: &[] },
+    MimeRecord { essence: "text/vnd.vcf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/vnd.wap.si", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/vnd.wap.sl", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/vnd.wap.wml", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["wml"] },
+    MimeRecord { essence: "text/vnd.wap.wmlscript", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["wmls"] },
+    MimeRecord { essence: "text/vnd.zoo.kcl", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/vtt", source: Some(MimeSource::Iana), compressible: Some(true), charset: Some("UTF-8"), extensions: &["vtt"] },
+    MimeRecord { essence: "text/wgsl", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["wgsl"] },
+    MimeRecord { essence: "text/x-asm", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["s", "asm"] },
+    MimeRecord { essence: "text/x-c", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["c", "cc", "cxx", "cpp", "h", "hh", "dic"] },
+    MimeRecord { essence: "text/x-component", source: Some(MimeSource::Nginx), compressible: None, charset: None, extensions: &["htc"] },
+    MimeRecord { essence: "text/x-fortran", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["f", "for", "f77", "f90"] },
+    MimeRecord { essence: "text/x-gwt-rpc", source: None, compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "text/x-handlebars-template", source: None, compressible: None, charset: None, extensions: &["hbs"] },
+    MimeRecord { essence: "text/x-java-source", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["java"] },
+    MimeRecord { essence: "text/x-jquery-tmpl", source: None, compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "text/x-lua", source: None, compressible: None, charset: None, extensions: &["lua"] },
+    MimeRecord { essence: "text/x-markdown", source: None, compressible: Some(true), charset: None, extensions: &["mkd"] },
+    MimeRecord { essence: "text/x-nfo", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["nfo"] },
+    MimeRecord { essence: "text/x-opml", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["opml"] },
+    MimeRecord { essence: "text/x-org", source: None, compressible: Some(true), charset: None, extensions: &["org"] },
+    MimeRecord { essence: "text/x-pascal", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["p", "pas"] },
+    MimeRecord { essence: "text/x-processing", source: None, compressible: Some(true), charset: None, extensions: &["pde"] },
+    MimeRecord { essence: "text/x-sass", source: None, compressible: None, charset: None, extensions: &["sass"] },
+    MimeRecord { essence: "text/x-scss", source: None, compressible: None, charset: None, extensions: &["scss"] },
+    MimeRecord { essence: "text/x-setext", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["etx"] },
+    MimeRecord { essence: "text/x-sfv", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["sfv"] },
+    MimeRecord { essence: "text/x-suse-ymp", source: None, compressible: Some(true), charset: None, extensions: &["ymp"] },
+    MimeRecord { essence: "text/x-uuencode", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["uu"] },
+    MimeRecord { essence: "text/x-vcalendar", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["vcs"] },
+    MimeRecord { essence: "text/x-vcard", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["vcf"] },
+    MimeRecord { essence: "text/xml", source: Some(MimeSource::Iana), compressible: Some(true), charset: None, extensions: &["xml"] },
+    MimeRecord { essence: "text/xml-external-parsed-entity", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "text/yaml", source: None, compressible: Some(true), charset: None, extensions: &["yaml", "yml"] },
+    MimeRecord { essence: "video/1d-interleaved-parityfec", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/3gpp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["3gp", "3gpp"] },
+    MimeRecord { essence: "video/3gpp-tt", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/3gpp2", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["3g2"] },
+    MimeRecord { essence: "video/av1", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/bmpeg", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/bt656", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/celb", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/dv", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/encaprtp", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/evc", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/ffv1", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/flexfec", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/h261", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["h261"] },
+    MimeRecord { essence: "video/h263", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["h263"] },
+    MimeRecord { essence: "video/h263-1998", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/h263-2000", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/h264", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["h264"] },
+    MimeRecord { essence: "video/h264-rcdo", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/h264-svc", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/h265", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/h266", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/iso.segment", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["m4s"] },
+    MimeRecord { essence: "video/jpeg", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["jpgv"] },
+    MimeRecord { essence: "video/jpeg2000", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/jpm", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["jpm", "jpgm"] },
+    MimeRecord { essence: "video/jxsv", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/matroska", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/matroska-3d", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/mj2", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["mj2", "mjp2"] },
+    MimeRecord { essence: "video/mp1s", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/mp2p", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/mp2t", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["ts", "m2t", "m2ts", "mts"] },
+    MimeRecord { essence: "video/mp4", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["mp4", "mp4v", "mpg4"] },
+    MimeRecord { essence: "video/mp4v-es", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/mpeg", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["mpeg", "mpg", "mpe", "m1v", "m2v"] },
+    MimeRecord { essence: "video/mpeg4-generic", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/mpv", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/nv", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/ogg", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["ogv"] },
+    MimeRecord { essence: "video/parityfec", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/pointer", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/quicktime", source: Some(MimeSource::Iana), compressible: Some(false), charset: None, extensions: &["qt", "mov"] },
+    MimeRecord { essence: "video/raptorfec", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/raw", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/rtp-enc-aescm128", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/rtploopback", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/rtx", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/scip", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/smpte291", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/smpte292m", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/ulpfec", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/vc1", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/vc2", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/vnd.cctv", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/vnd.dece.hd", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["uvh", "uvvh"] },
+    MimeRecord { essence: "video/vnd.dece.mobile", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["uvm", "uvvm"] },
+    MimeRecord { essence: "video/vnd.dece.mp4", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/vnd.dece.pd", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["uvp", "uvvp"] },
+    MimeRecord { essence: "video/vnd.dece.sd", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["uvs", "uvvs"] },
+    MimeRecord { essence: "video/vnd.dece.video", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["uvv", "uvvv"] },
+    MimeRecord { essence: "video/vnd.directv.mpeg", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/vnd.directv.mpeg-tts", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/vnd.dlna.mpeg-tts", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/vnd.dvb.file", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["dvb"] },
+    MimeRecord { essence: "video/vnd.fvt", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["fvt"] },
+    MimeRecord { essence: "video/vnd.hns.video", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/vnd.iptvforum.1dparityfec-1010", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/vnd.iptvforum.1dparityfec-2005", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/vnd.iptvforum.2dparityfec-1010", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/vnd.iptvforum.2dparityfec-2005", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/vnd.iptvforum.ttsavc", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/vnd.iptvforum.ttsmpeg2", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/vnd.motorola.video", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/vnd.motorola.videop", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/vnd.mpegurl", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["mxu", "m4u"] },
+    MimeRecord { essence: "video/vnd.ms-playready.media.pyv", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["pyv"] },
+    MimeRecord { essence: "video/vnd.nokia.interleaved-multimedia", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/vnd.nokia.mp4vr", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/vnd.nokia.videovoip", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/vnd.objectvideo", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/vnd.radgamettools.bink", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/vnd.radgamettools.smacker", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/vnd.sealed.mpeg1", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/vnd.sealed.mpeg4", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/vnd.sealed.swf", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/vnd.sealedmedia.softseal.mov", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/vnd.uvvu.mp4", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["uvu", "uvvu"] },
+    MimeRecord { essence: "video/vnd.vivo", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &["viv"] },
+    MimeRecord { essence: "video/vnd.youtube.yt", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/vp8", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/vp9", source: Some(MimeSource::Iana), compressible: None, charset: None, extensions: &[] },
+    MimeRecord { essence: "video/webm", source: Some(MimeSource::Apache), compressible: Some(false), charset: None, extensions: &["webm"] },
+    MimeRecord { essence: "video/x-f4v", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["f4v"] },
+    MimeRecord { essence: "video/x-fli", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["fli"] },
+    MimeRecord { essence: "video/x-flv", source: Some(MimeSource::Apache), compressible: Some(false), charset: None, extensions: &["flv"] },
+    MimeRecord { essence: "video/x-m4v", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["m4v"] },
+    MimeRecord { essence: "video/x-matroska", source: Some(MimeSource::Apache), compressible: Some(false), charset: None, extensions: &["mkv", "mk3d", "mks"] },
+    MimeRecord { essence: "video/x-mng", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["mng"] },
+    MimeRecord { essence: "video/x-ms-asf", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["asf", "asx"] },
+    MimeRecord { essence: "video/x-ms-vob", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["vob"] },
+    MimeRecord { essence: "video/x-ms-wm", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["wm"] },
+    MimeRecord { essence: "video/x-ms-wmv", source: Some(MimeSource::Apache), compressible: Some(false), charset: None, extensions: &["wmv"] },
+    MimeRecord { essence: "video/x-ms-wmx", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["wmx"] },
+    MimeRecord { essence: "video/x-ms-wvx", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["wvx"] },
+    MimeRecord { essence: "video/x-msvideo", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["avi"] },
+    MimeRecord { essence: "video/x-sgi-movie", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["movie"] },
+    MimeRecord { essence: "video/x-smv", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["smv"] },
+    MimeRecord { essence: "x-conference/x-cooltalk", source: Some(MimeSource::Apache), compressible: None, charset: None, extensions: &["ice"] },
+    MimeRecord { essence: "x-shader/x-fragment", source: None, compressible: Some(true), charset: None, extensions: &[] },
+    MimeRecord { essence: "x-shader/x-vertex", source: None, compressible: Some(true), charset: None, extensions: &[] },
+];
+
+#[rustfmt::skip]
+pub(crate) static EXTENSION_DB: [(&str, usize); 1208] = [
+    ("123", 1046),
+    ("1km", 510),
+    ("3dml", 2287),
+    ("3ds", 2100),
+    ("3g2", 2338),
+    ("3gp", 2336),
+    ("3gpp", 2336),
+    ("3mf", 2144),
+    ("7z", 1635),
+    ("aab", 1641),
+    ("aac", 1821),
+    ("aam", 1642),
+    ("aas", 1643),
+    ("abw", 1636),
+    ("ac", 345),
+    ("acc", 623),
+    ("ace", 1637),
+    ("acu", 592),
+    ("acutc", 593),
+    ("adp", 1823),
+    ("adts", 1821),
+    ("aep", 653),
+    ("afm", 1682),
+    ("afp", 960),
+    ("age", 615),
+    ("ahead", 617),
+    ("ai", 352),
+    ("aif", 1990),
+    ("aifc", 1990),
+    ("aiff", 1990),
+    ("air", 594),
+    ("ait", 772),
+    ("ami", 624),
+    ("aml", 53),
+    ("amlx", 54),
+    ("amr", 1824),
+    ("apk", 627),
+    ("apng", 2018),
+    ("appcache", 2204),
+    ("appinstaller", 33),
+    ("application", 1710),
+    ("appx", 36),
+    ("appxbundle", 37),
+    ("apr", 1047),
+    ("arc", 1684),
+    ("arj", 1640),
+    ("asc", 334),
+    ("asf", 2434),
+    ("asm", 2309),
+    ("aso", 588),
+    ("asx", 2434),
+    ("atc", 593),
+    ("atom", 41),
+    ("atomcat", 42),
+    ("atomdeleted", 43),
+    ("atomsvc", 45),
+    ("atx", 631),
+    ("au", 1832),
+    ("avci", 2019),
+    ("avcs", 2020),
+    ("avi", 2440),
+    ("avif", 2021),
+    ("aw", 35),
+    ("azf", 618),
+    ("azs", 619),
+    ("azv", 2067),
+    ("azw", 621),
+    ("b16", 2087),
+    ("bary", 2160),
+    ("bat", 1720),
+    ("bcpio", 1644),
+    ("bdf", 1672),
+    ("bdm", 1517),
+    ("bdo", 1159),
+    ("bdoc", 57),
+    ("bed", 1427),
+    ("bh2", 879),
+    ("bin", 312),
+    ("blb", 1647),
+    ("blorb", 1647),
+    ("bmi", 672),
+    ("bmml", 657),
+    ("bmp", 2022),
+    ("book", 866),
+    ("box", 1397),
+    ("boz", 1649),
+    ("bpk", 312),
+    ("bsp", 2179),
+    ("btf", 2058),
+    ("btif", 2058),
+    ("buffer", 312),
+    ("bz", 1648),
+    ("bz2", 1649),
+    ("c", 2310),
+    ("c11amc", 695),
+    ("c11amz", 696),
+    ("c4d", 694),
+    ("c4f", 694),
+    ("c4g", 694),
+    ("c4p", 694),
+    ("c4u", 694),
+    ("cab", 1103),
+    ("caf", 1991),
+    ("cap", 1527),
+    ("car", 735),
+    ("cat", 1119),
+    ("cb7", 1650),
+    ("cba", 1650),
+    ("cbr", 1650),
+    ("cbt", 1650),
+    ("cbz", 1650),
+    ("cc", 2310),
+    ("cco", 1656),
+    ("cct", 1664),
+    ("ccxml", 70),
+    ("cdbcmsg", 714),
+    ("cdf", 1728),
+    ("cdfx", 72),
+    ("cdkey", 1066),
+    ("cdmia", 73),
+    ("cdmic", 74),
+    ("cdmid", 75),
+    ("cdmio", 76),
+    ("cdmiq", 77),
+    ("cdx", 2004),
+    ("cdxml", 685),
+    ("cdy", 689),
+    ("cer", 346),
+    ("cfs", 1652),
+    ("cgm", 2023),
+    ("chat", 1653),
+    ("chm", 1111),
+    ("chrt", 1023),
+    ("cif", 2005),
+    ("cii", 629),
+    ("cil", 1101),
+    ("cjs", 306),
+    ("cla", 692),
+    ("class", 213),
+    ("cld", 2161),
+    ("clkk", 718),
+    ("clkp", 719),
+    ("clkt", 720),
+    ("clkw", 721),
+    ("clkx", 717),
+    ("clp", 1718),
+    ("cmc", 716),
+    ("cmdf", 2006),
+    ("cml", 2007),
+    ("cmp", 1617),
+    ("cmx", 2102),
+    ("cod", 1435),
+    ("coffee", 2208),
+    ("com", 1720),
+    ("conf", 2239),
+    ("cpio", 1659),
+    ("cpl", 99),
+    ("cpp", 2310),
+    ("cpt", 244),
+    ("crd", 1717),
+    ("crl", 347),
+    ("crt", 1771),
+    ("crx", 1655),
+    ("cryptonote", 1434),
+    ("csh", 1660),
+    ("csl", 691),
+    ("csml", 2008),
+    ("csp", 713),
+    ("css", 2212),
+    ("cst", 1664),
+    ("csv", 2213),
+    ("cu", 104),
+    ("curl", 2270),
+    ("cwl", 105),
+    ("cww", 361),
+    ("cxt", 1664),
+    ("cxx", 2310),
+    ("dae", 2162),
+    ("daf", 1081),
+    ("dart", 744),
+    ("dataless", 857),
+    ("davmount", 114),
+    ("dbf", 749),
+    ("dbk", 127),
+    ("dcr", 1664),
+    ("dcurl", 2271),
+    ("dd2", 1260),
+    ("ddd", 882),
+    ("ddf", 1521),
+    ("dds", 2083),
+    ("deb", 1662),
+    ("def", 2239),
+    ("deploy", 312),
+    ("der", 1771),
+    ("dfac", 766),
+    ("dgc", 1663),
+    ("dib", 2022),
+    ("dic", 2310),
+    ("dir", 1664),
+    ("dis", 1082),
+    ("disposition-notification", 2123),
+    ("dist", 312),
+    ("distz", 312),
+    ("djv", 2070),
+    ("djvu", 2070),
+    ("dll", 1720),
+    ("dmg", 1639),
+    ("dmp", 1527),
+    ("dms", 312),
+    ("dna", 759),
+    ("doc", 295),
+    ("docm", 1141),
+    ("docx", 1350),
+    ("dot", 295),
+    ("dotm", 1142),
+    ("dotx", 1360),
+    ("dp", 1371),
+    ("dpg", 765),
+    ("dpx", 2025),
+    ("dra", 1961),
+    ("drle", 2024),
+    ("dsc", 2242),
+    ("dssc", 131),
+    ("dtb", 1667),
+    ("dtd", 1796),
+    ("dts", 1962),
+    ("dtshd", 1963),
+    ("dump", 312),
+    ("dvb", 2399),
+    ("dvi", 1669),
+    ("dwd", 46),
+    ("dwf", 2163),
+    ("dwg", 2072),
+    ("dxf", 2073),
+    ("dxp", 1483),
+    ("dxr", 1664),
+    ("ear", 211),
+    ("ecelp4800", 1972),
+    ("ecelp7470", 1973),
+    ("ecelp9600", 1974),
+    ("ecma", 134),
+    ("edm", 1191),
+    ("edx", 1192),
+    ("efif", 1386),
+    ("ei6", 1384),
+    ("elc", 312),
+    ("emf", 2026),
+    ("eml", 2137),
+    ("emma", 152),
+    ("emotionml", 153),
+    ("emz", 1722),
+    ("eol", 1951),
+    ("eot", 1110),
+    ("eps", 352),
+    ("epub", 156),
+    ("es3", 820),
+    ("esa", 1372),
+    ("esf", 812),
+    ("et3", 820),
+    ("etx", 2326),
+    ("eva", 1671),
+    ("evy", 1670),
+    ("exe", 1719),
+    ("exi", 158),
+    ("exp", 160),
+    ("exr", 2017),
+    ("ext", 1193),
+    ("ez", 32),
+    ("ez2", 850),
+    ("ez3", 851),
+    ("f", 2312),
+    ("f4v", 2428),
+    ("f77", 2312),
+    ("f90", 2312),
+    ("fbs", 2074),
+    ("fcdt", 596),
+    ("fcs", 1006),
+    ("fdf", 163),
+    ("fdt", 164),
+    ("fe_launch", 755),
+    ("fg5", 878),
+    ("fgd", 1664),
+    ("fh", 2103),
+    ("fh4", 2103),
+    ("fh5", 2103),
+    ("fh7", 2103),
+    ("fhc", 2103),
+    ("fig", 1774),
+    ("fits", 2027),
+    ("flac", 1992),
+    ("fli", 2429),
+    ("flo", 1073),
+    ("flv", 2430),
+    ("flw", 1025),
+    ("flx", 2282),
+    ("fly", 2281),
+    ("fm", 866),
+    ("fnc", 868),
+    ("fo", 1480),
+    ("for", 2312),
+    ("fpx", 2075),
+    ("frame", 866),
+    ("fsc", 870),
+    ("fst", 2076),
+    ("ftc", 864),
+    ("fti", 630),
+    ("fvt", 2400),
+    ("fxp", 597),
+    ("fxpl", 597),
+    ("fzs", 890),
+    ("g2w", 909),
+    ("g3", 2028),
+    ("g3w", 910),
+    ("gac", 928),
+    ("gam", 1752),
+    ("gbr", 396),
+    ("gca", 1686),
+    ("gdl", 2165),
+    ("gdoc", 917),
+    ("ged", 2279),
+    ("geo", 793),
+    ("geojson", 174),
+    ("gex", 907),
+    ("ggb", 904),
+    ("ggs", 905),
+    ("ggt", 906),
+    ("ghf", 929),
+    ("gif", 2029),
+    ("gim", 930),
+    ("glb", 2147),
+    ("gltf", 2146),
+    ("gml", 180),
+    ("gmx", 914),
+    ("gnumeric", 1688),
+    ("gph", 863),
+    ("gpx", 185),
+    ("gqf", 926),
+    ("gqs", 926),
+    ("gram", 457),
+    ("gramps", 1689),
+    ("gre", 907),
+    ("grv", 931),
+    ("grxml", 458),
+    ("gsf", 1675),
+    ("gsheet", 919),
+    ("gslides", 918),
+    ("gtar", 1690),
+    ("gtm", 932),
+    ("gtw", 2168),
+    ("gv", 2284),
+    ("gxf", 187),
+    ("gxt", 908),
+    ("gz", 188),
+    ("h", 2310),
+    ("h261", 2348),
+    ("h263", 2349),
+    ("h264", 2352),
+    ("hal", 936),
+    ("hbci", 938),
+    ("hbs", 2314),
+    ("hdd", 1760),
+    ("hdf", 1692),
+    ("heic", 2030),
+    ("heics", 2031),
+    ("heif", 2032),
+    ("heifs", 2033),
+    ("hej2", 2034),
+    ("held", 48),
+    ("hh", 2310),
+    ("hjson", 191),
+    ("hlp", 1630),
+    ("hpgl", 944),
+    ("hpid", 945),
+    ("hps", 946),
+    ("hqx", 243),
+    ("hsj2", 2035),
+    ("htc", 2311),
+    ("htke", 1030),
+    ("htm", 2226),
+    ("html", 2226),
+    ("hvd", 1606),
+    ("hvp", 1608),
+    ("hvs", 1607),
+    ("i2g", 986),
+    ("icc", 963),
+    ("ice", 2443),
+    ("icm", 963),
+    ("ico", 2080),
+    ("ics", 2205),
+    ("ief", 2036),
+    ("ifb", 2205),
+    ("ifm", 1464),
+    ("iges", 2148),
+    ("igl", 965),
+    ("igm", 984),
+    ("igs", 2148),
+    ("igx", 1074),
+    ("iif", 1466),
+    ("img", 312),
+    ("imp", 589),
+    ("ims", 1112),
+    ("in", 2239),
+    ("ini", 2239),
+    ("ink", 205),
+    ("inkml", 205),
+    ("install", 1694),
+    ("iota", 652),
+    ("ipfix", 207),
+    ("ipk", 1467),
+    ("irm", 961),
+    ("irp", 1004),
+    ("iso", 1695),
+    ("itp", 1465),
+    ("its", 210),
+    ("ivp", 968),
+    ("ivu", 969),
+    ("jad", 2299),
+    ("jade", 2227),
+    ("jam", 1008),
+    ("jar", 211),
+    ("jardiff", 1699),
+    ("java", 2315),
+    ("jhc", 2042),
+    ("jisp", 1018),
+    ("jls", 2038),
+    ("jlt", 947),
+    ("jng", 2105),
+    ("jnlp", 1700),
+    ("joda", 1019),
+    ("jp2", 2039),
+    ("jpe", 2040),
+    ("jpeg", 2040),
+    ("jpf", 2044),
+    ("jpg", 2040),
+    ("jpg2", 2039),
+    ("jpgm", 2043),
+    ("jpgv", 2358),
+    ("jph", 2041),
+    ("jpm", 2043),
+    ("jpx", 2044),
+    ("js", 2228),
+    ("json", 221),
+    ("json5", 224),
+    ("jsonld", 232),
+    ("jsonml", 225),
+    ("jsx", 2230),
+    ("jt", 2149),
+    ("jxl", 2045),
+    ("jxr", 2046),
+    ("jxra", 2047),
+    ("jxrs", 2048),
+    ("jxs", 2049),
+    ("jxsc", 2050),
+    ("jxsi", 2051),
+    ("jxss", 2052),
+    ("kar", 1895),
+    ("karbon", 1022),
+    ("kdbx", 1702),
+    ("key", 643),
+    ("kfo", 1024),
+    ("kia", 1031),
+    ("kml", 920),
+    ("kmz", 921),
+    ("kne", 1032),
+    ("knp", 1032),
+    ("kon", 1026),
+    ("kpr", 1027),
+    ("kpt", 1027),
+    ("kpxx", 768),
+    ("ksp", 1028),
+    ("ktr", 1021),
+    ("ktx", 2053),
+    ("ktx2", 2054),
+    ("ktz", 1021),
+    ("kwd", 1029),
+    ("kwt", 1029),
+    ("lasxml", 1037),
+    ("latex", 1703),
+    ("lbd", 1042),
+    ("lbe", 1043),
+    ("les", 943),
+    ("less", 2231),
+    ("lgr", 233),
+    ("lha", 1705),
+    ("link66", 1438),
+    ("list", 2239),
+    ("list3820", 960),
+    ("listafp", 960),
+    ("litcoffee", 2208),
+    ("lnk", 1711),
+    ("log", 2239),
+    ("lostxml", 239),
+    ("lrf", 312),
+    ("lrm", 1113),
+    ("ltf", 869),
+    ("lua", 2317),
+    ("luac", 1704),
+    ("lvp", 1968),
+    ("lwp", 1052),
+    ("lzh", 1705),
+    ("m13", 1721),
+    ("m14", 1721),
+    ("m1v", 2370),
+    ("m21", 284),
+    ("m2a", 1903),
+    ("m2t", 2367),
+    ("m2ts", 2367),
+    ("m2v", 2370),
+    ("m3a", 1903),
+    ("m3u", 1995),
+    ("m3u8", 644),
+    ("m4a", 1899),
+    ("m4p", 285),
+    ("m4s", 2357),
+    ("m4u", 2410),
+    ("m4v", 2431),
+    ("ma", 250),
+    ("mads", 246),
+    ("maei", 277),
+    ("mag", 799),
+    ("maker", 866),
+    ("man", 2262),
+    ("manifest", 2204),
+    ("map", 221),
+    ("mar", 312),
+    ("markdown", 2232),
+    ("mathml", 251),
+    ("mb", 250),
+    ("mbk", 1083),
+    ("mbox", 265),
+    ("mc1", 1065),
+    ("mcd", 1062),
+    ("mcurl", 2272),
+    ("md", 2232),
+    ("mdb", 1715),
+    ("mdi", 2084),
+    ("mdx", 2234),
+    ("me", 2262),
+    ("mesh", 2150),
+    ("meta4", 271),
+    ("metalink", 270),
+    ("mets", 272),
+    ("mfm", 1071),
+    ("mft", 397),
+    ("mgp", 1369),
+    ("mgz", 1398),
+    ("mid", 1895),
+    ("midi", 1895),
+    ("mie", 1707),
+    ("mif", 1078),
+    ("mime", 2137),
+    ("mj2", 2364),
+    ("mjp2", 2364),
+    ("mjs", 2228),
+    ("mk3d", 2432),
+    ("mka", 1994),
+    ("mkd", 2318),
+    ("mks", 2432),
+    ("mkv", 2432),
+    ("mlp", 761),
+    ("mmd", 687),
+    ("mmf", 1475),
+    ("mml", 2233),
+    ("mmr", 2077),
+    ("mng", 2433),
+    ("mny", 1723),
+    ("mobi", 1708),
+    ("mods", 279),
+    ("mov", 2377),
+    ("movie", 2441),
+    ("mp2", 1903),
+    ("mp21", 284),
+    ("mp2a", 1903),
+    ("mp3", 1903),
+    ("mp4", 2368),
+    ("mp4a", 1899),
+    ("mp4s", 285),
+    ("mp4v", 2368),
+    ("mpc", 1090),
+    ("mpd", 111),
+    ("mpe", 2370),
+    ("mpeg", 2370),
+    ("mpf", 266),
+    ("mpg", 2370),
+    ("mpg4", 285),
+    ("mpga", 1903),
+    ("mpkg", 642),
+    ("mpm", 669),
+    ("mpn", 1089),
+    ("mpp", 112),
+    ("mpt", 1131),
+    ("mpy", 959),
+    ("mqy", 1084),
+    ("mrc", 248),
+    ("mrcx", 249),
+    ("ms", 2262),
+    ("mscml", 268),
+    ("mseed", 856),
+    ("mseq", 1147),
+    ("msf", 813),
+    ("msg", 1117),
+    ("msh", 2150),
+    ("msi", 1720),
+    ("msix", 293),
+    ("msixbundle", 294),
+    ("msl", 1085),
+    ("msm", 312),
+    ("msp", 312),
+    ("msty", 1154),
+    ("mtl", 2151),
+    ("mts", 2367),
+    ("mus", 1153),
+    ("musd", 278),
+    ("musicxml", 1429),
+    ("mvb", 1721),
+    ("mvt", 1054),
+    ("mwf", 1070),
+    ("mxf", 298),
+    ("mxl", 1428),
+    ("mxmf", 1897),
+    ("mxml", 1804),
+    ("mxs", 1534),
+    ("mxu", 2410),
+    ("n-gage", 1185),
+    ("n3", 2236),
+    ("nb", 250),
+    ("nbp", 1586),
+    ("nc", 1728),
+    ("ncx", 1666),
+    ("nfo", 2319),
+    ("ngdat", 1184),
+    ("nitf", 1171),
+    ("nlu", 1167),
+    ("nml", 809),
+    ("nnd", 1172),
+    ("nns", 1173),
+    ("nnw", 1174),
+    ("npx", 2086),
+    ("nq", 299),
+    ("nsc", 1658),
+    ("nsf", 1049),
+    ("nt", 300),
+    ("ntf", 1171),
+    ("numbers", 645),
+    ("nzb", 1730),
+    ("oa2", 876),
+    ("oa3", 877),
+    ("oas", 875),
+    ("obd", 1716),
+    ("obgx", 1286),
+    ("obj", 2152),
+    ("oda", 313),
+    ("odb", 1205),
+    ("odc", 1203),
+    ("odf", 1206),
+    ("odft", 1207),
+    ("odg", 1208),
+    ("odi", 1210),
+    ("odm", 1217),
+    ("odp", 1212),
+    ("ods", 1214),
+    ("odt", 1216),
+    ("oga", 1906),
+    ("ogex", 2171),
+    ("ogg", 1906),
+    ("ogv", 2374),
+    ("ogx", 317),
+    ("omdoc", 319),
+    ("onepkg", 320),
+    ("onetmp", 320),
+    ("onetoc", 320),
+    ("onetoc2", 320),
+    ("opf", 316),
+    ("opml", 2320),
+    ("oprc", 1376),
+    ("opus", 1906),
+    ("org", 1050),
+    ("osf", 1609),
+    ("osfpvg", 1610),
+    ("osm", 1290),
+    ("otc", 1204),
+    ("otf", 2012),
+    ("otg", 1209),
+    ("oth", 1220),
+    ("oti", 1211),
+    ("otp", 1213),
+    ("ots", 1215),
+    ("ott", 1219),
+    ("ova", 1761),
+    ("ovf", 1762),
+    ("owl", 381),
+    ("oxps", 323),
+    ("oxt", 1289),
+    ("p", 2322),
+    ("p10", 339),
+    ("p12", 1733),
+    ("p7b", 1734),
+    ("p7c", 341),
+    ("p7m", 341),
+    ("p7r", 1735),
+    ("p7s", 342),
+    ("p8", 343),
+    ("pac", 1729),
+    ("pages", 646),
+    ("pas", 2322),
+    ("paw", 1381),
+    ("pbd", 1390),
+    ("pbm", 2111),
+    ("pcap", 1527),
+    ("pcf", 1678),
+    ("pcl", 948),
+    ("pclxl", 949),
+    ("pct", 2109),
+    ("pcurl", 736),
+    ("pcx", 2097),
+    ("pdb", 1376),
+    ("pde", 2323),
+    ("pdf", 330),
+    ("pem", 1771),
+    ("pfa", 1682),
+    ("pfb", 1682),
+    ("pfm", 1682),
+    ("pfr", 171),
+    ("pfx", 1733),
+    ("pgm", 2112),
+    ("pgn", 1654),
+    ("pgp", 333),
+    ("php", 1693),
+    ("pic", 2109),
+    ("pkg", 312),
+    ("pki", 349),
+    ("pkipath", 348),
+    ("pkpass", 647),
+    ("pl", 1731),
+    ("plb", 562),
+    ("plc", 1086),
+    ("plf", 1389),
+    ("pls", 350),
+    ("pm", 1731),
+    ("pml", 727),
+    ("png", 2057),
+    ("pnm", 2110),
+    ("portpkg", 1053),
+    ("pot", 1122),
+    ("potm", 1127),
+    ("potx", 1319),
+    ("ppam", 1123),
+    ("ppd", 731),
+    ("ppm", 2113),
+    ("pps", 1122),
+    ("ppsm", 1126),
+    ("ppsx", 1314),
+    ("ppt", 1122),
+    ("pptm", 1124),
+    ("pptx", 1307),
+    ("pqa", 1376),
+    ("prc", 2153),
+    ("pre", 1048),
+    ("prf", 336),
+    ("provx", 359),
+    ("ps", 352),
+    ("psb", 563),
+    ("psd", 2066),
+    ("psf", 1677),
+    ("pskcxml", 375),
+    ("pti", 2059),
+    ("ptid", 1402),
+    ("pub", 1724),
+    ("pvb", 564),
+    ("pwn", 587),
+    ("pya", 1969),
+    ("pyo", 2174),
+    ("pyox", 2174),
+    ("pyv", 2411),
+    ("qam", 814),
+    ("qbo", 989),
+    ("qfx", 990),
+    ("qps", 1401),
+    ("qt", 2377),
+    ("qwd", 1407),
+    ("qwt", 1407),
+    ("qxb", 1407),
+    ("qxd", 1407),
+    ("qxl", 1407),
+    ("qxt", 1407),
+    ("ra", 1998),
+    ("ram", 1998),
+    ("raml", 378),
+    ("rapd", 392),
+    ("rar", 1426),
+    ("ras", 2101),
+    ("rcprofile", 1003),
+    ("rdf", 381),
+    ("rdz", 745),
+    ("relo", 326),
+    ("rep", 675),
+    ("res", 1668),
+    ("rgb", 2114),
+    ("rif", 382),
+    ("rip", 1979),
+    ("ris", 1739),
+    ("rl", 386),
+    ("rlc", 2078),
+    ("rld", 387),
+    ("rm", 1436),
+    ("rmi", 1895),
+    ("rmp", 1999),
+    ("rms", 1017),
+    ("rmvb", 1437),
+    ("rnc", 383),
+    ("rng", 1795),
+    ("roa", 399),
+    ("roff", 2262),
+    ("rp9", 693),
+    ("rpm", 1738),
+    ("rpss", 1190),
+    ("rpst", 1189),
+    ("rq", 452),
+    ("rs", 391),
+    ("rsat", 50),
+    ("rsd", 402),
+    ("rsheet", 502),
+    ("rss", 403),
+    ("rtf", 404),
+    ("rtx", 2248),
+    ("run", 1706),
+    ("rusd", 394),
+    ("s", 2309),
+    ("s3m", 1921),
+    ("saf", 1612),
+    ("sass", 2324),
+    ("sbml", 412),
+    ("sc", 962),
+    ("scd", 1725),
+    ("scm", 1051),
+    ("scq", 415),
+    ("scs", 416),
+    ("scss", 2325),
+    ("scurl", 2273),
+    ("sda", 1490),
+    ("sdc", 1489),
+    ("sdd", 1491),
+    ("sdkd", 1482),
+    ("sdkm", 1482),
+    ("sdp", 419),
+    ("sdw", 1493),
+    ("sea", 1740),
+    ("see", 1458),
+    ("seed", 857),
+    ("sema", 1460),
+    ("semd", 1461),
+    ("semf", 1462),
+    ("senmlx", 423),
+    ("sensmlx", 429),
+    ("ser", 212),
+    ("setpay", 435),
+    ("setreg", 437),
+    ("sfd-hdstx", 952),
+    ("sfs", 1484),
+    ("sfv", 2327),
+    ("sgi", 2061),
+    ("sgl", 1494),
+    ("sgm", 2253),
+    ("sgml", 2253),
+    ("sh", 1741),
+    ("shar", 1742),
+    ("shex", 2255),
+    ("shf", 440),
+    ("shtml", 2226),
+    ("sid", 2106),
+    ("sieve", 441),
+    ("sig", 335),
+    ("sil", 1923),
+    ("silo", 2150),
+    ("sis", 1515),
+    ("sisx", 1515),
+    ("sit", 1746),
+    ("sitx", 1747),
+    ("siv", 441),
+    ("skd", 1033),
+    ("skm", 1033),
+    ("skp", 1033),
+    ("skt", 1033),
+    ("sldm", 1125),
+    ("sldx", 1310),
+    ("slim", 2256),
+    ("slm", 2256),
+    ("sls", 393),
+    ("slt", 815),
+    ("sm", 1496),
+    ("smf", 1492),
+    ("smi", 448),
+    ("smil", 448),
+    ("smv", 2442),
+    ("smzip", 1495),
+    ("snd", 1832),
+    ("snf", 1679),
+    ("so", 312),
+    ("spc", 1734),
+    ("spdx", 2257),
+    ("spf", 1613),
+    ("spl", 1685),
+    ("spot", 2288),
+    ("spp", 418),
+    ("spq", 417),
+    ("spx", 1906),
+    ("sql", 456),
+    ("src", 1768),
+    ("srt", 1748),
+    ("sru", 459),
+    ("srx", 453),
+    ("ssdl", 460),
+    ("sse", 1034),
+    ("ssf", 816),
+    ("ssml", 461),
+    ("st", 1442),
+    ("stc", 1500),
+    ("std", 1502),
+    ("stf", 1591),
+    ("sti", 1504),
+    ("stk", 194),
+    ("stl", 2158),
+    ("stpx", 2155),
+    ("stpxz", 2157),
+    ("stpz", 2156),
+    ("str", 1383),
+    ("stw", 1508),
+    ("styl", 2259),
+    ("stylus", 2259),
+    ("sub", 2276),
+    ("sus", 1509),
+    ("susp", 1509),
+    ("sv4cpio", 1749),
+    ("sv4crc", 1750),
+    ("svc", 791),
+    ("svd", 1510),
+    ("svg", 2062),
+    ("svgz", 2062),
+    ("swa", 1664),
+    ("swf", 1743),
+    ("swi", 649),
+    ("swidtag", 466),
+    ("sxc", 1499),
+    ("sxd", 1501),
+    ("sxg", 1507),
+    ("sxi", 1503),
+    ("sxm", 1505),
+    ("sxw", 1506),
+    ("t", 2262),
+    ("t3", 1751),
+    ("t38", 2063),
+    ("taglet", 1155),
+    ("tao", 1526),
+    ("tap", 2093),
+    ("tar", 1753),
+    ("tcap", 585),
+    ("tcl", 1754),
+    ("td", 503),
+    ("teacher", 1477),
+    ("tei", 481),
+    ("teicorpus", 481),
+    ("tex", 1755),
+    ("texi", 1757),
+    ("texinfo", 1757),
+    ("text", 2239),
+    ("tfi", 483),
+    ("tfm", 1756),
+    ("tfx", 2065),
+    ("tga", 2115),
+    ("thmx", 1115),
+    ("tif", 2064),
+    ("tiff", 2064),
+    ("tk", 1754),
+    ("tmo", 1531),
+    ("toml", 492),
+    ("torrent", 1646),
+    ("tpl", 933),
+    ("tpt", 1533),
+    ("tr", 2262),
+    ("tra", 1535),
+    ("trig", 494),
+    ("trm", 1726),
+    ("ts", 2367),
+    ("tsd", 486),
+    ("tsv", 2261),
+    ("ttc", 2011),
+    ("ttf", 2014),
+    ("ttl", 2263),
+    ("ttml", 495),
+    ("twd", 1473),
+    ("twds", 1473),
+    ("txd", 892),
+    ("txf", 1087),
+    ("txt", 2239),
+    ("u32", 1641),
+    ("u3d", 2159),
+    ("u8dsn", 2127),
+    ("u8hdr", 2129),
+    ("u8mdn", 2128),
+    ("u8msg", 2126),
+    ("ubj", 499),
+    ("udeb", 1662),
+    ("ufd", 1538),
+    ("ufdl", 1538),
+    ("ulx", 1687),
+    ("umj", 1540),
+    ("unityweb", 1541),
+    ("uo", 1542),
+    ("uoml", 1542),
+    ("uri", 2265),
+    ("uris", 2265),
+    ("urls", 2265),
+    ("usda", 2177),
+    ("usdz", 2178),
+    ("ustar", 1759),
+    ("utz", 1539),
+    ("uu", 2329),
+    ("uva", 1950),
+    ("uvd", 751),
+    ("uvf", 751),
+    ("uvg", 2069),
+    ("uvh", 2390),
+    ("uvi", 2069),
+    ("uvm", 2391),
+    ("uvp", 2393),
+    ("uvs", 2394),
+    ("uvt", 752),
+    ("uvu", 2422),
+    ("uvv", 2395),
+    ("uvva", 1950),
+    ("uvvd", 751),
+    ("uvvf", 751),
+    ("uvvg", 2069),
+    ("uvvh", 2390),
+    ("uvvi", 2069),
+    ("uvvm", 2391),
+    ("uvvp", 2393),
+    ("uvvs", 2394),
+    ("uvvt", 752),
+    ("uvvu", 2422),
+    ("uvvv", 2395),
+    ("uvvx", 753),
+    ("uvvz", 754),
+    ("uvx", 753),
+    ("uvz", 754),
+    ("vbox", 1763),
+    ("vbox-extpack", 1764),
+    ("vcard", 2266),
+    ("vcd", 1651),
+    ("vcf", 2331),
+    ("vcg", 934),
+    ("vcs", 2330),
+    ("vcx", 1558),
+    ("vdi", 1765),
+    ("vds", 2176),
+    ("vhd", 1766),
+    ("vis", 1568),
+    ("viv", 2423),
+    ("vmdk", 1767),
+    ("vob", 2435),
+    ("vor", 1493),
+    ("vox", 1641),
+    ("vrml", 2181),
+    ("vsd", 1567),
+    ("vsf", 1570),
+    ("vss", 1567),
+    ("vst", 1567),
+    ("vsw", 1567),
+    ("vtf", 2094),
+    ("vtt", 2307),
+    ("vtu", 2180),
+    ("vxml", 1620),
+    ("w3d", 1664),
+    ("wad", 1665),
+    ("wadl", 1498),
+    ("war", 211),
+    ("wasm", 1624),
+    ("wav", 1987),
+    ("wax", 1996),
+    ("wbmp", 2095),
+    ("wbs", 722),
+    ("wbxml", 1573),
+    ("wcm", 1143),
+    ("wdb", 1143),
+    ("wdp", 2085),
+    ("weba", 1988),
+    ("webapp", 1769),
+    ("webm", 2427),
+    ("webmanifest", 247),
+    ("webp", 2098),
+    ("wg", 1387),
+    ("wgsl", 2308),
+    ("wgt", 1629),
+    ("wif", 1625),
+    ("wks", 1143),
+    ("wm", 2436),
+    ("wma", 1997),
+    ("wmd", 1712),
+    ("wmf", 2099),
+    ("wml", 2304),
+    ("wmlc", 1574),
+    ("wmls", 2305),
+    ("wmlsc", 1575),
+    ("wmv", 2437),
+    ("wmx", 2438),
+    ("wmz", 1713),
+    ("woff", 2015),
+    ("woff2", 2016),
+    ("wpd", 1588),
+    ("wpl", 1144),
+    ("wps", 1143),
+    ("wqd", 1589),
+    ("wri", 1727),
+    ("wrl", 2181),
+    ("wsc", 2143),
+    ("wsdl", 1633),
+    ("wspolicy", 1634),
+    ("wtb", 1577),
+    ("wvx", 2439),
+    ("x32", 1641),
+    ("x3d", 2185),
+    ("x3db", 2183),
+    ("x3dbz", 2182),
+    ("x3dv", 2186),
+    ("x3dvz", 2184),
+    ("x3dz", 2185),
+    ("x_b", 2172),
+    ("x_t", 2173),
+    ("xaml", 1781),
+    ("xap", 1744),
+    ("xar", 1596),
+    ("xav", 1782),
+    ("xbap", 1714),
+    ("xbd", 884),
+    ("xbm", 2116),
+    ("xca", 1783),
+    ("xcs", 62),
+    ("xdcf", 924),
+    ("xdf", 1784),
+    ("xdm", 1518),
+    ("xdp", 599),
+    ("xdssc", 132),
+    ("xdw", 883),
+    ("xel", 1785),
+    ("xenc", 1790),
+    ("xer", 329),
+    ("xfdf", 1791),
+    ("xfdl", 1598),
+    ("xht", 1792),
+    ("xhtm", 1404),
+    ("xhtml", 1792),
+    ("xhvml", 1804),
+    ("xif", 2096),
+    ("xla", 1105),
+    ("xlam", 1106),
+    ("xlc", 1105),
+    ("xlf", 1794),
+    ("xlm", 1105),
+    ("xls", 1105),
+    ("xlsb", 1107),
+    ("xlsm", 1108),
+    ("xlsx", 1335),
+    ("xlt", 1105),
+    ("xltm", 1109),
+    ("xltx", 1341),
+    ("xlw", 1105),
+    ("xm", 2003),
+    ("xml", 1795),
+    ("xns", 1787),
+    ("xo", 1236),
+    ("xop", 1800),
+    ("xpi", 1776),
+    ("xpl", 1801),
+    ("xpm", 2118),
+    ("xpr", 1005),
+    ("xps", 1145),
+    ("xpw", 985),
+    ("xpx", 985),
+    ("xsd", 1795),
+    ("xsf", 374),
+    ("xsl", 1795),
+    ("xslt", 1802),
+    ("xsm", 1516),
+    ("xspf", 1803),
+    ("xul", 1099),
+    ("xvm", 1804),
+    ("xvml", 1804),
+    ("xwd", 2119),
+    ("xyz", 2010),
+    ("xz", 1777),
+    ("yaml", 2334),
+    ("yang", 1806),
+    ("yin", 1813),
+    ("yml", 2334),
+    ("ymp", 2328),
+    ("z1", 1778),
+    ("z2", 1778),
+    ("z3", 1778),
+    ("z4", 1778),
+    ("z5", 1778),
+    ("z6", 1778),
+    ("z7", 1778),
+    ("z8", 1778),
+    ("zaz", 1619),
+    ("zip", 1814),
+    ("zir", 1618),
+    ("zirz", 1618),
+    ("zmm", 937),
+];
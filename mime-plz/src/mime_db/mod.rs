@@ -0,0 +1,202 @@
+// Records of the mime database
+// https://github.com/jshttp/mime-db
+
+use crate::{ContentType, MediaType};
+
+mod db;
+
+use db::{EXTENSION_DB, MIME_DB};
+
+// where the record was defined
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MimeSource {
+    Apache,
+    Iana,
+    Nginx,
+}
+
+/* Lowercase media type without parameters, known extensions in order of
+ * preference, whether the body is worth compressing and the default
+ * charset. compressible and charset are None when unknown.
+ */
+#[derive(Debug, Eq, PartialEq)]
+pub struct MimeRecord {
+    pub essence: &'static str,
+    pub source: Option<MimeSource>,
+    pub compressible: Option<bool>,
+    pub charset: Option<&'static str>,
+    pub extensions: &'static [&'static str],
+}
+
+impl MimeRecord {
+    pub fn media_type(&self) -> MediaType {
+        let (main_type, subtype) =
+            self.essence.split_once('/').unwrap_or_default();
+        MediaType::new(main_type, subtype)
+    }
+
+    pub fn content_type(&self) -> ContentType {
+        let main_type = self.essence.split('/').next().unwrap_or_default();
+        ContentType::from(main_type)
+    }
+
+    pub fn extension(&self) -> Option<&'static str> {
+        self.extensions.first().copied()
+    }
+}
+
+// type/subtype, case-insensitive
+pub fn lookup(essence: &str) -> Option<&'static MimeRecord> {
+    let essence = essence.to_ascii_lowercase();
+    MIME_DB
+        .binary_search_by(|record| record.essence.cmp(essence.as_str()))
+        .ok()
+        .map(|index| &MIME_DB[index])
+}
+
+pub fn lookup_media_type(
+    media_type: &MediaType,
+) -> Option<&'static MimeRecord> {
+    lookup(&media_type.essence())
+}
+
+pub fn preferred_extension(media_type: &MediaType) -> Option<&'static str> {
+    lookup_media_type(media_type).and_then(MimeRecord::extension)
+}
+
+/* Record of an extension without the leading dot, case-insensitive. When
+ * several records list it, iana is preferred over apache and nginx.
+ */
+pub fn lookup_extension(ext: &str) -> Option<&'static MimeRecord> {
+    let ext = ext.to_ascii_lowercase();
+    EXTENSION_DB
+        .binary_search_by(|(key, _)| key.cmp(&ext.as_str()))
+        .ok()
+        .map(|index| &MIME_DB[EXTENSION_DB[index].1])
+}
+
+/* Record of the extension of the last segment of a path, eg. "/a/b.css".
+ * Dot files and segments without an extension have none.
+ */
+pub fn lookup_path(path: &str) -> Option<&'static MimeRecord> {
+    let segment = path.rsplit('/').next().unwrap_or_default();
+    match segment.rsplit_once('.') {
+        Some((stem, ext)) if !stem.is_empty() && !ext.is_empty() => {
+            lookup_extension(ext)
+        }
+        _ => None,
+    }
+}
+
+/* Whether a body of media_type is worth compressing. Without a record
+ * value, text and the +json, +text and +xml suffixes are compressible.
+ */
+pub fn is_compressible(media_type: &MediaType) -> bool {
+    if let Some(compressible) =
+        lookup_media_type(media_type).and_then(|record| record.compressible)
+    {
+        return compressible;
+    }
+    media_type.main_type().eq_ignore_ascii_case("text")
+        || media_type.suffix().is_some_and(|suffix| {
+            ["json", "text", "xml"]
+                .iter()
+                .any(|known| suffix.eq_ignore_ascii_case(known))
+        })
+}
+
+// charset of the record, UTF-8 for other text types
+pub fn default_charset(media_type: &MediaType) -> Option<&'static str> {
+    if let Some(charset) =
+        lookup_media_type(media_type).and_then(|record| record.charset)
+    {
+        return Some(charset);
+    }
+    media_type.main_type().eq_ignore_ascii_case("text").then_some("UTF-8")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn media_type(value: &str) -> MediaType {
+        MediaType::parse(value).unwrap()
+    }
+
+    #[test]
+    fn test_db_sorted() {
+        assert!(MIME_DB.windows(2).all(|w| w[0].essence < w[1].essence));
+        assert!(EXTENSION_DB.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn test_lookup() {
+        let record = lookup("Application/JSON").unwrap();
+        assert_eq!(record.essence, "application/json");
+        assert_eq!(record.source, Some(MimeSource::Iana));
+        assert_eq!(record.compressible, Some(true));
+        assert_eq!(record.charset, Some("UTF-8"));
+        assert_eq!(record.extension(), Some("json"));
+        assert_eq!(record.content_type(), ContentType::Application);
+        assert!(lookup("application/x-unknown-type").is_none());
+    }
+
+    #[test]
+    fn test_preferred_extension() {
+        let html = media_type("text/html; charset=utf-8");
+        assert_eq!(preferred_extension(&html), Some("html"));
+        assert_eq!(
+            preferred_extension(&media_type("image/jpeg")),
+            Some("jpeg")
+        );
+        assert_eq!(
+            preferred_extension(&media_type("application/x-foo")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_lookup_extension() {
+        let essence = |ext| lookup_extension(ext).map(|record| record.essence);
+        assert_eq!(essence("HTML"), Some("text/html"));
+        assert_eq!(essence("mp4"), Some("video/mp4"));
+        assert_eq!(essence("js"), Some("text/javascript"));
+        assert_eq!(essence("wasm"), Some("application/wasm"));
+        assert_eq!(essence("nope"), None);
+    }
+
+    #[test]
+    fn test_lookup_path() {
+        let essence = |path| lookup_path(path).map(|record| record.essence);
+        assert_eq!(essence("/static/app.min.css"), Some("text/css"));
+        assert_eq!(essence("/a.b/image.PNG"), Some("image/png"));
+        assert_eq!(essence("/a.b/readme"), None);
+        assert_eq!(essence("/.htaccess"), None);
+        assert_eq!(essence("/dir/"), None);
+        assert_eq!(essence("file."), None);
+    }
+
+    #[test]
+    fn test_is_compressible() {
+        assert!(is_compressible(&media_type("text/html")));
+        assert!(is_compressible(&media_type("application/json")));
+        assert!(!is_compressible(&media_type("image/png")));
+        // no record
+        assert!(is_compressible(&media_type("text/x-custom")));
+        assert!(is_compressible(&media_type("application/vnd.custom+json")));
+        assert!(!is_compressible(&media_type("application/x-custom")));
+    }
+
+    #[test]
+    fn test_default_charset() {
+        assert_eq!(
+            default_charset(&media_type("application/json")),
+            Some("UTF-8")
+        );
+        assert_eq!(
+            default_charset(&media_type("text/x-custom")),
+            Some("UTF-8")
+        );
+        assert_eq!(default_charset(&media_type("image/png")), None);
+    }
+}
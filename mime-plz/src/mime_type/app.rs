@@ -1,2 +0,0 @@
-#[rustfmt::skip]
-pub const EXT_APP: [&str; 837] = ["123","1km","7z","aab","aam","aas","abw","ac","acc","ace","acu","acutc","aep","afm","afp","age","ahead","ai","air","ait","ami","aml","amlx","apk","appinstaller","application","appx","appxbundle","apr","arc","arj","asc","aso","atc","atom","atomcat","atomdeleted","atomsvc","atx","aw","azf","azs","azw","bat","bcpio","bdf","bdm","bdo","bdoc","bed","bh2","bin","blb","blorb","bmi","bmml","book","box","boz","bpk","buffer","bz","bz2","c11amc","c11amz","c4d","c4f","c4g","c4p","c4u","cab","cap","car","cat","cb7","cba","cbr","cbt","cbz","cco","cct","ccxml","cdbcmsg","cdf","cdfx","cdkey","cdmia","cdmic","cdmid","cdmio","cdmiq","cdxml","cdy","cer","cfs","chat","chm","chrt","cii","cil","cjs","cla","class","clkk","clkp","clkt","clkw","clkx","clp","cmc","cmp","cod","com","cpio","cpl","cpt","crd","crl","crt","crx","cryptonote","csh","csl","csp","cst","cu","cwl","cww","cxt","daf","dart","dataless","davmount","dbf","dbk","dcr","dd2","ddd","ddf","deb","deploy","der","dfac","dgc","dir","dis","dist","distz","dll","dmg","dmp","dms","dna","doc","docm","docx","dot","dotm","dotx","dp","dpg","dssc","dtb","dtd","dump","dvi","dwd","dxp","dxr","ear","ecma","edm","edx","efif","ei6","elc","emf","emma","emotionml","emz","eot","eps","epub","es3","esa","esf","et3","eva","evy","exe","exi","exp","ext","ez","ez2","ez3","fcdt","fcs","fdf","fdt","fe_launch","fg5","fgd","fig","flo","flw","fm","fnc","fo","frame","fsc","ftc","fti","fxp","fxpl","fzs","g2w","g3w","gac","gam","gbr","gca","gdoc","geo","geojson","gex","ggb","ggs","ggt","ghf","gim","gml","gmx","gnumeric","gph","gpx","gqf","gqs","gram","gramps","gre","grv","grxml","gsf","gsheet","gslides","gtar","gtm","gxf","gxt","gz","hal","hbci","hdd","hdf","held","hjson","hlp","hpgl","hpid","hps","hqx","htke","hvd","hvp","hvs","i2g","icc","icm","ifm","igl","igm","igx","iif","img","imp","ims","ink","inkml","install","iota","ipfix","ipk","irm","irp","iso","itp","its","ivp","ivu","jam","jar","jardiff","jisp","jlt","jnlp","joda","js","json","json5","jsonld","jsonml","karbon","kdbx","key","kfo","kia","kml","kmz","kne","knp","kon","kpr","kpt","kpxx","ksp","ktr","ktz","kwd","kwt","lasxml","latex","lbd","lbe","les","lgr","lha","link66","list3820","listafp","lnk","lostxml","lrf","lrm","ltf","luac","lwp","lzh","m13","m14","m21","m3u8","m4p","ma","mads","maei","mag","maker","map","mar","mathml","mb","mbk","mbox","mc1","mcd","mdb","meta4","metalink","mets","mfm","mft","mgp","mgz","mie","mif","mlp","mmd","mmf","mny","mobi","mods","mp21","mp4s","mpc","mpd","mpf","mpg4","mpkg","mpm","mpn","mpp","mpt","mpy","mqy","mrc","mrcx","mscml","mseed","mseq","msf","msg","msi","msix","msixbundle","msl","msm","msp","msty","mus","musd","musicxml","mvb","mvt","mwf","mxf","mxl","mxml","mxs","n-gage","nb","nbp","nc","ncx","ngdat","nitf","nlu","nml","nnd","nns","nnw","nq","nsc","nsf","nt","ntf","numbers","nzb","oa2","oa3","oas","obd","obgx","obj","oda","odb","odc","odf","odft","odg","odi","odm","odp","ods","odt","ogx","omdoc","onepkg","onetmp","onetoc","onetoc2","opf","oprc","org","osf","osfpvg","osm","otc","otg","oth","oti","otp","ots","ott","ova","ovf","owl","oxps","oxt","p10","p12","p7b","p7c","p7m","p7r","p7s","p8","pac","pages","paw","pbd","pcap","pcf","pcl","pclxl","pcurl","pdb","pdf","pem","pfa","pfb","pfm","pfr","pfx","pgn","pgp","php","pkg","pki","pkipath","pkpass","pl","plb","plc","plf","pls","pm","pml","portpkg","pot","potm","potx","ppam","ppd","pps","ppsm","ppsx","ppt","pptm","pptx","pqa","prc","pre","prf","provx","ps","psb","psf","pskcxml","ptid","pub","pvb","pwn","qam","qbo","qfx","qps","qwd","qwt","qxb","qxd","qxl","qxt","raml","rapd","rar","rcprofile","rdf","rdz","relo","rep","res","rif","ris","rl","rld","rm","rms","rmvb","rnc","rng","roa","rp9","rpm","rpss","rpst","rq","rs","rsat","rsd","rsheet","rss","rtf","run","rusd","saf","sbml","sc","scd","scm","scq","scs","sda","sdc","sdd","sdkd","sdkm","sdp","sdw","sea","see","seed","sema","semd","semf","senmlx","sensmlx","ser","setpay","setreg","sfd-hdstx","sfs","sgl","sh","shar","shf","sieve","sig","sis","sisx","sit","sitx","siv","skd","skm","skp","skt","sldm","sldx","sls","slt","sm","smf","smi","smil","smzip","snf","so","spc","spf","spl","spp","spq","sql","src","srt","sru","srx","ssdl","sse","ssf","ssml","st","stc","std","stf","sti","stk","stl","str","stw","sus","susp","sv4cpio","sv4crc","svc","svd","swa","swf","swi","swidtag","sxc","sxd","sxg","sxi","sxm","sxw","t3","taglet","tao","tar","tcap","tcl","td","teacher","tei","teicorpus","tex","texi","texinfo","tfi","tfm","thmx","tk","tmo","toml","torrent","tpl","tpt","tra","trig","trm","tsd","ttml","twd","twds","txd","txf","u32","ubj","udeb","ufd","ufdl","ulx","umj","unityweb","uo","uoml","ustar","utz","uvd","uvf","uvt","uvvd","uvvf","uvvt","uvvx","uvvz","uvx","uvz","vbox","vbox-extpack","vcd","vcg","vcx","vdi","vhd","vis","vmdk","vor","vox","vsd","vsf","vss","vst","vsw","vxml","w3d","wad","wadl","war","wasm","wbs","wbxml","wcm","wdb","webapp","webmanifest","wg","wgt","wif","wks","wmd","wmf","wmlc","wmlsc","wmz","wpd","wpl","wps","wqd","wri","wsdl","wspolicy","wtb","x32","xaml","xap","xar","xav","xbap","xbd","xca","xcs","xdcf","xdf","xdm","xdp","xdssc","xdw","xel","xenc","xer","xfdf","xfdl","xht","xhtm","xhtml","xhvml","xla","xlam","xlc","xlf","xlm","xls","xlsb","xlsm","xlsx","xlt","xltm","xltx","xlw","xml","xns","xo","xop","xpi","xpl","xpr","xps","xpw","xpx","xsd","xsf","xsl","xslt","xsm","xspf","xul","xvm","xvml","xz","yang","yin","z1","z2","z3","z4","z5","z6","z7","z8","zaz","zip","zir","zirz","zmm"];
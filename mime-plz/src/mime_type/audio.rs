@@ -1,2 +0,0 @@
-#[rustfmt::skip]
-pub const EXT_AUDIO: [&str; 53] = ["3gpp","aac","adp","adts","aif","aifc","aiff","amr","au","caf","dra","dts","dtshd","ecelp4800","ecelp7470","ecelp9600","eol","flac","kar","lvp","m2a","m3a","m3u","m4a","mid","midi","mka","mp2","mp2a","mp3","mp4a","mpga","mxmf","oga","ogg","opus","pya","ra","ram","rip","rmi","rmp","s3m","sil","snd","spx","uva","uvva","wav","wax","weba","wma","xm"];
@@ -0,0 +1,1486 @@
+// generated by build.rs from artifacts/db.json
+
+use crate::ContentType;
+
+#[rustfmt::skip]
+pub static EXTENSION_MAP: phf::Map<&'static str, ContentType> = ::phf::Map {
+    key: 12913932095322966823,
+    disps: &[
+        (0, 36),
+        (0, 53),
+        (1, 17),
+        (0, 4),
+        (0, 1),
+        (0, 28),
+        (0, 9),
+        (0, 23),
+        (0, 13),
+        (0, 3),
+        (0, 54),
+        (0, 6),
+        (0, 21),
+        (0, 261),
+        (0, 210),
+        (0, 217),
+        (0, 745),
+        (0, 97),
+        (0, 354),
+        (0, 207),
+        (0, 2),
+        (0, 0),
+        (0, 4),
+        (0, 1),
+        (0, 197),
+        (0, 2),
+        (0, 0),
+        (1, 41),
+        (0, 38),
+        (0, 527),
+        (0, 82),
+        (0, 153),
+        (0, 0),
+        (0, 31),
+        (0, 316),
+        (0, 1),
+        (0, 311),
+        (2, 755),
+        (0, 100),
+        (0, 23),
+        (0, 2),
+        (0, 58),
+        (0, 3),
+        (0, 14),
+        (0, 195),
+        (0, 128),
+        (0, 253),
+        (3, 40),
+        (0, 85),
+        (0, 61),
+        (0, 62),
+        (0, 363),
+        (0, 1),
+        (0, 2),
+        (0, 3),
+        (0, 7),
+        (0, 741),
+        (0, 16),
+        (0, 56),
+        (0, 734),
+        (0, 6),
+        (0, 55),
+        (0, 5),
+        (0, 666),
+        (0, 3),
+        (0, 143),
+        (0, 4),
+        (0, 17),
+        (0, 93),
+        (0, 15),
+        (2, 1010),
+        (0, 269),
+        (0, 0),
+        (0, 4),
+        (0, 4),
+        (0, 0),
+        (0, 26),
+        (0, 44),
+        (0, 38),
+        (0, 90),
+        (0, 19),
+        (0, 311),
+        (0, 157),
+        (0, 266),
+        (0, 15),
+        (0, 549),
+        (0, 9),
+        (0, 1038),
+        (0, 5),
+        (0, 58),
+        (0, 5),
+        (0, 2),
+        (0, 274),
+        (0, 597),
+        (0, 1),
+        (0, 419),
+        (5, 378),
+        (0, 72),
+        (0, 2),
+        (0, 1),
+        (0, 391),
+        (0, 376),
+        (0, 19),
+        (0, 58),
+        (3, 74),
+        (8, 947),
+        (0, 134),
+        (0, 179),
+        (0, 603),
+        (0, 41),
+        (0, 603),
+        (0, 189),
+        (0, 29),
+        (3, 842),
+        (0, 598),
+        (0, 56),
+        (0, 309),
+        (0, 959),
+        (0, 1),
+        (0, 0),
+        (0, 287),
+        (0, 511),
+        (0, 116),
+        (0, 846),
+        (0, 0),
+        (0, 10),
+        (0, 459),
+        (0, 249),
+        (0, 59),
+        (0, 5),
+        (0, 0),
+        (0, 338),
+        (1, 9),
+        (0, 13),
+        (0, 0),
+        (6, 21),
+        (3, 263),
+        (0, 276),
+        (3, 532),
+        (0, 342),
+        (0, 432),
+        (0, 81),
+        (0, 1),
+        (0, 271),
+        (0, 12),
+        (0, 8),
+        (0, 16),
+        (1, 884),
+        (0, 692),
+        (0, 11),
+        (0, 66),
+        (9, 642),
+        (0, 28),
+        (0, 1054),
+        (11, 1077),
+        (0, 0),
+        (2, 325),
+        (0, 348),
+        (0, 25),
+        (0, 0),
+        (0, 844),
+        (0, 1046),
+        (0, 782),
+        (0, 253),
+        (0, 6),
+        (0, 14),
+        (0, 267),
+        (0, 69),
+        (2, 409),
+        (0, 22),
+        (0, 56),
+        (2, 357),
+        (0, 0),
+        (0, 819),
+        (1, 933),
+        (0, 72),
+        (0, 5),
+        (0, 6),
+        (16, 387),
+        (0, 0),
+        (31, 474),
+        (0, 20),
+        (0, 1),
+        (0, 38),
+        (13, 977),
+        (0, 201),
+        (10, 1078),
+        (0, 17),
+        (0, 24),
+        (0, 81),
+        (0, 74),
+        (0, 0),
+        (1, 692),
+        (0, 0),
+        (1, 247),
+        (0, 1),
+        (3, 6),
+        (3, 506),
+        (0, 115),
+        (1, 260),
+        (0, 27),
+        (0, 343),
+        (0, 227),
+        (0, 42),
+        (4, 141),
+        (0, 172),
+        (2, 613),
+        (3, 78),
+        (0, 1157),
+        (0, 505),
+        (0, 55),
+        (0, 16),
+        (0, 0),
+        (0, 3),
+        (1, 400),
+        (0, 127),
+        (29, 359),
+        (0, 10),
+        (0, 0),
+        (0, 134),
+        (0, 1),
+        (0, 103),
+        (0, 679),
+        (0, 0),
+        (0, 0),
+        (29, 1015),
+        (0, 0),
+        (78, 122),
+        (0, 13),
+        (0, 86),
+        (0, 20),
+        (0, 16),
+        (11, 1197),
+        (66, 353),
+        (1, 12),
+        (0, 0),
+        (0, 541),
+        (0, 19),
+        (0, 379),
+        (0, 1),
+        (5, 311),
+    ],
+    entries: &[
+        ("m4s", ContentType::Video),
+        ("pvb", ContentType::Application),
+        ("z2", ContentType::Application),
+        ("msm", ContentType::Application),
+        ("ts", ContentType::Video),
+        ("wmd", ContentType::Application),
+        ("sensmlx", ContentType::Application),
+        ("igs", ContentType::Model),
+        ("aml", ContentType::Application),
+        ("sxd", ContentType::Application),
+        ("wqd", ContentType::Application),
+        ("bin", ContentType::Application),
+        ("fsc", ContentType::Application),
+        ("efif", ContentType::Application),
+        ("teicorpus", ContentType::Application),
+        ("hqx", ContentType::Application),
+        ("pfx", ContentType::Application),
+        ("knp", ContentType::Application),
+        ("pnm", ContentType::Image),
+        ("pml", ContentType::Application),
+        ("pbd", ContentType::Application),
+        ("xspf", ContentType::Application),
+        ("kar", ContentType::Audio),
+        ("mml", ContentType::Text),
+        ("plb", ContentType::Application),
+        ("chat", ContentType::Application),
+        ("weba", ContentType::Audio),
+        ("oa2", ContentType::Application),
+        ("lwp", ContentType::Application),
+        ("map", ContentType::Application),
+        ("sdkd", ContentType::Application),
+        ("nc", ContentType::Application),
+        ("sldm", ContentType::Application),
+        ("img", ContentType::Application),
+        ("mime", ContentType::Message),
+        ("taglet", ContentType::Application),
+        ("run", ContentType::Application),
+        ("owl", ContentType::Application),
+        ("m3u8", ContentType::Application),
+        ("rmp", ContentType::Audio),
+        ("tfm", ContentType::Application),
+        ("fcs", ContentType::Application),
+        ("wsc", ContentType::Message),
+        ("provx", ContentType::Application),
+        ("wg", ContentType::Application),
+        ("docm", ContentType::Application),
+        ("jam", ContentType::Application),
+        ("odft", ContentType::Application),
+        ("qxd", ContentType::Application),
+        ("karbon", ContentType::Application),
+        ("mk3d", ContentType::Video),
+        ("1km", ContentType::Application),
+        ("cdmiq", ContentType::Application),
+        ("g3w", ContentType::Application),
+        ("epub", ContentType::Application),
+        ("3mf", ContentType::Model),
+        ("dfac", ContentType::Application),
+        ("ods", ContentType::Application),
+        ("cdfx", ContentType::Application),
+        ("yaml", ContentType::Text),
+        ("aab", ContentType::Application),
+        ("mgp", ContentType::Application),
+        ("u32", ContentType::Application),
+        ("portpkg", ContentType::Application),
+        ("skp", ContentType::Application),
+        ("gdoc", ContentType::Application),
+        ("x3dbz", ContentType::Model),
+        ("stpx", ContentType::Model),
+        ("mpga", ContentType::Audio),
+        ("xdf", ContentType::Application),
+        ("aas", ContentType::Application),
+        ("hal", ContentType::Application),
+        ("onetoc", ContentType::Application),
+        ("yin", ContentType::Application),
+        ("ra", ContentType::Audio),
+        ("jls", ContentType::Image),
+        ("amr", ContentType::Audio),
+        ("ram", ContentType::Audio),
+        ("tfi", ContentType::Application),
+        ("lgr", ContentType::Application),
+        ("gam", ContentType::Application),
+        ("djvu", ContentType::Image),
+        ("m2ts", ContentType::Video),
+        ("cpl", ContentType::Application),
+        ("uvh", ContentType::Video),
+        ("mpy", ContentType::Application),
+        ("gltf", ContentType::Model),
+        ("rp9", ContentType::Application),
+        ("ged", ContentType::Text),
+        ("xsl", ContentType::Application),
+        ("qxb", ContentType::Application),
+        ("avci", ContentType::Image),
+        ("m14", ContentType::Application),
+        ("xfdl", ContentType::Application),
+        ("fcdt", ContentType::Application),
+        ("inkml", ContentType::Application),
+        ("sit", ContentType::Application),
+        ("svg", ContentType::Image),
+        ("manifest", ContentType::Text),
+        ("cab", ContentType::Application),
+        ("gsf", ContentType::Application),
+        ("wmx", ContentType::Video),
+        ("dsc", ContentType::Text),
+        ("utz", ContentType::Application),
+        ("kpr", ContentType::Application),
+        ("wml", ContentType::Text),
+        ("m4a", ContentType::Audio),
+        ("cwl", ContentType::Application),
+        ("nitf", ContentType::Application),
+        ("xca", ContentType::Application),
+        ("cpio", ContentType::Application),
+        ("uvvv", ContentType::Video),
+        ("urls", ContentType::Text),
+        ("ddd", ContentType::Application),
+        ("vtf", ContentType::Image),
+        ("flv", ContentType::Video),
+        ("yang", ContentType::Application),
+        ("wbxml", ContentType::Application),
+        ("paw", ContentType::Application),
+        ("u8msg", ContentType::Message),
+        ("exr", ContentType::Image),
+        ("ubj", ContentType::Application),
+        ("ipfix", ContentType::Application),
+        ("svc", ContentType::Application),
+        ("twds", ContentType::Application),
+        ("kpxx", ContentType::Application),
+        ("xml", ContentType::Application),
+        ("uva", ContentType::Audio),
+        ("sxw", ContentType::Application),
+        ("rpst", ContentType::Application),
+        ("uvvm", ContentType::Video),
+        ("mvb", ContentType::Application),
+        ("gqs", ContentType::Application),
+        ("ssdl", ContentType::Application),
+        ("xsd", ContentType::Application),
+        ("umj", ContentType::Application),
+        ("mpf", ContentType::Application),
+        ("torrent", ContentType::Application),
+        ("cxx", ContentType::Text),
+        ("123", ContentType::Application),
+        ("ahead", ContentType::Application),
+        ("gramps", ContentType::Application),
+        ("tpt", ContentType::Application),
+        ("stpz", ContentType::Model),
+        ("nzb", ContentType::Application),
+        ("srx", ContentType::Application),
+        ("gml", ContentType::Application),
+        ("uu", ContentType::Text),
+        ("vxml", ContentType::Application),
+        ("bcpio", ContentType::Application),
+        ("p12", ContentType::Application),
+        ("m4p", ContentType::Application),
+        ("smil", ContentType::Application),
+        ("slt", ContentType::Application),
+        ("aso", ContentType::Application),
+        ("mpg", ContentType::Video),
+        ("scm", ContentType::Application),
+        ("mobi", ContentType::Application),
+        ("xdssc", ContentType::Application),
+        ("sass", ContentType::Text),
+        ("mscml", ContentType::Application),
+        ("swidtag", ContentType::Application),
+        ("cdmid", ContentType::Application),
+        ("sru", ContentType::Application),
+        ("mseq", ContentType::Application),
+        ("zaz", ContentType::Application),
+        ("scq", ContentType::Application),
+        ("mseed", ContentType::Application),
+        ("ufd", ContentType::Application),
+        ("mid", ContentType::Audio),
+        ("sdd", ContentType::Application),
+        ("otc", ContentType::Application),
+        ("webmanifest", ContentType::Application),
+        ("xbap", ContentType::Application),
+        ("pyv", ContentType::Video),
+        ("xop", ContentType::Application),
+        ("twd", ContentType::Application),
+        ("numbers", ContentType::Application),
+        ("buffer", ContentType::Application),
+        ("igl", ContentType::Application),
+        ("dp", ContentType::Application),
+        ("vtu", ContentType::Model),
+        ("asm", ContentType::Text),
+        ("ser", ContentType::Application),
+        ("msix", ContentType::Application),
+        ("geojson", ContentType::Application),
+        ("ggs", ContentType::Application),
+        ("xslt", ContentType::Application),
+        ("dbf", ContentType::Application),
+        ("mxl", ContentType::Application),
+        ("icc", ContentType::Application),
+        ("xltm", ContentType::Application),
+        ("pyox", ContentType::Model),
+        ("semf", ContentType::Application),
+        ("texinfo", ContentType::Application),
+        ("dll", ContentType::Application),
+        ("mxml", ContentType::Application),
+        ("shf", ContentType::Application),
+        ("spf", ContentType::Application),
+        ("onetoc2", ContentType::Application),
+        ("xlc", ContentType::Application),
+        ("swf", ContentType::Application),
+        ("ogex", ContentType::Model),
+        ("cbt", ContentType::Application),
+        ("ink", ContentType::Application),
+        ("json", ContentType::Application),
+        ("mc1", ContentType::Application),
+        ("ppt", ContentType::Application),
+        ("qxt", ContentType::Application),
+        ("vbox", ContentType::Application),
+        ("bmi", ContentType::Application),
+        ("mxf", ContentType::Application),
+        ("iota", ContentType::Application),
+        ("pub", ContentType::Application),
+        ("vcg", ContentType::Application),
+        ("mets", ContentType::Application),
+        ("mp2a", ContentType::Audio),
+        ("pre", ContentType::Application),
+        ("ext", ContentType::Application),
+        ("atx", ContentType::Application),
+        ("pem", ContentType::Application),
+        ("pas", ContentType::Text),
+        ("rm", ContentType::Application),
+        ("caf", ContentType::Audio),
+        ("cbr", ContentType::Application),
+        ("ief", ContentType::Image),
+        ("cxt", ContentType::Application),
+        ("osm", ContentType::Application),
+        ("air", ContentType::Application),
+        ("kwd", ContentType::Application),
+        ("ini", ContentType::Text),
+        ("dvb", ContentType::Video),
+        ("tap", ContentType::Image),
+        ("jxsc", ContentType::Image),
+        ("uvvd", ContentType::Application),
+        ("ssf", ContentType::Application),
+        ("tei", ContentType::Application),
+        ("tr", ContentType::Text),
+        ("pptm", ContentType::Application),
+        ("swi", ContentType::Application),
+        ("iso", ContentType::Application),
+        ("gac", ContentType::Application),
+        ("tiff", ContentType::Image),
+        ("jxs", ContentType::Image),
+        ("t38", ContentType::Image),
+        ("kpt", ContentType::Application),
+        ("spc", ContentType::Application),
+        ("res", ContentType::Application),
+        ("bz", ContentType::Application),
+        ("rlc", ContentType::Image),
+        ("mathml", ContentType::Application),
+        ("txd", ContentType::Application),
+        ("jxr", ContentType::Image),
+        ("susp", ContentType::Application),
+        ("mp4s", ContentType::Application),
+        ("m3a", ContentType::Audio),
+        ("u8mdn", ContentType::Message),
+        ("odp", ContentType::Application),
+        ("war", ContentType::Application),
+        ("mft", ContentType::Application),
+        ("cww", ContentType::Application),
+        ("prf", ContentType::Application),
+        ("pgp", ContentType::Application),
+        ("clkx", ContentType::Application),
+        ("mbox", ContentType::Application),
+        ("bsp", ContentType::Model),
+        ("msl", ContentType::Application),
+        ("xenc", ContentType::Application),
+        ("ivp", ContentType::Application),
+        ("adts", ContentType::Audio),
+        ("dot", ContentType::Application),
+        ("jxss", ContentType::Image),
+        ("xhtm", ContentType::Application),
+        ("bdm", ContentType::Application),
+        ("age", ContentType::Application),
+        ("c11amc", ContentType::Application),
+        ("asx", ContentType::Video),
+        ("php", ContentType::Application),
+        ("qbo", ContentType::Application),
+        ("ez2", ContentType::Application),
+        ("udeb", ContentType::Application),
+        ("pcf", ContentType::Application),
+        ("mpc", ContentType::Application),
+        ("adp", ContentType::Audio),
+        ("x_b", ContentType::Model),
+        ("nsc", ContentType::Application),
+        ("pcurl", ContentType::Application),
+        ("skt", ContentType::Application),
+        ("ecelp7470", ContentType::Audio),
+        ("gim", ContentType::Application),
+        ("spl", ContentType::Application),
+        ("mag", ContentType::Application),
+        ("jxl", ContentType::Image),
+        ("tsv", ContentType::Text),
+        ("rs", ContentType::Application),
+        ("omdoc", ContentType::Application),
+        ("bat", ContentType::Application),
+        ("cu", ContentType::Application),
+        ("sgl", ContentType::Application),
+        ("vcard", ContentType::Text),
+        ("clkt", ContentType::Application),
+        ("csp", ContentType::Application),
+        ("boz", ContentType::Application),
+        ("arj", ContentType::Application),
+        ("bmp", ContentType::Image),
+        ("gca", ContentType::Application),
+        ("mcd", ContentType::Application),
+        ("jpgv", ContentType::Video),
+        ("zmm", ContentType::Application),
+        ("vrml", ContentType::Model),
+        ("png", ContentType::Image),
+        ("jpg2", ContentType::Image),
+        ("3gpp", ContentType::Audio),
+        ("sig", ContentType::Application),
+        ("sub", ContentType::Image),
+        ("mjp2", ContentType::Video),
+        ("z6", ContentType::Application),
+        ("webp", ContentType::Image),
+        ("rl", ContentType::Application),
+        ("btf", ContentType::Image),
+        ("cdbcmsg", ContentType::Application),
+        ("json5", ContentType::Application),
+        ("fpx", ContentType::Image),
+        ("ecma", ContentType::Application),
+        ("sls", ContentType::Application),
+        ("rusd", ContentType::Application),
+        ("clkp", ContentType::Application),
+        ("gram", ContentType::Application),
+        ("pages", ContentType::Application),
+        ("xls", ContentType::Application),
+        ("xcs", ContentType::Application),
+        ("ogx", ContentType::Application),
+        ("listafp", ContentType::Application),
+        ("g3", ContentType::Image),
+        ("mpeg", ContentType::Video),
+        ("cmx", ContentType::Image),
+        ("mmr", ContentType::Image),
+        ("cmc", ContentType::Application),
+        ("uvvu", ContentType::Video),
+        ("stylus", ContentType::Text),
+        ("sql", ContentType::Application),
+        ("fh", ContentType::Image),
+        ("smv", ContentType::Video),
+        ("uvvg", ContentType::Image),
+        ("cla", ContentType::Application),
+        ("dssc", ContentType::Application),
+        ("exi", ContentType::Application),
+        ("h261", ContentType::Video),
+        ("its", ContentType::Application),
+        ("distz", ContentType::Application),
+        ("jpgm", ContentType::Image),
+        ("mesh", ContentType::Model),
+        ("rpm", ContentType::Application),
+        ("pm", ContentType::Application),
+        ("dts", ContentType::Audio),
+        ("azv", ContentType::Image),
+        ("psb", ContentType::Application),
+        ("pti", ContentType::Image),
+        ("tex", ContentType::Application),
+        ("dcr", ContentType::Application),
+        ("lua", ContentType::Text),
+        ("itp", ContentType::Application),
+        ("rapd", ContentType::Application),
+        ("gnumeric", ContentType::Application),
+        ("scurl", ContentType::Text),
+        ("kmz", ContentType::Application),
+        ("scs", ContentType::Application),
+        ("jpm", ContentType::Image),
+        ("z3", ContentType::Application),
+        ("wcm", ContentType::Application),
+        ("pic", ContentType::Image),
+        ("hsj2", ContentType::Image),
+        ("esf", ContentType::Application),
+        ("relo", ContentType::Application),
+        ("curl", ContentType::Text),
+        ("org", ContentType::Application),
+        ("elc", ContentType::Application),
+        ("clp", ContentType::Application),
+        ("atomsvc", ContentType::Application),
+        ("vss", ContentType::Application),
+        ("jlt", ContentType::Application),
+        ("bary", ContentType::Model),
+        ("sea", ContentType::Application),
+        ("xpi", ContentType::Application),
+        ("md", ContentType::Text),
+        ("frame", ContentType::Application),
+        ("ktz", ContentType::Application),
+        ("xap", ContentType::Application),
+        ("xpr", ContentType::Application),
+        ("kon", ContentType::Application),
+        ("nt", ContentType::Application),
+        ("jsx", ContentType::Text),
+        ("dxr", ContentType::Application),
+        ("sxm", ContentType::Application),
+        ("maker", ContentType::Application),
+        ("wmlsc", ContentType::Application),
+        ("uvp", ContentType::Video),
+        ("odm", ContentType::Application),
+        ("svgz", ContentType::Image),
+        ("xhtml", ContentType::Application),
+        ("gsheet", ContentType::Application),
+        ("csv", ContentType::Text),
+        ("bh2", ContentType::Application),
+        ("xz", ContentType::Application),
+        ("xul", ContentType::Application),
+        ("sgm", ContentType::Text),
+        ("z7", ContentType::Application),
+        ("dwd", ContentType::Application),
+        ("ddf", ContentType::Application),
+        ("cgm", ContentType::Image),
+        ("pptx", ContentType::Application),
+        ("7z", ContentType::Application),
+        ("pct", ContentType::Image),
+        ("rtx", ContentType::Text),
+        ("lha", ContentType::Application),
+        ("potm", ContentType::Application),
+        ("wdp", ContentType::Image),
+        ("styl", ContentType::Text),
+        ("gxf", ContentType::Application),
+        ("sxi", ContentType::Application),
+        ("hjson", ContentType::Application),
+        ("tcap", ContentType::Application),
+        ("ott", ContentType::Application),
+        ("vbox-extpack", ContentType::Application),
+        ("mrcx", ContentType::Application),
+        ("azw", ContentType::Application),
+        ("eva", ContentType::Application),
+        ("npx", ContentType::Image),
+        ("std", ContentType::Application),
+        ("rep", ContentType::Application),
+        ("oth", ContentType::Application),
+        ("pbm", ContentType::Image),
+        ("key", ContentType::Application),
+        ("sisx", ContentType::Application),
+        ("skm", ContentType::Application),
+        ("iif", ContentType::Application),
+        ("emz", ContentType::Application),
+        ("sis", ContentType::Application),
+        ("rdf", ContentType::Application),
+        ("3gp", ContentType::Video),
+        ("dd2", ContentType::Application),
+        ("cba", ContentType::Application),
+        ("g2w", ContentType::Application),
+        ("xpx", ContentType::Application),
+        ("x3db", ContentType::Model),
+        ("vob", ContentType::Video),
+        ("jt", ContentType::Model),
+        ("m4u", ContentType::Video),
+        ("aam", ContentType::Application),
+        ("c4g", ContentType::Application),
+        ("pde", ContentType::Text),
+        ("odb", ContentType::Application),
+        ("dna", ContentType::Application),
+        ("oxps", ContentType::Application),
+        ("opf", ContentType::Application),
+        ("ttf", ContentType::Font),
+        ("vsw", ContentType::Application),
+        ("cmp", ContentType::Application),
+        ("ecelp4800", ContentType::Audio),
+        ("mov", ContentType::Video),
+        ("plf", ContentType::Application),
+        ("less", ContentType::Text),
+        ("appx", ContentType::Application),
+        ("oa3", ContentType::Application),
+        ("evy", ContentType::Application),
+        ("zir", ContentType::Application),
+        ("mar", ContentType::Application),
+        ("mrc", ContentType::Application),
+        ("qps", ContentType::Application),
+        ("html", ContentType::Text),
+        ("wmlc", ContentType::Application),
+        ("qwt", ContentType::Application),
+        ("wri", ContentType::Application),
+        ("woff", ContentType::Font),
+        ("xsf", ContentType::Application),
+        ("pls", ContentType::Application),
+        ("tsd", ContentType::Application),
+        ("xlsb", ContentType::Application),
+        ("gdl", ContentType::Model),
+        ("mpp", ContentType::Application),
+        ("nsf", ContentType::Application),
+        ("mp4", ContentType::Video),
+        ("lnk", ContentType::Application),
+        ("sm", ContentType::Application),
+        ("snd", ContentType::Audio),
+        ("movie", ContentType::Video),
+        ("cdxml", ContentType::Application),
+        ("obgx", ContentType::Application),
+        ("3dml", ContentType::Text),
+        ("fti", ContentType::Application),
+        ("plc", ContentType::Application),
+        ("cod", ContentType::Application),
+        ("rsheet", ContentType::Application),
+        ("geo", ContentType::Application),
+        ("mkd", ContentType::Text),
+        ("p7b", ContentType::Application),
+        ("c", ContentType::Text),
+        ("dotm", ContentType::Application),
+        ("fnc", ContentType::Application),
+        ("m2t", ContentType::Video),
+        ("tar", ContentType::Application),
+        ("icm", ContentType::Application),
+        ("gslides", ContentType::Application),
+        ("ms", ContentType::Text),
+        ("dtd", ContentType::Application),
+        ("bdo", ContentType::Application),
+        ("deploy", ContentType::Application),
+        ("eml", ContentType::Message),
+        ("n-gage", ContentType::Application),
+        ("siv", ContentType::Application),
+        ("xltx", ContentType::Application),
+        ("sc", ContentType::Application),
+        ("dist", ContentType::Application),
+        ("psd", ContentType::Image),
+        ("tcl", ContentType::Application),
+        ("dpx", ContentType::Image),
+        ("m2v", ContentType::Video),
+        ("nlu", ContentType::Application),
+        ("nnw", ContentType::Application),
+        ("fdf", ContentType::Application),
+        ("hlp", ContentType::Application),
+        ("rtf", ContentType::Application),
+        ("gmx", ContentType::Application),
+        ("jpf", ContentType::Image),
+        ("xpw", ContentType::Application),
+        ("pkpass", ContentType::Application),
+        ("m13", ContentType::Application),
+        ("atomcat", ContentType::Application),
+        ("pot", ContentType::Application),
+        ("qwd", ContentType::Application),
+        ("z8", ContentType::Application),
+        ("ace", ContentType::Application),
+        ("mp2", ContentType::Audio),
+        ("z5", ContentType::Application),
+        ("pac", ContentType::Application),
+        ("pps", ContentType::Application),
+        ("p", ContentType::Text),
+        ("au", ContentType::Audio),
+        ("cst", ContentType::Application),
+        ("rdz", ContentType::Application),
+        ("metalink", ContentType::Application),
+        ("mwf", ContentType::Application),
+        ("aac", ContentType::Audio),
+        ("setpay", ContentType::Application),
+        ("mp4v", ContentType::Video),
+        ("webm", ContentType::Video),
+        ("msixbundle", ContentType::Application),
+        ("xlsx", ContentType::Application),
+        ("dart", ContentType::Application),
+        ("cpp", ContentType::Text),
+        ("msi", ContentType::Application),
+        ("drle", ContentType::Image),
+        ("otf", ContentType::Font),
+        ("ftc", ContentType::Application),
+        ("cer", ContentType::Application),
+        ("mcurl", ContentType::Text),
+        ("vds", ContentType::Model),
+        ("spp", ContentType::Application),
+        ("cat", ContentType::Application),
+        ("blorb", ContentType::Application),
+        ("wmf", ContentType::Application),
+        ("jxra", ContentType::Image),
+        ("xlf", ContentType::Application),
+        ("dbk", ContentType::Application),
+        ("woff2", ContentType::Font),
+        ("xfdf", ContentType::Application),
+        ("uvs", ContentType::Video),
+        ("exp", ContentType::Application),
+        ("irm", ContentType::Application),
+        ("m2a", ContentType::Audio),
+        ("cb7", ContentType::Application),
+        ("oxt", ContentType::Application),
+        ("kdbx", ContentType::Application),
+        ("tpl", ContentType::Application),
+        ("chm", ContentType::Application),
+        ("msf", ContentType::Application),
+        ("sldx", ContentType::Application),
+        ("fzs", ContentType::Application),
+        ("onetmp", ContentType::Application),
+        ("rgb", ContentType::Image),
+        ("wps", ContentType::Application),
+        ("t3", ContentType::Application),
+        ("hbs", ContentType::Text),
+        ("hps", ContentType::Application),
+        ("der", ContentType::Application),
+        ("kml", ContentType::Application),
+        ("tao", ContentType::Application),
+        ("saf", ContentType::Application),
+        ("kne", ContentType::Application),
+        ("edm", ContentType::Application),
+        ("x3dz", ContentType::Model),
+        ("ots", ContentType::Application),
+        ("xlt", ContentType::Application),
+        ("cdkey", ContentType::Application),
+        ("afp", ContentType::Application),
+        ("ami", ContentType::Application),
+        ("sfd-hdstx", ContentType::Application),
+        ("fits", ContentType::Image),
+        ("com", ContentType::Application),
+        ("hpgl", ContentType::Application),
+        ("irp", ContentType::Application),
+        ("ecelp9600", ContentType::Audio),
+        ("xar", ContentType::Application),
+        ("mkv", ContentType::Video),
+        ("opus", ContentType::Audio),
+        ("disposition-notification", ContentType::Message),
+        ("bdf", ContentType::Application),
+        ("zip", ContentType::Application),
+        ("kwt", ContentType::Application),
+        ("nq", ContentType::Application),
+        ("luac", ContentType::Application),
+        ("pki", ContentType::Application),
+        ("smf", ContentType::Application),
+        ("fly", ContentType::Text),
+        ("mng", ContentType::Video),
+        ("xdp", ContentType::Application),
+        ("rld", ContentType::Application),
+        ("cil", ContentType::Application),
+        ("appcache", ContentType::Text),
+        ("jar", ContentType::Application),
+        ("stf", ContentType::Application),
+        ("esa", ContentType::Application),
+        ("dataless", ContentType::Application),
+        ("usda", ContentType::Model),
+        ("text", ContentType::Text),
+        ("mny", ContentType::Application),
+        ("dtb", ContentType::Application),
+        ("emma", ContentType::Application),
+        ("mpd", ContentType::Application),
+        ("ifb", ContentType::Text),
+        ("txf", ContentType::Application),
+        ("jade", ContentType::Text),
+        ("pclxl", ContentType::Application),
+        ("gex", ContentType::Application),
+        ("stl", ContentType::Application),
+        ("meta4", ContentType::Application),
+        ("rcprofile", ContentType::Application),
+        ("sus", ContentType::Application),
+        ("amlx", ContentType::Application),
+        ("wgt", ContentType::Application),
+        ("cryptonote", ContentType::Application),
+        ("oda", ContentType::Application),
+        ("wsdl", ContentType::Application),
+        ("dcurl", ContentType::Text),
+        ("fh4", ContentType::Image),
+        ("wif", ContentType::Application),
+        ("vtt", ContentType::Text),
+        ("sil", ContentType::Audio),
+        ("application", ContentType::Application),
+        ("vcd", ContentType::Application),
+        ("musicxml", ContentType::Application),
+        ("spq", ContentType::Application),
+        ("musd", ContentType::Application),
+        ("ear", ContentType::Application),
+        ("cdy", ContentType::Application),
+        ("daf", ContentType::Application),
+        ("fgd", ContentType::Application),
+        ("joda", ContentType::Application),
+        ("sfv", ContentType::Text),
+        ("xif", ContentType::Image),
+        ("mads", ContentType::Application),
+        ("latex", ContentType::Application),
+        ("h", ContentType::Text),
+        ("mpm", ContentType::Application),
+        ("dds", ContentType::Image),
+        ("x_t", ContentType::Model),
+        ("mdi", ContentType::Image),
+        ("sgi", ContentType::Image),
+        ("src", ContentType::Application),
+        ("jisp", ContentType::Application),
+        ("vdi", ContentType::Application),
+        ("bed", ContentType::Application),
+        ("fg5", ContentType::Application),
+        ("scd", ContentType::Application),
+        ("ifm", ContentType::Application),
+        ("mp3", ContentType::Audio),
+        ("blb", ContentType::Application),
+        ("fvt", ContentType::Video),
+        ("ulx", ContentType::Application),
+        ("raml", ContentType::Application),
+        ("trig", ContentType::Application),
+        ("h264", ContentType::Video),
+        ("sti", ContentType::Application),
+        ("acu", ContentType::Application),
+        ("wvx", ContentType::Video),
+        ("vor", ContentType::Application),
+        ("osf", ContentType::Application),
+        ("fst", ContentType::Image),
+        ("pgm", ContentType::Image),
+        ("odg", ContentType::Application),
+        ("appxbundle", ContentType::Application),
+        ("ico", ContentType::Image),
+        ("lbe", ContentType::Application),
+        ("jhc", ContentType::Image),
+        ("aep", ContentType::Application),
+        ("cpt", ContentType::Application),
+        ("ims", ContentType::Application),
+        ("dwf", ContentType::Model),
+        ("xvml", ContentType::Application),
+        ("vis", ContentType::Application),
+        ("apk", ContentType::Application),
+        ("ggb", ContentType::Application),
+        ("xpl", ContentType::Application),
+        ("mpt", ContentType::Application),
+        ("flw", ContentType::Application),
+        ("wadl", ContentType::Application),
+        ("wdb", ContentType::Application),
+        ("setreg", ContentType::Application),
+        ("in", ContentType::Text),
+        ("ris", ContentType::Application),
+        ("ppsm", ContentType::Application),
+        ("otg", ContentType::Application),
+        ("trm", ContentType::Application),
+        ("uvva", ContentType::Audio),
+        ("uri", ContentType::Text),
+        ("m21", ContentType::Application),
+        ("w3d", ContentType::Application),
+        ("jsonld", ContentType::Application),
+        ("xhvml", ContentType::Application),
+        ("teacher", ContentType::Application),
+        ("roa", ContentType::Application),
+        ("zirz", ContentType::Application),
+        ("grxml", ContentType::Application),
+        ("p8", ContentType::Application),
+        ("uvt", ContentType::Application),
+        ("uo", ContentType::Application),
+        ("skd", ContentType::Application),
+        ("heifs", ContentType::Image),
+        ("gpx", ContentType::Application),
+        ("lbd", ContentType::Application),
+        ("pdf", ContentType::Application),
+        ("uvz", ContentType::Application),
+        ("fh7", ContentType::Image),
+        ("wmv", ContentType::Video),
+        ("obj", ContentType::Application),
+        ("xel", ContentType::Application),
+        ("kia", ContentType::Application),
+        ("s3m", ContentType::Audio),
+        ("clkk", ContentType::Application),
+        ("ogv", ContentType::Video),
+        ("f4v", ContentType::Video),
+        ("htc", ContentType::Text),
+        ("jad", ContentType::Text),
+        ("shtml", ContentType::Text),
+        ("ait", ContentType::Application),
+        ("sieve", ContentType::Application),
+        ("m1v", ContentType::Video),
+        ("mmf", ContentType::Application),
+        ("coffee", ContentType::Text),
+        ("avcs", ContentType::Image),
+        ("lvp", ContentType::Audio),
+        ("xlam", ContentType::Application),
+        ("gtm", ContentType::Application),
+        ("bpk", ContentType::Application),
+        ("xdm", ContentType::Application),
+        ("st", ContentType::Application),
+        ("rss", ContentType::Application),
+        ("et3", ContentType::Application),
+        ("cfs", ContentType::Application),
+        ("xlm", ContentType::Application),
+        ("tif", ContentType::Image),
+        ("azf", ContentType::Application),
+        ("xer", ContentType::Application),
+        ("pyo", ContentType::Model),
+        ("pcl", ContentType::Application),
+        ("gv", ContentType::Text),
+        ("heif", ContentType::Image),
+        ("rq", ContentType::Application),
+        ("pkipath", ContentType::Application),
+        ("ovf", ContentType::Application),
+        ("ogg", ContentType::Audio),
+        ("link66", ContentType::Application),
+        ("senmlx", ContentType::Application),
+        ("mpkg", ContentType::Application),
+        ("h263", ContentType::Video),
+        ("texi", ContentType::Application),
+        ("ttl", ContentType::Text),
+        ("uvvf", ContentType::Application),
+        ("c11amz", ContentType::Application),
+        ("sgml", ContentType::Text),
+        ("stc", ContentType::Application),
+        ("uris", ContentType::Text),
+        ("ggt", ContentType::Application),
+        ("aiff", ContentType::Audio),
+        ("pskcxml", ContentType::Application),
+        ("sema", ContentType::Application),
+        ("sxc", ContentType::Application),
+        ("ac", ContentType::Application),
+        ("shex", ContentType::Text),
+        ("held", ContentType::Application),
+        ("fig", ContentType::Application),
+        ("me", ContentType::Text),
+        ("tk", ContentType::Application),
+        ("dib", ContentType::Image),
+        ("dmg", ContentType::Application),
+        ("dxp", ContentType::Application),
+        ("dir", ContentType::Application),
+        ("uvm", ContentType::Video),
+        ("nnd", ContentType::Application),
+        ("pfr", ContentType::Application),
+        ("u8dsn", ContentType::Message),
+        ("fxp", ContentType::Application),
+        ("nbp", ContentType::Application),
+        ("x32", ContentType::Application),
+        ("hdf", ContentType::Application),
+        ("rif", ContentType::Application),
+        ("uvvz", ContentType::Application),
+        ("pwn", ContentType::Application),
+        ("hej2", ContentType::Image),
+        ("pfa", ContentType::Application),
+        ("i2g", ContentType::Application),
+        ("flo", ContentType::Application),
+        ("crt", ContentType::Application),
+        ("gbr", ContentType::Application),
+        ("yml", ContentType::Text),
+        ("uvvx", ContentType::Application),
+        ("pcx", ContentType::Image),
+        ("fhc", ContentType::Image),
+        ("mbk", ContentType::Application),
+        ("u3d", ContentType::Model),
+        ("odf", ContentType::Application),
+        ("vst", ContentType::Application),
+        ("vcf", ContentType::Text),
+        ("mfm", ContentType::Application),
+        ("lzh", ContentType::Application),
+        ("dmp", ContentType::Application),
+        ("hdd", ContentType::Application),
+        ("gre", ContentType::Application),
+        ("cdf", ContentType::Application),
+        ("mods", ContentType::Application),
+        ("ngdat", ContentType::Application),
+        ("spx", ContentType::Audio),
+        ("snf", ContentType::Application),
+        ("x3d", ContentType::Model),
+        ("vmdk", ContentType::Application),
+        ("emotionml", ContentType::Application),
+        ("aifc", ContentType::Audio),
+        ("wgsl", ContentType::Text),
+        ("viv", ContentType::Video),
+        ("cap", ContentType::Application),
+        ("jpe", ContentType::Image),
+        ("qam", ContentType::Application),
+        ("dwg", ContentType::Image),
+        ("shar", ContentType::Application),
+        ("mpg4", ContentType::Application),
+        ("atc", ContentType::Application),
+        ("uvd", ContentType::Application),
+        ("c4f", ContentType::Application),
+        ("gxt", ContentType::Application),
+        ("aw", ContentType::Application),
+        ("spdx", ContentType::Text),
+        ("ktx2", ContentType::Image),
+        ("mjs", ContentType::Text),
+        ("ics", ContentType::Text),
+        ("fdt", ContentType::Application),
+        ("rsat", ContentType::Application),
+        ("ccxml", ContentType::Application),
+        ("uvvi", ContentType::Image),
+        ("s", ContentType::Text),
+        ("sdkm", ContentType::Application),
+        ("ppd", ContentType::Application),
+        ("uvi", ContentType::Image),
+        ("xns", ContentType::Application),
+        ("p10", ContentType::Application),
+        ("dic", ContentType::Text),
+        ("xbm", ContentType::Image),
+        ("ppm", ContentType::Image),
+        ("msty", ContentType::Application),
+        ("sid", ContentType::Image),
+        ("ras", ContentType::Image),
+        ("iges", ContentType::Model),
+        ("cdmio", ContentType::Application),
+        ("ksp", ContentType::Application),
+        ("heic", ContentType::Image),
+        ("wrl", ContentType::Model),
+        ("ttml", ContentType::Application),
+        ("afm", ContentType::Application),
+        ("lrm", ContentType::Application),
+        ("xlsm", ContentType::Application),
+        ("oga", ContentType::Audio),
+        ("cc", ContentType::Text),
+        ("emf", ContentType::Application),
+        ("mpn", ContentType::Application),
+        ("def", ContentType::Text),
+        ("log", ContentType::Text),
+        ("igm", ContentType::Application),
+        ("xbd", ContentType::Application),
+        ("pfm", ContentType::Application),
+        ("nns", ContentType::Application),
+        ("p7c", ContentType::Application),
+        ("tfx", ContentType::Image),
+        ("xht", ContentType::Application),
+        ("markdown", ContentType::Text),
+        ("wtb", ContentType::Application),
+        ("vcx", ContentType::Application),
+        ("xwd", ContentType::Image),
+        ("java", ContentType::Text),
+        ("tga", ContentType::Image),
+        ("fe_launch", ContentType::Application),
+        ("c4d", ContentType::Application),
+        ("jsonml", ContentType::Application),
+        ("sdc", ContentType::Application),
+        ("ai", ContentType::Application),
+        ("vhd", ContentType::Application),
+        ("c4p", ContentType::Application),
+        ("ppsx", ContentType::Application),
+        ("p7s", ContentType::Application),
+        ("f77", ContentType::Text),
+        ("mxmf", ContentType::Audio),
+        ("apng", ContentType::Image),
+        ("ntf", ContentType::Application),
+        ("jph", ContentType::Image),
+        ("dxf", ContentType::Image),
+        ("eol", ContentType::Audio),
+        ("rmvb", ContentType::Application),
+        ("mlp", ContentType::Application),
+        ("jxsi", ContentType::Image),
+        ("cct", ContentType::Application),
+        ("oti", ContentType::Application),
+        ("fo", ContentType::Application),
+        ("sbml", ContentType::Application),
+        ("conf", ContentType::Text),
+        ("man", ContentType::Text),
+        ("hbci", ContentType::Application),
+        ("btif", ContentType::Image),
+        ("rnc", ContentType::Application),
+        ("mts", ContentType::Model),
+        ("hvs", ContentType::Application),
+        ("hvd", ContentType::Application),
+        ("sdp", ContentType::Application),
+        ("mp4a", ContentType::Audio),
+        ("box", ContentType::Application),
+        ("f90", ContentType::Text),
+        ("c4u", ContentType::Application),
+        ("bz2", ContentType::Application),
+        ("xsm", ContentType::Application),
+        ("seed", ContentType::Application),
+        ("ps", ContentType::Application),
+        ("n3", ContentType::Text),
+        ("sxg", ContentType::Application),
+        ("opml", ContentType::Text),
+        ("kfo", ContentType::Application),
+        ("thmx", ContentType::Application),
+        ("stpxz", ContentType::Model),
+        ("prc", ContentType::Application),
+        ("xdcf", ContentType::Application),
+        ("msp", ContentType::Application),
+        ("csh", ContentType::Application),
+        ("edx", ContentType::Application),
+        ("silo", ContentType::Model),
+        ("wbs", ContentType::Application),
+        ("ufdl", ContentType::Application),
+        ("gqf", ContentType::Application),
+        ("bmml", ContentType::Application),
+        ("asc", ContentType::Application),
+        ("dotx", ContentType::Application),
+        ("sda", ContentType::Application),
+        ("mdx", ContentType::Text),
+        ("cbz", ContentType::Application),
+        ("xo", ContentType::Application),
+        ("mvt", ContentType::Application),
+        ("list3820", ContentType::Application),
+        ("msh", ContentType::Model),
+        ("gif", ContentType::Image),
+        ("xlw", ContentType::Application),
+        ("uvvs", ContentType::Video),
+        ("u8hdr", ContentType::Message),
+        ("mp21", ContentType::Application),
+        ("dra", ContentType::Audio),
+        ("apr", ContentType::Application),
+        ("pdb", ContentType::Application),
+        ("uvf", ContentType::Application),
+        ("nml", ContentType::Application),
+        ("davmount", ContentType::Application),
+        ("uvu", ContentType::Video),
+        ("wmz", ContentType::Application),
+        ("3g2", ContentType::Video),
+        ("potx", ContentType::Application),
+        ("m3u", ContentType::Audio),
+        ("wbmp", ContentType::Image),
+        ("cdmic", ContentType::Application),
+        ("etx", ContentType::Text),
+        ("cii", ContentType::Application),
+        ("mka", ContentType::Audio),
+        ("azs", ContentType::Application),
+        ("spot", ContentType::Text),
+        ("ppam", ContentType::Application),
+        ("xav", ContentType::Application),
+        ("mgz", ContentType::Application),
+        ("jxrs", ContentType::Image),
+        ("wmls", ContentType::Text),
+        ("uvvh", ContentType::Video),
+        ("ncx", ContentType::Application),
+        ("stk", ContentType::Application),
+        ("z1", ContentType::Application),
+        ("pqa", ContentType::Application),
+        ("ipk", ContentType::Application),
+        ("hvp", ContentType::Application),
+        ("t", ContentType::Text),
+        ("igx", ContentType::Application),
+        ("pkg", ContentType::Application),
+        ("sitx", ContentType::Application),
+        ("sv4cpio", ContentType::Application),
+        ("midi", ContentType::Audio),
+        ("mdb", ContentType::Application),
+        ("heics", ContentType::Image),
+        ("jnlp", ContentType::Application),
+        ("wks", ContentType::Application),
+        ("mtl", ContentType::Model),
+        ("nfo", ContentType::Text),
+        ("f", ContentType::Text),
+        ("hh", ContentType::Text),
+        ("litcoffee", ContentType::Text),
+        ("doc", ContentType::Application),
+        ("jpx", ContentType::Image),
+        ("js", ContentType::Application),
+        ("book", ContentType::Application),
+        ("flac", ContentType::Audio),
+        ("ssml", ContentType::Application),
+        ("rms", ContentType::Application),
+        ("see", ContentType::Application),
+        ("x3dvz", ContentType::Model),
+        ("fh5", ContentType::Image),
+        ("css", ContentType::Text),
+        ("ktx", ContentType::Image),
+        ("ova", ContentType::Application),
+        ("clkw", ContentType::Application),
+        ("list", ContentType::Text),
+        ("crd", ContentType::Application),
+        ("qfx", ContentType::Application),
+        ("ustar", ContentType::Application),
+        ("es3", ContentType::Application),
+        ("djv", ContentType::Image),
+        ("dump", ContentType::Application),
+        ("lasxml", ContentType::Application),
+        ("ei6", ContentType::Application),
+        ("ymp", ContentType::Text),
+        ("sh", ContentType::Application),
+        ("wav", ContentType::Audio),
+        ("appinstaller", ContentType::Application),
+        ("xvm", ContentType::Application),
+        ("sdw", ContentType::Application),
+        ("atom", ContentType::Application),
+        ("xla", ContentType::Application),
+        ("wma", ContentType::Audio),
+        ("wpd", ContentType::Application),
+        ("mxs", ContentType::Application),
+        ("osfpvg", ContentType::Application),
+        ("mqy", ContentType::Application),
+        ("rip", ContentType::Audio),
+        ("obd", ContentType::Application),
+        ("jng", ContentType::Image),
+        ("p7r", ContentType::Application),
+        ("xpm", ContentType::Image),
+        ("hpid", ContentType::Application),
+        ("so", ContentType::Application),
+        ("acutc", ContentType::Application),
+        ("acc", ContentType::Application),
+        ("jardiff", ContentType::Application),
+        ("aif", ContentType::Audio),
+        ("cjs", ContentType::Application),
+        ("fm", ContentType::Application),
+        ("flx", ContentType::Text),
+        ("ghf", ContentType::Application),
+        ("fli", ContentType::Video),
+        ("onepkg", ContentType::Application),
+        ("fbs", ContentType::Image),
+        ("txt", ContentType::Text),
+        ("ivu", ContentType::Application),
+        ("roff", ContentType::Text),
+        ("gz", ContentType::Application),
+        ("crx", ContentType::Application),
+        ("vsd", ContentType::Application),
+        ("qt", ContentType::Video),
+        ("uvvp", ContentType::Video),
+        ("chrt", ContentType::Application),
+        ("qxl", ContentType::Application),
+        ("xaml", ContentType::Application),
+        ("x3dv", ContentType::Model),
+        ("pgn", ContentType::Application),
+        ("uvx", ContentType::Application),
+        ("ez", ContentType::Application),
+        ("eps", ContentType::Application),
+        ("lostxml", ContentType::Application),
+        ("rmi", ContentType::Audio),
+        ("uvg", ContentType::Image),
+        ("arc", ContentType::Application),
+        ("odc", ContentType::Application),
+        ("htm", ContentType::Text),
+        ("mmd", ContentType::Application),
+        ("pfb", ContentType::Application),
+        ("smi", ContentType::Application),
+        ("cld", ContentType::Model),
+        ("unityweb", ContentType::Application),
+        ("lrf", ContentType::Application),
+        ("dms", ContentType::Application),
+        ("b16", ContentType::Image),
+        ("smzip", ContentType::Application),
+        ("svd", ContentType::Application),
+        ("class", ContentType::Application),
+        ("dgc", ContentType::Application),
+        ("sv4crc", ContentType::Application),
+        ("xdw", ContentType::Application),
+        ("wasm", ContentType::Application),
+        ("gtar", ContentType::Application),
+        ("exe", ContentType::Application),
+        ("maei", ContentType::Application),
+        ("wspolicy", ContentType::Application),
+        ("p7m", ContentType::Application),
+        ("pl", ContentType::Application),
+        ("crl", ContentType::Application),
+        ("vox", ContentType::Application),
+        ("fxpl", ContentType::Application),
+        ("htke", ContentType::Application),
+        ("avi", ContentType::Video),
+        ("les", ContentType::Application),
+        ("odi", ContentType::Application),
+        ("csl", ContentType::Application),
+        ("ltf", ContentType::Application),
+        ("rng", ContentType::Application),
+        ("jpg", ContentType::Image),
+        ("td", ContentType::Application),
+        ("tra", ContentType::Application),
+        ("psf", ContentType::Application),
+        ("car", ContentType::Application),
+        ("uvvt", ContentType::Application),
+        ("ptid", ContentType::Application),
+        ("pcap", ContentType::Application),
+        ("gph", ContentType::Application),
+        ("gtw", ContentType::Model),
+        ("dpg", ContentType::Application),
+        ("dtshd", ContentType::Audio),
+        ("3ds", ContentType::Image),
+        ("webapp", ContentType::Application),
+        ("slm", ContentType::Text),
+        ("mxu", ContentType::Video),
+        ("atomdeleted", ContentType::Application),
+        ("jpeg", ContentType::Image),
+        ("grv", ContentType::Application),
+        ("oas", ContentType::Application),
+        ("rar", ContentType::Application),
+        ("wad", ContentType::Application),
+        ("oprc", ContentType::Application),
+        ("mj2", ContentType::Video),
+        ("ktr", ContentType::Application),
+        ("otp", ContentType::Application),
+        ("odt", ContentType::Application),
+        ("deb", ContentType::Application),
+        ("dvi", ContentType::Application),
+        ("mks", ContentType::Video),
+        ("xm", ContentType::Audio),
+        ("stw", ContentType::Application),
+        ("asf", ContentType::Video),
+        ("z4", ContentType::Application),
+        ("m4v", ContentType::Video),
+        ("mb", ContentType::Application),
+        ("vcs", ContentType::Text),
+        ("sse", ContentType::Application),
+        ("avif", ContentType::Image),
+        ("semd", ContentType::Application),
+        ("scss", ContentType::Text),
+        ("pya", ContentType::Audio),
+        ("vsf", ContentType::Application),
+        ("rsd", ContentType::Application),
+        ("jp2", ContentType::Image),
+        ("dis", ContentType::Application),
+        ("dae", ContentType::Model),
+        ("bdoc", ContentType::Application),
+        ("cdmia", ContentType::Application),
+        ("tmo", ContentType::Application),
+        ("usdz", ContentType::Model),
+        ("wm", ContentType::Video),
+        ("str", ContentType::Application),
+        ("abw", ContentType::Application),
+        ("for", ContentType::Text),
+        ("nb", ContentType::Application),
+        ("uoml", ContentType::Application),
+        ("ez3", ContentType::Application),
+        ("ma", ContentType::Application),
+        ("xps", ContentType::Application),
+        ("sfs", ContentType::Application),
+        ("install", ContentType::Application),
+        ("mus", ContentType::Application),
+        ("toml", ContentType::Application),
+        ("slim", ContentType::Text),
+        ("eot", ContentType::Application),
+        ("glb", ContentType::Model),
+        ("wax", ContentType::Audio),
+        ("mie", ContentType::Application),
+        ("ttc", ContentType::Font),
+        ("cco", ContentType::Application),
+        ("rpss", ContentType::Application),
+        ("msg", ContentType::Application),
+        ("mpe", ContentType::Video),
+        ("imp", ContentType::Application),
+        ("docx", ContentType::Application),
+        ("swa", ContentType::Application),
+        ("uvv", ContentType::Video),
+        ("wpl", ContentType::Application),
+        ("mif", ContentType::Application),
+        ("srt", ContentType::Application),
+    ],
+};
+
+#[rustfmt::skip]
+#[deprecated(note = "use from_extension::content_type")]
+pub const EXT_APP: [&str; 837] = ["123","1km","7z","aab","aam","aas","abw","ac","acc","ace","acu","acutc","aep","afm","afp","age","ahead","ai","air","ait","ami","aml","amlx","apk","appinstaller","application","appx","appxbundle","apr","arc","arj","asc","aso","atc","atom","atomcat","atomdeleted","atomsvc","atx","aw","azf","azs","azw","bat","bcpio","bdf","bdm","bdo","bdoc","bed","bh2","bin","blb","blorb","bmi","bmml","book","box","boz","bpk","buffer","bz","bz2","c11amc","c11amz","c4d","c4f","c4g","c4p","c4u","cab","cap","car","cat","cb7","cba","cbr","cbt","cbz","cco","cct","ccxml","cdbcmsg","cdf","cdfx","cdkey","cdmia","cdmic","cdmid","cdmio","cdmiq","cdxml","cdy","cer","cfs","chat","chm","chrt","cii","cil","cjs","cla","class","clkk","clkp","clkt","clkw","clkx","clp","cmc","cmp","cod","com","cpio","cpl","cpt","crd","crl","crt","crx","cryptonote","csh","csl","csp","cst","cu","cwl","cww","cxt","daf","dart","dataless","davmount","dbf","dbk","dcr","dd2","ddd","ddf","deb","deploy","der","dfac","dgc","dir","dis","dist","distz","dll","dmg","dmp","dms","dna","doc","docm","docx","dot","dotm","dotx","dp","dpg","dssc","dtb","dtd","dump","dvi","dwd","dxp","dxr","ear","ecma","edm","edx","efif","ei6","elc","emf","emma","emotionml","emz","eot","eps","epub","es3","esa","esf","et3","eva","evy","exe","exi","exp","ext","ez","ez2","ez3","fcdt","fcs","fdf","fdt","fe_launch","fg5","fgd","fig","flo","flw","fm","fnc","fo","frame","fsc","ftc","fti","fxp","fxpl","fzs","g2w","g3w","gac","gam","gbr","gca","gdoc","geo","geojson","gex","ggb","ggs","ggt","ghf","gim","gml","gmx","gnumeric","gph","gpx","gqf","gqs","gram","gramps","gre","grv","grxml","gsf","gsheet","gslides","gtar","gtm","gxf","gxt","gz","hal","hbci","hdd","hdf","held","hjson","hlp","hpgl","hpid","hps","hqx","htke","hvd","hvp","hvs","i2g","icc","icm","ifm","igl","igm","igx","iif","img","imp","ims","ink","inkml","install","iota","ipfix","ipk","irm","irp","iso","itp","its","ivp","ivu","jam","jar","jardiff","jisp","jlt","jnlp","joda","js","json","json5","jsonld","jsonml","karbon","kdbx","key","kfo","kia","kml","kmz","kne","knp","kon","kpr","kpt","kpxx","ksp","ktr","ktz","kwd","kwt","lasxml","latex","lbd","lbe","les","lgr","lha","link66","list3820","listafp","lnk","lostxml","lrf","lrm","ltf","luac","lwp","lzh","m13","m14","m21","m3u8","m4p","ma","mads","maei","mag","maker","map","mar","mathml","mb","mbk","mbox","mc1","mcd","mdb","meta4","metalink","mets","mfm","mft","mgp","mgz","mie","mif","mlp","mmd","mmf","mny","mobi","mods","mp21","mp4s","mpc","mpd","mpf","mpg4","mpkg","mpm","mpn","mpp","mpt","mpy","mqy","mrc","mrcx","mscml","mseed","mseq","msf","msg","msi","msix","msixbundle","msl","msm","msp","msty","mus","musd","musicxml","mvb","mvt","mwf","mxf","mxl","mxml","mxs","n-gage","nb","nbp","nc","ncx","ngdat","nitf","nlu","nml","nnd","nns","nnw","nq","nsc","nsf","nt","ntf","numbers","nzb","oa2","oa3","oas","obd","obgx","obj","oda","odb","odc","odf","odft","odg","odi","odm","odp","ods","odt","ogx","omdoc","onepkg","onetmp","onetoc","onetoc2","opf","oprc","org","osf","osfpvg","osm","otc","otg","oth","oti","otp","ots","ott","ova","ovf","owl","oxps","oxt","p10","p12","p7b","p7c","p7m","p7r","p7s","p8","pac","pages","paw","pbd","pcap","pcf","pcl","pclxl","pcurl","pdb","pdf","pem","pfa","pfb","pfm","pfr","pfx","pgn","pgp","php","pkg","pki","pkipath","pkpass","pl","plb","plc","plf","pls","pm","pml","portpkg","pot","potm","potx","ppam","ppd","pps","ppsm","ppsx","ppt","pptm","pptx","pqa","prc","pre","prf","provx","ps","psb","psf","pskcxml","ptid","pub","pvb","pwn","qam","qbo","qfx","qps","qwd","qwt","qxb","qxd","qxl","qxt","raml","rapd","rar","rcprofile","rdf","rdz","relo","rep","res","rif","ris","rl","rld","rm","rms","rmvb","rnc","rng","roa","rp9","rpm","rpss","rpst","rq","rs","rsat","rsd","rsheet","rss","rtf","run","rusd","saf","sbml","sc","scd","scm","scq","scs","sda","sdc","sdd","sdkd","sdkm","sdp","sdw","sea","see","seed","sema","semd","semf","senmlx","sensmlx","ser","setpay","setreg","sfd-hdstx","sfs","sgl","sh","shar","shf","sieve","sig","sis","sisx","sit","sitx","siv","skd","skm","skp","skt","sldm","sldx","sls","slt","sm","smf","smi","smil","smzip","snf","so","spc","spf","spl","spp","spq","sql","src","srt","sru","srx","ssdl","sse","ssf","ssml","st","stc","std","stf","sti","stk","stl","str","stw","sus","susp","sv4cpio","sv4crc","svc","svd","swa","swf","swi","swidtag","sxc","sxd","sxg","sxi","sxm","sxw","t3","taglet","tao","tar","tcap","tcl","td","teacher","tei","teicorpus","tex","texi","texinfo","tfi","tfm","thmx","tk","tmo","toml","torrent","tpl","tpt","tra","trig","trm","tsd","ttml","twd","twds","txd","txf","u32","ubj","udeb","ufd","ufdl","ulx","umj","unityweb","uo","uoml","ustar","utz","uvd","uvf","uvt","uvvd","uvvf","uvvt","uvvx","uvvz","uvx","uvz","vbox","vbox-extpack","vcd","vcg","vcx","vdi","vhd","vis","vmdk","vor","vox","vsd","vsf","vss","vst","vsw","vxml","w3d","wad","wadl","war","wasm","wbs","wbxml","wcm","wdb","webapp","webmanifest","wg","wgt","wif","wks","wmd","wmf","wmlc","wmlsc","wmz","wpd","wpl","wps","wqd","wri","wsdl","wspolicy","wtb","x32","xaml","xap","xar","xav","xbap","xbd","xca","xcs","xdcf","xdf","xdm","xdp","xdssc","xdw","xel","xenc","xer","xfdf","xfdl","xht","xhtm","xhtml","xhvml","xla","xlam","xlc","xlf","xlm","xls","xlsb","xlsm","xlsx","xlt","xltm","xltx","xlw","xml","xns","xo","xop","xpi","xpl","xpr","xps","xpw","xpx","xsd","xsf","xsl","xslt","xsm","xspf","xul","xvm","xvml","xz","yang","yin","z1","z2","z3","z4","z5","z6","z7","z8","zaz","zip","zir","zirz","zmm"];
+
+#[rustfmt::skip]
+#[deprecated(note = "use from_extension::content_type")]
+pub const EXT_AUDIO: [&str; 53] = ["3gpp","aac","adp","adts","aif","aifc","aiff","amr","au","caf","dra","dts","dtshd","ecelp4800","ecelp7470","ecelp9600","eol","flac","kar","lvp","m2a","m3a","m3u","m4a","mid","midi","mka","mp2","mp2a","mp3","mp4a","mpga","mxmf","oga","ogg","opus","pya","ra","ram","rip","rmi","rmp","s3m","sil","snd","spx","uva","uvva","wav","wax","weba","wma","xm"];
+
+#[rustfmt::skip]
+#[deprecated(note = "use from_extension::content_type")]
+pub const EXT_FONT: [&str; 5] = ["otf","ttc","ttf","woff","woff2"];
+
+#[rustfmt::skip]
+#[deprecated(note = "use from_extension::content_type")]
+pub const EXT_IMAGE: [&str; 102] = ["3ds","apng","avci","avcs","avif","azv","b16","bmp","btf","btif","cgm","cmx","dds","dib","djv","djvu","dpx","drle","dwg","dxf","exr","fbs","fh","fh4","fh5","fh7","fhc","fits","fpx","fst","g3","gif","heic","heics","heif","heifs","hej2","hsj2","ico","ief","jhc","jls","jng","jp2","jpe","jpeg","jpf","jpg","jpg2","jpgm","jph","jpm","jpx","jxl","jxr","jxra","jxrs","jxs","jxsc","jxsi","jxss","ktx","ktx2","mdi","mmr","npx","pbm","pct","pcx","pgm","pic","png","pnm","ppm","psd","pti","ras","rgb","rlc","sgi","sid","sub","svg","svgz","t38","tap","tfx","tga","tif","tiff","uvg","uvi","uvvg","uvvi","vtf","wbmp","wdp","webp","xbm","xif","xpm","xwd"];
+
+#[rustfmt::skip]
+#[deprecated(note = "use from_extension::content_type")]
+pub const EXT_MESSAGE: [&str; 8] = ["disposition-notification","eml","mime","u8dsn","u8hdr","u8mdn","u8msg","wsc"];
+
+#[rustfmt::skip]
+#[deprecated(note = "use from_extension::content_type")]
+pub const EXT_MODEL: [&str; 39] = ["3mf","bary","bsp","cld","dae","dwf","gdl","glb","gltf","gtw","iges","igs","jt","mesh","msh","mtl","mts","ogex","pyo","pyox","silo","stpx","stpxz","stpz","u3d","usda","usdz","vds","vrml","vtu","wrl","x3d","x3db","x3dbz","x3dv","x3dvz","x3dz","x_b","x_t"];
+
+#[rustfmt::skip]
+#[deprecated(note = "use from_extension::content_type")]
+pub const EXT_TEXT: [&str; 98] = ["3dml","appcache","asm","c","cc","coffee","conf","cpp","css","csv","curl","cxx","dcurl","def","dic","dsc","etx","f","f77","f90","flx","fly","for","ged","gv","h","hbs","hh","htc","htm","html","ics","ifb","in","ini","jad","jade","java","jsx","less","list","litcoffee","log","lua","man","manifest","markdown","mcurl","md","mdx","me","mjs","mkd","mml","ms","n3","nfo","opml","p","pas","pde","roff","rtx","s","sass","scss","scurl","sfv","sgm","sgml","shex","shtml","slim","slm","spdx","spot","styl","stylus","t","text","tr","tsv","ttl","txt","uri","uris","urls","uu","vcard","vcf","vcs","vtt","wgsl","wml","wmls","yaml","yml","ymp"];
+
+#[rustfmt::skip]
+#[deprecated(note = "use from_extension::content_type")]
+pub const EXT_VIDEO: [&str; 59] = ["3g2","3gp","asf","asx","avi","dvb","f4v","fli","flv","fvt","h261","h263","h264","jpgv","m1v","m2t","m2ts","m2v","m4s","m4u","m4v","mj2","mjp2","mk3d","mks","mkv","mng","mov","movie","mp4","mp4v","mpe","mpeg","mpg","mxu","ogv","pyv","qt","smv","ts","uvh","uvm","uvp","uvs","uvu","uvv","uvvh","uvvm","uvvp","uvvs","uvvu","uvvv","viv","vob","webm","wm","wmv","wmx","wvx"];
@@ -1,2 +0,0 @@
-#[rustfmt::skip]
-pub const EXT_FONT: [&str; 5] = ["otf","ttc","ttf","woff","woff2"];
@@ -1,2 +0,0 @@
-#[rustfmt::skip]
-pub const EXT_IMAGE: [&str; 102] = ["3ds","apng","avci","avcs","avif","azv","b16","bmp","btf","btif","cgm","cmx","dds","dib","djv","djvu","dpx","drle","dwg","dxf","exr","fbs","fh","fh4","fh5","fh7","fhc","fits","fpx","fst","g3","gif","heic","heics","heif","heifs","hej2","hsj2","ico","ief","jhc","jls","jng","jp2","jpe","jpeg","jpf","jpg","jpg2","jpgm","jph","jpm","jpx","jxl","jxr","jxra","jxrs","jxs","jxsc","jxsi","jxss","ktx","ktx2","mdi","mmr","npx","pbm","pct","pcx","pgm","pic","png","pnm","ppm","psd","pti","ras","rgb","rlc","sgi","sid","sub","svg","svgz","t38","tap","tfx","tga","tif","tiff","uvg","uvi","uvvg","uvvi","vtf","wbmp","wdp","webp","xbm","xif","xpm","xwd"];
@@ -1,2 +0,0 @@
-#[rustfmt::skip]
-pub const EXT_MESSAGE: [&str; 8] = ["disposition-notification","eml","mime","u8dsn","u8hdr","u8mdn","u8msg","wsc"];
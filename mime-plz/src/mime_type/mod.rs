@@ -1,18 +1,4 @@
-// Different mime types
-mod app;
-mod audio;
-mod font;
-mod image;
-mod message;
-mod model;
-mod text;
-mod video;
+// Extension map and deprecated EXT_ arrays generated by build.rs
+mod extension_map;
 
-pub use app::*;
-pub use audio::*;
-pub use font::*;
-pub use image::*;
-pub use message::*;
-pub use model::*;
-pub use text::*;
-pub use video::*;
+pub use extension_map::*;
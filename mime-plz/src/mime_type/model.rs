@@ -1,2 +0,0 @@
-#[rustfmt::skip]
-pub const EXT_MODEL: [&str; 39] = ["3mf","bary","bsp","cld","dae","dwf","gdl","glb","gltf","gtw","iges","igs","jt","mesh","msh","mtl","mts","ogex","pyo","pyox","silo","stpx","stpxz","stpz","u3d","usda","usdz","vds","vrml","vtu","wrl","x3d","x3db","x3dbz","x3dv","x3dvz","x3dz","x_b","x_t"];
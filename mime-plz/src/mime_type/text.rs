@@ -1,2 +0,0 @@
-#[rustfmt::skip]
-pub const EXT_TEXT: [&str; 98] = ["3dml","appcache","asm","c","cc","coffee","conf","cpp","css","csv","curl","cxx","dcurl","def","dic","dsc","etx","f","f77","f90","flx","fly","for","ged","gv","h","hbs","hh","htc","htm","html","ics","ifb","in","ini","jad","jade","java","jsx","less","list","litcoffee","log","lua","man","manifest","markdown","mcurl","md","mdx","me","mjs","mkd","mml","ms","n3","nfo","opml","p","pas","pde","roff","rtx","s","sass","scss","scurl","sfv","sgm","sgml","shex","shtml","slim","slm","spdx","spot","styl","stylus","t","text","tr","tsv","ttl","txt","uri","uris","urls","uu","vcard","vcf","vcs","vtt","wgsl","wml","wmls","yaml","yml","ymp"];
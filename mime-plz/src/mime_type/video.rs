@@ -1,2 +0,0 @@
-#[rustfmt::skip]
-pub const EXT_VIDEO: [&str; 59] = ["3g2","3gp","asf","asx","avi","dvb","f4v","fli","flv","fvt","h261","h263","h264","jpgv","m1v","m2t","m2ts","m2v","m4s","m4u","m4v","mj2","mjp2","mk3d","mks","mkv","mng","mov","movie","mp4","mp4v","mpe","mpeg","mpg","mxu","ogv","pyv","qt","smv","ts","uvh","uvm","uvp","uvs","uvu","uvv","uvvh","uvvm","uvvp","uvvs","uvvu","uvvv","viv","vob","webm","wm","wmv","wmx","wvx"];
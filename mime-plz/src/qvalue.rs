@@ -0,0 +1,106 @@
+// Weight of a member in a proactive negotiation field
+
+use std::fmt::{self, Display};
+
+/* qvalue in thousandths, 0 is not acceptable.
+ * https://www.rfc-editor.org/rfc/rfc9110.html#section-12.4.2
+ */
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct QValue(u16);
+
+impl QValue {
+    pub const ZERO: QValue = QValue(0);
+    pub const ONE: QValue = QValue(1000);
+
+    // None when above 1000
+    pub fn from_thousandths(value: u16) -> Option<Self> {
+        (value <= 1000).then_some(QValue(value))
+    }
+
+    pub fn as_thousandths(&self) -> u16 {
+        self.0
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+
+    // qvalue = ( "0" [ "." 0*3DIGIT ] ) / ( "1" [ "." 0*3("0") ] )
+    pub fn parse(value: &[u8]) -> Option<Self> {
+        let (int, frac) = match value.split_first()? {
+            (b'0', rest) => (0, rest),
+            (b'1', rest) => (1000, rest),
+            _ => return None,
+        };
+        let digits = match frac {
+            [] => &[][..],
+            [b'.', digits @ ..] if digits.len() <= 3 => digits,
+            _ => return None,
+        };
+        if !digits.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+        let frac = digits
+            .iter()
+            .chain([b'0'; 3].iter())
+            .take(3)
+            .fold(0, |acc, b| acc * 10 + (b - b'0') as u16);
+        QValue::from_thousandths(int + frac)
+    }
+}
+
+impl Default for QValue {
+    fn default() -> Self {
+        QValue::ONE
+    }
+}
+
+// shortest form, eg. 0.5
+impl Display for QValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            1000 => f.write_str("1"),
+            0 => f.write_str("0"),
+            value => {
+                let frac = format!("{value:03}");
+                write!(f, "0.{}", frac.trim_end_matches('0'))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn q(value: &str) -> Option<u16> {
+        QValue::parse(value.as_bytes()).map(|q| q.as_thousandths())
+    }
+
+    #[test]
+    fn test_qvalue_parse() {
+        assert_eq!(q("1"), Some(1000));
+        assert_eq!(q("1.000"), Some(1000));
+        assert_eq!(q("0"), Some(0));
+        assert_eq!(q("0.5"), Some(500));
+        assert_eq!(q("0.05"), Some(50));
+        assert_eq!(q("0.123"), Some(123));
+        assert_eq!(q("0."), Some(0));
+    }
+
+    #[test]
+    fn test_qvalue_parse_invalid() {
+        for invalid in ["", "1.001", "2", "0.1234", ".5", "0,5", "1.5", "-0"] {
+            assert_eq!(q(invalid), None, "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_qvalue_display() {
+        assert_eq!(QValue::ONE.to_string(), "1");
+        assert_eq!(QValue::ZERO.to_string(), "0");
+        assert_eq!(QValue(500).to_string(), "0.5");
+        assert_eq!(QValue(50).to_string(), "0.05");
+        assert_eq!(QValue(123).to_string(), "0.123");
+    }
+}
@@ -0,0 +1,169 @@
+// Audio and video signatures that are not byte patterns
+// https://mimesniff.spec.whatwg.org/#signature-for-mp4
+
+const EBML_MAGIC: &[u8] = b"\x1A\x45\xDF\xA3";
+const EBML_DOC_TYPE: &[u8] = b"\x42\x82";
+
+// ftyp box with a brand starting with "mp4"
+pub(crate) fn is_mp4(input: &[u8]) -> bool {
+    if input.len() < 12 {
+        return false;
+    }
+    let box_size =
+        u32::from_be_bytes([input[0], input[1], input[2], input[3]]) as usize;
+    if input.len() < box_size || !box_size.is_multiple_of(4) {
+        return false;
+    }
+    if &input[4..8] != b"ftyp" {
+        return false;
+    }
+    if &input[8..11] == b"mp4" {
+        return true;
+    }
+    // compatible brands, after the major brand and minor version
+    (16..box_size)
+        .step_by(4)
+        .any(|offset| &input[offset..offset + 3] == b"mp4")
+}
+
+// EBML header with a "webm" DocType in the first 38 bytes
+pub(crate) fn is_webm(input: &[u8]) -> bool {
+    if !input.starts_with(EBML_MAGIC) {
+        return false;
+    }
+    let mut iter = EBML_MAGIC.len();
+    while iter < input.len() && iter < 38 {
+        if input[iter..].starts_with(EBML_DOC_TYPE) {
+            iter += EBML_DOC_TYPE.len();
+            if iter >= input.len() {
+                break;
+            }
+            iter += vint_size(input[iter], input.len());
+            if iter + 4 >= input.len() {
+                break;
+            }
+            return match_padded(input, iter, b"webm");
+        }
+        iter += 1;
+    }
+    false
+}
+
+// length of an EBML variable size integer from its first byte
+fn vint_size(first: u8, len: usize) -> usize {
+    let mut mask = 0x80;
+    let mut size = 1;
+    while size < 8 && size < len {
+        if first & mask != 0 {
+            break;
+        }
+        mask >>= 1;
+        size += 1;
+    }
+    size
+}
+
+// pattern after any 0x00 bytes from offset
+fn match_padded(input: &[u8], offset: usize, pattern: &[u8]) -> bool {
+    let start = input[offset..]
+        .iter()
+        .position(|&b| b != 0)
+        .map_or(input.len(), |pos| offset + pos);
+    input[start..].starts_with(pattern)
+}
+
+// https://mimesniff.spec.whatwg.org/#signature-for-mp3-without-id3
+const MP3_RATES: [u32; 15] = [
+    0, 32000, 40000, 48000, 56000, 64000, 80000, 96000, 112000, 128000,
+    160000, 192000, 224000, 256000, 320000,
+];
+const MP25_RATES: [u32; 15] = [
+    0, 8000, 16000, 24000, 32000, 40000, 48000, 56000, 64000, 80000, 96000,
+    112000, 128000, 144000, 160000,
+];
+const SAMPLE_RATES: [u32; 3] = [44100, 48000, 32000];
+
+// two consecutive frame headers
+pub(crate) fn is_mp3_without_id3(input: &[u8]) -> bool {
+    if !is_mp3_header(input, 0) {
+        return false;
+    }
+    let skipped = mp3_frame_size(input, 0);
+    if skipped < 4 || skipped > input.len() {
+        return false;
+    }
+    is_mp3_header(input, skipped)
+}
+
+fn is_mp3_header(input: &[u8], offset: usize) -> bool {
+    let Some(header) = input.get(offset..offset + 4) else {
+        return false;
+    };
+    let layer = (header[1] & 0x06) >> 1;
+    let bit_rate = (header[2] & 0xF0) >> 4;
+    let sample_rate = (header[2] & 0x0C) >> 2;
+    header[0] == 0xFF
+        && header[1] & 0xE0 == 0xE0
+        && layer != 0
+        && bit_rate != 15
+        && sample_rate != 3
+}
+
+// header at offset is valid, see is_mp3_header
+fn mp3_frame_size(input: &[u8], offset: usize) -> usize {
+    let version = (input[offset + 1] & 0x18) >> 3;
+    let bit_rate_index = ((input[offset + 2] & 0xF0) >> 4) as usize;
+    let bit_rate = if version & 0x01 != 0 {
+        MP3_RATES[bit_rate_index]
+    } else {
+        MP25_RATES[bit_rate_index]
+    };
+    let sample_rate = SAMPLE_RATES[((input[offset + 2] & 0x0C) >> 2) as usize];
+    let padding = (input[offset + 2] & 0x02) >> 1;
+    let scale = if version == 1 {
+        72
+    } else {
+        144
+    };
+    (bit_rate * scale / sample_rate + padding as u32) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_mp4() {
+        let mut input = b"\0\0\0\x18ftypisom\0\0\x02\0isommp41".to_vec();
+        assert!(is_mp4(&input));
+        input[20] = b'x';
+        assert!(!is_mp4(&input));
+        assert!(is_mp4(b"\0\0\0\x0Cftypmp42"));
+        // box larger than the input
+        assert!(!is_mp4(b"\0\0\0\x20ftypmp42"));
+        assert!(!is_mp4(b"\0\0\0\x0Cftyp"));
+    }
+
+    #[test]
+    fn test_is_webm() {
+        let input = b"\x1A\x45\xDF\xA3\x9F\x42\x86\x81\x01\x42\x82\x84webm\
+                      \x42\x87\x81\x04";
+        assert!(is_webm(input));
+        let input = b"\x1A\x45\xDF\xA3\x9F\x42\x82\x88matroska\x42\x87";
+        assert!(!is_webm(input));
+        assert!(!is_webm(b"\x1A\x45\xDF"));
+    }
+
+    #[test]
+    fn test_is_mp3_without_id3() {
+        // mpeg 1 layer 3, 128 kbit/s, 44.1 kHz, 417 byte frames
+        let mut input = vec![0; 421];
+        input[..4].copy_from_slice(b"\xFF\xFB\x90\x64");
+        input[417..].copy_from_slice(b"\xFF\xFB\x90\x64");
+        assert!(is_mp3_without_id3(&input));
+        assert!(!is_mp3_without_id3(&input[..420]));
+        // free bit rate
+        input[2] = 0x00;
+        assert!(!is_mp3_without_id3(&input));
+    }
+}
@@ -0,0 +1,310 @@
+// MIME type sniffing
+// https://mimesniff.spec.whatwg.org/
+
+use crate::{ContentType, MediaType};
+
+mod av;
+mod pattern;
+
+use av::{is_mp3_without_id3, is_mp4, is_webm};
+use pattern::*;
+
+// bytes of the body that are sniffed
+pub const RESOURCE_HEADER_LEN: usize = 1445;
+
+/* Computed media type of a body with the supplied Content-Type, in a
+ * browsing context.
+ *
+ * Steps:
+ *      1. No supplied or an unknown type, identify the type from the body.
+ *         Scriptable types, eg. html, only when no_sniff is false.
+ *      2. no_sniff (X-Content-Type-Options: nosniff), the supplied type.
+ *      3. text/plain as sent by old Apache versions, text or binary.
+ *      4. xml and html types are kept, feeds are not detected.
+ *      5. image, audio and video types are replaced by the type matching
+ *         the body, if any.
+ *
+ * https://mimesniff.spec.whatwg.org/#determining-the-computed-mime-type-of-a-resource
+ */
+pub fn sniff(
+    body: &[u8],
+    supplied: Option<&MediaType>,
+    no_sniff: bool,
+) -> MediaType {
+    let header = &body[..body.len().min(RESOURCE_HEADER_LEN)];
+    let Some(supplied) = supplied.filter(|supplied| !is_unknown(supplied))
+    else {
+        return identify_unknown(header, !no_sniff);
+    };
+    if no_sniff {
+        return supplied.clone();
+    }
+    if is_apache_text_plain(supplied) {
+        return text_or_binary(header, supplied);
+    }
+    let matched = match supplied.content_type() {
+        ContentType::Image => match_image(header),
+        ContentType::Audio | ContentType::Video => match_audio_video(header),
+        _ => None,
+    };
+    matched.unwrap_or_else(|| supplied.clone())
+}
+
+/* Type of a body without a usable Content-Type. html, xml and pdf are only
+ * detected when sniff_scriptable is true.
+ *
+ * https://mimesniff.spec.whatwg.org/#rules-for-identifying-an-unknown-mime-type
+ */
+pub fn identify_unknown(body: &[u8], sniff_scriptable: bool) -> MediaType {
+    let header = &body[..body.len().min(RESOURCE_HEADER_LEN)];
+    if sniff_scriptable {
+        if match_html(header) {
+            return essence_to_media_type("text/html");
+        }
+        if let Some(essence) = match_patterns(&SCRIPTABLE_PATTERNS, header) {
+            return essence_to_media_type(essence);
+        }
+    }
+    match_patterns(&NON_SCRIPTABLE_PATTERNS, header)
+        .map(essence_to_media_type)
+        .or_else(|| match_image(header))
+        .or_else(|| match_audio_video(header))
+        .or_else(|| match_archive(header))
+        .unwrap_or_else(|| {
+            if has_binary_data(header) {
+                essence_to_media_type("application/octet-stream")
+            } else {
+                essence_to_media_type("text/plain")
+            }
+        })
+}
+
+pub fn match_image(body: &[u8]) -> Option<MediaType> {
+    match_patterns(&IMAGE_PATTERNS, body).map(essence_to_media_type)
+}
+
+pub fn match_audio_video(body: &[u8]) -> Option<MediaType> {
+    let essence =
+        match_patterns(&AUDIO_VIDEO_PATTERNS, body).or_else(|| {
+            if is_mp4(body) {
+                Some("video/mp4")
+            } else if is_webm(body) {
+                Some("video/webm")
+            } else if is_mp3_without_id3(body) {
+                Some("audio/mpeg")
+            } else {
+                None
+            }
+        })?;
+    Some(essence_to_media_type(essence))
+}
+
+pub fn match_font(body: &[u8]) -> Option<MediaType> {
+    match_patterns(&FONT_PATTERNS, body).map(essence_to_media_type)
+}
+
+pub fn match_archive(body: &[u8]) -> Option<MediaType> {
+    match_patterns(&ARCHIVE_PATTERNS, body).map(essence_to_media_type)
+}
+
+/* Binary data byte, a control character that is not whitespace or ESC.
+ * https://mimesniff.spec.whatwg.org/#binary-data-byte
+ */
+pub fn is_binary_data_byte(byte: u8) -> bool {
+    matches!(byte, 0x00..=0x08 | 0x0B | 0x0E..=0x1A | 0x1C..=0x1F)
+}
+
+fn has_binary_data(header: &[u8]) -> bool {
+    header.iter().any(|&b| is_binary_data_byte(b))
+}
+
+/* Supplied text/plain is kept when the body starts with a bom or has no
+ * binary data, otherwise the body is identified without scriptable types.
+ *
+ * https://mimesniff.spec.whatwg.org/#rules-for-text-or-binary
+ */
+fn text_or_binary(header: &[u8], supplied: &MediaType) -> MediaType {
+    if header.starts_with(b"\xFE\xFF")
+        || header.starts_with(b"\xFF\xFE")
+        || header.starts_with(b"\xEF\xBB\xBF")
+        || !has_binary_data(header)
+    {
+        return supplied.clone();
+    }
+    identify_unknown(header, false)
+}
+
+// unknown/unknown, application/unknown or */*
+fn is_unknown(media_type: &MediaType) -> bool {
+    matches!(
+        media_type.essence().as_str(),
+        "unknown/unknown" | "application/unknown" | "*/*"
+    )
+}
+
+/* Exactly text/plain, optionally with charset ISO-8859-1, iso-8859-1 or
+ * UTF-8, as the values are compared case-sensitive.
+ */
+fn is_apache_text_plain(media_type: &MediaType) -> bool {
+    if media_type.main_type() != "text"
+        || media_type.subtype() != "plain"
+        || media_type.suffix().is_some()
+    {
+        return false;
+    }
+    match media_type.params() {
+        [] => true,
+        [(name, value)] => {
+            name == "charset"
+                && matches!(
+                    value.as_str(),
+                    "ISO-8859-1" | "iso-8859-1" | "UTF-8"
+                )
+        }
+        _ => false,
+    }
+}
+
+// essence is a valid type/subtype
+fn essence_to_media_type(essence: &str) -> MediaType {
+    let (main_type, subtype) = essence.split_once('/').unwrap_or_default();
+    MediaType::new(main_type, subtype)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PNG: &[u8] = b"\x89PNG\r\n\x1A\n\0\0\0\rIHDR";
+    const GIF: &[u8] = b"GIF89a\x01\0\x01\0\x80\0\0";
+
+    fn essence(media_type: MediaType) -> String {
+        media_type.essence()
+    }
+
+    fn supplied(value: &str) -> MediaType {
+        MediaType::parse(value).unwrap()
+    }
+
+    #[test]
+    fn test_identify_unknown_scriptable() {
+        let html = b"  <!DOCTYPE html><html></html>";
+        assert_eq!(essence(identify_unknown(html, true)), "text/html");
+        // not scriptable, no binary data
+        assert_eq!(essence(identify_unknown(html, false)), "text/plain");
+        assert_eq!(
+            essence(identify_unknown(b"<?xml version=\"1.0\"?><a/>", true)),
+            "text/xml"
+        );
+        assert_eq!(
+            essence(identify_unknown(b"%PDF-1.7\n%\xE2\xE3", true)),
+            "application/pdf"
+        );
+    }
+
+    #[test]
+    fn test_identify_unknown_binary() {
+        assert_eq!(essence(identify_unknown(PNG, true)), "image/png");
+        assert_eq!(
+            essence(identify_unknown(b"\x1F\x8B\x08\0\0\0\0\0", true)),
+            "application/x-gzip"
+        );
+        assert_eq!(
+            essence(identify_unknown(b"PK\x03\x04\x14\0", true)),
+            "application/zip"
+        );
+        assert_eq!(
+            essence(identify_unknown(b"OggS\0\x02", true)),
+            "application/ogg"
+        );
+        assert_eq!(
+            essence(identify_unknown(b"\x01\x02\x03", true)),
+            "application/octet-stream"
+        );
+        assert_eq!(
+            essence(identify_unknown(b"plain text\r\n\x1B[0m", true)),
+            "text/plain"
+        );
+        assert_eq!(
+            essence(identify_unknown(b"\xEF\xBB\xBFa\0", true)),
+            "text/plain"
+        );
+        assert_eq!(essence(identify_unknown(b"", true)), "text/plain");
+    }
+
+    #[test]
+    fn test_match_font() {
+        assert_eq!(
+            essence(match_font(b"wOF2\0\x01\0\0").unwrap()),
+            "font/woff2"
+        );
+        assert_eq!(
+            essence(match_font(b"\0\x01\0\0\0\x0F").unwrap()),
+            "font/ttf"
+        );
+        let mut eot = [0x01; 40];
+        eot[34..36].copy_from_slice(b"LP");
+        assert_eq!(
+            essence(match_font(&eot).unwrap()),
+            "application/vnd.ms-fontobject"
+        );
+        assert!(match_font(b"OTT").is_none());
+    }
+
+    #[test]
+    fn test_sniff_no_supplied() {
+        assert_eq!(essence(sniff(GIF, None, false)), "image/gif");
+        let unknown = supplied("application/unknown");
+        assert_eq!(
+            essence(sniff(b"<p>a</p>", Some(&unknown), false)),
+            "text/html"
+        );
+        // nosniff never gives a scriptable type
+        assert_eq!(essence(sniff(b"<p>a</p>", None, true)), "text/plain");
+    }
+
+    #[test]
+    fn test_sniff_no_sniff_keeps_supplied() {
+        let json = supplied("application/json");
+        assert_eq!(sniff(PNG, Some(&json), true), json);
+        assert_eq!(sniff(PNG, Some(&json), false), json);
+    }
+
+    #[test]
+    fn test_sniff_apache_text_plain() {
+        let text = supplied("text/plain; charset=ISO-8859-1");
+        assert_eq!(essence(sniff(PNG, Some(&text), false)), "image/png");
+        assert_eq!(sniff(b"hello", Some(&text), false), text);
+        assert_eq!(
+            essence(sniff(b"\0\x01\x02", Some(&text), false)),
+            "application/octet-stream"
+        );
+        // html is not sniffed from text
+        assert_eq!(sniff(b"<html>", Some(&text), false), text);
+        // other charsets are trusted
+        let text = supplied("text/plain; charset=windows-1252");
+        assert_eq!(sniff(PNG, Some(&text), false), text);
+    }
+
+    #[test]
+    fn test_sniff_image_and_video() {
+        let jpeg = supplied("image/jpeg");
+        let sniffed = sniff(PNG, Some(&jpeg), false);
+        assert_eq!(essence(sniffed.clone()), "image/png");
+        assert_eq!(sniffed.content_type(), ContentType::Image);
+        // no match, supplied kept
+        assert_eq!(sniff(b"garbage", Some(&jpeg), false), jpeg);
+        let mp4 = b"\0\0\0\x0Cftypmp42";
+        let video = supplied("video/quicktime");
+        assert_eq!(essence(sniff(mp4, Some(&video), false)), "video/mp4");
+        let html = supplied("text/html");
+        assert_eq!(sniff(PNG, Some(&html), false), html);
+    }
+
+    #[test]
+    fn test_sniff_resource_header_only() {
+        let mut body = vec![b'a'; RESOURCE_HEADER_LEN];
+        body.push(0);
+        assert_eq!(essence(sniff(&body, None, false)), "text/plain");
+    }
+}
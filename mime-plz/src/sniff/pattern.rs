@@ -0,0 +1,215 @@
+// Byte patterns
+// https://mimesniff.spec.whatwg.org/#matching-a-mime-type-pattern
+
+/* Pattern with an optional mask, None is all 0xFF. Leading bytes in
+ * ignored are skipped before matching.
+ */
+pub(crate) struct Pattern {
+    bytes: &'static [u8],
+    mask: Option<&'static [u8]>,
+    ignored: &'static [u8],
+    pub(crate) essence: &'static str,
+}
+
+impl Pattern {
+    const fn new(bytes: &'static [u8], essence: &'static str) -> Self {
+        Pattern {
+            bytes,
+            mask: None,
+            ignored: &[],
+            essence,
+        }
+    }
+
+    const fn masked(
+        bytes: &'static [u8],
+        mask: &'static [u8],
+        essence: &'static str,
+    ) -> Self {
+        Pattern {
+            bytes,
+            mask: Some(mask),
+            ignored: &[],
+            essence,
+        }
+    }
+
+    const fn skip_whitespace(mut self) -> Self {
+        self.ignored = WHITESPACE;
+        self
+    }
+
+    pub(crate) fn matches(&self, input: &[u8]) -> bool {
+        if input.len() < self.bytes.len() {
+            return false;
+        }
+        let start = input
+            .iter()
+            .position(|b| !self.ignored.contains(b))
+            .unwrap_or(input.len());
+        let Some(input) = input.get(start..start + self.bytes.len()) else {
+            return false;
+        };
+        input.iter().enumerate().all(|(index, byte)| {
+            let mask = self.mask.map_or(0xFF, |mask| mask[index]);
+            byte & mask == self.bytes[index]
+        })
+    }
+}
+
+// first match in patterns
+pub(crate) fn match_patterns(
+    patterns: &[Pattern],
+    input: &[u8],
+) -> Option<&'static str> {
+    patterns
+        .iter()
+        .find(|pattern| pattern.matches(input))
+        .map(|pattern| pattern.essence)
+}
+
+const WHITESPACE: &[u8] = b"\t\n\x0C\r ";
+
+/* Tags that identify html, case-insensitive after leading whitespace and
+ * followed by a tag-terminating byte, ie. space or ">".
+ */
+const HTML_TAGS: [&[u8]; 17] = [
+    b"<!DOCTYPE HTML",
+    b"<HTML",
+    b"<HEAD",
+    b"<SCRIPT",
+    b"<IFRAME",
+    b"<H1",
+    b"<DIV",
+    b"<FONT",
+    b"<TABLE",
+    b"<A",
+    b"<STYLE",
+    b"<TITLE",
+    b"<B",
+    b"<BODY",
+    b"<BR",
+    b"<P",
+    b"<!--",
+];
+
+pub(crate) fn match_html(input: &[u8]) -> bool {
+    let start = input
+        .iter()
+        .position(|b| !WHITESPACE.contains(b))
+        .unwrap_or(input.len());
+    let input = &input[start..];
+    HTML_TAGS.iter().any(|tag| {
+        input.len() > tag.len()
+            && input[..tag.len()].eq_ignore_ascii_case(tag)
+            && matches!(input[tag.len()], b' ' | b'>')
+    })
+}
+
+// scriptable types other than html
+pub(crate) const SCRIPTABLE_PATTERNS: [Pattern; 2] = [
+    Pattern::new(b"<?xml", "text/xml").skip_whitespace(),
+    Pattern::new(b"%PDF-", "application/pdf"),
+];
+
+pub(crate) const NON_SCRIPTABLE_PATTERNS: [Pattern; 4] = [
+    Pattern::new(b"%!PS-Adobe-", "application/postscript"),
+    // utf-16be, utf-16le and utf-8 bom
+    Pattern::masked(b"\xFE\xFF\0\0", b"\xFF\xFF\0\0", "text/plain"),
+    Pattern::masked(b"\xFF\xFE\0\0", b"\xFF\xFF\0\0", "text/plain"),
+    Pattern::masked(b"\xEF\xBB\xBF\0", b"\xFF\xFF\xFF\0", "text/plain"),
+];
+
+// https://mimesniff.spec.whatwg.org/#matching-an-image-type-pattern
+pub(crate) const IMAGE_PATTERNS: [Pattern; 8] = [
+    Pattern::new(b"\0\0\x01\0", "image/x-icon"),
+    Pattern::new(b"\0\0\x02\0", "image/x-icon"),
+    Pattern::new(b"BM", "image/bmp"),
+    Pattern::new(b"GIF87a", "image/gif"),
+    Pattern::new(b"GIF89a", "image/gif"),
+    Pattern::masked(
+        b"RIFF\0\0\0\0WEBPVP",
+        b"\xFF\xFF\xFF\xFF\0\0\0\0\xFF\xFF\xFF\xFF\xFF\xFF",
+        "image/webp",
+    ),
+    Pattern::new(b"\x89PNG\r\n\x1A\n", "image/png"),
+    Pattern::new(b"\xFF\xD8\xFF", "image/jpeg"),
+];
+
+// https://mimesniff.spec.whatwg.org/#matching-an-audio-or-video-type-pattern
+pub(crate) const AUDIO_VIDEO_PATTERNS: [Pattern; 7] = [
+    Pattern::new(b".snd", "audio/basic"),
+    Pattern::masked(
+        b"FORM\0\0\0\0AIFF",
+        b"\xFF\xFF\xFF\xFF\0\0\0\0\xFF\xFF\xFF\xFF",
+        "audio/aiff",
+    ),
+    Pattern::new(b"ID3", "audio/mpeg"),
+    Pattern::new(b"OggS\0", "application/ogg"),
+    Pattern::new(b"MThd\0\0\0\x06", "audio/midi"),
+    Pattern::masked(
+        b"RIFF\0\0\0\0AVI ",
+        b"\xFF\xFF\xFF\xFF\0\0\0\0\xFF\xFF\xFF\xFF",
+        "video/avi",
+    ),
+    Pattern::masked(
+        b"RIFF\0\0\0\0WAVE",
+        b"\xFF\xFF\xFF\xFF\0\0\0\0\xFF\xFF\xFF\xFF",
+        "audio/wave",
+    ),
+];
+
+// https://mimesniff.spec.whatwg.org/#matching-a-font-type-pattern
+pub(crate) const FONT_PATTERNS: [Pattern; 6] = [
+    // embedded opentype, "LP" at offset 34
+    Pattern::masked(
+        b"\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0LP",
+        b"\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\xFF\xFF",
+        "application/vnd.ms-fontobject",
+    ),
+    Pattern::new(b"\0\x01\0\0", "font/ttf"),
+    Pattern::new(b"OTTO", "font/otf"),
+    Pattern::new(b"ttcf", "font/collection"),
+    Pattern::new(b"wOFF", "font/woff"),
+    Pattern::new(b"wOF2", "font/woff2"),
+];
+
+// https://mimesniff.spec.whatwg.org/#matching-an-archive-type-pattern
+pub(crate) const ARCHIVE_PATTERNS: [Pattern; 3] = [
+    Pattern::new(b"\x1F\x8B\x08", "application/x-gzip"),
+    Pattern::new(b"PK\x03\x04", "application/zip"),
+    Pattern::new(b"Rar!\x1A\x07\0", "application/x-rar-compressed"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pattern_mask() {
+        let webp = &IMAGE_PATTERNS[5];
+        assert!(webp.matches(b"RIFF\x10\x20\x30\x40WEBPVP8 "));
+        assert!(!webp.matches(b"RIFF\x10\x20\x30\x40WEBP"));
+        assert!(!webp.matches(b"RIFX\x10\x20\x30\x40WEBPVP8 "));
+    }
+
+    #[test]
+    fn test_pattern_skip_whitespace() {
+        let xml = &SCRIPTABLE_PATTERNS[0];
+        assert!(xml.matches(b" \r\n\t<?xml version=\"1.0\"?>"));
+        assert!(!xml.matches(b"a<?xml"));
+        assert!(!xml.matches(b"   <?x"));
+        assert!(!SCRIPTABLE_PATTERNS[1].matches(b" %PDF-1.7"));
+    }
+
+    #[test]
+    fn test_match_html() {
+        assert!(match_html(b"<!doctype html>"));
+        assert!(match_html(b"\n  <HtMl lang=\"en\">"));
+        assert!(match_html(b"<p>text"));
+        assert!(match_html(b"<!-- comment -->"));
+        assert!(!match_html(b"<pre>"));
+        assert!(!match_html(b"<html"));
+        assert!(!match_html(b"text <html>"));
+    }
+}